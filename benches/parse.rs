@@ -0,0 +1,46 @@
+//! 文件名解析吞吐基准
+//!
+//! 以 `cargo bench --bench parse` 运行，criterion 输出每个样本集单次解析的耗时与吞吐。
+
+use anime_organizer::parser::FilenameParser;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::hint::black_box;
+
+/// 常见发布组命名，全部可以解析
+const RELEASE_NAMES: &[&str] = &[
+    "[ANi] 妖怪旅館營業中 貳 - 07 [1080P][Baha][WEB-DL][AAC AVC][CHT].mp4",
+    "[ANi] Test - 1 [1080P][Baha][WEB-DL][AAC AVC][CHT].mp4",
+    "[Group] Anime - 01+02 [1080p].mkv",
+    "[ANi] Test - 12.5 [1080P].MKV",
+    "[ANi] Title - S02E03 [1080P].mp4",
+    "[ANi] 魯邦三世 不死身的血族 - 電影 [1080P][Baha].mp4",
+    "[Sub] 测试 第十二話 [1080P].mkv",
+    "[dmhy] Test Show [05][1080P].mp4",
+    "[Sub] Test Show (2024) - 05 (1080p) x264.mkv",
+    "[LoliHouse] Test Show - 11 [WebRip 1080p HEVC-10bit AAC ASSx2].mkv",
+];
+
+/// 真实下载目录中的文件名，含部分无法解析的命名
+const CORPUS: &str = include_str!("../tests/data/final_test.txt");
+
+fn parse(criterion: &mut Criterion) {
+    let corpus = CORPUS
+        .lines()
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+    let mut group = criterion.benchmark_group("parse");
+    for (label, names) in [("release", RELEASE_NAMES), ("corpus", corpus.as_slice())] {
+        group.throughput(Throughput::Elements(names.len() as u64));
+        group.bench_function(label, |bencher| {
+            bencher.iter(|| {
+                for name in names {
+                    black_box(FilenameParser::parse(black_box(name)));
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
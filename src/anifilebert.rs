@@ -3,7 +3,7 @@
 //! The rule parser remains the default. This module is loaded only when the
 //! `anifilebert` feature is enabled and the CLI asks for it.
 
-use crate::parser::{split_series_and_season, AnimeFileInfo};
+use crate::parser::{split_alt_titles, split_series_and_season, AnimeFileInfo};
 use flate2::read::GzDecoder;
 use ort::{
     ep::ExecutionProviderDispatch,
@@ -401,13 +401,16 @@ fn info_from_entities(
         .filter(|value| !value.is_empty())
         .unwrap_or_else(|| tag_entities(entities));
 
+    let (anime_name, alt_titles) = split_alt_titles(&title);
     Some(AnimeFileInfo {
         publisher,
-        anime_name: title,
+        anime_name,
         episode,
         tags,
         extension,
         original_path: path.to_string_lossy().to_string(),
+        alt_titles,
+        is_final: false,
     })
 }
 
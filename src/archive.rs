@@ -0,0 +1,172 @@
+//! 压缩包解压模块（需 `archives` feature）
+//!
+//! 识别源目录中的 `.zip` 压缩包，只解压其中符合扩展名过滤的视频文件，
+//! 供整理流程按普通文件继续解析。`.rar` 暂不支持，会被识别后跳过。
+//!
+//! # 示例
+//!
+//! ```no_run
+//! use anime_organizer::archive::{extract_videos, is_archive};
+//! use std::collections::HashSet;
+//! use std::path::Path;
+//!
+//! let extensions: HashSet<String> = [".mkv".to_string()].into_iter().collect();
+//! let archive = Path::new("/downloads/pack.zip");
+//! if is_archive(archive) {
+//!     let videos = extract_videos(archive, Path::new("/tmp/extract"), &extensions)?;
+//! }
+//! # Ok::<(), anime_organizer::error::AppError>(())
+//! ```
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{AppError, Result};
+use crate::scan::has_extension;
+
+/// 可识别的压缩包扩展名
+pub const ARCHIVE_EXTENSIONS: &[&str] = &[".zip", ".rar"];
+
+/// 判断路径是否为可识别的压缩包。
+#[must_use]
+pub fn is_archive(path: &Path) -> bool {
+    let extensions = ARCHIVE_EXTENSIONS
+        .iter()
+        .map(|extension| (*extension).to_string())
+        .collect();
+    has_extension(path, &extensions)
+}
+
+/// 将压缩包中扩展名位于 `extensions` 的文件解压到 `destination/{压缩包名}/`，返回解压出的文件。
+///
+/// 压缩包内的目录层级会被丢弃，只保留文件名；同名文件以后出现的为准。
+///
+/// # 错误
+///
+/// - `AppError::FileOperation` - 压缩包格式不支持（如 RAR）、读取或解压失败
+pub fn extract_videos(
+    archive: &Path,
+    destination: &Path,
+    extensions: &HashSet<String>,
+) -> Result<Vec<PathBuf>> {
+    let archive_error = |message: String| AppError::FileOperation {
+        path: archive.to_path_buf(),
+        message,
+    };
+    let is_zip = archive
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("zip"));
+    if !is_zip {
+        return Err(archive_error("暂不支持该压缩格式，仅支持 ZIP".to_string()));
+    }
+
+    let file = fs::File::open(archive)?;
+    let mut zip =
+        zip::ZipArchive::new(file).map_err(|e| archive_error(format!("读取 ZIP 失败: {e}")))?;
+    let output_dir = destination.join(archive.file_stem().unwrap_or_default());
+    let mut extracted = Vec::new();
+
+    for index in 0..zip.len() {
+        let mut entry = zip
+            .by_index(index)
+            .map_err(|e| archive_error(format!("读取 ZIP 条目失败: {e}")))?;
+        if !entry.is_file() {
+            continue;
+        }
+        // enclosed_name 拒绝绝对路径与 `..`，防止写出目标目录。
+        let Some(file_name) = entry
+            .enclosed_name()
+            .and_then(|name| name.file_name().map(PathBuf::from))
+        else {
+            continue;
+        };
+        if !has_extension(&file_name, extensions) {
+            continue;
+        }
+
+        fs::create_dir_all(&output_dir)?;
+        let output_path = output_dir.join(file_name);
+        let mut output = fs::File::create(&output_path)?;
+        std::io::copy(&mut entry, &mut output)?;
+        if !extracted.contains(&output_path) {
+            extracted.push(output_path);
+        }
+    }
+
+    Ok(extracted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn write_zip(path: &Path, entries: &[(&str, &[u8])]) {
+        let mut writer = zip::ZipWriter::new(fs::File::create(path).unwrap());
+        for (name, content) in entries {
+            writer
+                .start_file(*name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(content).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    fn video_extensions() -> HashSet<String> {
+        [".mkv".to_string(), ".mp4".to_string()]
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn recognizes_archive_extensions() {
+        assert!(is_archive(Path::new("pack.zip")));
+        assert!(is_archive(Path::new("pack.RAR")));
+        assert!(!is_archive(Path::new("video.mkv")));
+    }
+
+    #[test]
+    fn extracts_only_video_entries() {
+        let directory = tempfile::tempdir().unwrap();
+        let archive = directory.path().join("pack.zip");
+        write_zip(
+            &archive,
+            &[
+                ("nested/[ANi] Test - 01 [1080P].mkv", b"video"),
+                ("readme.txt", b"text"),
+            ],
+        );
+        let destination = directory.path().join("out");
+
+        let files = extract_videos(&archive, &destination, &video_extensions()).unwrap();
+
+        let expected = destination.join("pack").join("[ANi] Test - 01 [1080P].mkv");
+        assert_eq!(files, vec![expected.clone()]);
+        assert_eq!(fs::read(expected).unwrap(), b"video");
+        assert!(!destination.join("pack").join("readme.txt").exists());
+    }
+
+    #[test]
+    fn rejects_path_traversal_entries() {
+        let directory = tempfile::tempdir().unwrap();
+        let archive = directory.path().join("evil.zip");
+        write_zip(&archive, &[("../escape.mkv", b"video")]);
+        let destination = directory.path().join("out");
+
+        let files = extract_videos(&archive, &destination, &video_extensions()).unwrap();
+
+        assert!(files.is_empty());
+        assert!(!directory.path().join("escape.mkv").exists());
+    }
+
+    #[test]
+    fn rar_is_reported_as_unsupported() {
+        let directory = tempfile::tempdir().unwrap();
+        let archive = directory.path().join("pack.rar");
+        fs::write(&archive, b"Rar!").unwrap();
+
+        assert!(extract_videos(&archive, directory.path(), &video_extensions()).is_err());
+    }
+}
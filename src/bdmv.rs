@@ -0,0 +1,231 @@
+//! 蓝光原盘（BDMV）目录模块
+//!
+//! 蓝光原盘以目录结构保存：
+//!
+//! ```text
+//! [VCB-Studio] 妖精的尾巴 [BDMV]/
+//! └── BDMV/
+//!     ├── index.bdmv
+//!     └── STREAM/
+//!         ├── 00000.m2ts
+//!         └── 00001.m2ts
+//! ```
+//!
+//! 其中的 `m2ts` 逐个解析没有意义，`--bdmv` 时改为把包含 `BDMV/` 的整个原盘目录作为一个单位，
+//! 按目录名中的番名整理到 `目标/番名/原盘目录名/`。移动、复制与硬链接都作用于整个目录树。
+//!
+//! # 示例
+//!
+//! ```
+//! use anime_organizer::bdmv::disc_anime_name;
+//!
+//! assert_eq!(
+//!     disc_anime_name("[VCB-Studio] 妖精的尾巴 [Ma10p_1080p]").as_deref(),
+//!     Some("妖精的尾巴")
+//! );
+//! assert_eq!(disc_anime_name("Anime Vol.2").as_deref(), Some("Anime"));
+//! ```
+
+use crate::error::Result;
+use crate::filesystem::same_device;
+use crate::organizer::{ExecutedOp, OperationMode, OperationOutcome, SkipReason};
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use walkdir::WalkDir;
+
+/// 原盘目录中的 BDMV 子目录名
+pub const BDMV_DIR: &str = "BDMV";
+
+/// 原盘目录名末尾的卷号：`Vol.1`、`Vol 2`、`Disc 3`、`Disk1`
+static VOLUME_SUFFIX_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)[\s_-]*(?:vol|disc|disk)\.?\s*\d{1,2}$").expect("卷号正则表达式编译失败")
+});
+
+/// `dir` 是否为蓝光原盘根目录：包含 `BDMV/` 子目录，且其中有 `index.bdmv` 或 `STREAM/`。
+#[must_use]
+pub fn is_disc_root(dir: &Path) -> bool {
+    let bdmv = dir.join(BDMV_DIR);
+    bdmv.join("index.bdmv").is_file() || bdmv.join("STREAM").is_dir()
+}
+
+/// 递归查找 `root` 下的蓝光原盘根目录（含 `root` 自身），按路径排序；
+/// 原盘目录内部不再继续查找。
+#[must_use]
+pub fn find_disc_roots(root: &Path) -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+    let mut walker = WalkDir::new(root).into_iter();
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if entry.file_type().is_dir() && is_disc_root(entry.path()) {
+            roots.push(entry.into_path());
+            walker.skip_current_dir();
+        }
+    }
+    roots.sort();
+    roots
+}
+
+/// 从原盘目录名中取出番名：去掉开头的 `[发布组]`、第一个括号起的标签与末尾的卷号；
+/// 剩余为空时返回 `None`。
+#[must_use]
+pub fn disc_anime_name(dir_name: &str) -> Option<String> {
+    let mut name = dir_name.trim();
+    while let Some(rest) = name.strip_prefix('[') {
+        name = rest
+            .split_once(']')
+            .map_or("", |(_, after)| after)
+            .trim_start();
+    }
+    let name = name
+        .find(['[', '(', '（', '【'])
+        .map_or(name, |index| &name[..index]);
+    let name = VOLUME_SUFFIX_REGEX.replace(name.trim(), "");
+    let name = name.trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// 原盘目录整理到的位置：`target/番名/原盘目录名`；无法取出番名时返回 `None`。
+#[must_use]
+pub fn disc_target(disc_root: &Path, target: &Path) -> Option<PathBuf> {
+    let dir_name = disc_root.file_name()?.to_str()?;
+    Some(target.join(disc_anime_name(dir_name)?).join(dir_name))
+}
+
+/// 按 `mode` 把整个原盘目录 `source` 整理到 `target`（`target` 为原盘目录自身的新位置）。
+///
+/// 目标已存在时跳过；复制先写入同级的临时目录再改名，失败时清理临时目录。
+/// move 无法直接改名（如跨设备）时改为复制后删除源目录；`auto` 同盘时硬链接、跨盘时复制。
+///
+/// # 错误
+///
+/// 创建目录、复制、硬链接或改名失败时返回 `AppError::Io`。
+pub fn organize_disc(
+    source: &Path,
+    target: &Path,
+    mode: OperationMode,
+) -> Result<OperationOutcome> {
+    if target.exists() {
+        return Ok(OperationOutcome::Skipped(SkipReason::AlreadyExists));
+    }
+    let parent = target.parent().unwrap_or(target);
+    fs::create_dir_all(parent)?;
+    let mode = mode.resolve(same_device(source, parent));
+    match mode {
+        OperationMode::Move => {
+            if fs::rename(source, target).is_err() {
+                transfer_via_temp(source, target, OperationMode::Copy)?;
+                fs::remove_dir_all(source)?;
+            }
+        }
+        mode => transfer_via_temp(source, target, mode)?,
+    }
+    Ok(OperationOutcome::Performed(match mode {
+        OperationMode::Move => ExecutedOp::Moved,
+        OperationMode::Copy => ExecutedOp::Copied,
+        OperationMode::Link | OperationMode::Auto => ExecutedOp::Linked,
+    }))
+}
+
+/// 复制或硬链接整个目录树到同级临时目录，完成后改名为 `target`。
+fn transfer_via_temp(source: &Path, target: &Path, mode: OperationMode) -> Result<()> {
+    let temp = crate::cleanup::temp_file_path(target);
+    let result = transfer_tree(source, &temp, mode).and_then(|()| Ok(fs::rename(&temp, target)?));
+    if result.is_err() {
+        let _ = fs::remove_dir_all(&temp);
+    }
+    result
+}
+
+fn transfer_tree(source: &Path, target: &Path, mode: OperationMode) -> Result<()> {
+    for entry in WalkDir::new(source) {
+        let entry = entry.map_err(std::io::Error::from)?;
+        let relative = entry
+            .path()
+            .strip_prefix(source)
+            .expect("WalkDir 返回的路径位于根目录之下");
+        let destination = target.join(relative);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&destination)?;
+        } else if mode == OperationMode::Copy {
+            fs::copy(entry.path(), &destination)?;
+        } else {
+            fs::hard_link(entry.path(), &destination)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 在 `parent` 下创建带两个 m2ts 的原盘目录
+    fn write_disc(parent: &Path, name: &str) -> PathBuf {
+        let disc = parent.join(name);
+        let stream = disc.join(BDMV_DIR).join("STREAM");
+        fs::create_dir_all(&stream).unwrap();
+        fs::write(disc.join(BDMV_DIR).join("index.bdmv"), b"index").unwrap();
+        fs::write(stream.join("00000.m2ts"), b"stream 0").unwrap();
+        fs::write(stream.join("00001.m2ts"), b"stream 1").unwrap();
+        disc
+    }
+
+    #[test]
+    fn finds_disc_roots_without_descending() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = write_disc(dir.path(), "[Group] Anime [BDMV]");
+        let second = write_disc(&dir.path().join("nested"), "Other Vol.1");
+        fs::create_dir_all(dir.path().join("plain").join("BDMV")).unwrap();
+
+        assert_eq!(find_disc_roots(dir.path()), vec![first, second]);
+    }
+
+    #[test]
+    fn disc_names_strip_group_tags_and_volume() {
+        assert_eq!(
+            disc_anime_name("[Group][Sub] Anime Name (2019) [BDMV]").as_deref(),
+            Some("Anime Name")
+        );
+        assert_eq!(disc_anime_name("Anime Disc 3").as_deref(), Some("Anime"));
+        assert_eq!(disc_anime_name("Anime_Vol2").as_deref(), Some("Anime"));
+        assert_eq!(disc_anime_name("[BDMV]"), None);
+    }
+
+    #[test]
+    fn copy_and_link_transfer_the_whole_tree() {
+        for mode in [OperationMode::Copy, OperationMode::Link] {
+            let dir = tempfile::tempdir().unwrap();
+            let disc = write_disc(dir.path(), "[Group] Anime [BDMV]");
+            let target = disc_target(&disc, &dir.path().join("library")).unwrap();
+            assert!(target.ends_with("Anime/[Group] Anime [BDMV]"));
+
+            let outcome = organize_disc(&disc, &target, mode).unwrap();
+
+            assert!(matches!(outcome, OperationOutcome::Performed(_)), "{mode}");
+            let stream = target.join(BDMV_DIR).join("STREAM");
+            assert_eq!(fs::read(stream.join("00001.m2ts")).unwrap(), b"stream 1");
+            assert!(disc.join(BDMV_DIR).join("index.bdmv").is_file());
+            assert_eq!(
+                organize_disc(&disc, &target, mode).unwrap(),
+                OperationOutcome::Skipped(SkipReason::AlreadyExists)
+            );
+        }
+    }
+
+    #[test]
+    fn move_relocates_the_disc() {
+        let dir = tempfile::tempdir().unwrap();
+        let disc = write_disc(dir.path(), "Anime Vol.1");
+        let target = disc_target(&disc, &dir.path().join("library")).unwrap();
+
+        let outcome = organize_disc(&disc, &target, OperationMode::Move).unwrap();
+
+        assert_eq!(outcome, OperationOutcome::Performed(ExecutedOp::Moved));
+        assert!(!disc.exists());
+        assert!(is_disc_root(&target));
+    }
+}
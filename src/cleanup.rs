@@ -0,0 +1,194 @@
+//! 临时文件清理模块
+//!
+//! 复制或跨设备移动时，文件会先写入目标目录下的临时文件再改名。
+//! 临时文件名带有本工具专用的前缀与后缀：
+//!
+//! ```text
+//! .aniorg-tmp-{进程号}-{原文件名}.aniorg-tmp
+//! ```
+//!
+//! 后缀可用 `--temp-suffix` 修改（如避免同步工具上传 `.part` 文件）。
+//! 进程被中断时这些文件可能残留，可通过 `aniorg clean <dir>` 扫描删除，
+//! 自定义过后缀时需传入同样的 `--temp-suffix`。
+//! 识别同时要求前缀和后缀，普通的 `*.part` 文件不会被误删。
+
+use std::path::{Path, PathBuf};
+
+use walkdir::WalkDir;
+
+use crate::error::Result;
+
+/// 临时文件名前缀
+pub const TEMP_FILE_PREFIX: &str = ".aniorg-tmp-";
+/// 默认的临时文件名后缀
+pub const TEMP_FILE_SUFFIX: &str = ".aniorg-tmp";
+
+/// 返回写入 `target_path` 时使用的临时文件路径（与目标位于同一目录），后缀为默认的
+/// [`TEMP_FILE_SUFFIX`]。
+#[must_use]
+pub fn temp_file_path(target_path: &Path) -> PathBuf {
+    temp_file_path_with(target_path, TEMP_FILE_SUFFIX)
+}
+
+/// 以 `suffix` 为后缀返回写入 `target_path` 时使用的临时文件路径。
+///
+/// ```
+/// use anime_organizer::cleanup::temp_file_path_with;
+/// use std::path::Path;
+///
+/// let path = temp_file_path_with(Path::new("anime/Test/01.mkv"), ".tmp");
+/// assert!(path.to_string_lossy().ends_with("-01.mkv.tmp"));
+/// ```
+#[must_use]
+pub fn temp_file_path_with(target_path: &Path, suffix: &str) -> PathBuf {
+    let file_name = target_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    target_path.with_file_name(format!(
+        "{TEMP_FILE_PREFIX}{}-{file_name}{suffix}",
+        std::process::id()
+    ))
+}
+
+/// 判断路径是否为本工具以默认后缀产生的临时文件。
+#[must_use]
+pub fn is_temp_file(path: &Path) -> bool {
+    is_temp_file_with(path, TEMP_FILE_SUFFIX)
+}
+
+/// 判断路径是否为本工具以 `suffix` 为后缀产生的临时文件。
+#[must_use]
+pub fn is_temp_file_with(path: &Path, suffix: &str) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| {
+            name.len() > TEMP_FILE_PREFIX.len() + suffix.len()
+                && name.starts_with(TEMP_FILE_PREFIX)
+                && name.ends_with(suffix)
+        })
+}
+
+/// 校验 `--temp-suffix`：不能为空或包含路径分隔符。
+///
+/// # 错误
+///
+/// - `AppError::ParseError` - 后缀无效
+pub fn validate_temp_suffix(suffix: &str) -> Result<()> {
+    if suffix.trim().is_empty() || suffix.contains(['/', '\\']) {
+        return Err(crate::error::AppError::ParseError(format!(
+            "无效的临时文件后缀 {suffix:?}: 不能为空或包含路径分隔符"
+        )));
+    }
+    Ok(())
+}
+
+/// 递归查找 `root` 下本工具以 `suffix` 为后缀产生的临时文件，按路径排序。
+#[must_use]
+pub fn find_temp_files(root: &Path, suffix: &str) -> Vec<PathBuf> {
+    let mut files = WalkDir::new(root)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .filter(|path| is_temp_file_with(path, suffix))
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+/// 删除 `root` 下本工具以 `suffix` 为后缀产生的临时文件，返回匹配到的文件列表。
+///
+/// `dry_run` 为 `true` 时只查找不删除。
+///
+/// # 错误
+///
+/// - `AppError::Io` - 删除文件失败
+pub fn clean_temp_files(root: &Path, suffix: &str, dry_run: bool) -> Result<Vec<PathBuf>> {
+    let files = find_temp_files(root, suffix);
+    if !dry_run {
+        for file in &files {
+            std::fs::remove_file(file)?;
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn temp_file_path_uses_prefix_and_suffix() {
+        let path = temp_file_path(Path::new("anime/Test/01.mkv"));
+        assert_eq!(path.parent(), Some(Path::new("anime/Test")));
+        assert!(is_temp_file(&path), "{}", path.display());
+        assert!(path
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .ends_with("-01.mkv.aniorg-tmp"));
+    }
+
+    #[test]
+    fn custom_suffix_is_used_and_recognized() {
+        let path = temp_file_path_with(Path::new("anime/Test/01.mkv"), ".syncignore");
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        assert!(name.starts_with(TEMP_FILE_PREFIX), "{name}");
+        assert!(name.ends_with("-01.mkv.syncignore"), "{name}");
+        assert!(is_temp_file_with(&path, ".syncignore"));
+        assert!(!is_temp_file(&path));
+    }
+
+    #[test]
+    fn temp_suffix_must_be_a_plain_suffix() {
+        assert!(validate_temp_suffix(".tmp").is_ok());
+        for suffix in ["", " ", "/tmp", ".a\\b"] {
+            assert!(validate_temp_suffix(suffix).is_err(), "{suffix:?}");
+        }
+    }
+
+    #[test]
+    fn user_part_files_are_not_temp_files() {
+        for name in [
+            "01.mkv.part",
+            "01.mkv.aniorg-tmp",
+            "aniorg-tmp-01.mkv.aniorg-tmp",
+            ".aniorg-tmp-01.mkv",
+            ".aniorg-tmp-.aniorg-tmp",
+            ".aniorg-tmp-42-01.mkv.part",
+            "01.mkv",
+        ] {
+            assert!(!is_temp_file(Path::new(name)), "{name}");
+        }
+        assert!(is_temp_file(Path::new(".aniorg-tmp-42-01.mkv.aniorg-tmp")));
+        assert!(is_temp_file_with(
+            Path::new(".aniorg-tmp-42-01.mkv.part"),
+            ".part"
+        ));
+    }
+
+    #[test]
+    fn clean_removes_only_tool_temp_files() {
+        let directory = tempfile::tempdir().unwrap();
+        let nested = directory.path().join("Test");
+        fs::create_dir(&nested).unwrap();
+        let temp = nested.join(".aniorg-tmp-42-01.mkv.aniorg-tmp");
+        let user_part = nested.join("02.mkv.part");
+        let video = nested.join("01.mkv");
+        for path in [&temp, &user_part, &video] {
+            fs::write(path, b"data").unwrap();
+        }
+
+        let preview = clean_temp_files(directory.path(), TEMP_FILE_SUFFIX, true).unwrap();
+        assert_eq!(preview, vec![temp.clone()]);
+        assert!(temp.exists());
+
+        let removed = clean_temp_files(directory.path(), TEMP_FILE_SUFFIX, false).unwrap();
+        assert_eq!(removed, vec![temp.clone()]);
+        assert!(!temp.exists());
+        assert!(user_part.exists());
+        assert!(video.exists());
+    }
+}
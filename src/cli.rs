@@ -1,9 +1,8 @@
-use anime_organizer::OperationMode;
-#[cfg(any(
-    feature = "scraper",
-    feature = "clouddrive",
-    feature = "torrent-scraper"
-))]
+use anime_organizer::cleanup;
+use anime_organizer::parser::{DEFAULT_SEASON_FORMAT, DEFAULT_SPECIALS_DIR};
+use anime_organizer::scan::SortKey;
+use anime_organizer::tags::TagCategory;
+use anime_organizer::{ConflictPolicy, OperationMode, SameFileCheck};
 use clap::Subcommand;
 use clap::{Args, Parser, ValueEnum};
 use serde::{Deserialize, Serialize};
@@ -19,9 +18,17 @@ const APP_LONG_ABOUT: &str = concat!(
     "    aniorg --source=\"D:\\Downloads\" --scrape-metadata\n\n",
     "生成 MiruPlay MLIP 媒体库：\n",
     "    aniorg --source=\"D:\\Downloads\" --target=\"E:\\Anime\" --mlip\n\n",
+    "清理中断后残留的临时文件：\n",
+    "    aniorg clean \"E:\\Anime\" --dry-run\n\n",
     "启用 scraper 子命令（需以 --features scraper 编译）：\n",
     "    aniorg scrape --days 7 --format json\n",
-    "    aniorg match --input scraped.json --format github\n"
+    "    aniorg match --input scraped.json --format github\n\n",
+    "退出码：\n",
+    "    0  全部成功（或没有可整理的文件）\n",
+    "    1  运行出错，例如源目录不存在\n",
+    "    2  命令行参数用法错误\n",
+    "    3  部分文件整理失败\n",
+    "    4  所有文件均整理失败\n"
 );
 
 /// 跨平台动漫文件整理工具
@@ -36,11 +43,6 @@ pub(crate) struct Cli {
     #[arg(long)]
     pub(crate) daemon: bool,
 
-    #[cfg(any(
-        feature = "scraper",
-        feature = "clouddrive",
-        feature = "torrent-scraper"
-    ))]
     #[command(subcommand)]
     pub(crate) command: Option<Commands>,
 
@@ -48,43 +50,154 @@ pub(crate) struct Cli {
     pub(crate) organize: OrganizeArgs,
 }
 
+/// 整理模式参数
+///
+/// 部分参数支持 `ANIORG_*` 环境变量作为默认值，优先级为：
+/// 命令行参数 > 环境变量 > 内置默认值。
 #[derive(Args, Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
 pub(crate) struct OrganizeArgs {
-    /// 源目录路径（整理模式必填）
-    #[arg(short, long, value_name = "PATH")]
+    /// 源目录路径（整理模式必填）；含 `*`、`?` 时按通配符展开为文件列表（方括号按字面匹配）
+    #[arg(short, long, value_name = "PATH", env = "ANIORG_SOURCE")]
     pub(crate) source: Option<PathBuf>,
 
-    /// 目标根目录（默认：与源目录相同）
-    #[arg(short, long, value_name = "PATH")]
-    pub(crate) target: Option<PathBuf>,
-
-    /// 操作模式：move（移动）、copy（复制）、link（硬链接）
-    #[arg(short, long, value_enum, default_value = "link")]
+    /// 目标根目录（默认：与源目录相同）；可多次指定，对每个目标分别整理一次。
+    /// 含 `{year}`、`{publisher}`、`{anime}` 占位符时为模板（如 `/library/{year}/{anime}`），按文件渲染实际目标
+    #[arg(short, long, value_name = "PATH", env = "ANIORG_TARGET")]
+    #[serde(deserialize_with = "deserialize_targets")]
+    pub(crate) target: Vec<PathBuf>,
+
+    /// 操作模式：move（移动）、copy（复制）、link（硬链接）、auto（同盘硬链接、跨盘复制）；
+    /// 也接受别名 mv、cp、hardlink、ln
+    #[arg(
+        short,
+        long,
+        value_name = "MODE",
+        value_parser = str::parse::<OperationMode>,
+        default_value = "link",
+        env = "ANIORG_MODE"
+    )]
     pub(crate) mode: OperationMode,
 
     /// 硬链接失败时的回退模式：move 或 copy（默认不回退）
-    #[arg(long, value_enum, value_name = "MODE")]
+    #[arg(
+        long,
+        value_enum,
+        value_name = "MODE",
+        env = "ANIORG_FALLBACK_ON_LINK_FAILURE"
+    )]
     pub(crate) fallback_on_link_failure: Option<FallbackMode>,
 
+    /// link 模式下建立硬链接后读取目标的链接数，确认不小于 2，否则视为失败（仅 Unix 可校验）
+    #[arg(long)]
+    pub(crate) verify_links: bool,
+
+    /// 移动、复制或链接后打开目标文件读取首字节，确认可读（如 NAS 上），否则视为失败
+    #[arg(long)]
+    pub(crate) verify_readable: bool,
+
+    /// `--verify-readable` 失败时的重试次数，每次间隔 1 秒
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = 0,
+        requires = "verify_readable"
+    )]
+    pub(crate) verify_readable_retries: u32,
+
+    /// 源文件的处理顺序：name（文件名，默认）、size（从小到大）、mtime（从旧到新）
+    #[arg(long, value_enum, value_name = "KEY", default_value = "name")]
+    pub(crate) sort: SortKey,
+
+    /// 复制限速（字节/秒），分块复制时按平均速率休眠；硬链接与同盘移动不受影响
+    #[arg(long, value_name = "BYTES", value_parser = clap::value_parser!(u64).range(1..), env = "ANIORG_LIMIT_RATE")]
+    pub(crate) limit_rate: Option<u64>,
+
     /// 仅预览不执行
     #[arg(long)]
     pub(crate) dry_run: bool,
 
-    /// 包含的扩展名（逗号分隔，默认：mp4,mkv,avi,mov,wmv,flv,rmvb）
-    #[arg(long, value_name = "EXT", value_delimiter = ',')]
+    /// 预览时估算复制耗时所用的吞吐（字节/秒），默认 100 MiB/s；同时指定 `--limit-rate` 时取较小者
+    #[arg(long, value_name = "BYTES", value_parser = clap::value_parser!(u64).range(1..), requires = "dry_run")]
+    pub(crate) throughput: Option<u64>,
+
+    /// 整理结束时把失败文件的源路径写入该文件（每行一个），可再用 `--from-file` 只重跑这些文件
+    #[arg(long, value_name = "FILE")]
+    pub(crate) failures_out: Option<PathBuf>,
+
+    /// 只处理列表文件中的源文件（每行一个路径，如 `--failures-out` 的输出），不再扫描源目录；
+    /// 相对路径相对于当前目录，已不存在的文件跳过并警告
+    #[arg(long, value_name = "FILE")]
+    pub(crate) from_file: Option<PathBuf>,
+
+    /// 预览时将结构化的整理计划写入 JSON 文件，审核后可用 `aniorg apply` 执行
+    #[arg(long, value_name = "FILE", requires = "dry_run")]
+    pub(crate) plan_out: Option<PathBuf>,
+
+    /// 增量状态文件：记录已成功处理的源文件路径、修改时间与大小，下次运行跳过未变动的文件；
+    /// 文件不存在时自动创建，预览模式只读取不写入
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["scrape_metadata", "mlip"])]
+    pub(crate) state: Option<PathBuf>,
+
+    /// 预览与 CSV 报告中的路径相对于源/目标目录显示（两者有共同上级目录时相对该目录）
+    #[arg(long)]
+    pub(crate) relative: bool,
+
+    /// 包含的扩展名（逗号分隔，默认：mp4,mkv,avi,mov,wmv,flv,rmvb），支持通配符 `*`、`?`，如 `mp*`
+    #[arg(
+        long,
+        value_name = "EXT",
+        value_delimiter = ',',
+        env = "ANIORG_INCLUDE_EXT"
+    )]
     pub(crate) include_ext: Option<Vec<String>>,
 
+    /// 排除的扩展名（逗号分隔），从默认或 `--include-ext` 列表中剔除
+    #[arg(
+        long,
+        value_name = "EXT",
+        value_delimiter = ',',
+        env = "ANIORG_EXCLUDE_EXT"
+    )]
+    pub(crate) exclude_ext: Option<Vec<String>>,
+
+    /// 扩展名优先级（逗号分隔，靠前优先），同一番剧同一集存在多个扩展名版本时只整理优先级最高的，
+    /// 如 `--prefer-ext mkv,mp4`；未列出的扩展名优先级最低
+    #[arg(
+        long,
+        value_name = "EXT",
+        value_delimiter = ',',
+        env = "ANIORG_PREFER_EXT"
+    )]
+    pub(crate) prefer_ext: Option<Vec<String>>,
+
+    /// 跟随源目录中的符号链接；指回上级目录的链接环会被跳过并警告
+    #[arg(long)]
+    pub(crate) follow_symlinks: bool,
+
+    /// 同时遍历隐藏文件/目录（`.` 开头）和系统目录（如 `$RECYCLE.BIN`），默认跳过
+    #[arg(long)]
+    pub(crate) include_hidden: bool,
+
     /// 显示详细日志
     #[arg(short, long)]
     pub(crate) verbose: bool,
 
+    /// 静默模式：不输出任何非错误信息，仅用退出码表示结果（有失败文件时非零）
+    #[arg(short, long, conflicts_with_all = ["verbose", "output"])]
+    pub(crate) quiet: bool,
+
     /// 启用元数据刮削（生成 NFO 文件和下载封面图片）
     #[arg(long = "scrape-metadata", visible_alias = "刮削")]
     pub(crate) scrape_metadata: bool,
 
     /// TMDB API Key（用于下载封面图片）
-    #[arg(long, value_name = "KEY")]
+    #[arg(
+        long,
+        value_name = "KEY",
+        env = "ANIORG_TMDB_API_KEY",
+        hide_env_values = true
+    )]
     pub(crate) tmdb_api_key: Option<String>,
 
     /// 自定义别名文件（JSON），会覆盖本地别名库中的同名项
@@ -104,7 +217,7 @@ pub(crate) struct OrganizeArgs {
     pub(crate) force_overwrite: bool,
 
     /// Bangumi/AnimeAtlas 缓存目录（默认使用系统缓存目录，不写入媒体库）
-    #[arg(long, value_name = "PATH")]
+    #[arg(long, value_name = "PATH", env = "ANIORG_BANGUMI_CACHE")]
     pub(crate) bangumi_cache: Option<PathBuf>,
 
     /// 本地 Bangumi 元数据源（subject.jsonlines 或其目录；启用后保持离线）
@@ -115,6 +228,20 @@ pub(crate) struct OrganizeArgs {
     #[arg(long = "season-mode", visible_alias = "分季")]
     pub(crate) season_mode: bool,
 
+    /// 分季目录名模板：`{n}` 为季号，`{n:02}` 补零，如 `S{n:02}`、`第{n}季`；无法识别季号时按第 1 季
+    #[arg(long, value_name = "TEMPLATE")]
+    pub(crate) season_format: Option<String>,
+
+    /// 媒体服务器命名预设：plex（`番名/Season 01/番名 - s01e05.mkv`）；
+    /// 隐含 `--season-mode`，`--season-format` 仍可覆盖季目录名
+    #[arg(
+        long,
+        value_enum,
+        value_name = "PRESET",
+        conflicts_with_all = ["name_separator", "raw_anime_name"]
+    )]
+    pub(crate) preset: Option<NamingPreset>,
+
     /// 生成/更新目标目录根部的 MLIP 媒体库索引 library.db
     #[arg(long)]
     pub(crate) library_index: bool,
@@ -132,8 +259,255 @@ pub(crate) struct OrganizeArgs {
     pub(crate) probe_runtime: bool,
 
     /// 文件名解析器：rules（默认规则）、anifilebert（ONNX）、auto（规则失败后回退 ONNX）
-    #[arg(long, value_enum, default_value = "rules")]
+    #[arg(
+        long,
+        value_enum,
+        default_value = "rules",
+        env = "ANIORG_FILENAME_PARSER"
+    )]
     pub(crate) filename_parser: FilenameParserMode,
+
+    /// 在每部番剧目录下生成按集数排序的 `番名.m3u` 播放列表
+    #[arg(long)]
+    pub(crate) generate_playlist: bool,
+
+    /// 逐文件结果输出格式：text（默认）、csv 或 ndjson（stdout 仅输出数据行，摘要改写到 stderr）
+    #[arg(long, value_enum, default_value = "text")]
+    pub(crate) output: OutputFormat,
+
+    /// 整理完成后将目标文件设为只读（硬链接模式会同时影响源文件）
+    #[arg(long)]
+    pub(crate) read_only: bool,
+
+    /// 覆盖内容不同的已存在目标文件前，先将其改名为 `原名.bak` 备份
+    #[arg(long)]
+    pub(crate) backup: bool,
+
+    /// 目标已存在时判断"相同文件"（相同则跳过）的方式：sample（大小及首尾内容，默认）、
+    /// size（仅大小）、inode（互为硬链接）、hash（全部内容）、name（文件名已存在即跳过）
+    #[arg(long, value_enum, value_name = "CHECK", default_value = "sample")]
+    pub(crate) same_file_check: SameFileCheck,
+
+    /// 目标已存在且与源文件不同时的处理：overwrite（覆盖，默认）、
+    /// keep-newer（源较新才覆盖）、keep-larger（源较大才覆盖），未覆盖的计为跳过
+    #[arg(
+        long,
+        value_enum,
+        value_name = "POLICY",
+        default_value = "overwrite",
+        conflicts_with = "force"
+    )]
+    pub(crate) on_conflict: ConflictPolicy,
+
+    /// 将目标文件重命名为 `集数{分隔符}标签.扩展名`（如 `.` 得到 `01.[1080P].mkv`）；未指定时保留源文件名
+    #[arg(long, value_name = "SEP", allow_hyphen_values = true)]
+    pub(crate) name_separator: Option<String>,
+
+    /// 改名时区间集数（如 `01-12`、`01~12`）的渲染模板，`{start}`、`{end}` 为起止集号，
+    /// 如 `{start}~{end}` 或 `{start}-{end}话`；单集不受影响。需配合 `--name-separator` 或 `--renumber`
+    #[arg(long, value_name = "FORMAT")]
+    pub(crate) episode_range_format: Option<String>,
+
+    /// 目标文件名的最大字节数（如 `255`），超出时从尾部逐个去掉标签，集数与扩展名始终保留
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u16).range(16..))]
+    pub(crate) max_filename_len: Option<u16>,
+
+    /// 随视频一起搬运 macOS AppleDouble 伴随文件 `._文件名`（默认跳过这类文件及 `.DS_Store`）
+    #[arg(long)]
+    pub(crate) keep_apple_double: bool,
+
+    /// 随视频一起搬运同目录的元数据文件：与视频同名的 `.nfo`/图片（改名时保持关联），
+    /// 以及 `poster.jpg`、`thumb.png` 等目录级图片
+    #[arg(long)]
+    pub(crate) with_metadata: bool,
+
+    /// 关闭所有保护性检查：已存在的目标不比较内容、不备份直接覆盖，NFO 和图片同样覆盖
+    #[arg(long, conflicts_with_all = ["backup", "dry_run"])]
+    pub(crate) force: bool,
+
+    /// 解析后对集数整数部分加上偏移量（可为负），如 `--episode-offset=-12` 将 13 变为 01
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = 0,
+        allow_hyphen_values = true
+    )]
+    pub(crate) episode_offset: i64,
+
+    /// 按原集数顺序将每部番剧重编为从 01 开始的连续集数（如 02 03 05 → 01 02 03），
+    /// 目标文件改名为 `集数 标签` 形式；会改写集数，建议先配合 `--dry-run` 检查映射
+    #[arg(long)]
+    pub(crate) renumber: bool,
+
+    /// 剧场版、总集篇、特别篇整理到番剧目录下的对应子目录时，番剧目录名保留这些关键字（默认剔除）
+    #[arg(long)]
+    pub(crate) keep_kind_keyword: bool,
+
+    /// 番剧目录直接使用解析出的原始番名：不剔除剧场版等类型关键字、不拆分季信息、不应用别名
+    #[arg(long, conflicts_with_all = ["season_mode", "aliases", "scrape_metadata", "mlip"])]
+    pub(crate) raw_anime_name: bool,
+
+    /// 英文番名规范化为标题大小写（如 `the final season` → `The Final Season`），OVA、TV 等缩写保持全大写；不影响中文名
+    #[arg(long, conflicts_with = "raw_anime_name")]
+    pub(crate) title_case: bool,
+
+    /// 标准规则无法解析时，启发式地取文件名中最后一个独立数字作为集数（best-effort），
+    /// 用于 `Anime-1080p-01.mkv` 这类画质与集数黏连的命名；分辨率、年份不会被当作集数
+    #[arg(long)]
+    pub(crate) loose_episode: bool,
+
+    /// 番名含 ` / ` 并列的多语言标题（如 `动漫名 / Anime Name`）时，取第 N 个（从 1 开始）作为番名，
+    /// 默认取第一个；超出标题个数时仍用第一个
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u16).range(1..))]
+    pub(crate) primary_title: Option<u16>,
+
+    /// 保留源文件扩展名的大小写（如 `.MKV`），默认解析时统一转为小写；
+    /// 仅影响改名（`--name-separator` 等）生成的目标文件名
+    #[arg(long)]
+    pub(crate) preserve_ext_case: bool,
+
+    /// 归一化发布组名：去除 `-Raws`、`字幕组`、`Sub` 等常见后缀（如 `ANi-Raws` → `ANi`），
+    /// 影响元数据匹配时的发布组提示
+    #[arg(long)]
+    pub(crate) normalize_publisher: bool,
+
+    /// `--normalize-publisher` 去除的后缀列表（逗号分隔），替换默认的 Raws,Raw,字幕组,字幕組,字幕社,Subs,Sub
+    #[arg(
+        long,
+        value_name = "SUFFIX",
+        value_delimiter = ',',
+        requires = "normalize_publisher"
+    )]
+    pub(crate) publisher_suffixes: Option<Vec<String>>,
+
+    /// 特殊集（特别篇与 `12.5` 这类小数集）所在子目录名，如 `特典`、`SP`；季模式下默认 `Specials`，
+    /// 非季模式下未指定时特殊集与正片同目录
+    #[arg(long, value_name = "NAME")]
+    pub(crate) specials_dir: Option<String>,
+
+    /// 目标文件名只保留指定类别的标签（逗号分隔：resolution、source、codec、lang、other），未指定时保留源文件名
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "CATEGORIES")]
+    pub(crate) keep_tags: Option<Vec<TagCategory>>,
+
+    /// 解压源目录中 `.zip` 压缩包内的视频并一并整理（需 `archives` feature），结束后清理解压文件
+    #[arg(long)]
+    pub(crate) extract_archives: bool,
+
+    /// 结束时按番剧输出成功/失败集数与总大小汇总表
+    #[arg(long)]
+    pub(crate) summary_by_anime: bool,
+
+    /// 番名别名映射文件（`别名 = 规范名` 的 TOML 或 `别名,规范名` 的 CSV），命中时替换为规范名
+    #[arg(long, value_name = "FILE")]
+    pub(crate) aliases: Option<PathBuf>,
+
+    /// 仅整理修改时间晚于阈值的文件：相对时长（如 `7d`、`12h`）或本地日期（如 `2024-01-01`）
+    #[arg(long, value_name = "DURATION|DATE")]
+    pub(crate) newer_than: Option<String>,
+
+    /// 仅整理指定集数：区间（如 `5-10`）、列表（如 `1,3,5`）或二者组合（如 `1-3,7`），
+    /// 按整理后的集数（已应用 `--episode-offset` 与 `--renumber`）判断
+    #[arg(long, value_name = "RANGES")]
+    pub(crate) episodes: Option<String>,
+
+    /// 排除指定发布组的文件（逗号分隔，不区分大小写），如 `--exclude-group A,B`；
+    /// 启用 `--normalize-publisher` 时按归一化后的组名比较
+    #[arg(long, value_name = "GROUP", value_delimiter = ',')]
+    pub(crate) exclude_group: Option<Vec<String>>,
+
+    /// 复制或跨设备移动时临时文件的后缀，默认 `.aniorg-tmp`；
+    /// 同步工具会误传 `.part` 等文件时可改为其忽略的后缀
+    #[arg(long, value_name = "SUFFIX")]
+    pub(crate) temp_suffix: Option<String>,
+
+    /// 目标中已存在仅大小写不同的番剧目录（如 `anime` 与 `Anime`）时并入该目录，
+    /// 避免在大小写敏感的文件系统上分裂为两个目录
+    #[arg(long)]
+    pub(crate) case_insensitive_merge: bool,
+
+    /// 照常整理 0 字节文件（默认跳过这类下载失败留下的占位文件，并在跳过明细中单列"空文件"）
+    #[arg(long)]
+    pub(crate) allow_empty: bool,
+
+    /// 识别蓝光原盘（含 `BDMV/` 的目录），把整个原盘目录按目录名中的番名整理到
+    /// `番名/原盘目录名/`，其中的 m2ts 不再逐个解析
+    #[arg(long)]
+    pub(crate) bdmv: bool,
+
+    /// 移动完成后在源位置留下指向新位置的符号链接（需配合 `--mode=move`），便于 BT 客户端继续做种
+    #[arg(long)]
+    pub(crate) leave_symlink: bool,
+
+    /// 按内容去重：与本次已处理文件大小和内容完全相同的文件将被跳过
+    #[arg(long)]
+    pub(crate) dedup_by_content: bool,
+
+    /// 每成功整理一个文件后执行的命令，参数中的 `{target}`、`{anime}` 替换为目标路径和番名
+    #[arg(long, value_name = "COMMAND")]
+    pub(crate) post_hook: Option<String>,
+
+    /// `--post-hook` 执行失败时将该文件计为失败（默认只警告）
+    #[arg(long, requires = "post_hook")]
+    pub(crate) fail_on_hook_error: bool,
+
+    /// 整理结束后向该 webhook POST 一份 JSON 处理摘要（需 `notify` feature），失败只警告
+    #[arg(long, value_name = "URL")]
+    pub(crate) notify: Option<String>,
+
+    /// 整理结束后发送桌面通知（Linux 使用 `notify-send`，macOS 使用 `osascript`），失败只警告
+    #[arg(long)]
+    pub(crate) notify_desktop: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum OutputFormat {
+    /// 人类可读的文本日志
+    #[default]
+    Text,
+    /// CSV：源路径、目标路径、番名、集数、模式、状态
+    Csv,
+    /// NDJSON：每处理完一个文件立即输出并 flush 一行 JSON
+    Ndjson,
+}
+
+impl OutputFormat {
+    /// 是否为结构化输出；结构化输出时 stdout 只写数据行
+    pub(crate) fn is_structured(self) -> bool {
+        !matches!(self, Self::Text)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum NamingPreset {
+    /// Plex：`番名/Season 01/番名 - s01e05.mkv`，区间集数写作 `s01e01-e12`
+    Plex,
+}
+
+impl NamingPreset {
+    /// 预设的季目录模板
+    pub(crate) fn season_format(self) -> &'static str {
+        match self {
+            Self::Plex => "Season {n:02}",
+        }
+    }
+
+    /// 预设的目标文件名模板，见 [`AnimeFileInfo::target_filename_from`]
+    ///
+    /// [`AnimeFileInfo::target_filename_from`]: anime_organizer::parser::AnimeFileInfo::target_filename_from
+    pub(crate) fn filename_format(self) -> &'static str {
+        match self {
+            Self::Plex => "{anime} - s{n:02}e{episode}",
+        }
+    }
+
+    /// 预设的区间集数模板
+    pub(crate) fn episode_range_format(self) -> &'static str {
+        match self {
+            Self::Plex => "{start}-e{end}",
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
@@ -166,19 +540,70 @@ impl FallbackMode {
     }
 }
 
+/// 兼容旧版任务中以单个字符串（或 `null`）保存的 `target`。
+fn deserialize_targets<'de, D>(deserializer: D) -> Result<Vec<PathBuf>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Targets {
+        One(PathBuf),
+        Many(Vec<PathBuf>),
+    }
+
+    Ok(match Option::<Targets>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(Targets::One(target)) => vec![target],
+        Some(Targets::Many(targets)) => targets,
+    })
+}
+
 impl OrganizeArgs {
+    /// 是否按季目录整理（`--season-mode` 或 `--preset`）
+    pub(crate) fn uses_season_dirs(&self) -> bool {
+        self.season_mode || self.preset.is_some()
+    }
+
+    /// 分季目录名模板，未指定时取 `--preset` 的模板，否则为 `Season {n}`
+    pub(crate) fn season_format(&self) -> &str {
+        self.season_format.as_deref().unwrap_or_else(|| {
+            self.preset
+                .map_or(DEFAULT_SEASON_FORMAT, NamingPreset::season_format)
+        })
+    }
+
+    /// 特殊集目录名，未指定时为 `Specials`
+    pub(crate) fn specials_dir(&self) -> &str {
+        self.specials_dir.as_deref().unwrap_or(DEFAULT_SPECIALS_DIR)
+    }
+
     pub(crate) fn writes_library_index(&self) -> bool {
         self.library_index || self.mlip
     }
+
+    /// 是否覆盖已有的 NFO 和图片（`--force-overwrite` 或 `--force`）
+    pub(crate) fn overwrites_metadata(&self) -> bool {
+        self.force_overwrite || self.force
+    }
 }
 
-#[cfg(any(
-    feature = "scraper",
-    feature = "clouddrive",
-    feature = "torrent-scraper"
-))]
 #[derive(Subcommand, Debug)]
 pub(crate) enum Commands {
+    /// 删除本工具中断后残留的临时文件（`.aniorg-tmp-*.aniorg-tmp`）
+    Clean(CleanArgs),
+    /// 常驻监听源目录，新文件写完后自动整理
+    Watch(Box<WatchArgs>),
+    /// 打印文件名各字段的原始匹配与处理后的值，便于排查解析失败
+    DebugParse(DebugParseArgs),
+    /// 解析单个文件名字符串并打印各字段，不访问文件系统
+    Parse(ParseArgs),
+    /// 执行 `--dry-run --plan-out` 生成的整理计划
+    Apply(ApplyArgs),
+    /// 依次执行任务清单中的多组整理任务（每行一组整理参数）
+    Batch(BatchArgs),
+    /// 统计源目录中视频文件名的解析覆盖率，按失败原因分类，不做任何文件操作
+    Coverage(CoverageArgs),
     #[cfg(feature = "scraper")]
     Scrape(ScrapeArgs),
     #[cfg(feature = "scraper")]
@@ -213,6 +638,94 @@ pub(crate) enum Commands {
     TorrentScrape(TorrentScrapeArgs),
 }
 
+#[derive(Args, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct CleanArgs {
+    /// 要扫描的目录
+    #[arg(value_name = "DIR")]
+    pub(crate) dir: PathBuf,
+
+    /// 仅列出将删除的临时文件
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+
+    /// 整理时通过 `--temp-suffix` 自定义过的临时文件后缀
+    #[arg(long, value_name = "SUFFIX", default_value = cleanup::TEMP_FILE_SUFFIX)]
+    pub(crate) temp_suffix: String,
+}
+
+#[derive(Args, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct ApplyArgs {
+    /// 计划文件路径
+    #[arg(value_name = "PLAN")]
+    pub(crate) plan: PathBuf,
+
+    /// 任一操作失败时撤销已完成的操作（移动的文件搬回、复制或链接的目标删除），
+    /// 被覆盖的已有目标恢复为原来的文件，计划带 `--backup` 时生成的 `.bak` 一并删除
+    #[arg(long)]
+    pub(crate) atomic: bool,
+}
+
+#[derive(Args, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct BatchArgs {
+    /// 任务清单文件：每行一组与默认整理模式相同的参数（如 `--source a --target b --mode copy`），
+    /// 空行与 `#` 开头的行忽略
+    #[arg(value_name = "TASKS")]
+    pub(crate) tasks: PathBuf,
+}
+
+#[derive(Args, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct CoverageArgs {
+    /// 要统计的目录（递归扫描默认视频扩展名的文件）
+    #[arg(long, value_name = "DIR")]
+    pub(crate) source: PathBuf,
+
+    /// 每种失败原因最多列出的示例文件名数
+    #[arg(long, default_value_t = 5, value_name = "N")]
+    pub(crate) examples: usize,
+}
+
+/// 任务清单中的一行，按默认整理模式的参数解析
+#[derive(Parser, Debug)]
+#[command(no_binary_name = true)]
+pub(crate) struct BatchTask {
+    #[command(flatten)]
+    pub(crate) organize: OrganizeArgs,
+}
+
+#[derive(Args, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct DebugParseArgs {
+    /// 要解析的文件名（可带路径，只取文件名部分）
+    #[arg(value_name = "FILENAME")]
+    pub(crate) filename: PathBuf,
+}
+
+#[derive(Args, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct ParseArgs {
+    /// 要解析的文件名字符串，按原样解析（不拆分路径）
+    #[arg(value_name = "FILENAME")]
+    pub(crate) filename: String,
+}
+
+#[derive(Args, Debug, Clone)]
+pub(crate) struct WatchArgs {
+    #[command(flatten)]
+    pub(crate) organize: OrganizeArgs,
+
+    /// 系统不支持文件事件（如部分网络文件系统）时轮询源目录的间隔（毫秒）
+    #[arg(long, default_value_t = 1000, value_name = "MS")]
+    pub(crate) poll_ms: u64,
+
+    /// 文件大小保持不变多久后视为写入完成（毫秒）
+    #[arg(long, default_value_t = 5000, value_name = "MS")]
+    pub(crate) settle_ms: u64,
+}
+
 #[cfg(feature = "scraper")]
 #[derive(Args, Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
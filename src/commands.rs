@@ -1,37 +1,40 @@
-#[cfg(any(
-    feature = "scraper",
-    feature = "clouddrive",
-    feature = "torrent-scraper"
-))]
 use crate::cli::*;
-#[cfg(any(
-    feature = "scraper",
-    feature = "clouddrive",
-    feature = "torrent-scraper"
-))]
+use crate::{build_extensions, run_organize_entry, RunSummary};
+use anime_organizer::cleanup;
+use anime_organizer::coverage::ParseCoverage;
 use anime_organizer::error::AppError;
+use anime_organizer::filesystem::StdFileSystem;
 #[cfg(feature = "scraper")]
 use anime_organizer::metadata::AliasLookup;
+use anime_organizer::organizer::OperationOutcome;
+use anime_organizer::parser::FilenameParser;
+use anime_organizer::plan::OrganizePlan;
+use anime_organizer::scan::{self, ScanOptions};
 #[cfg(feature = "scraper")]
 use anime_organizer::scraper::{
     db_builder::build_bangumi_db,
     matcher::{format_github_output, match_aliases},
     MatchResult, ScrapedAnime, Scraper,
 };
+use anime_organizer::watch::DirectoryWatcher;
+use clap::Parser;
 #[cfg(feature = "scraper")]
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "scraper")]
 use std::io::Write;
 #[cfg(feature = "scraper")]
 use std::path::PathBuf;
+use std::time::Duration;
 
-#[cfg(any(
-    feature = "scraper",
-    feature = "clouddrive",
-    feature = "torrent-scraper"
-))]
 pub(crate) fn run_command(command: Commands) -> Result<(), AppError> {
     match command {
+        Commands::Clean(args) => run_clean(args),
+        Commands::Watch(args) => run_watch(*args),
+        Commands::DebugParse(args) => run_debug_parse(args),
+        Commands::Parse(args) => run_parse(&args),
+        Commands::Apply(args) => run_apply(args),
+        Commands::Batch(args) => run_batch(&args).map(|_| ()),
+        Commands::Coverage(args) => run_coverage(&args),
         #[cfg(feature = "scraper")]
         Commands::Scrape(args) => {
             let runtime = tokio::runtime::Runtime::new()
@@ -65,6 +68,260 @@ pub(crate) fn run_command(command: Commands) -> Result<(), AppError> {
     }
 }
 
+fn run_clean(args: CleanArgs) -> Result<(), AppError> {
+    if !args.dir.is_dir() {
+        return Err(AppError::TargetNotFound(args.dir));
+    }
+    cleanup::validate_temp_suffix(&args.temp_suffix)?;
+    let files = cleanup::clean_temp_files(&args.dir, &args.temp_suffix, args.dry_run)?;
+    for file in &files {
+        if args.dry_run {
+            println!("[DRY-RUN] 将删除: {}", file.display());
+        } else {
+            println!("已删除: {}", file.display());
+        }
+    }
+    println!("清理完成：共 {} 个临时文件", files.len());
+    Ok(())
+}
+
+/// 执行计划文件中的操作；源文件已不存在等单条失败不中断，全部执行后若有失败则返回错误。
+fn run_apply(args: ApplyArgs) -> Result<(), AppError> {
+    let plan = OrganizePlan::load(&args.plan)?;
+    let results = if args.atomic {
+        plan.apply_atomic(&StdFileSystem)?
+            .into_iter()
+            .map(Ok)
+            .collect()
+    } else {
+        plan.apply(&StdFileSystem)
+    };
+    let (mut succeeded, mut skipped, mut failed) = (0, 0, 0);
+    for (operation, result) in plan.operations.iter().zip(results) {
+        match result {
+            Ok(OperationOutcome::Skipped(reason)) => {
+                skipped += 1;
+                println!("跳过（{reason}）: {}", operation.source.display());
+            }
+            Ok(_) => {
+                succeeded += 1;
+                println!(
+                    "成功: {} -> {}",
+                    operation.source.display(),
+                    operation.target.display()
+                );
+            }
+            Err(error) => {
+                failed += 1;
+                eprintln!("失败: {error}");
+            }
+        }
+    }
+    println!("执行完成：成功{succeeded}个，跳过{skipped}个，失败{failed}个");
+    if failed > 0 {
+        return Err(AppError::PlanError(format!(
+            "{failed} 个操作执行失败 {}",
+            args.plan.display()
+        )));
+    }
+    Ok(())
+}
+
+/// 解析任务清单：每个非空、非 `#` 注释行按 shell 规则拆分后解析为一组整理参数。
+///
+/// # 错误
+///
+/// 任一行引号不匹配或参数无效时返回 `AppError::ParseError`，并指出行号。
+pub(crate) fn parse_batch_tasks(content: &str) -> Result<Vec<OrganizeArgs>, AppError> {
+    let mut tasks = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid = |message: String| {
+            AppError::ParseError(format!("任务清单第 {} 行无效: {message}", index + 1))
+        };
+        let words = shlex::split(line).ok_or_else(|| invalid("引号不匹配".to_string()))?;
+        let task = BatchTask::try_parse_from(words).map_err(|error| {
+            invalid(
+                error
+                    .to_string()
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .trim_start_matches("error: ")
+                    .to_string(),
+            )
+        })?;
+        tasks.push(task.organize);
+    }
+    Ok(tasks)
+}
+
+/// `aniorg batch`：依次执行任务清单中的各组整理任务并汇总结果。
+///
+/// 某个任务出错（如源目录不存在）时继续执行其余任务，最后返回第一个错误；
+/// 全部正常结束时返回合并后的文件统计。
+pub(crate) fn run_batch(args: &BatchArgs) -> Result<RunSummary, AppError> {
+    let content = std::fs::read_to_string(&args.tasks).map_err(|error| {
+        AppError::ParseError(format!(
+            "读取任务清单失败 {}: {error}",
+            args.tasks.display()
+        ))
+    })?;
+    let tasks = parse_batch_tasks(&content)?;
+    let mut total = RunSummary::default();
+    let mut failed_tasks = 0;
+    let mut first_error = None;
+    for (index, task) in tasks.into_iter().enumerate() {
+        let source = task
+            .source
+            .as_deref()
+            .map(|source| source.display().to_string())
+            .unwrap_or_default();
+        println!("==> 任务 {}: {source}", index + 1);
+        match run_organize_entry(task) {
+            Ok(summary) => {
+                total.succeeded += summary.succeeded;
+                total.skipped += summary.skipped;
+                total.failed += summary.failed;
+            }
+            Err(error) => {
+                eprintln!("任务 {} 执行失败: {error}", index + 1);
+                failed_tasks += 1;
+                first_error.get_or_insert(error);
+            }
+        }
+    }
+    println!(
+        "批处理完成：任务出错{failed_tasks}个；文件成功{}个，已存在跳过{}个，失败{}个",
+        total.succeeded, total.skipped, total.failed
+    );
+    match first_error {
+        Some(error) => Err(error),
+        None => Ok(total),
+    }
+}
+
+fn run_debug_parse(args: DebugParseArgs) -> Result<(), AppError> {
+    let filename = args
+        .filename
+        .file_name()
+        .unwrap_or(args.filename.as_os_str())
+        .to_string_lossy();
+    let trace = FilenameParser::debug_parse(&filename);
+    for field in &trace.fields {
+        println!(
+            "{:<10} 原始: {:?}  处理后: {:?}",
+            field.name, field.raw, field.value
+        );
+    }
+    match trace.failure {
+        Some(reason) => Err(AppError::ParseError(reason)),
+        None => Ok(()),
+    }
+}
+
+/// 用 [`FilenameParser::parse_str`] 解析文件名字符串，逐行打印各字段；无法解析时以错误退出。
+fn run_parse(args: &ParseArgs) -> Result<(), AppError> {
+    let info = FilenameParser::parse_str(&args.filename)
+        .ok_or_else(|| AppError::ParseError(format!("无法解析: {}", args.filename)))?;
+    for (name, value) in [
+        ("publisher", &info.publisher),
+        ("anime", &info.anime_name),
+        ("episode", &info.episode),
+        ("tags", &info.tags),
+        ("ext", &info.extension),
+    ] {
+        println!("{name:<10} {value}");
+    }
+    Ok(())
+}
+
+/// 统计源目录中视频文件名的解析覆盖率，只读取文件名，不做任何文件操作。
+fn run_coverage(args: &CoverageArgs) -> Result<(), AppError> {
+    if !args.source.is_dir() {
+        return Err(AppError::SourceNotFound(args.source.clone()));
+    }
+    let options = ScanOptions::with_extensions(build_extensions(&OrganizeArgs::default()));
+    let files = scan::collect_files(&args.source, &options);
+    let filenames: Vec<String> = files
+        .iter()
+        .filter_map(|path| path.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .collect();
+    let coverage = ParseCoverage::from_filenames(filenames.iter().map(String::as_str));
+
+    println!("扫描文件：{} 个", coverage.total);
+    println!(
+        "可解析：{} 个（{:.1}%）",
+        coverage.parsed,
+        coverage.ratio() * 100.0
+    );
+    println!("无法解析：{} 个", coverage.failed());
+    for (kind, filenames) in &coverage.failures {
+        println!("  {}：{} 个", kind.label(), filenames.len());
+        for filename in filenames.iter().take(args.examples) {
+            println!("    {filename}");
+        }
+        if filenames.len() > args.examples {
+            println!("    …… 另有 {} 个", filenames.len() - args.examples);
+        }
+    }
+    Ok(())
+}
+
+/// 轮询源目录，对写入完成的新文件逐个执行整理；启动时已存在的文件不处理。
+fn run_watch(args: WatchArgs) -> Result<(), AppError> {
+    let source = args
+        .organize
+        .source
+        .clone()
+        .ok_or_else(|| AppError::ParseError("watch 子命令必须提供 --source".to_string()))?;
+    if !source.is_dir() {
+        return Err(AppError::SourceNotFound(source));
+    }
+    let targets = if args.organize.target.is_empty() {
+        vec![source.clone()]
+    } else {
+        args.organize.target.clone()
+    };
+
+    let options = ScanOptions {
+        follow_symlinks: args.organize.follow_symlinks,
+        include_hidden: args.organize.include_hidden,
+        excluded_dirs: targets
+            .iter()
+            .filter_map(|target| scan::nested_target_dir(&source, target))
+            .collect(),
+        ..ScanOptions::with_extensions(crate::build_extensions(&args.organize))
+    };
+    let mut watcher = DirectoryWatcher::new(
+        &source,
+        options,
+        Duration::from_millis(args.settle_ms),
+        Duration::from_millis(args.poll_ms),
+    )?;
+    eprintln!("正在监听 {}（按 Ctrl+C 退出）", source.display());
+
+    loop {
+        for path in watcher.next_settled()? {
+            if args.organize.verbose {
+                eprintln!("检测到新文件: {}", path.display());
+            }
+            let file_args = OrganizeArgs {
+                source: Some(path.clone()),
+                target: targets.clone(),
+                ..args.organize.clone()
+            };
+            if let Err(error) = crate::run_organize_entry(file_args) {
+                eprintln!("整理失败 {}: {error}", path.display());
+            }
+        }
+    }
+}
+
 #[cfg(feature = "scraper")]
 pub(crate) async fn scrape_result(args: &ScrapeArgs) -> Result<Vec<ScrapedAnime>, AppError> {
     let scraper = Scraper::new();
@@ -738,7 +995,7 @@ fn run_list_folder(args: ListFolderArgs) -> Result<(), AppError> {
             let size = if file.is_directory {
                 "-".to_string()
             } else {
-                format_size(file.size)
+                anime_organizer::report::format_size(u64::try_from(file.size).unwrap_or_default())
             };
             let name = if file.name.is_empty() {
                 file.full_path_name.clone()
@@ -759,23 +1016,6 @@ fn run_list_folder(args: ListFolderArgs) -> Result<(), AppError> {
     })
 }
 
-#[allow(dead_code)]
-fn format_size(bytes: i64) -> String {
-    const KB: i64 = 1024;
-    const MB: i64 = KB * 1024;
-    const GB: i64 = MB * 1024;
-
-    if bytes >= GB {
-        format!("{:.1} GB", bytes as f64 / GB as f64)
-    } else if bytes >= MB {
-        format!("{:.1} MB", bytes as f64 / MB as f64)
-    } else if bytes >= KB {
-        format!("{:.1} KB", bytes as f64 / KB as f64)
-    } else {
-        format!("{} B", bytes)
-    }
-}
-
 #[cfg(feature = "torrent-scraper")]
 async fn run_torrent_scrape(args: TorrentScrapeArgs) -> Result<(), AppError> {
     use anime_organizer::torrent::dmhy;
@@ -918,3 +1158,40 @@ mod tests {
         assert_eq!(count, 1);
     }
 }
+
+#[cfg(test)]
+mod batch_tests {
+    use super::*;
+    use anime_organizer::OperationMode;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn batch_tasks_skip_comments_and_keep_order() {
+        let tasks = parse_batch_tasks(
+            "# 每行一组参数\n\
+             --source /dl/a --target /anime --mode copy\n\
+             \n\
+             --source \"/dl/with space\" --dry-run\n",
+        )
+        .unwrap();
+
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].source.as_deref(), Some(Path::new("/dl/a")));
+        assert_eq!(tasks[0].target, [PathBuf::from("/anime")]);
+        assert_eq!(tasks[0].mode, OperationMode::Copy);
+        assert_eq!(
+            tasks[1].source.as_deref(),
+            Some(Path::new("/dl/with space"))
+        );
+        assert!(tasks[1].dry_run);
+    }
+
+    #[test]
+    fn invalid_batch_lines_report_line_number() {
+        let error = parse_batch_tasks("--source a\n--no-such-flag\n").unwrap_err();
+        assert!(error.to_string().contains("第 2 行"), "{error}");
+
+        let error = parse_batch_tasks("--source \"unterminated\n").unwrap_err();
+        assert!(error.to_string().contains("引号不匹配"), "{error}");
+    }
+}
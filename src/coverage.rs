@@ -0,0 +1,185 @@
+//! 解析覆盖率统计模块
+//!
+//! `aniorg coverage` 用它统计一批文件名中能被解析的比例，并按失败原因分类，
+//! 便于评估和调整命名规则。失败原因取自 [`FilenameParser::debug_parse`] 中断的位置。
+//!
+//! # 示例
+//!
+//! ```
+//! use anime_organizer::coverage::{ParseCoverage, ParseFailureKind};
+//!
+//! let mut coverage = ParseCoverage::default();
+//! coverage.record("[ANi] 测试 - 01 [1080P].mkv");
+//! coverage.record("测试 - 02.mkv");
+//! assert_eq!((coverage.total, coverage.parsed), (2, 1));
+//! assert_eq!(coverage.failure_count(ParseFailureKind::MissingPublisher), 1);
+//! ```
+
+use crate::parser::{FilenameParser, ParseTrace};
+use std::collections::BTreeMap;
+
+/// 解析失败的原因，按解析步骤排列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ParseFailureKind {
+    /// 文件名不以 `[发布组]` 开头
+    MissingPublisher,
+    /// 未找到集数
+    MissingEpisode,
+    /// 集数之后没有支持的扩展名
+    UnsupportedExtension,
+}
+
+impl ParseFailureKind {
+    /// 根据调试解析结果判断失败原因；解析成功时返回 `None`。
+    ///
+    /// 按已匹配的字段判断解析在哪一步中断，与 `debug-parse` 的输出一致。
+    #[must_use]
+    pub fn from_trace(trace: &ParseTrace) -> Option<Self> {
+        trace.failure.as_ref()?;
+        Some(if trace.field("publisher").is_none() {
+            Self::MissingPublisher
+        } else if trace.field("episode").is_none() {
+            Self::MissingEpisode
+        } else {
+            Self::UnsupportedExtension
+        })
+    }
+
+    /// 面向用户的说明
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::MissingPublisher => "缺少 [发布组] 前缀",
+            Self::MissingEpisode => "未找到集数",
+            Self::UnsupportedExtension => "扩展名不受支持",
+        }
+    }
+}
+
+/// 一批文件名的解析覆盖率
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseCoverage {
+    /// 统计的文件名总数
+    pub total: usize,
+    /// 可解析的文件名数
+    pub parsed: usize,
+    /// 按失败原因分组的文件名，保持记录顺序
+    pub failures: BTreeMap<ParseFailureKind, Vec<String>>,
+}
+
+impl ParseCoverage {
+    /// 统计一批文件名
+    #[must_use]
+    pub fn from_filenames<'a>(filenames: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut coverage = Self::default();
+        for filename in filenames {
+            coverage.record(filename);
+        }
+        coverage
+    }
+
+    /// 解析一个文件名并计入统计，返回失败原因（成功时为 `None`）
+    pub fn record(&mut self, filename: &str) -> Option<ParseFailureKind> {
+        self.total += 1;
+        let kind = ParseFailureKind::from_trace(&FilenameParser::debug_parse(filename));
+        match kind {
+            Some(kind) => self
+                .failures
+                .entry(kind)
+                .or_default()
+                .push(filename.to_string()),
+            None => self.parsed += 1,
+        }
+        kind
+    }
+
+    /// 无法解析的文件名数
+    #[must_use]
+    pub fn failed(&self) -> usize {
+        self.total - self.parsed
+    }
+
+    /// 某一失败原因的文件名数
+    #[must_use]
+    pub fn failure_count(&self, kind: ParseFailureKind) -> usize {
+        self.failures.get(&kind).map_or(0, Vec::len)
+    }
+
+    /// 可解析比例（0.0–1.0）；没有文件时为 0
+    #[must_use]
+    pub fn ratio(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.parsed as f64 / self.total as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failures_are_classified_by_parse_step() {
+        let coverage = ParseCoverage::from_filenames([
+            "[ANi] 测试 - 01 [1080P].mkv",
+            "[ANi] 测试 - 02 [1080P].mkv",
+            "测试 - 03 [1080P].mkv",
+            "[ANi] 测试 [1080P].mkv",
+            "[ANi] 测试 - 04 [1080P]",
+        ]);
+
+        assert_eq!(coverage.total, 5);
+        assert_eq!(coverage.parsed, 2);
+        assert_eq!(coverage.failed(), 3);
+        assert_eq!(
+            coverage.failures,
+            BTreeMap::from([
+                (
+                    ParseFailureKind::MissingPublisher,
+                    vec!["测试 - 03 [1080P].mkv".to_string()]
+                ),
+                (
+                    ParseFailureKind::MissingEpisode,
+                    vec!["[ANi] 测试 [1080P].mkv".to_string()]
+                ),
+                (
+                    ParseFailureKind::UnsupportedExtension,
+                    vec!["[ANi] 测试 - 04 [1080P]".to_string()]
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn ratio_is_parsed_over_total() {
+        let mut coverage = ParseCoverage::default();
+        assert!(coverage.ratio().abs() < f64::EPSILON);
+
+        for filename in [
+            "[ANi] 测试 - 01.mkv",
+            "[ANi] 测试 - 02.mkv",
+            "[ANi] 测试 - 03.mkv",
+            "测试.mkv",
+        ] {
+            coverage.record(filename);
+        }
+        assert!((coverage.ratio() - 0.75).abs() < f64::EPSILON);
+        assert_eq!(
+            coverage.failure_count(ParseFailureKind::MissingPublisher),
+            1
+        );
+        assert_eq!(coverage.failure_count(ParseFailureKind::MissingEpisode), 0);
+    }
+
+    #[test]
+    fn record_returns_failure_kind() {
+        let mut coverage = ParseCoverage::default();
+        assert_eq!(coverage.record("[ANi] 测试 - 01.mkv"), None);
+        assert_eq!(
+            coverage.record("[ANi] 测试.mkv"),
+            Some(ParseFailureKind::MissingEpisode)
+        );
+    }
+}
@@ -67,7 +67,7 @@ impl JobOrigin {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", content = "args", rename_all = "snake_case")]
 pub(crate) enum JobSpec {
-    Organize(OrganizeArgs),
+    Organize(Box<OrganizeArgs>),
     #[cfg(feature = "clouddrive")]
     RssPoll {
         subscription_id: i64,
@@ -175,7 +175,7 @@ impl JobSpec {
                 if args.source.is_none() {
                     return Err("source is required".to_string());
                 }
-                if args.target.is_none() {
+                if args.target.is_empty() {
                     return Err("target is required".to_string());
                 }
                 if args.rebuild_library_index && !args.writes_library_index() {
@@ -425,7 +425,7 @@ mod tests {
     fn organize() -> OrganizeArgs {
         OrganizeArgs {
             source: Some(PathBuf::from("source")),
-            target: Some(PathBuf::from("target")),
+            target: vec![PathBuf::from("target")],
             mode: OperationMode::Copy,
             fallback_on_link_failure: None,
             dry_run: false,
@@ -445,6 +445,7 @@ mod tests {
             rebuild_library_index: false,
             probe_runtime: false,
             filename_parser: FilenameParserMode::Rules,
+            ..OrganizeArgs::default()
         }
     }
 
@@ -454,7 +455,7 @@ mod tests {
             idempotency_key: Some("qbittorrent:ABC".to_string()),
             origin: JobOrigin::Qbittorrent,
             confirmed: false,
-            job: JobSpec::Organize(organize()),
+            job: JobSpec::Organize(Box::new(organize())),
         };
         let json = serde_json::to_string(&request).unwrap();
         let decoded: EnqueueRequest = serde_json::from_str(&json).unwrap();
@@ -562,12 +563,27 @@ mod tests {
             args.source.unwrap().to_string_lossy(),
             r"C:\Downloads\Ani\[ANi] Example - 01.mkv"
         );
-        assert_eq!(args.target.unwrap().to_string_lossy(), "S:\\动漫");
+        assert_eq!(args.target[0].to_string_lossy(), "S:\\动漫");
         assert_eq!(args.mode, OperationMode::Copy);
         assert!(args.mlip);
         assert!(args.verbose);
     }
 
+    #[test]
+    fn organize_target_accepts_single_path_or_list() {
+        let single: OrganizeArgs = serde_json::from_str(r#"{"target":"/anime"}"#).unwrap();
+        let many: OrganizeArgs =
+            serde_json::from_str(r#"{"target":["/anime","/backup"]}"#).unwrap();
+        let missing: OrganizeArgs = serde_json::from_str(r#"{"target":null}"#).unwrap();
+
+        assert_eq!(single.target, vec![PathBuf::from("/anime")]);
+        assert_eq!(
+            many.target,
+            vec![PathBuf::from("/anime"), PathBuf::from("/backup")]
+        );
+        assert!(missing.target.is_empty());
+    }
+
     #[cfg(feature = "scraper")]
     #[test]
     fn alias_mutations_require_confirmation_without_touching_database() {
@@ -656,7 +672,7 @@ mod tests {
 
     #[test]
     fn every_compiled_job_variant_round_trips() {
-        assert_round_trip(JobSpec::Organize(organize()));
+        assert_round_trip(JobSpec::Organize(Box::new(organize())));
         #[cfg(feature = "clouddrive")]
         {
             assert_round_trip(JobSpec::RssPoll { subscription_id: 1 });
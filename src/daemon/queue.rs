@@ -625,9 +625,9 @@ mod tests {
             idempotency_key: key.map(str::to_string),
             origin,
             confirmed: false,
-            job: JobSpec::Organize(OrganizeArgs {
+            job: JobSpec::Organize(Box::new(OrganizeArgs {
                 source: Some(PathBuf::from("source")),
-                target: Some(PathBuf::from("target")),
+                target: vec![PathBuf::from("target")],
                 mode: OperationMode::Copy,
                 fallback_on_link_failure: None,
                 dry_run: false,
@@ -647,7 +647,8 @@ mod tests {
                 rebuild_library_index: false,
                 probe_runtime: false,
                 filename_parser: FilenameParserMode::Rules,
-            }),
+                ..OrganizeArgs::default()
+            })),
         }
     }
 
@@ -138,7 +138,7 @@ fn execute(
     match &spec {
         JobSpec::Organize(args) => {
             if args.rebuild_library_index {
-                let target = args.target.as_ref().map_or_else(
+                let target = args.target.first().map_or_else(
                     || "the target library".to_string(),
                     |path| path.display().to_string(),
                 );
@@ -155,7 +155,7 @@ fn execute(
                     &format!("Starting MLIP rebuild for {target} {artwork}"),
                 );
             }
-            let result = crate::run_organize_entry_with_log(args.clone(), &|message| {
+            let result = crate::run_organize_entry_with_log(args.as_ref().clone(), &|message| {
                 let _ = queue.append_log(job.id, "info", message);
             });
             if result.is_ok() && args.rebuild_library_index {
@@ -166,9 +166,13 @@ fn execute(
                 );
             }
             result
-                .map(|_| JobResult {
+                .map(|summary| JobResult {
                     summary: "organize completed".to_string(),
-                    data: serde_json::json!({}),
+                    data: serde_json::json!({
+                        "succeeded": summary.succeeded,
+                        "skipped": summary.skipped,
+                        "failed": summary.failed,
+                    }),
                     artifacts: Vec::new(),
                 })
                 .map_err(|error| error.to_string())
@@ -0,0 +1,332 @@
+//! 内容去重模块
+//!
+//! 不同发布组的同一集有时内容完全相同。[`ContentIndex`] 先按文件大小分组，
+//! 只有大小相同时才分块计算哈希，哈希相同再逐块比对确认，避免误判且不把整个文件读入内存。
+//!
+//! 同一集常同时存在 `.mkv` 与 `.mp4` 等多个扩展名版本，[`non_preferred_extensions`]
+//! 按 `--prefer-ext` 给出的优先级找出其中应跳过的版本。
+//!
+//! # 示例
+//!
+//! ```no_run
+//! use anime_organizer::dedup::{ContentIndex, DedupDecision};
+//! use std::path::Path;
+//!
+//! let mut index = ContentIndex::new();
+//! assert_eq!(index.insert(Path::new("/downloads/a.mkv"))?, DedupDecision::Unique);
+//! if let DedupDecision::DuplicateOf(original) = index.insert(Path::new("/downloads/b.mkv"))? {
+//!     println!("与 {} 内容相同", original.display());
+//! }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use crate::parser::AnimeFileInfo;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::hash::Hasher;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// 分块读取的缓冲区大小
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// 去重判定结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DedupDecision {
+    /// 内容首次出现
+    Unique,
+    /// 与已登记的文件内容相同
+    DuplicateOf(PathBuf),
+}
+
+#[derive(Debug)]
+struct IndexedFile {
+    path: PathBuf,
+    hash: Option<u64>,
+}
+
+/// 按内容登记文件，用于判定后续文件是否重复
+#[derive(Debug, Default)]
+pub struct ContentIndex {
+    by_size: HashMap<u64, Vec<IndexedFile>>,
+}
+
+impl ContentIndex {
+    /// 创建空索引
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 登记文件；若与已登记文件内容相同则返回该文件且不登记新文件。
+    ///
+    /// # 错误
+    ///
+    /// 读取文件元数据或内容失败时返回 IO 错误。
+    pub fn insert(&mut self, path: &Path) -> io::Result<DedupDecision> {
+        let size = fs::metadata(path)?.len();
+        let candidates = self.by_size.entry(size).or_default();
+        let mut hash = None;
+        for candidate in candidates.iter_mut() {
+            let candidate_hash = match candidate.hash {
+                Some(value) => value,
+                None => *candidate.hash.insert(content_hash(&candidate.path)?),
+            };
+            let own_hash = match hash {
+                Some(value) => value,
+                None => *hash.insert(content_hash(path)?),
+            };
+            if candidate_hash == own_hash && files_identical(&candidate.path, path)? {
+                return Ok(DedupDecision::DuplicateOf(candidate.path.clone()));
+            }
+        }
+        candidates.push(IndexedFile {
+            path: path.to_path_buf(),
+            hash,
+        });
+        Ok(DedupDecision::Unique)
+    }
+}
+
+/// 分块计算文件内容哈希（进程内比较用，不保证跨版本稳定）。
+///
+/// # 错误
+///
+/// 读取文件失败时返回 IO 错误。
+pub fn content_hash(path: &Path) -> io::Result<u64> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = DefaultHasher::new();
+    let mut buffer = vec![0; CHUNK_SIZE];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.write(&buffer[..read]);
+    }
+    Ok(hasher.finish())
+}
+
+/// 分块逐字节比较两个文件内容是否完全相同。
+///
+/// # 错误
+///
+/// 读取文件失败时返回 IO 错误。
+pub fn files_identical(left: &Path, right: &Path) -> io::Result<bool> {
+    if fs::metadata(left)?.len() != fs::metadata(right)?.len() {
+        return Ok(false);
+    }
+    let mut left = fs::File::open(left)?;
+    let mut right = fs::File::open(right)?;
+    let mut left_buffer = vec![0; CHUNK_SIZE];
+    let mut right_buffer = vec![0; CHUNK_SIZE];
+    loop {
+        let read = read_full(&mut left, &mut left_buffer)?;
+        if read != read_full(&mut right, &mut right_buffer)? {
+            return Ok(false);
+        }
+        if read == 0 {
+            return Ok(true);
+        }
+        if left_buffer[..read] != right_buffer[..read] {
+            return Ok(false);
+        }
+    }
+}
+
+/// 尽量填满缓冲区，返回实际读取的字节数（到达文件末尾时可能小于缓冲区）。
+fn read_full(file: &mut fs::File, buffer: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        let read = file.read(&mut buffer[filled..])?;
+        if read == 0 {
+            break;
+        }
+        filled += read;
+    }
+    Ok(filled)
+}
+
+/// 按扩展名优先级对多扩展版本去重，返回应跳过文件的 `original_path`。
+///
+/// 同一 `(anime_name, episode)` 下出现多个不同扩展名时，只保留 `preferred` 中排名最靠前的
+/// 扩展名（比较时忽略大小写与前导点），未列出的扩展名排在所有已列出的之后；组内没有任何
+/// 已列出的扩展名时无法判断优劣，全部保留。同一扩展名的多个版本（如不同发布组）不受影响。
+///
+/// ```
+/// use anime_organizer::dedup::non_preferred_extensions;
+/// use anime_organizer::parser::FilenameParser;
+///
+/// let files = ["mp4", "mkv", "avi"]
+///     .map(|ext| FilenameParser::parse_str(&format!("[ANi] Test - 01 [1080P].{ext}")).unwrap());
+/// let skipped = non_preferred_extensions(&files, &["mkv".to_string(), "mp4".to_string()]);
+/// assert_eq!(skipped.len(), 2);
+/// assert!(!skipped.contains(&files[1].original_path));
+/// ```
+pub fn non_preferred_extensions(files: &[AnimeFileInfo], preferred: &[String]) -> HashSet<String> {
+    let preferred = preferred
+        .iter()
+        .map(|ext| ext.trim_start_matches('.').to_lowercase())
+        .collect::<Vec<_>>();
+    let rank = |file: &AnimeFileInfo| {
+        let ext = file.extension.trim_start_matches('.').to_lowercase();
+        preferred
+            .iter()
+            .position(|preferred| *preferred == ext)
+            .unwrap_or(preferred.len())
+    };
+
+    let mut best: HashMap<(&str, &str), usize> = HashMap::new();
+    for file in files {
+        let best = best
+            .entry((file.anime_name.as_str(), file.episode.as_str()))
+            .or_insert(usize::MAX);
+        *best = (*best).min(rank(file));
+    }
+    files
+        .iter()
+        .filter(|file| {
+            let best = best[&(file.anime_name.as_str(), file.episode.as_str())];
+            best < preferred.len() && rank(file) > best
+        })
+        .map(|file| file.original_path.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &Path, name: &str, content: &[u8]) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn identical_content_is_duplicate() {
+        let directory = tempfile::tempdir().unwrap();
+        let first = write(directory.path(), "[A] Test - 01.mkv", b"same video");
+        let second = write(directory.path(), "[B] Test - 01.mkv", b"same video");
+        let mut index = ContentIndex::new();
+
+        assert_eq!(index.insert(&first).unwrap(), DedupDecision::Unique);
+        assert_eq!(
+            index.insert(&second).unwrap(),
+            DedupDecision::DuplicateOf(first)
+        );
+    }
+
+    #[test]
+    fn same_size_different_content_is_unique() {
+        let directory = tempfile::tempdir().unwrap();
+        let first = write(directory.path(), "a.mkv", b"video AAAA");
+        let second = write(directory.path(), "b.mkv", b"video BBBB");
+        let mut index = ContentIndex::new();
+
+        assert_eq!(index.insert(&first).unwrap(), DedupDecision::Unique);
+        assert_eq!(index.insert(&second).unwrap(), DedupDecision::Unique);
+    }
+
+    #[test]
+    fn different_size_skips_hashing() {
+        let directory = tempfile::tempdir().unwrap();
+        let first = write(directory.path(), "a.mkv", b"short");
+        let second = write(directory.path(), "b.mkv", b"much longer");
+        let mut index = ContentIndex::new();
+
+        index.insert(&first).unwrap();
+        assert_eq!(index.insert(&second).unwrap(), DedupDecision::Unique);
+        assert!(index
+            .by_size
+            .values()
+            .flatten()
+            .all(|file| file.hash.is_none()));
+    }
+
+    #[test]
+    fn multi_chunk_files_compare_every_chunk() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut content = vec![7u8; CHUNK_SIZE * 2 + 10];
+        let first = write(directory.path(), "a.mkv", &content);
+        content[CHUNK_SIZE + 1] = 8;
+        let second = write(directory.path(), "b.mkv", &content);
+
+        assert!(!files_identical(&first, &second).unwrap());
+        assert!(files_identical(&first, &first).unwrap());
+        assert_ne!(
+            content_hash(&first).unwrap(),
+            content_hash(&second).unwrap()
+        );
+    }
+
+    fn episode(anime_name: &str, episode: &str, extension: &str) -> AnimeFileInfo {
+        AnimeFileInfo::builder()
+            .publisher("ANi")
+            .anime_name(anime_name)
+            .episode(episode)
+            .extension(extension)
+            .original_path(format!("{anime_name} - {episode}.{extension}"))
+            .build()
+    }
+
+    fn preferred(exts: &[&str]) -> Vec<String> {
+        exts.iter().map(|ext| (*ext).to_string()).collect()
+    }
+
+    fn sorted(skipped: HashSet<String>) -> Vec<String> {
+        let mut skipped = skipped.into_iter().collect::<Vec<_>>();
+        skipped.sort();
+        skipped
+    }
+
+    #[test]
+    fn keeps_highest_priority_extension_per_episode() {
+        let files = [
+            episode("A", "01", "mp4"),
+            episode("A", "01", "mkv"),
+            episode("A", "02", "mp4"),
+            episode("B", "01", "mp4"),
+        ];
+        let skipped = non_preferred_extensions(&files, &preferred(&["mkv", "mp4"]));
+
+        assert_eq!(sorted(skipped), ["A - 01.mp4"]);
+    }
+
+    #[test]
+    fn unlisted_extensions_rank_last() {
+        let files = [
+            episode("A", "01", "avi"),
+            episode("A", "01", "mp4"),
+            episode("A", "02", "avi"),
+            episode("A", "02", "wmv"),
+        ];
+        let skipped = non_preferred_extensions(&files, &preferred(&["mkv", "mp4"]));
+
+        assert_eq!(sorted(skipped), ["A - 01.avi"]);
+    }
+
+    #[test]
+    fn preference_ignores_case_and_leading_dot() {
+        let files = [episode("A", "01", "MKV"), episode("A", "01", "mp4")];
+        let skipped = non_preferred_extensions(&files, &preferred(&[".Mp4", "mkv"]));
+
+        assert_eq!(sorted(skipped), ["A - 01.MKV"]);
+    }
+
+    #[test]
+    fn same_extension_versions_are_all_kept() {
+        let mut other_group = episode("A", "01", "mkv");
+        other_group.publisher = "LoliHouse".to_string();
+        other_group.original_path = "[LoliHouse] A - 01.mkv".to_string();
+        let files = [
+            episode("A", "01", "mkv"),
+            other_group,
+            episode("A", "01", "mp4"),
+        ];
+        let skipped = non_preferred_extensions(&files, &preferred(&["mkv"]));
+
+        assert_eq!(sorted(skipped), ["A - 01.mp4"]);
+    }
+}
@@ -0,0 +1,147 @@
+//! 按集数过滤模块
+//!
+//! 解析 `--episodes` 的取值：区间（`5-10`）、列表（`1,3,5`）、单值（`7`）及其组合
+//! （`1-3,7,10-12`），整理时跳过集数不在其中的文件。
+//!
+//! # 示例
+//!
+//! ```
+//! use anime_organizer::episode_filter::EpisodeFilter;
+//! use anime_organizer::parser::FilenameParser;
+//!
+//! let filter: EpisodeFilter = "5-10,12".parse()?;
+//! assert!(filter.contains(7));
+//! assert!(!filter.contains(11));
+//!
+//! let info = FilenameParser::parse_str("[ANi] 测试 - 12 [1080P].mp4").unwrap();
+//! assert!(filter.matches(&info));
+//! # Ok::<(), anime_organizer::AppError>(())
+//! ```
+
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::error::{AppError, Result};
+use crate::parser::AnimeFileInfo;
+
+/// 要整理的集数集合
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EpisodeFilter {
+    ranges: Vec<RangeInclusive<u32>>,
+}
+
+impl FromStr for EpisodeFilter {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let invalid = || {
+            AppError::ParseError(format!(
+                "无效的集数范围 {value:?}，应为区间（如 5-10）、列表（如 1,3,5）或二者组合"
+            ))
+        };
+        let ranges = value
+            .split(',')
+            .map(|part| parse_range(part.trim()).ok_or_else(invalid))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { ranges })
+    }
+}
+
+impl EpisodeFilter {
+    /// 集号是否在集合中
+    #[must_use]
+    pub fn contains(&self, episode: u32) -> bool {
+        self.ranges.iter().any(|range| range.contains(&episode))
+    }
+
+    /// 文件的集数是否在集合中。
+    ///
+    /// 合并多集（如 `01+02`）任一集命中即可；分段放送（如 `13A`）按其数字部分判断；
+    /// 小数集（如 `12.5`）与没有数字集号的特别篇不在任何范围内。
+    #[must_use]
+    pub fn matches(&self, info: &AnimeFileInfo) -> bool {
+        info.episode.split('+').any(|part| {
+            part.trim()
+                .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+                .parse::<u32>()
+                .is_ok_and(|episode| self.contains(episode))
+        })
+    }
+}
+
+/// 解析单个区间或单值；区间两端可带空格，起点不能大于终点。
+fn parse_range(part: &str) -> Option<RangeInclusive<u32>> {
+    let (start, end) = part.split_once('-').unwrap_or((part, part));
+    let start = start.trim().parse::<u32>().ok()?;
+    let end = end.trim().parse::<u32>().ok()?;
+    (start <= end).then_some(start..=end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::FilenameParser;
+
+    fn filter(value: &str) -> EpisodeFilter {
+        value.parse().unwrap()
+    }
+
+    fn info(episode: &str) -> AnimeFileInfo {
+        FilenameParser::parse_str(&format!("[ANi] 测试 - {episode} [1080P].mp4")).unwrap()
+    }
+
+    #[test]
+    fn parses_ranges() {
+        let episodes = filter("5-10");
+        assert!(!episodes.contains(4));
+        assert!(episodes.contains(5));
+        assert!(episodes.contains(10));
+        assert!(!episodes.contains(11));
+        assert!(filter(" 3 - 3 ").contains(3));
+    }
+
+    #[test]
+    fn parses_lists() {
+        let episodes = filter("1,3,5");
+        assert_eq!(
+            (1..=6)
+                .filter(|&episode| episodes.contains(episode))
+                .collect::<Vec<_>>(),
+            [1, 3, 5]
+        );
+        let episodes = filter("1-2, 7 ,10-11");
+        assert_eq!(
+            (1..=12)
+                .filter(|&episode| episodes.contains(episode))
+                .collect::<Vec<_>>(),
+            [1, 2, 7, 10, 11]
+        );
+    }
+
+    #[test]
+    fn parses_single_values() {
+        let episodes = filter("07");
+        assert!(episodes.contains(7));
+        assert!(!episodes.contains(8));
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        for value in ["", "a", "5-", "-5", "10-5", "1,,3", "1-2-3", "1.5"] {
+            assert!(
+                matches!(value.parse::<EpisodeFilter>(), Err(AppError::ParseError(_))),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn matches_file_episodes() {
+        let episodes = filter("5-10");
+        assert!(episodes.matches(&info("05")));
+        assert!(!episodes.matches(&info("11")));
+        assert!(episodes.matches(&info("04+05")));
+        assert!(episodes.matches(&info("07A")));
+        assert!(!episodes.matches(&info("7.5")));
+    }
+}
@@ -22,6 +22,10 @@ pub enum AppError {
     #[error("源目录不存在: {0}")]
     SourceNotFound(PathBuf),
 
+    /// 通配符形式的源路径没有匹配到任何文件
+    #[error("通配符没有匹配到任何文件: {0}")]
+    GlobNoMatch(PathBuf),
+
     /// 目标目录不存在
     #[error("目标目录不存在: {0}")]
     TargetNotFound(PathBuf),
@@ -59,6 +63,34 @@ pub enum AppError {
     #[error("图片下载失败: {0}")]
     ImageDownloadError(String),
 
+    /// 整理计划文件无效
+    #[error("整理计划无效: {0}")]
+    PlanError(String),
+
+    /// 事务中某个操作失败，此前已完成的操作已撤销
+    #[error(
+        "{error}（已撤销 {rolled_back} 个已完成的操作，{} 个撤销失败）",
+        .rollback_errors.len()
+    )]
+    TransactionRolledBack {
+        /// 导致回滚的错误
+        error: Box<AppError>,
+        /// 已尝试撤销的操作数
+        rolled_back: usize,
+        /// 撤销失败的操作
+        rollback_errors: Vec<AppError>,
+    },
+
+    /// 增量整理状态文件无效
+    #[error("状态文件无效: {0}")]
+    StateError(String),
+
+    /// 操作模式取值无效
+    #[error(
+        "无效的操作模式 {0:?}，可选值: move（别名 mv）、copy（别名 cp）、link（别名 hardlink、ln）、auto"
+    )]
+    InvalidOperationMode(String),
+
     /// 别名库加载失败
     #[error("别名库加载失败: {0}")]
     AliasLoadError(String),
@@ -86,6 +118,18 @@ pub enum AppError {
     /// Torrent 响应解析失败
     #[error("Torrent 解析失败: {0}")]
     TorrentParseError(String),
+
+    /// 整理后钩子命令执行失败
+    #[error("整理后命令执行失败: {0}")]
+    HookError(String),
+
+    /// 整理结束通知发送失败
+    #[error("通知发送失败: {0}")]
+    NotifyError(String),
+
+    /// 目录监听失败
+    #[error("目录监听失败: {0}")]
+    WatchError(String),
 }
 
 /// 应用程序结果类型别名
@@ -0,0 +1,594 @@
+//! 文件系统抽象模块
+//!
+//! [`FileOrganizer`](crate::organizer::FileOrganizer) 通过 [`FileSystem`] trait 执行写操作，
+//! 生产环境使用直接调用 `std::fs` 的 [`StdFileSystem`]，测试可注入内存实现来模拟
+//! 跨设备（`EXDEV`）、权限不足等难以在真实文件系统上复现的错误。
+//!
+//! 目标是否已存在、内容比对等只读检查仍直接访问真实文件系统。
+
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+
+/// 分块复制时每次读写的字节数
+pub const COPY_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// 整理过程中用到的文件写操作
+pub trait FileSystem {
+    /// 复制文件内容，返回写入的字节数
+    fn copy(&self, from: &Path, to: &Path) -> io::Result<u64>;
+    /// 重命名或移动文件
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()>;
+    /// 创建硬链接
+    fn hard_link(&self, original: &Path, link: &Path) -> io::Result<()>;
+    /// 在 `link` 处创建指向文件 `original` 的符号链接
+    fn symlink(&self, original: &Path, link: &Path) -> io::Result<()>;
+    /// 递归创建目录
+    fn create_dir_all(&self, path: &Path) -> io::Result<()>;
+    /// 删除文件
+    fn remove_file(&self, path: &Path) -> io::Result<()>;
+    /// 删除空目录
+    fn remove_dir(&self, path: &Path) -> io::Result<()>;
+    /// 两个已存在的路径是否位于同一文件系统，无法判断时返回 `None`；
+    /// 默认实现见 [`same_device`]
+    fn same_filesystem(&self, left: &Path, right: &Path) -> Option<bool> {
+        same_device(left, right)
+    }
+}
+
+/// 基于 `std::fs` 的真实文件系统
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdFileSystem;
+
+impl FileSystem for StdFileSystem {
+    fn copy(&self, from: &Path, to: &Path) -> io::Result<u64> {
+        std::fs::copy(from, to)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        std::fs::rename(from, to)
+    }
+
+    fn hard_link(&self, original: &Path, link: &Path) -> io::Result<()> {
+        std::fs::hard_link(original, link)
+    }
+
+    fn symlink(&self, original: &Path, link: &Path) -> io::Result<()> {
+        #[cfg(unix)]
+        return std::os::unix::fs::symlink(original, link);
+        // Windows 创建符号链接需要管理员权限或开发者模式，失败时由调用方回退。
+        #[cfg(windows)]
+        return std::os::windows::fs::symlink_file(original, link);
+        #[cfg(not(any(unix, windows)))]
+        {
+            let _ = (original, link);
+            Err(io::Error::from(io::ErrorKind::Unsupported))
+        }
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        std::fs::create_dir_all(path)
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        std::fs::remove_file(path)
+    }
+
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        std::fs::remove_dir(path)
+    }
+}
+
+/// 读取文件的硬链接数；当前平台无法获取时返回 `None`（目前仅 Unix 支持）。
+///
+/// # 错误
+///
+/// 读取文件元数据失败时返回 I/O 错误。
+pub fn hard_link_count(path: &Path) -> io::Result<Option<u64>> {
+    let metadata = fs::metadata(path)?;
+    #[cfg(unix)]
+    return Ok(Some(std::os::unix::fs::MetadataExt::nlink(&metadata)));
+    #[cfg(not(unix))]
+    {
+        let _ = metadata;
+        Ok(None)
+    }
+}
+
+/// 打开文件并读取首字节，确认文件可读；空文件视为可读。
+///
+/// # 错误
+///
+/// 打开或读取失败时返回 I/O 错误。
+pub fn check_readable(path: &Path) -> io::Result<()> {
+    let mut byte = [0u8; 1];
+    io::Read::read(&mut fs::File::open(path)?, &mut byte)?;
+    Ok(())
+}
+
+/// 两个路径是否位于同一文件系统（设备）；当前平台无法判断或读取元数据失败时返回 `None`（目前仅 Unix 支持）。
+#[must_use]
+pub fn same_device(left: &Path, right: &Path) -> Option<bool> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        Some(fs::metadata(left).ok()?.dev() == fs::metadata(right).ok()?.dev())
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = (left, right);
+        None
+    }
+}
+
+/// 在 `parent` 下查找与 `name` 仅大小写不同的已存在目录，返回其实际名称。
+///
+/// 存在完全同名的目录、没有大小写变体或 `parent` 无法读取时返回 `None`；
+/// 有多个变体时取按名称排序的第一个。
+///
+/// ```
+/// use anime_organizer::filesystem::find_case_variant_dir;
+///
+/// let dir = tempfile::tempdir().unwrap();
+/// std::fs::create_dir(dir.path().join("anime")).unwrap();
+/// assert_eq!(find_case_variant_dir(dir.path(), "Anime").as_deref(), Some("anime"));
+/// assert_eq!(find_case_variant_dir(dir.path(), "Other"), None);
+/// ```
+#[must_use]
+pub fn find_case_variant_dir(parent: &Path, name: &str) -> Option<String> {
+    let lower = name.to_lowercase();
+    let mut variants = fs::read_dir(parent)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|dir_name| dir_name.to_lowercase() == lower)
+        .collect::<Vec<_>>();
+    if variants.iter().any(|dir_name| dir_name == name) {
+        return None;
+    }
+    variants.sort();
+    variants.into_iter().next()
+}
+
+/// 复制文件并按块回报进度，回调参数为（已复制字节数, 总字节数）。
+///
+/// `progress` 为 `None` 时直接使用 `std::fs::copy`，以保留平台的快速复制路径；
+/// 否则按 [`COPY_CHUNK_SIZE`] 分块读写，每写完一块回调一次。与 `std::fs::copy`
+/// 一样会覆盖已存在的目标并复制权限位。
+///
+/// ```
+/// use anime_organizer::filesystem::copy_with_progress;
+/// use std::cell::Cell;
+///
+/// let dir = tempfile::tempdir()?;
+/// let (from, to) = (dir.path().join("a.mkv"), dir.path().join("b.mkv"));
+/// std::fs::write(&from, b"video")?;
+/// let last = Cell::new((0, 0));
+/// let progress = |copied, total| last.set((copied, total));
+/// assert_eq!(copy_with_progress(&from, &to, Some(&progress))?, 5);
+/// assert_eq!(last.get(), (5, 5));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn copy_with_progress(
+    from: &Path,
+    to: &Path,
+    progress: Option<&dyn Fn(u64, u64)>,
+) -> io::Result<u64> {
+    copy_with_options(
+        from,
+        to,
+        &CopyOptions {
+            progress,
+            ..CopyOptions::default()
+        },
+    )
+}
+
+/// 限速器使用的时钟，测试可注入模拟实现
+pub trait Clock {
+    /// 自某一固定起点以来经过的时间，单调不减
+    fn elapsed(&self) -> Duration;
+    /// 休眠指定时长
+    fn sleep(&self, duration: Duration);
+}
+
+static SYSTEM_CLOCK_ORIGIN: LazyLock<Instant> = LazyLock::new(Instant::now);
+
+/// 基于 [`Instant`] 与 [`std::thread::sleep`] 的真实时钟
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn elapsed(&self) -> Duration {
+        SYSTEM_CLOCK_ORIGIN.elapsed()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+/// 按平均速率限流：每消耗一批字节后，若实际耗时短于该速率下应耗的时间则休眠补足。
+///
+/// ```
+/// use anime_organizer::filesystem::{Clock, RateLimiter};
+/// use std::cell::Cell;
+/// use std::time::Duration;
+///
+/// struct MockClock(Cell<Duration>);
+/// impl Clock for MockClock {
+///     fn elapsed(&self) -> Duration { self.0.get() }
+///     fn sleep(&self, duration: Duration) { self.0.set(self.0.get() + duration) }
+/// }
+///
+/// let clock = MockClock(Cell::new(Duration::ZERO));
+/// let mut limiter = RateLimiter::new(1000, &clock);
+/// limiter.consume(500);
+/// limiter.consume(1500);
+/// assert_eq!(clock.elapsed(), Duration::from_secs(2));
+/// ```
+pub struct RateLimiter<'a> {
+    bytes_per_second: u64,
+    clock: &'a dyn Clock,
+    started: Duration,
+    consumed: u64,
+}
+
+impl<'a> RateLimiter<'a> {
+    /// 以当前时刻为起点创建限速器；`bytes_per_second` 为 0 时按 1 处理
+    #[must_use]
+    pub fn new(bytes_per_second: u64, clock: &'a dyn Clock) -> Self {
+        Self {
+            bytes_per_second: bytes_per_second.max(1),
+            clock,
+            started: clock.elapsed(),
+            consumed: 0,
+        }
+    }
+
+    /// 记录已传输的字节数，必要时休眠使平均速率不超过上限
+    pub fn consume(&mut self, bytes: u64) {
+        self.consumed += bytes;
+        let expected = Duration::from_secs_f64(self.consumed as f64 / self.bytes_per_second as f64);
+        let elapsed = self.clock.elapsed().saturating_sub(self.started);
+        if expected > elapsed {
+            self.clock.sleep(expected - elapsed);
+        }
+    }
+
+    /// 限速时每次读写的块大小：约 0.1 秒的数据量，避免低速率下一次写入一大块后长时间停顿
+    #[must_use]
+    pub fn chunk_size(&self) -> usize {
+        usize::try_from(self.bytes_per_second / 10)
+            .unwrap_or(COPY_CHUNK_SIZE)
+            .clamp(MIN_LIMITED_CHUNK_SIZE, COPY_CHUNK_SIZE)
+    }
+}
+
+/// 限速时的最小块大小
+const MIN_LIMITED_CHUNK_SIZE: usize = 4 * 1024;
+
+/// 分块复制的选项
+#[derive(Clone, Copy)]
+pub struct CopyOptions<'a> {
+    /// 进度回调，参数见 [`copy_with_progress`]
+    pub progress: Option<&'a dyn Fn(u64, u64)>,
+    /// 限速（字节/秒），`None` 表示不限速
+    pub limit_rate: Option<u64>,
+    /// 限速使用的时钟
+    pub clock: &'a dyn Clock,
+}
+
+impl Default for CopyOptions<'_> {
+    fn default() -> Self {
+        Self {
+            progress: None,
+            limit_rate: None,
+            clock: &SystemClock,
+        }
+    }
+}
+
+/// 按选项复制文件：既无进度回调也不限速时直接使用 `std::fs::copy`，否则分块读写。
+///
+/// 限速时每个文件单独计时（见 [`RateLimiter`]），平均速率不超过 `limit_rate`。
+pub fn copy_with_options(from: &Path, to: &Path, options: &CopyOptions<'_>) -> io::Result<u64> {
+    if options.progress.is_none() && options.limit_rate.is_none() {
+        return fs::copy(from, to);
+    }
+    let progress = options.progress.unwrap_or(&|_, _| {});
+    let mut limiter = options
+        .limit_rate
+        .map(|rate| RateLimiter::new(rate, options.clock));
+    let chunk_size = limiter
+        .as_ref()
+        .map_or(COPY_CHUNK_SIZE, RateLimiter::chunk_size);
+    let mut reader = File::open(from)?;
+    let metadata = reader.metadata()?;
+    let total = metadata.len();
+    let mut writer = File::create(to)?;
+    let mut buffer = vec![0; chunk_size];
+    let mut copied = 0;
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        writer.write_all(&buffer[..read])?;
+        copied += read as u64;
+        if let Some(limiter) = &mut limiter {
+            limiter.consume(read as u64);
+        }
+        progress(copied, total.max(copied));
+    }
+    if copied == 0 {
+        progress(0, 0);
+    }
+    writer.flush()?;
+    fs::set_permissions(to, metadata.permissions())?;
+    Ok(copied)
+}
+
+/// 按 [`CopyOptions`] 分块复制（回报进度、限速）的真实文件系统，其余操作与 [`StdFileSystem`] 相同
+pub struct ChunkedFileSystem<'a> {
+    options: CopyOptions<'a>,
+}
+
+impl<'a> ChunkedFileSystem<'a> {
+    /// 使用复制选项创建
+    #[must_use]
+    pub fn new(options: CopyOptions<'a>) -> Self {
+        Self { options }
+    }
+}
+
+impl FileSystem for ChunkedFileSystem<'_> {
+    fn copy(&self, from: &Path, to: &Path) -> io::Result<u64> {
+        copy_with_options(from, to, &self.options)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        StdFileSystem.rename(from, to)
+    }
+
+    fn hard_link(&self, original: &Path, link: &Path) -> io::Result<()> {
+        StdFileSystem.hard_link(original, link)
+    }
+
+    fn symlink(&self, original: &Path, link: &Path) -> io::Result<()> {
+        StdFileSystem.symlink(original, link)
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        StdFileSystem.create_dir_all(path)
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        StdFileSystem.remove_file(path)
+    }
+
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        StdFileSystem.remove_dir(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+
+    fn copy_both(content: &[u8]) -> (Vec<u8>, Vec<u8>, Vec<(u64, u64)>) {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source.mkv");
+        fs::write(&source, content).unwrap();
+        let calls = RefCell::new(Vec::new());
+
+        let std_copied = fs::copy(&source, dir.path().join("std.mkv")).unwrap();
+        let chunked_copied = copy_with_progress(
+            &source,
+            &dir.path().join("chunked.mkv"),
+            Some(&|copied, total| calls.borrow_mut().push((copied, total))),
+        )
+        .unwrap();
+
+        assert_eq!(std_copied, chunked_copied);
+        (
+            fs::read(dir.path().join("std.mkv")).unwrap(),
+            fs::read(dir.path().join("chunked.mkv")).unwrap(),
+            calls.into_inner(),
+        )
+    }
+
+    #[test]
+    fn check_readable_reads_files_and_rejects_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let video = dir.path().join("video.mkv");
+        let empty = dir.path().join("empty.mkv");
+        fs::write(&video, b"video").unwrap();
+        fs::write(&empty, b"").unwrap();
+
+        assert!(check_readable(&video).is_ok());
+        assert!(check_readable(&empty).is_ok());
+        assert!(check_readable(dir.path()).is_err());
+        assert!(check_readable(&dir.path().join("missing.mkv")).is_err());
+    }
+
+    #[test]
+    fn chunked_copy_matches_std_copy() {
+        let content = (0..COPY_CHUNK_SIZE * 2 + 123)
+            .map(|index| (index % 251) as u8)
+            .collect::<Vec<_>>();
+        let (std_content, chunked_content, calls) = copy_both(&content);
+
+        assert_eq!(std_content, content);
+        assert_eq!(chunked_content, std_content);
+        let total = content.len() as u64;
+        assert_eq!(
+            calls,
+            [
+                (COPY_CHUNK_SIZE as u64, total),
+                (COPY_CHUNK_SIZE as u64 * 2, total),
+                (total, total)
+            ]
+        );
+    }
+
+    #[test]
+    fn chunked_copy_reports_empty_file() {
+        let (std_content, chunked_content, calls) = copy_both(b"");
+
+        assert!(std_content.is_empty() && chunked_content.is_empty());
+        assert_eq!(calls, [(0, 0)]);
+    }
+
+    #[test]
+    fn chunked_copy_overwrites_and_keeps_permissions() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source.mkv");
+        let target = dir.path().join("target.mkv");
+        fs::write(&source, b"new").unwrap();
+        fs::write(&target, b"old content").unwrap();
+        let mut permissions = fs::metadata(&source).unwrap().permissions();
+        permissions.set_readonly(true);
+        fs::set_permissions(&source, permissions).unwrap();
+
+        copy_with_progress(&source, &target, Some(&|_, _| {})).unwrap();
+
+        assert_eq!(fs::read(&target).unwrap(), b"new");
+        assert!(fs::metadata(&target).unwrap().permissions().readonly());
+    }
+
+    #[test]
+    fn chunked_file_system_copies_with_callback() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source.mkv");
+        fs::write(&source, b"video").unwrap();
+        let calls = RefCell::new(0);
+        let progress = |_, _| *calls.borrow_mut() += 1;
+
+        ChunkedFileSystem::new(CopyOptions {
+            progress: Some(&progress),
+            ..CopyOptions::default()
+        })
+        .copy(&source, &dir.path().join("target.mkv"))
+        .unwrap();
+
+        assert_eq!(*calls.borrow(), 1);
+        assert_eq!(fs::read(dir.path().join("target.mkv")).unwrap(), b"video");
+    }
+
+    /// 模拟时钟：休眠只推进时间，`work` 模拟读写本身的耗时
+    struct MockClock {
+        now: Cell<Duration>,
+        slept: Cell<Duration>,
+    }
+
+    impl MockClock {
+        fn new() -> Self {
+            Self {
+                now: Cell::new(Duration::from_secs(100)),
+                slept: Cell::new(Duration::ZERO),
+            }
+        }
+
+        fn work(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration);
+        }
+    }
+
+    impl Clock for MockClock {
+        fn elapsed(&self) -> Duration {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration);
+            self.slept.set(self.slept.get() + duration);
+        }
+    }
+
+    #[test]
+    fn rate_limiter_keeps_average_rate_under_limit() {
+        let clock = MockClock::new();
+        let start = clock.elapsed();
+        let mut limiter = RateLimiter::new(1000, &clock);
+        let mut transferred = 0u64;
+        for chunk in [100, 700, 50, 1200, 300, 650] {
+            clock.work(Duration::from_millis(20));
+            limiter.consume(chunk);
+            transferred += chunk;
+            let elapsed = (clock.elapsed() - start).as_secs_f64();
+            assert!(transferred as f64 / elapsed <= 1000.0 + 1e-6);
+        }
+        assert_eq!(clock.elapsed() - start, Duration::from_secs(3));
+    }
+
+    #[test]
+    fn rate_limiter_does_not_sleep_when_already_slow() {
+        let clock = MockClock::new();
+        let mut limiter = RateLimiter::new(1000, &clock);
+        clock.work(Duration::from_secs(2));
+        limiter.consume(1000);
+
+        assert_eq!(clock.slept.get(), Duration::ZERO);
+    }
+
+    #[test]
+    fn rate_limiter_chunk_size_follows_rate() {
+        let clock = MockClock::new();
+        assert_eq!(RateLimiter::new(1, &clock).chunk_size(), 4 * 1024);
+        assert_eq!(RateLimiter::new(0, &clock).chunk_size(), 4 * 1024);
+        assert_eq!(RateLimiter::new(1_000_000, &clock).chunk_size(), 100_000);
+        assert_eq!(
+            RateLimiter::new(u64::MAX, &clock).chunk_size(),
+            COPY_CHUNK_SIZE
+        );
+    }
+
+    #[test]
+    fn limited_copy_takes_at_least_size_over_rate() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source.mkv");
+        let content = vec![9u8; 100_000];
+        fs::write(&source, &content).unwrap();
+        let clock = MockClock::new();
+
+        let copied = copy_with_options(
+            &source,
+            &dir.path().join("target.mkv"),
+            &CopyOptions {
+                limit_rate: Some(50_000),
+                clock: &clock,
+                ..CopyOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(copied, 100_000);
+        assert_eq!(clock.slept.get(), Duration::from_secs(2));
+        assert_eq!(fs::read(dir.path().join("target.mkv")).unwrap(), content);
+    }
+
+    #[test]
+    fn case_variant_prefers_exact_directory_and_ignores_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("ANIME")).unwrap();
+        fs::write(dir.path().join("Show"), b"file").unwrap();
+
+        assert_eq!(find_case_variant_dir(dir.path(), "ANIME"), None);
+        assert_eq!(
+            find_case_variant_dir(dir.path(), "Anime").as_deref(),
+            Some("ANIME")
+        );
+        assert_eq!(find_case_variant_dir(dir.path(), "show"), None);
+        assert_eq!(
+            find_case_variant_dir(&dir.path().join("missing"), "x"),
+            None
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! 整理后钩子模块
+//!
+//! 每成功整理一个文件后执行用户指定的命令（如通知媒体服务器刷新库）。
+//! 命令按 POSIX shell 规则拆分参数（支持引号与转义），但不经过 shell 执行；
+//! 占位符在拆分后逐个参数替换，因此路径中的空格不会破坏参数边界。
+
+use crate::error::{AppError, Result};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// 目标文件路径占位符
+pub const TARGET_PLACEHOLDER: &str = "{target}";
+/// 番名占位符
+pub const ANIME_PLACEHOLDER: &str = "{anime}";
+
+/// 整理后执行的命令
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostHook {
+    args: Vec<String>,
+}
+
+impl PostHook {
+    /// 拆分命令字符串
+    ///
+    /// ```
+    /// use anime_organizer::hook::PostHook;
+    /// use std::path::Path;
+    ///
+    /// let hook = PostHook::parse("notify --file '{target}' --title {anime}").unwrap();
+    /// assert_eq!(
+    ///     hook.render(Path::new("/anime/A B/01.mkv"), "A B"),
+    ///     ["notify", "--file", "/anime/A B/01.mkv", "--title", "A B"]
+    /// );
+    /// ```
+    pub fn parse(command: &str) -> Result<Self> {
+        let args = shlex::split(command).ok_or_else(|| {
+            AppError::HookError(format!("无法拆分命令（引号未闭合？）: {command}"))
+        })?;
+        if args.is_empty() {
+            return Err(AppError::HookError("命令为空".to_string()));
+        }
+        Ok(Self { args })
+    }
+
+    /// 替换占位符，返回程序名与参数
+    #[must_use]
+    pub fn render(&self, target: &Path, anime: &str) -> Vec<String> {
+        let target = target.to_string_lossy();
+        self.args
+            .iter()
+            .map(|arg| {
+                arg.replace(TARGET_PLACEHOLDER, &target)
+                    .replace(ANIME_PLACEHOLDER, anime)
+            })
+            .collect()
+    }
+
+    /// 执行命令；启动失败或退出码非零时返回错误
+    pub fn run(&self, target: &Path, anime: &str) -> Result<()> {
+        let args = self.render(target, anime);
+        // 捕获输出，避免污染 `--output=csv` 的标准输出。
+        let output = Command::new(&args[0])
+            .args(&args[1..])
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|error| AppError::HookError(format!("{}: {error}", args[0])))?;
+        if output.status.success() {
+            return Ok(());
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(AppError::HookError(format!(
+            "{} 退出状态 {}{}",
+            args[0],
+            output.status,
+            if stderr.trim().is_empty() {
+                String::new()
+            } else {
+                format!(": {}", stderr.trim())
+            }
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_respects_quotes_and_escapes() {
+        let hook =
+            PostHook::parse(r#"curl -X POST "http://localhost/refresh?a=1 b" it\'s"#).unwrap();
+        assert_eq!(
+            hook.render(Path::new("x"), "y"),
+            [
+                "curl",
+                "-X",
+                "POST",
+                "http://localhost/refresh?a=1 b",
+                "it's"
+            ]
+        );
+    }
+
+    #[test]
+    fn placeholders_replaced_inside_arguments() {
+        let hook = PostHook::parse("echo --path={target} {anime}-{anime}").unwrap();
+        assert_eq!(
+            hook.render(Path::new("/a/b c.mkv"), "Show"),
+            ["echo", "--path=/a/b c.mkv", "Show-Show"]
+        );
+    }
+
+    #[test]
+    fn rejects_unbalanced_quotes_and_empty_command() {
+        assert!(matches!(
+            PostHook::parse("echo 'oops"),
+            Err(AppError::HookError(_))
+        ));
+        assert!(matches!(
+            PostHook::parse("   "),
+            Err(AppError::HookError(_))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_reports_non_zero_exit() {
+        assert!(PostHook::parse("true")
+            .unwrap()
+            .run(Path::new("x"), "y")
+            .is_ok());
+        let error = PostHook::parse("sh -c 'echo boom >&2; exit 3'")
+            .unwrap()
+            .run(Path::new("x"), "y")
+            .unwrap_err();
+        assert!(error.to_string().contains("boom"), "{error}");
+    }
+}
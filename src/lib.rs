@@ -31,27 +31,75 @@
 //! - [`parser`] - 文件名解析模块
 //! - [`organizer`] - 文件整理模块
 //! - [`error`] - 错误处理模块
+/// - `archive` - 压缩包解压模块（需 `archives` feature）
+/// - [`bdmv`] - 蓝光原盘目录模块（整个原盘目录作为一个单位整理）
+/// - [`cleanup`] - 临时文件清理模块
+/// - [`coverage`] - 解析覆盖率统计模块
+/// - [`dedup`] - 按内容去重模块
+/// - [`episode_filter`] - 按集数过滤模块
+/// - [`filesystem`] - 文件系统抽象（便于测试注入错误）
+/// - [`hook`] - 整理后钩子命令模块
 /// - [`metadata`] - 元数据模块（Bangumi 元数据，TMDB/AniDB 可选图片补充）
+/// - [`name_aliases`] - 番名别名映射模块
 /// - [`nfo`] - NFO 文件生成模块
+/// - [`notify`] - 整理结束通知模块（webhook 与桌面通知）
+/// - [`plan`] - 整理计划模块（dry-run 计划的保存与执行）
+/// - [`playlist`] - M3U 播放列表生成模块
+/// - [`renumber`] - 集数重编号模块（连续化集数）
+/// - [`report`] - 整理结果报告模块（CSV 导出）
+/// - [`scan`] - 并行目录扫描模块
+/// - [`state`] - 增量整理状态模块（跳过上次已处理的文件）
+/// - [`tags`] - 文件名标签分类模块
+/// - [`target_template`] - 目标根目录模板模块（按番名、年份分流）
+/// - [`time_filter`] - 按修改时间过滤模块
+/// - [`transaction`] - 可撤销的整理事务模块（失败时回滚已完成的操作）
+/// - [`watch`] - 目录监听模块（轮询检测新文件）
 /// - `scraper` - 数据源刮削模块（需 `scraper` feature，参考 `cargo doc --features scraper`）
 #[cfg(feature = "anifilebert")]
 pub mod anifilebert;
+#[cfg(feature = "archives")]
+pub mod archive;
+pub mod bdmv;
+pub mod cleanup;
+pub mod coverage;
+pub mod dedup;
+pub mod episode_filter;
 pub mod error;
+pub mod filesystem;
+pub mod hook;
 pub mod library_index;
 pub mod metadata;
+pub mod name_aliases;
 pub mod nfo;
+pub mod notify;
 pub mod organizer;
 pub mod parser;
+pub mod plan;
+pub mod playlist;
+pub mod renumber;
+pub mod report;
 #[cfg(feature = "clouddrive")]
 pub mod rss;
+pub mod scan;
 #[cfg(feature = "scraper")]
 pub mod scraper;
+pub mod state;
+pub mod tags;
+pub mod target_template;
+pub mod time_filter;
 #[cfg(feature = "torrent-scraper")]
 pub mod torrent;
+pub mod transaction;
+pub mod watch;
 
 pub use error::{AppError, Result};
 pub use library_index::{ExtraKind, LibraryExtraRecord, LibraryIndex, LibraryIndexRecord};
 pub use metadata::AnimeMetadata;
 pub use nfo::{EpisodeNfo, NfoWriter, TvShowNfo};
-pub use organizer::{FileOrganizer, OperationMode};
-pub use parser::{AnimeFileInfo, FilenameParser};
+pub use organizer::{
+    ConflictPolicy, ExecutedOp, FileOrganizer, OperationMode, OperationOutcome, OperationPlan,
+    OrganizeOptions, OrganizedFile, SameFileCheck, SkipReason,
+};
+pub use parser::{
+    compare_episodes, AnimeFileInfo, AnimeFileInfoBuilder, EpisodeKind, FilenameParser, SlugCjk,
+};
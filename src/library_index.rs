@@ -1329,11 +1329,23 @@ fn next_extra_ordinal(tokens: &[&str], index: usize) -> Option<i64> {
 
 fn parse_target_filename(file_name: &str) -> Option<(f64, String)> {
     let stem = Path::new(file_name).file_stem()?.to_str()?.trim();
-    let mut parts = stem.splitn(2, char::is_whitespace);
-    let episode_raw = parts.next()?.trim();
+    let digits_end = stem
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(stem.len());
+    let episode_raw = stem[..digits_end].trim_end_matches('.');
     let episode = episode_raw.parse::<f64>().ok()?;
-    let tags = parts.next().unwrap_or_default().trim().to_string();
-    Some((episode, tags))
+    let rest = &stem[episode_raw.len()..];
+    let tags = if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+        rest.trim()
+    } else {
+        // `--name-separator` 生成的 `01.[1080P]`、`01_[1080P]`
+        let tags = rest.trim_start_matches(|c: char| !c.is_alphanumeric() && c != '[' && c != '+');
+        if !tags.starts_with('[') {
+            return None;
+        }
+        tags
+    };
+    Some((episode, tags.to_string()))
 }
 
 fn season_directory_identity(components: &[String]) -> Option<(String, i64)> {
@@ -1343,9 +1355,26 @@ fn season_directory_identity(components: &[String]) -> Option<(String, i64)> {
     Some((series, season))
 }
 
+/// 识别 `Season N`、`SNN` 与 `第N季` 形式的季目录（见 `--season-format`）。
 fn parse_season_dir(value: &str) -> Option<i64> {
-    let lower = value.trim().to_ascii_lowercase();
-    let raw = lower.strip_prefix("season")?.trim();
+    let trimmed = value.trim();
+    let lower = trimmed.to_ascii_lowercase();
+    let raw = lower
+        .strip_prefix("season")
+        .or_else(|| {
+            lower
+                .strip_prefix('s')
+                .filter(|raw| raw.bytes().all(|b| b.is_ascii_digit()))
+        })
+        .or_else(|| {
+            trimmed
+                .strip_prefix('第')
+                .and_then(|raw| raw.strip_suffix('季'))
+        })?
+        .trim();
+    if raw.is_empty() || !raw.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
     raw.parse::<i64>().ok().filter(|season| *season > 0)
 }
 
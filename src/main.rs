@@ -12,12 +12,7 @@ mod mlip;
 mod title_resolver;
 
 use crate::cli::*;
-#[cfg(any(
-    feature = "scraper",
-    feature = "clouddrive",
-    feature = "torrent-scraper"
-))]
-use crate::commands::run_command;
+use crate::commands::{run_batch, run_command};
 #[cfg(feature = "metadata")]
 use crate::mlip::{
     anime_group_min_episode, apply_bangumi_episode_details, create_episode_nfo, download_images,
@@ -26,8 +21,28 @@ use crate::mlip::{
 #[cfg(feature = "metadata")]
 use anime_organizer::library_index::{Artwork, ArtworkKind};
 use anime_organizer::{
-    error::AppError, AnimeFileInfo, FileOrganizer, FilenameParser, LibraryExtraRecord,
-    LibraryIndex, LibraryIndexRecord, OperationMode,
+    bdmv, cleanup,
+    dedup::{self, ContentIndex, DedupDecision},
+    episode_filter::EpisodeFilter,
+    error::AppError,
+    filesystem::{self, ChunkedFileSystem, CopyOptions},
+    hook::PostHook,
+    name_aliases::NameAliases,
+    notify::{self, NotifySummary},
+    parser,
+    plan::{OrganizePlan, PlanEstimate, PlanOptions},
+    playlist::{self, PlaylistEntry},
+    renumber::{self, EpisodeRenumber},
+    report::{
+        self, OrganizeSummary, RecordStatus, RelativePaths, ReportRecord, SkipStats, TransferStats,
+    },
+    scan::{self, ScanOptions},
+    state::{FileStamp, ProcessedState},
+    target_template::TargetTemplate,
+    time_filter::{modified_since, NewerThan},
+    AnimeFileInfo, EpisodeKind, FileOrganizer, FilenameParser, LibraryExtraRecord, LibraryIndex,
+    LibraryIndexRecord, OperationMode, OperationOutcome, OperationPlan, OrganizeOptions,
+    OrganizedFile, SkipReason,
 };
 #[cfg(feature = "metadata")]
 use anime_organizer::{
@@ -35,15 +50,22 @@ use anime_organizer::{
     nfo::{NfoWriter, TvShowNfo},
     AnimeMetadata,
 };
-use clap::Parser;
+#[cfg(feature = "daemon")]
+use clap::{parser::ValueSource, ArgMatches};
+use clap::{CommandFactory, FromArgMatches};
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 #[cfg(feature = "metadata")]
 use std::sync::Arc;
+use std::time::{Instant, SystemTime};
 use walkdir::WalkDir;
 
+/// 不小于该大小的文件复制时显示字节级进度
+const COPY_PROGRESS_MIN_BYTES: u64 = 64 * 1024 * 1024;
 /// 默认支持的视频扩展名
 const DEFAULT_EXTENSIONS: &[&str] = &[".mp4", ".mkv", ".avi", ".mov", ".wmv", ".flv", ".rmvb"];
 #[cfg(feature = "metadata")]
@@ -59,89 +81,59 @@ const HTTP_USER_AGENT: &str = concat!(
     env!("CARGO_PKG_VERSION"),
     " (https://github.com/ModerRAS/anime-organizer)"
 );
+/// 运行出错（参数、目录或 IO 等错误）时的退出码
+const EXIT_ERROR: i32 = 1;
+/// 部分文件整理失败时的退出码（`2` 留给 clap 的参数用法错误）
+const EXIT_PARTIAL_FAILURE: i32 = 3;
+/// 所有文件均整理失败时的退出码
+const EXIT_ALL_FAILED: i32 = 4;
+/// `--output ndjson` 的 stdout；每行在一次加锁内写完并 flush，多线程输出时各行不交错
+static NDJSON_STDOUT: std::sync::LazyLock<std::sync::Mutex<std::io::Stdout>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(std::io::stdout()));
 static ANIFILEBERT_AUTO_WARNED: std::sync::OnceLock<()> = std::sync::OnceLock::new();
 
 fn main() {
-    if let Err(error) = run() {
-        eprintln!("错误: {error}");
-        std::process::exit(1);
+    match run() {
+        Ok(summary) => std::process::exit(summary.exit_code()),
+        Err(error) => {
+            eprintln!("错误: {error}");
+            std::process::exit(EXIT_ERROR);
+        }
     }
 }
 
-#[cfg(any(
-    feature = "scraper",
-    feature = "clouddrive",
-    feature = "torrent-scraper"
-))]
-fn run() -> Result<(), AppError> {
-    let cli = Cli::parse();
+fn run() -> Result<RunSummary, AppError> {
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|error| error.exit());
 
     #[cfg(feature = "daemon")]
     if cli.daemon {
-        reject_daemon_conflicts(&cli)?;
-        return daemon::run();
-    }
-
-    if let Some(command) = cli.command {
-        return run_command(command);
+        reject_daemon_conflicts(&cli, &matches)?;
+        return daemon::run().map(|()| RunSummary::default());
     }
 
-    run_organize_entry(cli.organize)
-}
-
-#[cfg(not(any(
-    feature = "scraper",
-    feature = "clouddrive",
-    feature = "torrent-scraper"
-)))]
-fn run() -> Result<(), AppError> {
-    let cli = Cli::parse();
-
-    #[cfg(feature = "daemon")]
-    if cli.daemon {
-        reject_daemon_conflicts(&cli)?;
-        return daemon::run();
+    match cli.command {
+        Some(Commands::Batch(args)) => return run_batch(&args),
+        Some(command) => return run_command(command).map(|()| RunSummary::default()),
+        None => {}
     }
 
     run_organize_entry(cli.organize)
 }
 
 #[cfg(feature = "daemon")]
-fn reject_daemon_conflicts(cli: &Cli) -> Result<(), AppError> {
-    #[cfg(any(
-        feature = "scraper",
-        feature = "clouddrive",
-        feature = "torrent-scraper"
-    ))]
+fn reject_daemon_conflicts(cli: &Cli, matches: &ArgMatches) -> Result<(), AppError> {
     if cli.command.is_some() {
         return Err(AppError::ParseError(
             "--daemon cannot be combined with a subcommand".to_string(),
         ));
     }
 
-    let args = &cli.organize;
-    if args.source.is_some()
-        || args.target.is_some()
-        || args.fallback_on_link_failure.is_some()
-        || args.dry_run
-        || args.include_ext.is_some()
-        || args.verbose
-        || args.scrape_metadata
-        || args.tmdb_api_key.is_some()
-        || args.alias_file.is_some()
-        || args.no_images
-        || args.no_episode_metadata
-        || args.force_overwrite
-        || args.bangumi_cache.is_some()
-        || args.metadata_source.is_some()
-        || args.season_mode
-        || args.library_index
-        || args.mlip
-        || args.rebuild_library_index
-        || args.probe_runtime
-        || args.mode != OperationMode::Link
-        || args.filename_parser != FilenameParserMode::Rules
-    {
+    // 只看命令行上实际给出的参数：来自 `ANIORG_*` 环境变量或默认值的不算冲突
+    if Cli::command().get_arguments().any(|arg| {
+        arg.get_id() != "daemon"
+            && matches.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine)
+    }) {
         return Err(AppError::ParseError(
             "--daemon cannot be combined with organize arguments".to_string(),
         ));
@@ -150,7 +142,7 @@ fn reject_daemon_conflicts(cli: &Cli) -> Result<(), AppError> {
 }
 
 #[cfg(feature = "metadata")]
-pub(crate) fn run_organize_entry(args: OrganizeArgs) -> Result<(), AppError> {
+pub(crate) fn run_organize_entry(args: OrganizeArgs) -> Result<RunSummary, AppError> {
     run_organize_entry_with_log(args, &|_| {})
 }
 
@@ -158,18 +150,27 @@ pub(crate) fn run_organize_entry(args: OrganizeArgs) -> Result<(), AppError> {
 pub(crate) fn run_organize_entry_with_log(
     args: OrganizeArgs,
     log: &dyn Fn(&str),
-) -> Result<(), AppError> {
-    if args.scrape_metadata || args.mlip {
+) -> Result<RunSummary, AppError> {
+    validate_target_args(&args)?;
+    print_output_header(args.output);
+    let notify_args = wants_notification(&args).then(|| args.clone());
+    let result = if args.scrape_metadata || args.mlip {
         let runtime = tokio::runtime::Runtime::new()
             .map_err(|e| AppError::MetadataFetchError(format!("创建异步运行时失败: {e}")))?;
-        runtime.block_on(run_with_metadata(args, log))
+        for_each_target(args, |args, outputs| {
+            runtime.block_on(run_with_metadata(args, outputs, log))
+        })
     } else {
-        run_organize(args, log)
+        for_each_target(args, |args, outputs| run_organize(args, outputs, log))
+    };
+    if let Some(args) = notify_args {
+        notify_finished(&args, &result);
     }
+    result
 }
 
 #[cfg(not(feature = "metadata"))]
-pub(crate) fn run_organize_entry(args: OrganizeArgs) -> Result<(), AppError> {
+pub(crate) fn run_organize_entry(args: OrganizeArgs) -> Result<RunSummary, AppError> {
     run_organize_entry_with_log(args, &|_| {})
 }
 
@@ -177,46 +178,236 @@ pub(crate) fn run_organize_entry(args: OrganizeArgs) -> Result<(), AppError> {
 pub(crate) fn run_organize_entry_with_log(
     args: OrganizeArgs,
     log: &dyn Fn(&str),
-) -> Result<(), AppError> {
+) -> Result<RunSummary, AppError> {
     if args.scrape_metadata || args.mlip {
         return Err(AppError::MetadataFetchError(
             "元数据功能未启用，请使用 --features metadata 编译".to_string(),
         ));
     }
 
-    run_organize(args, log)
+    validate_target_args(&args)?;
+    print_output_header(args.output);
+    let notify_args = wants_notification(&args).then(|| args.clone());
+    let result = for_each_target(args, |args, outputs| run_organize(args, outputs, log));
+    if let Some(args) = notify_args {
+        notify_finished(&args, &result);
+    }
+    result
+}
+
+/// 多个 `--target` 时对每个目标分别整理一次，统计按目标分别输出后合并；
+/// 增量状态等跨目标的输出（见 [`RunOutputs`]）在全部目标处理完后统一写出。
+///
+/// 某个目标出错（如目录不存在）时继续处理其余目标，最后返回第一个错误。
+fn for_each_target(
+    args: OrganizeArgs,
+    mut run: impl FnMut(OrganizeArgs, &mut RunOutputs) -> Result<RunSummary, AppError>,
+) -> Result<RunSummary, AppError> {
+    let mut outputs = RunOutputs::load(&args)?;
+    let result = run_each_target(&args, &mut outputs, &mut run);
+    outputs.save(&args, result.is_ok())?;
+    result
+}
+
+fn run_each_target(
+    args: &OrganizeArgs,
+    outputs: &mut RunOutputs,
+    run: &mut impl FnMut(OrganizeArgs, &mut RunOutputs) -> Result<RunSummary, AppError>,
+) -> Result<RunSummary, AppError> {
+    if args.target.len() <= 1 {
+        return run(args.clone(), outputs);
+    }
+
+    let mut total = RunSummary::default();
+    let mut first_error = None;
+    for target in &args.target {
+        report_line(args, &format!("==> 目标: {}", target.display()));
+        let target_args = OrganizeArgs {
+            target: vec![target.clone()],
+            ..args.clone()
+        };
+        match run(target_args, outputs) {
+            Ok(summary) => {
+                total.succeeded += summary.succeeded;
+                total.skipped += summary.skipped;
+                total.failed += summary.failed;
+            }
+            Err(error) => {
+                eprintln!("目标 {} 处理失败: {error}", target.display());
+                first_error.get_or_insert(error);
+            }
+        }
+    }
+    match first_error {
+        Some(error) => Err(error),
+        None => Ok(total),
+    }
+}
+
+/// 多个 `--target` 共用、全部目标处理完后统一写出的结果，避免后一个目标读到或覆盖前一个目标的输出
+#[derive(Debug, Default)]
+struct RunOutputs {
+    /// `--state`：运行开始时读取的增量状态，各目标都据此判断文件是否未变动
+    processed_state: Option<ProcessedState>,
+    /// 本次成功处理的源文件及其整理前的指纹
+    processed: Vec<(PathBuf, FileStamp)>,
+    /// 本次在任一目标上失败的源文件，供 `--failures-out` 写出
+    failed: Vec<PathBuf>,
+    /// 各目标预览期间累计的计划操作，供 `--plan-out` 合并写出
+    planned: Vec<OperationPlan>,
+}
+
+impl RunOutputs {
+    fn load(args: &OrganizeArgs) -> Result<Self, AppError> {
+        Ok(Self {
+            processed_state: load_processed_state(args)?,
+            ..Self::default()
+        })
+    }
+
+    /// 写出跨目标的结果。增量状态只记录在所有目标上都未失败的文件；
+    /// 有目标整体出错（如目录不存在）时不写回，以免下次跳过该目标尚未处理的文件。
+    fn save(self, args: &OrganizeArgs, all_targets_ok: bool) -> Result<(), AppError> {
+        save_failures(args, &self.failed)?;
+        save_plan(args, self.planned)?;
+        if let Some(mut state) = self.processed_state.filter(|_| all_targets_ok) {
+            let failed: HashSet<&PathBuf> = self.failed.iter().collect();
+            for (path, stamp) in &self.processed {
+                if !failed.contains(path) {
+                    state.record(path, *stamp);
+                }
+            }
+            save_processed_state(args, Some(state))?;
+        }
+        Ok(())
+    }
+}
+
+fn wants_notification(args: &OrganizeArgs) -> bool {
+    args.notify.is_some() || args.notify_desktop
+}
+
+/// `--notify` / `--notify-desktop`：整理结束后发送处理摘要，发送失败只警告，不影响整理结果。
+fn notify_finished(args: &OrganizeArgs, result: &Result<RunSummary, AppError>) {
+    let (summary, error) = match result {
+        Ok(summary) => (*summary, None),
+        Err(error) => (RunSummary::default(), Some(error.to_string())),
+    };
+    let summary = NotifySummary {
+        source: args
+            .source
+            .as_ref()
+            .map_or_else(|| ".".to_string(), |source| source.display().to_string()),
+        targets: args
+            .target
+            .iter()
+            .map(|target| target.display().to_string())
+            .collect(),
+        mode: args.mode,
+        dry_run: args.dry_run,
+        succeeded: summary.succeeded,
+        skipped: summary.skipped,
+        failed: summary.failed,
+        error,
+    };
+    if let Some(url) = &args.notify {
+        if let Err(error) = notify::post_webhook(url, &summary) {
+            eprintln!("警告: {error}");
+        }
+    }
+    if args.notify_desktop {
+        if let Err(error) = notify::send_desktop(&summary) {
+            eprintln!("警告: {error}");
+        }
+    }
+}
+
+fn validate_target_args(args: &OrganizeArgs) -> Result<(), AppError> {
+    if args.target.len() > 1 && args.mode == OperationMode::Move {
+        return Err(AppError::ParseError(
+            "多个 --target 不能与 --mode=move 同时使用".to_string(),
+        ));
+    }
+    Ok(())
 }
 
 /// 仅文件整理流程（无元数据）
-fn run_organize(args: OrganizeArgs, log: &dyn Fn(&str)) -> Result<(), AppError> {
+fn run_organize(
+    args: OrganizeArgs,
+    outputs: &mut RunOutputs,
+    log: &dyn Fn(&str),
+) -> Result<RunSummary, AppError> {
+    let started = Instant::now();
     validate_library_index_args(&args)?;
     validate_filename_parser_args(&args)?;
+    validate_season_format_args(&args)?;
+    validate_name_separator_args(&args)?;
+    validate_episode_range_format_args(&args)?;
+    validate_specials_dir_args(&args)?;
+    validate_temp_suffix_args(&args)?;
+    validate_leave_symlink_args(&args)?;
+    let target_template = load_target_template(&args)?;
     let (source, target) = resolve_source_and_target(&args)?;
-    let fallback_mode = args
-        .fallback_on_link_failure
-        .map(FallbackMode::to_operation_mode);
-    let extensions = build_extensions(&args.include_ext);
+    let extensions = build_extensions(&args);
     let subtitle_candidates = FileOrganizer::collect_external_subtitle_candidates(&source);
+    let file_context =
+        FileOperationContext::new(&args, &source, &target, &subtitle_candidates, log);
+    warn_read_only_link(&args);
     log(&format!("Scanning {}", source.display()));
     let probe_runtime = runtime_probe_enabled(&args);
 
     let mut processed = 0;
     let mut succeeded = 0;
+    let mut skipped = 0;
     let mut failed = 0;
+    let mut transferred_bytes = 0;
+    let mut outcomes: Vec<(String, bool, u64)> = Vec::new();
+    let mut failures: Vec<PathBuf> = Vec::new();
+    let mut content_index = args.dedup_by_content.then(ContentIndex::new);
     let mut library_records = Vec::new();
-
-    for entry in WalkDir::new(&source)
-        .into_iter()
-        .filter_map(|item| item.ok())
-        .filter(|item| item.file_type().is_file())
-    {
-        let path = entry.path();
-        if !has_valid_extension(path, &extensions) {
+    let mut playlists: HashMap<PathBuf, Vec<PlaylistEntry>> = HashMap::new();
+
+    let name_aliases = load_name_aliases(&args)?;
+    let post_hook = load_post_hook(&args)?;
+    let episode_filter = load_episode_filter(&args)?;
+    let discs = bdmv_discs(&source, &target, &args);
+    let mut source_files = collect_source_files(&source, &target, &extensions, &args, &discs)?;
+    let mut skips = std::mem::take(&mut source_files.skips);
+    let disc_summary = organize_bdmv_discs(&discs, &target, &args, &mut skips);
+    processed += disc_summary.processed;
+    succeeded += disc_summary.succeeded;
+    skipped += disc_summary.skipped;
+    failed += disc_summary.failed;
+    let renumbered = renumber_source_files(&source_files.files, &args, &name_aliases)?;
+    let non_preferred = non_preferred_source_files(&source_files.files, &args, &name_aliases)?;
+    for path in &source_files.files {
+        let path = path.as_path();
+        if outputs
+            .processed_state
+            .as_ref()
+            .is_some_and(|state| state.is_unchanged(path))
+        {
+            if args.verbose {
+                eprintln!("跳过：上次已处理且未变动 {}", path.display());
+            }
+            skips.record(SkipReason::Unchanged);
             continue;
         }
-
-        let anime_file = match parse_anime_file(path, args.filename_parser, args.verbose)? {
-            Some(info) => info,
+        let anime_file = match parse_anime_file(path, &args, args.verbose)? {
+            Some(info) => match apply_episode_offset(info, args.episode_offset) {
+                Some(info) => {
+                    let mut info = normalize_anime_name(info, &args, &name_aliases);
+                    if let Some(episode) = renumbered.get(&info.original_path) {
+                        info.episode.clone_from(episode);
+                    }
+                    info
+                }
+                None => {
+                    failed += 1;
+                    failures.push(path.to_path_buf());
+                    continue;
+                }
+            },
             None => {
                 if args.verbose {
                     eprintln!(
@@ -224,30 +415,72 @@ fn run_organize(args: OrganizeArgs, log: &dyn Fn(&str)) -> Result<(), AppError>
                         path.file_name().unwrap_or_default().to_string_lossy()
                     );
                 }
+                skips.record(SkipReason::UnrecognizedName);
                 continue;
             }
         };
 
+        if is_filtered_episode(episode_filter.as_ref(), &anime_file, args.verbose)
+            || is_excluded_group(&args, &anime_file)
+        {
+            skips.record(SkipReason::Filtered);
+            continue;
+        }
+        if non_preferred.contains(&anime_file.original_path) {
+            report_non_preferred(&anime_file, args.verbose);
+            skips.record(SkipReason::NonPreferredExtension);
+            continue;
+        }
+        if is_duplicate_content(&mut content_index, path, args.verbose) {
+            skips.record(SkipReason::DuplicateContent);
+            continue;
+        }
         processed += 1;
-        let target_dir = if args.season_mode {
-            target
-                .join(anime_file.series_name())
-                .join(anime_file.season_dir_name())
-        } else {
-            target.join(&anime_file.anime_name)
-        };
-        match organize_file_to_dir(
-            &anime_file,
-            &target_dir,
-            args.mode,
-            args.dry_run,
-            fallback_mode,
-            args.verbose,
-            &subtitle_candidates,
-            log,
-        ) {
-            Ok(target_path) => {
-                succeeded += 1;
+        let file_target = target_template
+            .as_ref()
+            .map_or_else(|| target.clone(), |template| template.render(&anime_file));
+        let (anime_root, target_dir) =
+            episode_dirs(&anime_file, &file_target, &args, args.uses_season_dirs());
+        let anime_name = anime_file.anime_name.clone();
+        let bytes = file_size(path);
+        // 移动后源文件已不存在，指纹需在整理前读取。
+        let stamp = outputs
+            .processed_state
+            .is_some()
+            .then(|| FileStamp::of(path).ok())
+            .flatten();
+        match organize_file_to_dir(&anime_file, &target_dir, &file_context) {
+            Ok(OrganizedFile {
+                target: target_path,
+                outcome,
+            }) => {
+                let hook_ok = run_post_hook(
+                    post_hook.as_ref(),
+                    &args,
+                    outcome,
+                    &target_path,
+                    &anime_name,
+                );
+                match outcome {
+                    OperationOutcome::Skipped(reason) => {
+                        skipped += 1;
+                        skips.record(reason);
+                    }
+                    _ if hook_ok => succeeded += 1,
+                    _ => {
+                        failed += 1;
+                        failures.push(path.to_path_buf());
+                    }
+                }
+                if matches!(outcome, OperationOutcome::Performed(_)) {
+                    transferred_bytes += bytes;
+                }
+                if let Some(stamp) = stamp {
+                    if hook_ok && outcome != OperationOutcome::DryRun {
+                        outputs.processed.push((path.to_path_buf(), stamp));
+                    }
+                }
+                outcomes.push((anime_name, hook_ok, bytes));
                 if args.writes_library_index() {
                     if let Some(mut record) =
                         LibraryIndexRecord::from_target_path(&target, &target_path)?
@@ -256,30 +489,81 @@ fn run_organize(args: OrganizeArgs, log: &dyn Fn(&str)) -> Result<(), AppError>
                         library_records.push(record);
                     }
                 }
+                if args.generate_playlist {
+                    playlists
+                        .entry(anime_root)
+                        .or_default()
+                        .push(PlaylistEntry::new(anime_file, target_path));
+                }
+            }
+            Err(_) => {
+                failed += 1;
+                failures.push(path.to_path_buf());
+                outcomes.push((anime_name, false, bytes));
             }
-            Err(_) => failed += 1,
         }
     }
 
-    println!("处理完成：总计{processed}个文件，成功{succeeded}个，失败{failed}个");
+    let summary = OrganizeSummary {
+        processed,
+        succeeded,
+        skipped,
+        failed,
+    };
+    report_line(&args, &summary.to_string());
+    if !skips.is_empty() {
+        report_line(&args, &skips.render());
+    }
+    let transfer = TransferStats {
+        bytes: transferred_bytes,
+        elapsed: started.elapsed(),
+    };
+    if !args.dry_run {
+        report_line(&args, &transfer.render());
+    }
     log(&format!(
-        "Processed {processed} files: {succeeded} succeeded, {failed} failed"
+        "Processed {processed} files: {succeeded} succeeded, {skipped} skipped, {failed} failed"
     ));
+    print_anime_summary(&args, &outcomes);
+    print_time_estimate(&args, &source, &target, &outcomes);
+    file_context.report_plan_estimate(&args);
+    outputs.planned.extend(file_context.planned.take());
+    outputs.failed.extend(failures);
+    // 先清理解压临时目录，避免媒体库全量扫描把解压副本当作媒体文件。
+    drop(source_files);
+    finish_playlists(&args, playlists)?;
     finish_library_index(&args, &target, &extensions, &library_records)?;
-    Ok(())
+    Ok(RunSummary {
+        succeeded,
+        skipped,
+        failed,
+        transfer,
+    })
 }
 
 /// 带元数据刮削的流程
 #[cfg(feature = "metadata")]
-async fn run_with_metadata(args: OrganizeArgs, log: &dyn Fn(&str)) -> Result<(), AppError> {
+async fn run_with_metadata(
+    args: OrganizeArgs,
+    outputs: &mut RunOutputs,
+    log: &dyn Fn(&str),
+) -> Result<RunSummary, AppError> {
+    let started = Instant::now();
     validate_library_index_args(&args)?;
     validate_filename_parser_args(&args)?;
+    validate_season_format_args(&args)?;
+    validate_name_separator_args(&args)?;
+    validate_episode_range_format_args(&args)?;
+    validate_specials_dir_args(&args)?;
+    validate_temp_suffix_args(&args)?;
+    validate_leave_symlink_args(&args)?;
+    validate_target_template_args(&args)?;
     let (source, target) = resolve_source_and_target(&args)?;
-    let fallback_mode = args
-        .fallback_on_link_failure
-        .map(FallbackMode::to_operation_mode);
-    let extensions = build_extensions(&args.include_ext);
+    let extensions = build_extensions(&args);
     let subtitle_candidates = FileOrganizer::collect_external_subtitle_candidates(&source);
+    let file_context =
+        FileOperationContext::new(&args, &source, &target, &subtitle_candidates, log);
+    warn_read_only_link(&args);
     let bangumi = Arc::new(BangumiClient::with_source(
         args.bangumi_cache.clone(),
         args.metadata_source.clone(),
@@ -318,12 +602,43 @@ async fn run_with_metadata(args: OrganizeArgs, log: &dyn Fn(&str)) -> Result<(),
     }
     let probe_runtime = runtime_probe_enabled(&args);
 
-    let anime_groups =
-        collect_anime_groups(&source, &extensions, args.filename_parser, args.verbose)?;
+    let name_aliases = load_name_aliases(&args)?;
+    let post_hook = load_post_hook(&args)?;
+    let discs = bdmv_discs(&source, &target, &args);
+    let mut source_files = collect_source_files(&source, &target, &extensions, &args, &discs)?;
+    let mut skips = std::mem::take(&mut source_files.skips);
+    let disc_summary = organize_bdmv_discs(&discs, &target, &args, &mut skips);
+    let mut anime_groups =
+        collect_anime_groups(&source_files.files, &args, &name_aliases, &mut skips)?;
+    if let Some(preferred) = &args.prefer_ext {
+        for files in anime_groups.values_mut() {
+            let non_preferred = dedup::non_preferred_extensions(files, preferred);
+            files.retain(|file| {
+                let keep = !non_preferred.contains(&file.original_path);
+                if !keep {
+                    report_non_preferred(file, args.verbose);
+                    skips.record(SkipReason::NonPreferredExtension);
+                }
+                keep
+            });
+        }
+    }
+    if args.renumber {
+        let mut mapping = Vec::new();
+        for files in anime_groups.values_mut() {
+            mapping.extend(renumber::renumber_episodes(files));
+        }
+        report_renumber(&args, mapping);
+    }
     log(&format!("Discovered {} anime groups", anime_groups.len()));
-    let mut processed = 0;
-    let mut succeeded = 0;
-    let mut failed = 0;
+    let mut processed = disc_summary.processed;
+    let mut succeeded = disc_summary.succeeded;
+    let mut skipped = disc_summary.skipped;
+    let mut failed = disc_summary.failed;
+    let mut transferred_bytes = 0;
+    let mut outcomes: Vec<(String, bool, u64)> = Vec::new();
+    let mut failures: Vec<PathBuf> = Vec::new();
+    let mut content_index = args.dedup_by_content.then(ContentIndex::new);
     let (mut metadata_cache, mut episode_cache) = prefetch_group_metadata(
         &anime_groups,
         Arc::clone(&alias_lookup),
@@ -333,7 +648,7 @@ async fn run_with_metadata(args: OrganizeArgs, log: &dyn Fn(&str)) -> Result<(),
         allow_online_title_resolution,
         !args.no_episode_metadata,
         !args.no_images && !args.dry_run,
-        args.force_overwrite,
+        args.overwrites_metadata(),
         args.verbose,
     )
     .await;
@@ -342,6 +657,7 @@ async fn run_with_metadata(args: OrganizeArgs, log: &dyn Fn(&str)) -> Result<(),
         metadata_cache.len()
     ));
     let mut library_records = Vec::new();
+    let mut playlists: HashMap<PathBuf, Vec<PlaylistEntry>> = HashMap::new();
 
     for (anime_name, files) in anime_groups {
         let Some(first_file) = files.first() else {
@@ -350,7 +666,7 @@ async fn run_with_metadata(args: OrganizeArgs, log: &dyn Fn(&str)) -> Result<(),
 
         let series_name = first_file.series_name();
         let season_number = first_file.season_number().unwrap_or(1);
-        let anime_root = target.join(&series_name);
+        let (anime_root, _) = episode_dirs(first_file, &target, &args, true);
 
         let cache_key = (anime_name.clone(), season_number);
         let metadata = if let Some(cached) = metadata_cache.get(&cache_key) {
@@ -394,7 +710,7 @@ async fn run_with_metadata(args: OrganizeArgs, log: &dyn Fn(&str)) -> Result<(),
         if let Some(ref meta) = metadata {
             if args.scrape_metadata {
                 let tvshow_nfo_path = anime_root.join("tvshow.nfo");
-                if args.force_overwrite || !tvshow_nfo_path.exists() {
+                if args.overwrites_metadata() || !tvshow_nfo_path.exists() {
                     let nfo = TvShowNfo::from(meta);
                     if args.dry_run {
                         if args.verbose {
@@ -411,21 +727,43 @@ async fn run_with_metadata(args: OrganizeArgs, log: &dyn Fn(&str)) -> Result<(),
         }
 
         for file in files {
-            let season_dir = target.join(file.series_name()).join(file.season_dir_name());
+            let source_path = Path::new(&file.original_path);
+            if is_duplicate_content(&mut content_index, source_path, args.verbose) {
+                skips.record(SkipReason::DuplicateContent);
+                continue;
+            }
+            let (_, season_dir) = episode_dirs(&file, &target, &args, true);
             processed += 1;
 
-            match organize_file_to_dir(
-                &file,
-                &season_dir,
-                args.mode,
-                args.dry_run,
-                fallback_mode,
-                args.verbose,
-                &subtitle_candidates,
-                log,
-            ) {
-                Ok(target_path) => {
-                    succeeded += 1;
+            let anime_name = file.anime_name.clone();
+            let bytes = file_size(source_path);
+            match organize_file_to_dir(&file, &season_dir, &file_context) {
+                Ok(OrganizedFile {
+                    target: target_path,
+                    outcome,
+                }) => {
+                    let hook_ok = run_post_hook(
+                        post_hook.as_ref(),
+                        &args,
+                        outcome,
+                        &target_path,
+                        &anime_name,
+                    );
+                    match outcome {
+                        OperationOutcome::Skipped(reason) => {
+                            skipped += 1;
+                            skips.record(reason);
+                        }
+                        _ if hook_ok => succeeded += 1,
+                        _ => {
+                            failed += 1;
+                            failures.push(source_path.to_path_buf());
+                        }
+                    }
+                    if matches!(outcome, OperationOutcome::Performed(_)) {
+                        transferred_bytes += bytes;
+                    }
+                    outcomes.push((anime_name, hook_ok, bytes));
 
                     if args.writes_library_index() {
                         if let Some(mut record) =
@@ -453,7 +791,7 @@ async fn run_with_metadata(args: OrganizeArgs, log: &dyn Fn(&str)) -> Result<(),
                     if args.scrape_metadata {
                         if let Some(ref meta) = metadata {
                             let episode_nfo_path = target_path.with_extension("nfo");
-                            if args.force_overwrite || !episode_nfo_path.exists() {
+                            if args.overwrites_metadata() || !episode_nfo_path.exists() {
                                 let episode_nfo = create_episode_nfo(&file, meta);
                                 if args.dry_run {
                                     if args.verbose {
@@ -470,23 +808,60 @@ async fn run_with_metadata(args: OrganizeArgs, log: &dyn Fn(&str)) -> Result<(),
                             }
                         }
                     }
+
+                    if args.generate_playlist {
+                        playlists
+                            .entry(target.join(file.series_name()))
+                            .or_default()
+                            .push(PlaylistEntry::new(file, target_path));
+                    }
+                }
+                Err(_) => {
+                    failed += 1;
+                    failures.push(source_path.to_path_buf());
+                    outcomes.push((anime_name, false, bytes));
                 }
-                Err(_) => failed += 1,
             }
         }
     }
 
-    println!("处理完成：总计{processed}个文件，成功{succeeded}个，失败{failed}个");
+    let summary = OrganizeSummary {
+        processed,
+        succeeded,
+        skipped,
+        failed,
+    };
+    report_line(&args, &summary.to_string());
+    if !skips.is_empty() {
+        report_line(&args, &skips.render());
+    }
+    let transfer = TransferStats {
+        bytes: transferred_bytes,
+        elapsed: started.elapsed(),
+    };
+    if !args.dry_run {
+        report_line(&args, &transfer.render());
+    }
     log(&format!(
-        "Processed {processed} files: {succeeded} succeeded, {failed} failed"
+        "Processed {processed} files: {succeeded} succeeded, {skipped} skipped, {failed} failed"
     ));
+    print_anime_summary(&args, &outcomes);
+    print_time_estimate(&args, &source, &target, &outcomes);
     if !metadata_cache.is_empty() {
         let matched = metadata_cache
             .values()
             .filter(|item| item.is_some())
             .count();
-        println!("元数据匹配：{matched}/{} 部动画", metadata_cache.len());
+        report_line(
+            &args,
+            &format!("元数据匹配：{matched}/{} 部动画", metadata_cache.len()),
+        );
     }
+    file_context.report_plan_estimate(&args);
+    outputs.planned.extend(file_context.planned.take());
+    outputs.failed.extend(failures);
+    drop(source_files);
+    finish_playlists(&args, playlists)?;
 
     finish_library_index_with_metadata(
         &args,
@@ -500,7 +875,7 @@ async fn run_with_metadata(args: OrganizeArgs, log: &dyn Fn(&str)) -> Result<(),
             bangumi: bangumi.as_ref(),
             tmdb: tmdb.as_deref(),
             download_images: !args.no_images,
-            force_overwrite: args.force_overwrite,
+            force_overwrite: args.overwrites_metadata(),
             fetch_episode_metadata: !args.no_episode_metadata,
             allow_online_title_resolution,
             probe_runtime,
@@ -509,7 +884,12 @@ async fn run_with_metadata(args: OrganizeArgs, log: &dyn Fn(&str)) -> Result<(),
     )
     .await?;
 
-    Ok(())
+    Ok(RunSummary {
+        succeeded,
+        skipped,
+        failed,
+        transfer,
+    })
 }
 
 #[cfg(feature = "metadata")]
@@ -630,52 +1010,384 @@ async fn prefetch_group_metadata(
     (metadata_cache, episode_cache)
 }
 
-#[allow(clippy::too_many_arguments)]
+/// 单个文件整理时共享的运行参数。
+struct FileOperationContext<'a> {
+    options: OrganizeOptions,
+    fallback_mode: Option<OperationMode>,
+    verbose: bool,
+    output: OutputFormat,
+    read_only: bool,
+    /// 在终端显示大文件复制的字节级进度
+    copy_progress: bool,
+    /// `--limit-rate`：复制限速（字节/秒）
+    limit_rate: Option<u64>,
+    subtitle_candidates: &'a [PathBuf],
+    log: &'a dyn Fn(&str),
+    /// 预览模式下累计的计划操作，供 `--plan-out` 写出
+    planned: RefCell<Vec<OperationPlan>>,
+}
+
+impl<'a> FileOperationContext<'a> {
+    fn new(
+        args: &OrganizeArgs,
+        source: &Path,
+        target: &Path,
+        subtitle_candidates: &'a [PathBuf],
+        log: &'a dyn Fn(&str),
+    ) -> Self {
+        Self {
+            options: OrganizeOptions {
+                mode: args.mode,
+                dry_run: args.dry_run,
+                backup: args.backup,
+                keep_tags: args.keep_tags.clone(),
+                leave_symlink: args.leave_symlink,
+                force: args.force,
+                // 重编号后保留源文件名会与新集数不符，因此改名为 `集数 标签` 形式。
+                name_separator: args.name_separator.clone().or_else(|| {
+                    args.renumber
+                        .then(|| parser::DEFAULT_NAME_SEPARATOR.to_string())
+                }),
+                filename_format: args
+                    .preset
+                    .map(|preset| preset.filename_format().to_string()),
+                episode_range_format: args.episode_range_format.clone().or_else(|| {
+                    args.preset
+                        .map(|preset| preset.episode_range_format().to_string())
+                }),
+                keep_apple_double: args.keep_apple_double,
+                with_metadata: args.with_metadata,
+                same_file_check: args.same_file_check,
+                on_conflict: args.on_conflict,
+                verify_links: args.verify_links,
+                verify_readable: args.verify_readable,
+                verify_readable_retries: args.verify_readable_retries,
+                max_filename_len: args.max_filename_len.map(usize::from),
+                relative_paths: args.relative.then(|| RelativePaths::new(source, target)),
+                temp_suffix: args.temp_suffix.clone(),
+                quiet: args.quiet,
+            },
+            fallback_mode: args
+                .fallback_on_link_failure
+                .map(FallbackMode::to_operation_mode),
+            verbose: args.verbose,
+            output: args.output,
+            read_only: args.read_only,
+            copy_progress: !args.quiet
+                && !args.dry_run
+                && !args.output.is_structured()
+                && std::io::stderr().is_terminal(),
+            limit_rate: args.limit_rate,
+            subtitle_candidates,
+            log,
+            planned: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// 预览模式下根据累计的计划输出将新建的目录数、操作的文件数与已存在的目标数。
+    fn report_plan_estimate(&self, args: &OrganizeArgs) {
+        if !self.options.dry_run {
+            return;
+        }
+        let estimate = PlanEstimate::from_operations(&self.planned.borrow());
+        report_line(args, &estimate.describe(self.options.mode));
+    }
+}
+
+/// `--plan-out`：将全部目标预览期间累计的计划合并写入文件
+fn save_plan(args: &OrganizeArgs, operations: Vec<OperationPlan>) -> Result<(), AppError> {
+    let Some(path) = &args.plan_out else {
+        return Ok(());
+    };
+    let plan = OrganizePlan::new(args.mode, operations).with_options(PlanOptions {
+        backup: args.backup,
+        force: args.force,
+        same_file_check: args.same_file_check,
+        on_conflict: args.on_conflict,
+    });
+    plan.save(path)?;
+    report_line(
+        args,
+        &format!(
+            "已写入整理计划（{} 个操作）: {}",
+            plan.operations.len(),
+            path.display()
+        ),
+    );
+    Ok(())
+}
+
+/// `--failures-out`：写出本次失败文件的源路径（多个目标上都失败的只写一次）；
+/// 没有失败时写出空文件，避免残留上次的列表
+fn save_failures(args: &OrganizeArgs, failures: &[PathBuf]) -> Result<(), AppError> {
+    let Some(path) = &args.failures_out else {
+        return Ok(());
+    };
+    let mut seen = HashSet::new();
+    let failures: Vec<PathBuf> = failures
+        .iter()
+        .filter(|failure| seen.insert(*failure))
+        .cloned()
+        .collect();
+    report::write_path_list(path, &failures)?;
+    report_line(
+        args,
+        &format!(
+            "已写入失败列表（{} 个文件）: {}",
+            failures.len(),
+            path.display()
+        ),
+    );
+    Ok(())
+}
+
+/// 整理流程的统计摘要，`main` 据此决定进程退出码
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct RunSummary {
+    pub(crate) succeeded: usize,
+    /// 目标已存在且内容相同、无需操作的文件数
+    pub(crate) skipped: usize,
+    pub(crate) failed: usize,
+    /// 实际传输的数据量与整理耗时
+    pub(crate) transfer: TransferStats,
+}
+
+impl RunSummary {
+    /// 全部成功或跳过（或没有待整理文件）返回 0，部分失败返回 2，全部失败返回 3。
+    fn exit_code(self) -> i32 {
+        match (self.succeeded + self.skipped, self.failed) {
+            (_, 0) => 0,
+            (0, _) => EXIT_ALL_FAILED,
+            _ => EXIT_PARTIAL_FAILURE,
+        }
+    }
+}
+
+/// 输出面向用户的进度或摘要信息；结构化输出时改写到 stderr，保证 stdout 只含数据行。
+fn status_line(output: OutputFormat, message: &str) {
+    if output.is_structured() {
+        eprintln!("{message}");
+    } else {
+        println!("{message}");
+    }
+}
+
+/// 输出整理流程的统计或摘要；`--quiet` 时不输出。
+fn report_line(args: &OrganizeArgs, message: &str) {
+    if !args.quiet {
+        status_line(args.output, message);
+    }
+}
+
+fn warn_read_only_link(args: &OrganizeArgs) {
+    if args.read_only
+        && !args.quiet
+        && matches!(args.mode, OperationMode::Link | OperationMode::Auto)
+    {
+        eprintln!("警告：硬链接与源文件共享数据，--read-only 会同时将源文件设为只读");
+    }
+}
+
+fn print_output_header(output: OutputFormat) {
+    if output == OutputFormat::Csv {
+        println!("{}", report::CSV_HEADER);
+    }
+}
+
+fn print_output_records(
+    context: &FileOperationContext<'_>,
+    anime_file: &AnimeFileInfo,
+    plans: &[OperationPlan],
+    mode: OperationMode,
+    status: RecordStatus,
+) {
+    if !context.output.is_structured() {
+        return;
+    }
+    for plan in plans {
+        let (source, target) = match &context.options.relative_paths {
+            Some(paths) => (paths.source(&plan.source), paths.target(&plan.target)),
+            None => (
+                plan.source.to_string_lossy().to_string(),
+                plan.target.to_string_lossy().to_string(),
+            ),
+        };
+        let record = ReportRecord {
+            source,
+            target,
+            anime_name: anime_file.anime_name.clone(),
+            episode: anime_file.episode.clone(),
+            mode,
+            status,
+        };
+        match context.output {
+            OutputFormat::Csv => println!("{}", record.to_csv_row()),
+            OutputFormat::Ndjson => {
+                if let Err(error) = record.write_json_line(&NDJSON_STDOUT) {
+                    eprintln!("输出 NDJSON 记录失败: {error}");
+                }
+            }
+            OutputFormat::Text => {}
+        }
+    }
+}
+
+/// 返回番剧根目录与本集所在目录。
+///
+/// 季模式或指定 `--specials-dir` 时特殊集放入特殊集目录，否则与正片同目录；
+/// 剧场版、总集篇放入对应的子目录，季模式下均替代季目录；
+/// 根目录名默认剔除这些类型关键字，`--keep-kind-keyword` 或 `--raw-anime-name` 时保留；
+/// `--case-insensitive-merge` 时改用目标中已存在的大小写变体目录。
+fn episode_dirs(
+    anime_file: &AnimeFileInfo,
+    target: &Path,
+    args: &OrganizeArgs,
+    season_mode: bool,
+) -> (PathBuf, PathBuf) {
+    let kind = anime_file.episode_kind();
+    let anime_name =
+        if kind == EpisodeKind::Regular || args.keep_kind_keyword || args.raw_anime_name {
+            anime_file.anime_name.clone()
+        } else {
+            anime_file.anime_name_without_kind_keyword()
+        };
+    let anime_dir_name = if season_mode {
+        parser::split_series_and_season(&anime_name).0
+    } else {
+        anime_name
+    };
+    let anime_root = match args
+        .case_insensitive_merge
+        .then(|| filesystem::find_case_variant_dir(target, &anime_dir_name))
+        .flatten()
+    {
+        Some(existing) => target.join(existing),
+        None => target.join(anime_dir_name),
+    };
+    let target_dir = match kind.dir_name() {
+        _ if anime_file.is_special() && (season_mode || args.specials_dir.is_some()) => {
+            anime_root.join(args.specials_dir())
+        }
+        Some(kind_dir) => anime_root.join(kind_dir),
+        None if season_mode => {
+            anime_root.join(anime_file.season_dir_name_with(args.season_format()))
+        }
+        None => anime_root.clone(),
+    };
+    (anime_root, target_dir)
+}
+
 fn organize_file_to_dir(
     anime_file: &AnimeFileInfo,
     target_dir: &Path,
-    mode: OperationMode,
-    dry_run: bool,
-    fallback_mode: Option<OperationMode>,
-    verbose: bool,
-    subtitle_candidates: &[PathBuf],
-    log: &dyn Fn(&str),
-) -> Result<PathBuf, AppError> {
+    context: &FileOperationContext<'_>,
+) -> Result<OrganizedFile, AppError> {
     let subtitles = FileOrganizer::find_external_subtitles_from(
         Path::new(&anime_file.original_path),
-        subtitle_candidates,
+        context.subtitle_candidates,
     );
-    match FileOrganizer::organize_to_dir_with_subtitles(
-        anime_file, target_dir, mode, dry_run, &subtitles,
-    ) {
-        Ok(target_path) => {
-            if verbose && !dry_run {
-                println!(
-                    "成功: {} -> {}",
-                    anime_file.original_path,
-                    target_path.display()
-                );
+    let plans = FileOrganizer::plan_to_dir_with_subtitles(
+        anime_file,
+        target_dir,
+        &subtitles,
+        &context.options,
+    )?;
+    if context.options.dry_run {
+        context.planned.borrow_mut().extend(plans.iter().cloned());
+    }
+    if context.options.dry_run && context.output.is_structured() {
+        print_output_records(
+            context,
+            anime_file,
+            &plans,
+            context.options.mode,
+            RecordStatus::DryRun,
+        );
+        return Ok(OrganizedFile {
+            target: plans[0].target.clone(),
+            outcome: OperationOutcome::DryRun,
+        });
+    }
+
+    let result = organize_file_with_fallback(anime_file, target_dir, context, &subtitles).and_then(
+        |organized| {
+            if context.read_only && !context.options.dry_run {
+                for plan in &plans {
+                    FileOrganizer::set_read_only(&plan.target).inspect_err(|error| {
+                        eprintln!("设置只读失败 {}: {error}", plan.target.display());
+                    })?;
+                }
+            }
+            Ok(organized)
+        },
+    );
+    let status = match &result {
+        Ok((organized, _)) => match organized.outcome {
+            OperationOutcome::Performed(_) => RecordStatus::Success,
+            OperationOutcome::Skipped(_) => RecordStatus::Skipped,
+            OperationOutcome::DryRun => RecordStatus::DryRun,
+        },
+        Err(_) => RecordStatus::Failed,
+    };
+    let mode = match &result {
+        Ok((_, mode)) => *mode,
+        Err(_) => context.options.mode,
+    };
+    print_output_records(context, anime_file, &plans, mode, status);
+    result.map(|(organized, _)| organized)
+}
+
+fn organize_file_with_fallback(
+    anime_file: &AnimeFileInfo,
+    target_dir: &Path,
+    context: &FileOperationContext<'_>,
+    subtitles: &[PathBuf],
+) -> Result<(OrganizedFile, OperationMode), AppError> {
+    let mode = context.options.mode;
+    match organize_with_progress(anime_file, target_dir, &context.options, subtitles, context) {
+        Ok(organized) => {
+            if context.verbose {
+                match organized.outcome {
+                    OperationOutcome::Performed(_) => status_line(
+                        context.output,
+                        &format!(
+                            "成功: {} -> {}",
+                            anime_file.original_path,
+                            organized.target.display()
+                        ),
+                    ),
+                    OperationOutcome::Skipped(reason) => status_line(
+                        context.output,
+                        &format!("跳过（{reason}）: {}", anime_file.original_path),
+                    ),
+                    OperationOutcome::DryRun => {}
+                }
             }
-            log(&format!("Organized {}", target_path.display()));
-            Ok(target_path)
+            (context.log)(&format!("Organized {}", organized.target.display()));
+            Ok((organized, mode))
         }
         Err(error) => {
             if mode == OperationMode::Link {
-                if let Some(fallback) = fallback_mode {
+                if let Some(fallback) = context.fallback_mode {
                     if matches!(
                         error,
                         AppError::CrossDeviceLink | AppError::HardLinkNotSupported
                     ) {
-                        if verbose {
+                        if context.verbose {
                             eprintln!(
                                 "硬链接失败，回退为 {}: {}",
                                 fallback, anime_file.original_path
                             );
                         }
 
-                        return FileOrganizer::organize_to_dir_with_subtitles(
-                            anime_file, target_dir, fallback, dry_run, &subtitles,
+                        let options = OrganizeOptions {
+                            mode: fallback,
+                            ..context.options.clone()
+                        };
+                        return organize_with_progress(
+                            anime_file, target_dir, &options, subtitles, context,
                         )
+                        .map(|organized| (organized, fallback))
                         .map_err(|fallback_error| {
                             eprintln!(
                                 "处理文件失败 {}: {fallback_error}",
@@ -688,25 +1400,187 @@ fn organize_file_to_dir(
             }
 
             eprintln!("处理文件失败 {}: {error}", anime_file.original_path);
-            log(&format!("Failed {}: {error}", anime_file.original_path));
+            (context.log)(&format!("Failed {}: {error}", anime_file.original_path));
             Err(error)
         }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum LibraryIndexWriteMode {
-    Initialize,
-    Incremental,
-    Rebuild,
+/// 复制大文件时在终端逐块显示进度，`--limit-rate` 时分块限速复制；其他情况与直接整理相同。
+fn organize_with_progress(
+    anime_file: &AnimeFileInfo,
+    target_dir: &Path,
+    options: &OrganizeOptions,
+    subtitles: &[PathBuf],
+    context: &FileOperationContext<'_>,
+) -> Result<OrganizedFile, AppError> {
+    if !context.copy_progress && context.limit_rate.is_none() {
+        return FileOrganizer::organize_to_dir_with_subtitles(
+            anime_file, target_dir, options, subtitles,
+        );
+    }
+    let name = Path::new(&anime_file.original_path)
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy();
+    let last_percent = Cell::new(None);
+    let progress = |copied: u64, total: u64| {
+        if total < COPY_PROGRESS_MIN_BYTES {
+            return;
+        }
+        let percent = copied * 100 / total;
+        if last_percent.replace(Some(percent)) == Some(percent) {
+            return;
+        }
+        eprint!(
+            "\r复制中 {name}: {percent:>3}% ({} / {} MiB)",
+            copied >> 20,
+            total >> 20
+        );
+        if copied == total {
+            eprintln!();
+        }
+    };
+    let copy_options = CopyOptions {
+        progress: context
+            .copy_progress
+            .then_some(&progress as &dyn Fn(u64, u64)),
+        limit_rate: context.limit_rate,
+        ..CopyOptions::default()
+    };
+    FileOrganizer::organize_to_dir_with_fs(
+        &ChunkedFileSystem::new(copy_options),
+        anime_file,
+        target_dir,
+        options,
+        subtitles,
+    )
 }
 
-impl LibraryIndexWriteMode {
-    fn label(self) -> &'static str {
-        match self {
-            Self::Initialize => "初始化",
-            Self::Incremental => "增量更新",
-            Self::Rebuild => "重建",
+/// `--dedup-by-content`：与本次已登记文件内容相同时返回 `true`，调用方应跳过该文件。
+fn is_duplicate_content(index: &mut Option<ContentIndex>, path: &Path, verbose: bool) -> bool {
+    let Some(index) = index else {
+        return false;
+    };
+    match index.insert(path) {
+        Ok(DedupDecision::Unique) => false,
+        Ok(DedupDecision::DuplicateOf(original)) => {
+            if verbose {
+                eprintln!(
+                    "跳过：内容与 {} 相同 {}",
+                    original.display(),
+                    path.display()
+                );
+            }
+            true
+        }
+        Err(error) => {
+            eprintln!("计算内容哈希失败 {}: {error}", path.display());
+            false
+        }
+    }
+}
+
+fn print_anime_summary(args: &OrganizeArgs, outcomes: &[(String, bool, u64)]) {
+    if !args.summary_by_anime {
+        return;
+    }
+    let summary = report::summarize_by_anime(
+        outcomes
+            .iter()
+            .map(|(anime_name, success, bytes)| (anime_name.as_str(), *success, *bytes)),
+    );
+    report_line(args, &report::render_anime_summary(&summary));
+}
+
+/// 预览模式下按待整理文件的总大小估算实际执行的耗时。
+fn print_time_estimate(
+    args: &OrganizeArgs,
+    source: &Path,
+    target: &Path,
+    outcomes: &[(String, bool, u64)],
+) {
+    if !args.dry_run {
+        return;
+    }
+    let total_bytes = outcomes
+        .iter()
+        .filter(|(_, success, _)| *success)
+        .map(|(_, _, bytes)| bytes)
+        .sum();
+    let throughput = args
+        .throughput
+        .unwrap_or(report::DEFAULT_COPY_THROUGHPUT)
+        .min(args.limit_rate.unwrap_or(u64::MAX));
+    // 无法判断时按同盘处理：跨盘移动多见于显式配置，误判只影响估算。
+    let same_device = filesystem::same_device(source, target).unwrap_or(true);
+    let total = report::format_size(total_bytes);
+    let message = match report::estimate_time(args.mode, total_bytes, throughput, same_device) {
+        report::TimeEstimate::Instant => {
+            format!("预计耗时：即时（{} 模式，共 {total}）", args.mode)
+        }
+        report::TimeEstimate::Copy(duration) => format!(
+            "预计耗时：约 {}（共 {total}，按 {}/s 估算）",
+            report::format_duration(duration),
+            report::format_size(throughput)
+        ),
+    };
+    report_line(args, &message);
+}
+
+/// 源文件大小，须在整理前读取（移动后源已不存在）；读取失败时按 0 计。
+fn file_size(path: &Path) -> u64 {
+    std::fs::metadata(path)
+        .map(|metadata| metadata.len())
+        .unwrap_or_default()
+}
+
+fn finish_playlists(
+    args: &OrganizeArgs,
+    playlists: HashMap<PathBuf, Vec<PlaylistEntry>>,
+) -> Result<(), AppError> {
+    if !args.generate_playlist {
+        return Ok(());
+    }
+
+    let mut playlists = playlists.into_iter().collect::<Vec<_>>();
+    playlists.sort_by(|left, right| left.0.cmp(&right.0));
+    for (anime_root, mut entries) in playlists {
+        let Some(title) = anime_root.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if args.dry_run {
+            report_line(
+                args,
+                &format!(
+                    "[DRY-RUN] 生成播放列表: {} ({} 集)",
+                    anime_root.join(format!("{title}.m3u")).display(),
+                    entries.len()
+                ),
+            );
+            continue;
+        }
+        let path = playlist::write_m3u(&anime_root, title, &mut entries)?;
+        if args.verbose {
+            report_line(args, &format!("已生成播放列表: {}", path.display()));
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy)]
+enum LibraryIndexWriteMode {
+    Initialize,
+    Incremental,
+    Rebuild,
+}
+
+impl LibraryIndexWriteMode {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Initialize => "初始化",
+            Self::Incremental => "增量更新",
+            Self::Rebuild => "重建",
         }
     }
 }
@@ -720,6 +1594,63 @@ fn validate_library_index_args(args: &OrganizeArgs) -> Result<(), AppError> {
     Ok(())
 }
 
+fn validate_season_format_args(args: &OrganizeArgs) -> Result<(), AppError> {
+    parser::validate_season_format(args.season_format())
+}
+
+fn validate_episode_range_format_args(args: &OrganizeArgs) -> Result<(), AppError> {
+    args.episode_range_format
+        .as_deref()
+        .map_or(Ok(()), parser::validate_episode_range_format)
+}
+
+fn validate_specials_dir_args(args: &OrganizeArgs) -> Result<(), AppError> {
+    args.specials_dir
+        .as_deref()
+        .map_or(Ok(()), parser::validate_specials_dir)
+}
+
+fn validate_temp_suffix_args(args: &OrganizeArgs) -> Result<(), AppError> {
+    args.temp_suffix
+        .as_deref()
+        .map_or(Ok(()), cleanup::validate_temp_suffix)
+}
+
+fn validate_name_separator_args(args: &OrganizeArgs) -> Result<(), AppError> {
+    args.name_separator
+        .as_deref()
+        .map_or(Ok(()), parser::validate_name_separator)
+}
+
+/// `--target` 为模板（如 `/library/{year}/{anime}`）时解析模板，否则返回 `None`。
+fn load_target_template(args: &OrganizeArgs) -> Result<Option<TargetTemplate>, AppError> {
+    args.target
+        .first()
+        .filter(|target| TargetTemplate::is_template(target))
+        .map(|target| TargetTemplate::parse(&target.to_string_lossy()))
+        .transpose()
+}
+
+/// 元数据流程按番剧整体写入 NFO 与图片，暂不支持按文件渲染的目标模板。
+#[cfg(feature = "metadata")]
+fn validate_target_template_args(args: &OrganizeArgs) -> Result<(), AppError> {
+    if load_target_template(args)?.is_some() {
+        return Err(AppError::ParseError(
+            "目标模板不能与 --scrape-metadata 或 --mlip 同时使用".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_leave_symlink_args(args: &OrganizeArgs) -> Result<(), AppError> {
+    if args.leave_symlink && args.mode != OperationMode::Move {
+        return Err(AppError::ParseError(
+            "--leave-symlink 需要配合 --mode=move 使用".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 fn validate_filename_parser_args(args: &OrganizeArgs) -> Result<(), AppError> {
     let _ = args;
     #[cfg(not(feature = "anifilebert"))]
@@ -789,14 +1720,17 @@ fn finish_library_index(
         LibraryIndexWriteMode::Incremental => LibraryIndex::update(target, current_records)?,
     };
 
-    println!(
-        "媒体库索引{}完成：{} 部作品，{} 集，{} 个文件，{} 个特典 ({})",
-        mode.label(),
-        stats.series,
-        stats.episodes,
-        stats.media_files,
-        stats.extras,
-        LibraryIndex::database_path(target).display()
+    report_line(
+        args,
+        &format!(
+            "媒体库索引{}完成：{} 部作品，{} 集，{} 个文件，{} 个特典 ({})",
+            mode.label(),
+            stats.series,
+            stats.episodes,
+            stats.media_files,
+            stats.extras,
+            LibraryIndex::database_path(target).display()
+        ),
     );
     Ok(())
 }
@@ -1030,14 +1964,17 @@ async fn finish_library_index_with_metadata(
         LibraryIndexWriteMode::Incremental => LibraryIndex::update(target, current_records)?,
     };
 
-    println!(
-        "媒体库索引{}完成：{} 部作品，{} 集，{} 个文件，{} 个特典 ({})",
-        mode.label(),
-        stats.series,
-        stats.episodes,
-        stats.media_files,
-        stats.extras,
-        LibraryIndex::database_path(target).display()
+    report_line(
+        args,
+        &format!(
+            "媒体库索引{}完成：{} 部作品，{} 集，{} 个文件，{} 个特典 ({})",
+            mode.label(),
+            stats.series,
+            stats.episodes,
+            stats.media_files,
+            stats.extras,
+            LibraryIndex::database_path(target).display()
+        ),
     );
     Ok(())
 }
@@ -1188,17 +2125,33 @@ fn normalized_relative_path(path: &Path) -> String {
 
 fn parse_anime_file(
     path: &Path,
-    mode: FilenameParserMode,
+    args: &OrganizeArgs,
     verbose: bool,
 ) -> Result<Option<AnimeFileInfo>, AppError> {
-    match mode {
-        FilenameParserMode::Rules => Ok(FilenameParser::parse(path)),
-        FilenameParserMode::Anifilebert => parse_anifilebert(path, true, verbose),
-        FilenameParserMode::Auto => match FilenameParser::parse(path) {
-            Some(info) => Ok(Some(info)),
-            None => parse_anifilebert(path, false, verbose),
+    let parse_rules = |path: &Path| {
+        if args.loose_episode {
+            FilenameParser::parse_loose(path)
+        } else {
+            FilenameParser::parse(path)
+        }
+    };
+    let info = match args.filename_parser {
+        FilenameParserMode::Rules => parse_rules(path),
+        FilenameParserMode::Anifilebert => parse_anifilebert(path, true, verbose)?,
+        FilenameParserMode::Auto => match parse_rules(path) {
+            Some(info) => Some(info),
+            None => parse_anifilebert(path, false, verbose)?,
         },
-    }
+    };
+    Ok(info.map(|mut info| {
+        if let Some(index) = args.primary_title {
+            info.select_primary_title(usize::from(index));
+        }
+        if args.preserve_ext_case {
+            info.restore_extension_case();
+        }
+        info
+    }))
 }
 
 #[cfg(feature = "anifilebert")]
@@ -1238,34 +2191,565 @@ fn parse_anifilebert(
     Ok(None)
 }
 
-fn collect_anime_groups(
+/// 应用 `--episode-offset`；偏移后集数无效时输出原因并跳过该文件。
+fn apply_episode_offset(mut info: AnimeFileInfo, offset: i64) -> Option<AnimeFileInfo> {
+    match info.apply_episode_offset(offset) {
+        Ok(()) => Some(info),
+        Err(error) => {
+            eprintln!("跳过 {}: {error}", info.original_path);
+            None
+        }
+    }
+}
+
+/// 预先解析全部文件（应用集数偏移与名称别名），供需要看到同一番剧全部集数的选项使用。
+///
+/// 逐文件整理流程本身是边解析边整理的，因此这里额外解析一遍；
+/// 解析失败或偏移无效的文件直接忽略，留给主循环按原逻辑报告。
+fn preparse_source_files(
+    files: &[PathBuf],
+    args: &OrganizeArgs,
+    name_aliases: &NameAliases,
+) -> Result<Vec<AnimeFileInfo>, AppError> {
+    let mut infos = Vec::new();
+    for path in files {
+        if let Some(mut info) = parse_anime_file(path, args, false)? {
+            if info.apply_episode_offset(args.episode_offset).is_ok() {
+                infos.push(normalize_anime_name(info, args, name_aliases));
+            }
+        }
+    }
+    Ok(infos)
+}
+
+/// `--prefer-ext`：返回同一集存在更优先扩展名版本、应跳过的文件原始路径。
+fn non_preferred_source_files(
+    files: &[PathBuf],
+    args: &OrganizeArgs,
+    name_aliases: &NameAliases,
+) -> Result<HashSet<String>, AppError> {
+    let Some(preferred) = &args.prefer_ext else {
+        return Ok(HashSet::new());
+    };
+    let infos = preparse_source_files(files, args, name_aliases)?;
+    Ok(dedup::non_preferred_extensions(&infos, preferred))
+}
+
+fn report_non_preferred(file: &AnimeFileInfo, verbose: bool) {
+    if verbose {
+        eprintln!(
+            "跳过：同一集已有优先扩展名版本 {}",
+            Path::new(&file.original_path)
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+        );
+    }
+}
+
+/// `--renumber`：预先解析全部文件计算连续集数，返回 原始路径 → 新集数。
+fn renumber_source_files(
+    files: &[PathBuf],
+    args: &OrganizeArgs,
+    name_aliases: &NameAliases,
+) -> Result<HashMap<String, String>, AppError> {
+    if !args.renumber {
+        return Ok(HashMap::new());
+    }
+    let mut infos = preparse_source_files(files, args, name_aliases)?;
+    report_renumber(args, renumber::renumber_episodes(&mut infos));
+    Ok(infos
+        .into_iter()
+        .map(|info| (info.original_path, info.episode))
+        .collect())
+}
+
+/// 重编号改写集数不可逆，始终警告并列出映射（`--quiet` 除外）。
+fn report_renumber(args: &OrganizeArgs, mapping: Vec<EpisodeRenumber>) {
+    if args.quiet {
+        return;
+    }
+    eprintln!(
+        "警告：--renumber 将改写 {} 个集数，请确认映射无误（可先使用 --dry-run 预览）",
+        mapping.len()
+    );
+    let prefix = if args.dry_run { "[dry-run] " } else { "" };
+    for entry in mapping {
+        eprintln!(
+            "{prefix}重编号 {}: {} -> {}",
+            entry.anime_name, entry.from, entry.to
+        );
+    }
+}
+
+fn load_name_aliases(args: &OrganizeArgs) -> Result<NameAliases, AppError> {
+    let Some(path) = &args.aliases else {
+        return Ok(NameAliases::default());
+    };
+    let aliases = NameAliases::load(path)?;
+    if args.verbose {
+        eprintln!("已加载 {} 条番名别名映射", aliases.len());
+    }
+    Ok(aliases)
+}
+
+/// `--state`：读取上次的增量状态，文件不存在时为空状态。
+fn load_processed_state(args: &OrganizeArgs) -> Result<Option<ProcessedState>, AppError> {
+    args.state.as_deref().map(ProcessedState::load).transpose()
+}
+
+/// `--state`：写回增量状态；预览模式不写入。
+fn save_processed_state(
+    args: &OrganizeArgs,
+    state: Option<ProcessedState>,
+) -> Result<(), AppError> {
+    let (Some(path), Some(state)) = (&args.state, state) else {
+        return Ok(());
+    };
+    if args.dry_run {
+        return Ok(());
+    }
+    state.save(path)?;
+    if args.verbose {
+        eprintln!(
+            "已写入增量状态（{} 个文件）: {}",
+            state.len(),
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+fn load_post_hook(args: &OrganizeArgs) -> Result<Option<PostHook>, AppError> {
+    args.post_hook.as_deref().map(PostHook::parse).transpose()
+}
+
+/// `--post-hook`：文件实际整理后执行钩子命令，预览模式只打印。
+///
+/// 返回该文件是否仍计为成功：钩子失败默认只警告，`--fail-on-hook-error` 时计为失败。
+fn run_post_hook(
+    hook: Option<&PostHook>,
+    args: &OrganizeArgs,
+    outcome: OperationOutcome,
+    target: &Path,
+    anime_name: &str,
+) -> bool {
+    let Some(hook) = hook else {
+        return true;
+    };
+    match outcome {
+        OperationOutcome::Performed(_) => {}
+        OperationOutcome::DryRun => {
+            if args.verbose {
+                eprintln!(
+                    "[dry-run] 将执行: {}",
+                    hook.render(target, anime_name).join(" ")
+                );
+            }
+            return true;
+        }
+        OperationOutcome::Skipped(_) => return true,
+    }
+    match hook.run(target, anime_name) {
+        Ok(()) => true,
+        Err(error) => {
+            eprintln!("警告: {error}（{}）", target.display());
+            !args.fail_on_hook_error
+        }
+    }
+}
+
+/// `--aliases`：番名命中别名时替换为规范名；
+/// 未命中且指定 `--title-case` 时将英文番名规范化为标题大小写。
+/// `--normalize-publisher` 时同时去除发布组名的常见后缀。
+fn normalize_anime_name(
+    mut info: AnimeFileInfo,
+    args: &OrganizeArgs,
+    name_aliases: &NameAliases,
+) -> AnimeFileInfo {
+    if let Some(canonical) = name_aliases.canonical_name(&info.anime_name) {
+        info.anime_name = canonical.to_string();
+    } else if args.title_case {
+        info.anime_name = parser::title_case(&info.anime_name);
+    }
+    if args.normalize_publisher {
+        info.publisher = match &args.publisher_suffixes {
+            Some(suffixes) => parser::normalize_publisher(&info.publisher, suffixes),
+            None => {
+                parser::normalize_publisher(&info.publisher, parser::DEFAULT_PUBLISHER_SUFFIXES)
+            }
+        };
+    }
+    info
+}
+
+/// 待整理的源文件；解压出的文件位于 `_extract_dir` 中，需保持到整理结束后再清理。
+struct SourceFiles {
+    files: Vec<PathBuf>,
+    /// 扫描阶段被跳过的文件统计
+    skips: SkipStats,
+    #[cfg(feature = "archives")]
+    _extract_dir: Option<tempfile::TempDir>,
+}
+
+/// `--newer-than`：换算为文件修改时间下限。
+fn newer_than_cutoff(args: &OrganizeArgs) -> Result<Option<SystemTime>, AppError> {
+    args.newer_than
+        .as_deref()
+        .map(|value| Ok(value.parse::<NewerThan>()?.cutoff(SystemTime::now())))
+        .transpose()
+}
+
+/// `--episodes`：解析集数范围。
+fn load_episode_filter(args: &OrganizeArgs) -> Result<Option<EpisodeFilter>, AppError> {
+    args.episodes
+        .as_deref()
+        .map(str::parse::<EpisodeFilter>)
+        .transpose()
+}
+
+/// 文件集数不在 `--episodes` 范围内时跳过。
+fn is_filtered_episode(
+    episode_filter: Option<&EpisodeFilter>,
+    info: &AnimeFileInfo,
+    verbose: bool,
+) -> bool {
+    let filtered = episode_filter.is_some_and(|filter| !filter.matches(info));
+    if filtered && verbose {
+        eprintln!(
+            "跳过：集数 {} 不在 --episodes 范围内 {}",
+            info.episode, info.original_path
+        );
+    }
+    filtered
+}
+
+/// 文件发布组在 `--exclude-group` 列表中时跳过（不区分大小写）。
+fn is_excluded_group(args: &OrganizeArgs, info: &AnimeFileInfo) -> bool {
+    let excluded = args.exclude_group.as_ref().is_some_and(|groups| {
+        groups
+            .iter()
+            .any(|group| group.trim().eq_ignore_ascii_case(info.publisher.trim()))
+    });
+    if excluded && args.verbose {
+        eprintln!(
+            "跳过：发布组 {} 在 --exclude-group 中 {}",
+            info.publisher, info.original_path
+        );
+    }
+    excluded
+}
+
+/// `discs` 为 `--bdmv` 找到的蓝光原盘目录，整体整理，扫描时跳过其中的文件。
+fn collect_source_files(
     source: &Path,
+    target: &Path,
     extensions: &HashSet<String>,
-    filename_parser: FilenameParserMode,
-    verbose: bool,
-) -> Result<HashMap<String, Vec<AnimeFileInfo>>, AppError> {
-    let mut groups: HashMap<String, Vec<AnimeFileInfo>> = HashMap::new();
+    args: &OrganizeArgs,
+    discs: &[PathBuf],
+) -> Result<SourceFiles, AppError> {
+    let modified_after = newer_than_cutoff(args)?;
+    let mut skips = SkipStats::default();
+    let mut files = Vec::new();
+    let mut excluded_dirs = excluded_target_dirs(source, target, args);
+    excluded_dirs.extend(discs.iter().cloned());
+    // 不在扫描阶段过滤，以便按原因统计被跳过的文件。
+    let options = ScanOptions {
+        follow_symlinks: args.follow_symlinks,
+        include_hidden: args.include_hidden,
+        excluded_dirs,
+        ..ScanOptions::default()
+    };
+    let paths = match &args.from_file {
+        Some(list) => listed_source_files(list)?,
+        None => scan_source(source, args, &options),
+    };
+    if let Some(pattern) =
+        glob_source(args).filter(|_| args.from_file.is_none() && paths.is_empty())
+    {
+        return Err(AppError::GlobNoMatch(pattern.to_path_buf()));
+    }
+    for path in paths {
+        if !scan::has_extension(&path, extensions) {
+            if !(args.extract_archives && is_archive_path(&path)) {
+                skips.record(SkipReason::UnsupportedExtension);
+            }
+        } else if !args.allow_empty && is_empty_file(&path) {
+            if args.verbose {
+                eprintln!("跳过：0 字节文件 {}", path.display());
+            }
+            skips.record(SkipReason::EmptyFile);
+        } else if modified_after.is_some_and(|cutoff| !modified_since(&path, cutoff)) {
+            skips.record(SkipReason::Filtered);
+        } else {
+            files.push(path);
+        }
+    }
+    let mut source_files = if args.extract_archives {
+        let mut source_files = extract_source_archives(source, target, extensions, args, files)?;
+        source_files.skips = skips;
+        source_files
+    } else {
+        SourceFiles {
+            files,
+            skips,
+            #[cfg(feature = "archives")]
+            _extract_dir: None,
+        }
+    };
+    scan::sort_files(&mut source_files.files, args.sort);
+    Ok(source_files)
+}
 
-    for entry in WalkDir::new(source)
+/// `--from-file`：读取列表中的源文件，已不存在的文件跳过并警告
+fn listed_source_files(list: &Path) -> Result<Vec<PathBuf>, AppError> {
+    Ok(report::read_path_list(list)?
         .into_iter()
-        .filter_map(|item| item.ok())
-        .filter(|item| item.file_type().is_file())
-    {
-        let path = entry.path();
-        if !has_valid_extension(path, extensions) {
+        .filter(|path| {
+            let exists = path.is_file();
+            if !exists {
+                eprintln!("警告：列表中的文件不存在，已跳过 {}", path.display());
+            }
+            exists
+        })
+        .collect())
+}
+
+/// 是否为 0 字节文件；读取元数据失败时交由后续整理报错
+fn is_empty_file(path: &Path) -> bool {
+    std::fs::metadata(path).is_ok_and(|metadata| metadata.len() == 0)
+}
+
+/// `--bdmv`：源目录中的蓝光原盘目录，不含位于目标目录内（已整理）的原盘；
+/// 通配符源与 `--from-file` 不查找。
+fn bdmv_discs(source: &Path, target: &Path, args: &OrganizeArgs) -> Vec<PathBuf> {
+    if !args.bdmv || glob_source(args).is_some() || args.from_file.is_some() {
+        return Vec::new();
+    }
+    let excluded = excluded_target_dirs(source, target, args);
+    bdmv::find_disc_roots(source)
+        .into_iter()
+        .filter(|disc| !excluded.iter().any(|dir| disc.starts_with(dir)))
+        .collect()
+}
+
+/// `--bdmv`：把每个蓝光原盘目录整体整理到 `目标/番名/原盘目录名/`，预览模式只打印计划。
+/// 目录名中取不出番名的原盘计入"命名不匹配"。
+fn organize_bdmv_discs(
+    discs: &[PathBuf],
+    target: &Path,
+    args: &OrganizeArgs,
+    skips: &mut SkipStats,
+) -> OrganizeSummary {
+    let mut summary = OrganizeSummary::default();
+    for disc in discs {
+        let Some(disc_target) = bdmv::disc_target(disc, target) else {
+            if args.verbose {
+                eprintln!("跳过：无法从蓝光原盘目录名取出番名 {}", disc.display());
+            }
+            skips.record(SkipReason::UnrecognizedName);
             continue;
+        };
+        summary.processed += 1;
+        if args.dry_run {
+            report_line(
+                args,
+                &format!(
+                    "[DRY-RUN] 蓝光原盘 {} -> {}",
+                    disc.display(),
+                    disc_target.display()
+                ),
+            );
+            continue;
+        }
+        match bdmv::organize_disc(disc, &disc_target, args.mode) {
+            Ok(OperationOutcome::Skipped(reason)) => {
+                summary.skipped += 1;
+                skips.record(reason);
+            }
+            Ok(_) => {
+                summary.succeeded += 1;
+                if args.verbose {
+                    status_line(
+                        args.output,
+                        &format!(
+                            "成功: 蓝光原盘 {} -> {}",
+                            disc.display(),
+                            disc_target.display()
+                        ),
+                    );
+                }
+            }
+            Err(error) => {
+                summary.failed += 1;
+                eprintln!("处理蓝光原盘失败 {}: {error}", disc.display());
+            }
         }
+    }
+    summary
+}
 
-        if let Some(info) = parse_anime_file(path, filename_parser, verbose)? {
-            groups
-                .entry(info.anime_name.clone())
-                .or_default()
-                .push(info);
-        } else if verbose {
-            eprintln!(
-                "跳过：无法解析文件名 {}",
-                path.file_name().unwrap_or_default().to_string_lossy()
-            );
+/// 通配符形式的 `--source`；普通路径返回 `None`
+fn glob_source(args: &OrganizeArgs) -> Option<&Path> {
+    args.source
+        .as_deref()
+        .filter(|path| scan::is_glob_pattern(path))
+}
+
+/// 枚举源文件：通配符源展开为匹配的文件（再按 `options.extensions` 过滤），否则递归扫描源目录
+fn scan_source(source: &Path, args: &OrganizeArgs, options: &ScanOptions) -> Vec<PathBuf> {
+    match glob_source(args) {
+        Some(pattern) => scan::expand_glob(pattern, options)
+            .into_iter()
+            .filter(|path| {
+                options
+                    .extensions
+                    .as_ref()
+                    .is_none_or(|extensions| scan::has_extension(path, extensions))
+            })
+            .collect(),
+        None => scan::collect_files(source, options),
+    }
+}
+
+/// 位于源目录之内的目标目录（含 `--target` 指定的全部目标），扫描时跳过以免整理已整理的文件
+fn excluded_target_dirs(source: &Path, target: &Path, args: &OrganizeArgs) -> Vec<PathBuf> {
+    let mut excluded = Vec::new();
+    let roots = args.target.iter().map(|target| {
+        if TargetTemplate::is_template(target) {
+            TargetTemplate::parse(&target.to_string_lossy())
+                .map_or_else(|_| target.clone(), |template| template.base().to_path_buf())
+        } else {
+            target.clone()
+        }
+    });
+    for target in std::iter::once(target.to_path_buf()).chain(roots) {
+        if let Some(dir) = scan::nested_target_dir(source, &target) {
+            if !excluded.contains(&dir) {
+                excluded.push(dir);
+            }
+        }
+    }
+    excluded
+}
+
+#[cfg(feature = "archives")]
+fn is_archive_path(path: &Path) -> bool {
+    anime_organizer::archive::is_archive(path)
+}
+
+#[cfg(not(feature = "archives"))]
+fn is_archive_path(_path: &Path) -> bool {
+    false
+}
+
+#[cfg(feature = "archives")]
+fn extract_source_archives(
+    source: &Path,
+    target: &Path,
+    extensions: &HashSet<String>,
+    args: &OrganizeArgs,
+    mut files: Vec<PathBuf>,
+) -> Result<SourceFiles, AppError> {
+    use anime_organizer::archive;
+
+    let archive_extensions = archive::ARCHIVE_EXTENSIONS
+        .iter()
+        .map(|extension| (*extension).to_string())
+        .collect();
+    let options = ScanOptions {
+        follow_symlinks: args.follow_symlinks,
+        include_hidden: args.include_hidden,
+        excluded_dirs: excluded_target_dirs(source, target, args),
+        ..ScanOptions::with_extensions(archive_extensions)
+    };
+    let archives = scan_source(source, args, &options);
+    if archives.is_empty() {
+        return Ok(SourceFiles {
+            files,
+            skips: SkipStats::default(),
+            _extract_dir: None,
+        });
+    }
+
+    // 解压到目标目录下，保证硬链接与移动不跨设备；预览模式不写目标目录。
+    let builder = tempfile::Builder::new().prefix(".aniorg-extract-").clone();
+    let extract_dir = if args.dry_run {
+        builder.tempdir()?
+    } else {
+        builder.tempdir_in(target)?
+    };
+    for archive_path in archives {
+        match archive::extract_videos(&archive_path, extract_dir.path(), extensions) {
+            Ok(extracted) => {
+                if args.verbose {
+                    eprintln!(
+                        "已解压 {} 个视频: {}",
+                        extracted.len(),
+                        archive_path.display()
+                    );
+                }
+                files.extend(extracted);
+            }
+            Err(error) => eprintln!("跳过压缩包 {}: {error}", archive_path.display()),
+        }
+    }
+
+    Ok(SourceFiles {
+        files,
+        skips: SkipStats::default(),
+        _extract_dir: Some(extract_dir),
+    })
+}
+
+#[cfg(not(feature = "archives"))]
+fn extract_source_archives(
+    _source: &Path,
+    _target: &Path,
+    _extensions: &HashSet<String>,
+    _args: &OrganizeArgs,
+    _files: Vec<PathBuf>,
+) -> Result<SourceFiles, AppError> {
+    Err(AppError::ParseError(
+        "压缩包支持未启用，请使用 --features archives 编译".to_string(),
+    ))
+}
+
+fn collect_anime_groups(
+    files: &[PathBuf],
+    args: &OrganizeArgs,
+    name_aliases: &NameAliases,
+    skips: &mut SkipStats,
+) -> Result<HashMap<String, Vec<AnimeFileInfo>>, AppError> {
+    let mut groups: HashMap<String, Vec<AnimeFileInfo>> = HashMap::new();
+    let episode_filter = load_episode_filter(args)?;
+
+    for path in files {
+        let path = path.as_path();
+        if let Some(info) = parse_anime_file(path, args, args.verbose)? {
+            if let Some(info) = apply_episode_offset(info, args.episode_offset) {
+                if is_filtered_episode(episode_filter.as_ref(), &info, args.verbose) {
+                    skips.record(SkipReason::Filtered);
+                    continue;
+                }
+                let info = normalize_anime_name(info, args, name_aliases);
+                if is_excluded_group(args, &info) {
+                    skips.record(SkipReason::Filtered);
+                    continue;
+                }
+                groups
+                    .entry(info.anime_name.clone())
+                    .or_default()
+                    .push(info);
+            }
+        } else {
+            if args.verbose {
+                eprintln!(
+                    "跳过：无法解析文件名 {}",
+                    path.file_name().unwrap_or_default().to_string_lossy()
+                );
+            }
+            skips.record(SkipReason::UnrecognizedName);
         }
     }
 
@@ -1277,11 +2761,26 @@ fn resolve_source_and_target(args: &OrganizeArgs) -> Result<(PathBuf, PathBuf),
         AppError::ParseError("整理模式下必须提供 --source；若要使用工作流子命令，请执行 aniorg scrape 或 aniorg match".to_string())
     })?;
 
+    // 通配符源以第一个含通配符的层级之前的目录作为源目录，文件由 `collect_source_files` 展开。
+    let source = if scan::is_glob_pattern(&source) {
+        scan::glob_base(&source)
+    } else {
+        source
+    };
     if !source.exists() {
         return Err(AppError::SourceNotFound(source));
     }
 
-    let target = args.target.clone().unwrap_or_else(|| source.clone());
+    // 目标模板以第一个含占位符的层级之前的目录作为目标根，实际目录按文件渲染。
+    let target = match args.target.first() {
+        Some(target) if TargetTemplate::is_template(target) => {
+            TargetTemplate::parse(&target.to_string_lossy())?
+                .base()
+                .to_path_buf()
+        }
+        Some(target) => target.clone(),
+        None => source.clone(),
+    };
     if !target.exists() {
         return Err(AppError::TargetNotFound(target));
     }
@@ -1289,22 +2788,43 @@ fn resolve_source_and_target(args: &OrganizeArgs) -> Result<(PathBuf, PathBuf),
     Ok((source, target))
 }
 
-fn build_extensions(include_ext: &Option<Vec<String>>) -> HashSet<String> {
-    match include_ext {
-        Some(exts) => exts
-            .iter()
-            .map(|ext| {
-                if ext.starts_with('.') {
-                    ext.to_lowercase()
-                } else {
-                    format!(".{}", ext.to_lowercase())
-                }
-            })
-            .collect(),
+/// 根据 `--include-ext` / `--exclude-ext` 得到本次处理的扩展名
+pub(crate) fn build_extensions(args: &OrganizeArgs) -> HashSet<String> {
+    select_extensions(args.include_ext.as_deref(), args.exclude_ext.as_deref())
+}
+
+/// 计算最终处理的扩展名集合：包含集合（未指定时为默认列表）减去排除集合。
+/// 两者都可含通配符（见 [`scan::extension_matches`]），排除项剔除与其匹配的包含项；
+/// 包含项本身是通配符（如 `mp*`）时无法在此逐一剔除，排除项以 `!` 前缀留在集合中，
+/// 由 [`scan::has_extension`] 逐个文件排除。
+fn select_extensions(include: Option<&[String]>, exclude: Option<&[String]>) -> HashSet<String> {
+    let mut extensions = match include {
+        Some(exts) => exts.iter().map(|ext| normalize_extension(ext)).collect(),
         None => DEFAULT_EXTENSIONS
             .iter()
             .map(|ext| (*ext).to_string())
-            .collect(),
+            .collect::<HashSet<_>>(),
+    };
+    let excluded: Vec<String> = exclude
+        .unwrap_or_default()
+        .iter()
+        .map(|ext| normalize_extension(ext))
+        .collect();
+    for pattern in &excluded {
+        extensions.retain(|ext| !scan::extension_matches(pattern, ext));
+    }
+    if extensions.iter().any(|ext| ext.contains(['*', '?'])) {
+        extensions.extend(excluded.iter().map(|pattern| format!("!{pattern}")));
+    }
+    extensions
+}
+
+/// 统一为带前导点的小写形式，`MKV` 与 `.mkv` 视为同一扩展名
+fn normalize_extension(ext: &str) -> String {
+    if ext.starts_with('.') {
+        ext.to_lowercase()
+    } else {
+        format!(".{}", ext.to_lowercase())
     }
 }
 
@@ -1425,16 +2945,47 @@ fn animeatlas_timestamp_is_fresh(
 }
 
 fn has_valid_extension(path: &Path, extensions: &HashSet<String>) -> bool {
-    path.extension()
-        .and_then(|ext| ext.to_str())
-        .map(|ext| extensions.contains(&format!(".{}", ext.to_lowercase())))
-        .unwrap_or(false)
+    scan::has_extension(path, extensions)
 }
 
 #[cfg(all(test, feature = "metadata"))]
 mod tests {
     use super::*;
 
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| (*value).to_string()).collect()
+    }
+
+    #[test]
+    fn exclude_ext_removes_from_default_extensions() {
+        let extensions = select_extensions(None, Some(&strings(&["MKV", ".rmvb"])));
+        assert!(!extensions.contains(".mkv"));
+        assert!(!extensions.contains(".rmvb"));
+        assert!(extensions.contains(".mp4"));
+        assert_eq!(extensions.len(), DEFAULT_EXTENSIONS.len() - 2);
+    }
+
+    #[test]
+    fn exclude_ext_applies_after_include_ext() {
+        let extensions = select_extensions(
+            Some(&strings(&["mkv", ".MP4", "ts"])),
+            Some(&strings(&["mp4", "avi"])),
+        );
+        assert_eq!(
+            extensions,
+            HashSet::from([".mkv".to_string(), ".ts".to_string()])
+        );
+        assert!(select_extensions(Some(&strings(&["mkv"])), Some(&strings(&["mkv"]))).is_empty());
+    }
+
+    #[test]
+    fn exclude_ext_applies_per_file_under_wildcard_include() {
+        let extensions = select_extensions(Some(&strings(&["mp*"])), Some(&strings(&["MPG"])));
+        assert!(has_valid_extension(Path::new("a.mp4"), &extensions));
+        assert!(!has_valid_extension(Path::new("a.mpg"), &extensions));
+        assert!(!has_valid_extension(Path::new("a.mkv"), &extensions));
+    }
+
     #[test]
     fn animeatlas_cache_refreshes_daily() {
         let now = std::time::UNIX_EPOCH + ANIMEATLAS_CACHE_TTL * 2;
@@ -1480,16 +3031,38 @@ mod tests {
     #[cfg(feature = "daemon")]
     #[test]
     fn daemon_flag_parses_without_organize_arguments() {
-        let cli = Cli::try_parse_from(["aniorg", "--daemon"]).unwrap();
+        let matches = Cli::command().get_matches_from(["aniorg", "--daemon"]);
+        let cli = Cli::from_arg_matches(&matches).unwrap();
         assert!(cli.daemon);
-        assert!(reject_daemon_conflicts(&cli).is_ok());
+        assert!(reject_daemon_conflicts(&cli, &matches).is_ok());
     }
 
     #[cfg(feature = "daemon")]
     #[test]
     fn daemon_rejects_source_arguments() {
-        let cli = Cli::try_parse_from(["aniorg", "--daemon", "--source", "downloads"]).unwrap();
-        assert!(reject_daemon_conflicts(&cli).is_err());
+        let matches =
+            Cli::command().get_matches_from(["aniorg", "--daemon", "--source", "downloads"]);
+        let cli = Cli::from_arg_matches(&matches).unwrap();
+        assert!(reject_daemon_conflicts(&cli, &matches).is_err());
+    }
+
+    #[test]
+    fn exit_code_reflects_failure_counts() {
+        let summary = |succeeded, failed| RunSummary {
+            succeeded,
+            skipped: 0,
+            failed,
+            transfer: TransferStats::default(),
+        };
+        assert_eq!(summary(0, 0).exit_code(), 0);
+        assert_eq!(summary(3, 0).exit_code(), 0);
+        assert_eq!(summary(2, 1).exit_code(), EXIT_PARTIAL_FAILURE);
+        assert_eq!(summary(0, 2).exit_code(), EXIT_ALL_FAILED);
+        let skipped_only = RunSummary {
+            skipped: 1,
+            ..summary(0, 1)
+        };
+        assert_eq!(skipped_only.exit_code(), EXIT_PARTIAL_FAILURE);
     }
 
     #[test]
@@ -1508,7 +3081,7 @@ mod tests {
 
         run_organize_entry(OrganizeArgs {
             source: Some(source.path().to_path_buf()),
-            target: Some(target.path().to_path_buf()),
+            target: vec![target.path().to_path_buf()],
             mode: OperationMode::Copy,
             library_index: true,
             ..OrganizeArgs::default()
@@ -1537,7 +3110,7 @@ mod tests {
         std::fs::write(source.path().join("[ANi] Test Anime - 01.mkv"), b"video").unwrap();
         run_organize_entry(OrganizeArgs {
             source: Some(source.path().to_path_buf()),
-            target: Some(target.path().to_path_buf()),
+            target: vec![target.path().to_path_buf()],
             mode: OperationMode::Copy,
             dry_run: true,
             ..OrganizeArgs::default()
@@ -1545,4 +3118,44 @@ mod tests {
         .unwrap();
         assert!(!target.path().join("Test Anime").exists());
     }
+
+    #[test]
+    fn organize_entry_generates_sorted_playlist() {
+        let source = tempfile::tempdir().unwrap();
+        let target = tempfile::tempdir().unwrap();
+        for episode in ["10", "02", "12.5"] {
+            std::fs::write(
+                source
+                    .path()
+                    .join(format!("[ANi] Test Anime - {episode}.mkv")),
+                b"video",
+            )
+            .unwrap();
+        }
+
+        run_organize_entry(OrganizeArgs {
+            source: Some(source.path().to_path_buf()),
+            target: vec![target.path().to_path_buf()],
+            mode: OperationMode::Copy,
+            generate_playlist: true,
+            ..OrganizeArgs::default()
+        })
+        .unwrap();
+
+        let playlist =
+            std::fs::read_to_string(target.path().join("Test Anime").join("Test Anime.m3u"))
+                .unwrap();
+        let lines = playlist
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            [
+                "[ANi] Test Anime - 02.mkv",
+                "[ANi] Test Anime - 10.mkv",
+                "[ANi] Test Anime - 12.5.mkv",
+            ]
+        );
+    }
 }
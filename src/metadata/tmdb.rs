@@ -8,6 +8,11 @@
 //! 2. TMDB（备选海报和背景图）
 //! 3. AniDB（仅当 alias 中有 anidb_id 时）
 //! 4. 跳过（不报错）
+//!
+//! ## 绝对集数换算
+//!
+//! 跨季连续编号的资源可借助 TMDB 的每季集数换算回分季集数：换算本身是纯函数
+//! [`absolute_to_season_episode`]，查询各季集数的网络部分需启用 `tmdb` feature。
 
 use crate::error::{AppError, Result};
 use regex::Regex;
@@ -90,6 +95,24 @@ pub struct TmdbImage {
     pub iso_639_1: Option<String>,
 }
 
+/// TMDB 单季概要（`/tv/{id}` 详情中的 `seasons` 项）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TmdbSeason {
+    /// 季号，0 为特别篇
+    pub season_number: u32,
+    /// 本季集数
+    #[serde(default)]
+    pub episode_count: u32,
+}
+
+/// 只含各季概要的 TMDB 电视剧详情
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TmdbTvSeasons {
+    /// 各季概要
+    #[serde(default)]
+    pub seasons: Vec<TmdbSeason>,
+}
+
 /// TMDB 图片尺寸
 #[derive(Debug, Clone, Copy)]
 pub enum ImageSize {
@@ -242,6 +265,51 @@ impl TmdbClient {
         Ok(show)
     }
 
+    /// 获取 TMDB 电视剧的各季集数（含季号为 0 的特别篇）
+    #[cfg(feature = "tmdb")]
+    pub async fn get_seasons(&self, tmdb_id: u32) -> Result<Vec<TmdbSeason>> {
+        let url = format!("{TMDB_API_BASE}/tv/{tmdb_id}");
+        let resp = self
+            .http
+            .get(&url)
+            .query(&[("api_key", self.api_key.as_str())])
+            .send()
+            .await
+            .map_err(|e| AppError::TmdbApiError(format!("TMDB 季度请求失败: {e}")))?;
+
+        if !resp.status().is_success() {
+            return Err(AppError::TmdbApiError(format!(
+                "获取 TMDB 季度信息失败 (HTTP {})",
+                resp.status()
+            )));
+        }
+
+        let details: TmdbTvSeasons = resp
+            .json()
+            .await
+            .map_err(|e| AppError::TmdbApiError(format!("解析 TMDB 季度信息失败: {e}")))?;
+
+        Ok(details.seasons)
+    }
+
+    /// 按 TMDB 的每季集数把绝对集数换算为 `(季号, 季内集数)`。
+    ///
+    /// 离线或请求失败时记录警告并返回 `None`，调用方应保留原集数继续整理。
+    #[cfg(feature = "tmdb")]
+    pub async fn season_episode_for_absolute(
+        &self,
+        tmdb_id: u32,
+        absolute: u32,
+    ) -> Option<(u32, u32)> {
+        match self.get_seasons(tmdb_id).await {
+            Ok(seasons) => absolute_to_season_episode(&seasons, absolute),
+            Err(e) => {
+                tracing::warn!("无法获取 TMDB {tmdb_id} 的季度信息，跳过集数换算: {e}");
+                None
+            }
+        }
+    }
+
     /// 获取 TMDB 电视剧图片列表
     #[cfg(feature = "metadata")]
     pub async fn get_images(&self, tmdb_id: u32) -> Result<TmdbImages> {
@@ -420,6 +488,38 @@ fn normalize_title(value: &str) -> String {
         .collect()
 }
 
+/// 按各季集数把绝对集数（从 1 开始）换算为 `(季号, 季内集数)`。
+///
+/// 季号为 0 的特别篇不参与计数，其余各季按季号顺序依次累加；集数为 0 或
+/// 超出全部正片集数时返回 `None`。
+///
+/// ```
+/// use anime_organizer::metadata::tmdb::{absolute_to_season_episode, TmdbSeason};
+///
+/// let seasons = [
+///     TmdbSeason { season_number: 1, episode_count: 25 },
+///     TmdbSeason { season_number: 2, episode_count: 12 },
+/// ];
+/// assert_eq!(absolute_to_season_episode(&seasons, 28), Some((2, 3)));
+/// ```
+#[must_use]
+pub fn absolute_to_season_episode(seasons: &[TmdbSeason], absolute: u32) -> Option<(u32, u32)> {
+    let mut regular: Vec<&TmdbSeason> = seasons
+        .iter()
+        .filter(|season| season.season_number > 0)
+        .collect();
+    regular.sort_by_key(|season| season.season_number);
+
+    let mut remaining = absolute.checked_sub(1)?;
+    for season in regular {
+        if remaining < season.episode_count {
+            return Some((season.season_number, remaining + 1));
+        }
+        remaining -= season.episode_count;
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -531,6 +631,59 @@ mod tests {
         assert!(result.results[0].backdrop_path.is_none());
     }
 
+    fn season(season_number: u32, episode_count: u32) -> TmdbSeason {
+        TmdbSeason {
+            season_number,
+            episode_count,
+        }
+    }
+
+    #[test]
+    fn test_absolute_to_season_episode() {
+        let seasons = [season(1, 25), season(2, 12), season(3, 22)];
+        assert_eq!(absolute_to_season_episode(&seasons, 1), Some((1, 1)));
+        assert_eq!(absolute_to_season_episode(&seasons, 25), Some((1, 25)));
+        assert_eq!(absolute_to_season_episode(&seasons, 26), Some((2, 1)));
+        assert_eq!(absolute_to_season_episode(&seasons, 37), Some((2, 12)));
+        assert_eq!(absolute_to_season_episode(&seasons, 38), Some((3, 1)));
+        assert_eq!(absolute_to_season_episode(&seasons, 59), Some((3, 22)));
+    }
+
+    #[test]
+    fn test_absolute_to_season_episode_out_of_range() {
+        let seasons = [season(1, 12), season(2, 12)];
+        assert_eq!(absolute_to_season_episode(&seasons, 0), None);
+        assert_eq!(absolute_to_season_episode(&seasons, 25), None);
+        assert_eq!(absolute_to_season_episode(&[], 1), None);
+    }
+
+    #[test]
+    fn test_absolute_to_season_episode_skips_specials_and_sorts_seasons() {
+        let seasons = [season(2, 10), season(0, 5), season(1, 12), season(3, 0)];
+        assert_eq!(absolute_to_season_episode(&seasons, 12), Some((1, 12)));
+        assert_eq!(absolute_to_season_episode(&seasons, 13), Some((2, 1)));
+        assert_eq!(absolute_to_season_episode(&seasons, 23), None);
+    }
+
+    #[test]
+    fn test_tmdb_seasons_deserialization() {
+        let json = r#"{
+            "id": 1429,
+            "name": "Attack on Titan",
+            "seasons": [
+                {"season_number": 0, "episode_count": 38, "name": "Specials"},
+                {"season_number": 1, "episode_count": 25, "name": "Season 1"},
+                {"season_number": 2, "name": "Season 2"}
+            ]
+        }"#;
+
+        let details: TmdbTvSeasons = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            details.seasons,
+            [season(0, 38), season(1, 25), season(2, 0)]
+        );
+    }
+
     #[test]
     fn test_tmdb_images_deserialization() {
         let json = r#"{
@@ -0,0 +1,249 @@
+//! 番名别名映射模块
+//!
+//! 同一部番的中日英名或罗马音混用会被整理到多个目录。别名映射文件把这些写法统一为规范名，
+//! 查找时忽略大小写与空白。
+//!
+//! 支持两种文件格式（按扩展名区分）：
+//!
+//! - `.csv`：每行 `别名,规范名`，字段可用双引号包裹
+//! - 其他（TOML 风格）：每行 `别名 = 规范名`，键和值可用双引号包裹，`#` 开头为注释
+//!
+//! # 示例
+//!
+//! ```
+//! use anime_organizer::name_aliases::NameAliases;
+//!
+//! let aliases = NameAliases::parse_toml(r#"
+//! "Sousou no Frieren" = "葬送的芙莉莲"
+//! Frieren = 葬送的芙莉莲
+//! "#)?;
+//! assert_eq!(aliases.resolve("sousou no  FRIEREN"), "葬送的芙莉莲");
+//! assert_eq!(aliases.resolve("Other Show"), "Other Show");
+//! # Ok::<(), anime_organizer::AppError>(())
+//! ```
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::error::{AppError, Result};
+
+/// 别名到规范名的映射
+#[derive(Debug, Clone, Default)]
+pub struct NameAliases {
+    canonical_by_key: HashMap<String, String>,
+}
+
+impl NameAliases {
+    /// 从文件加载别名映射；`.csv` 按 CSV 解析，其余按 TOML 风格的 `别名 = 规范名` 解析。
+    ///
+    /// # 错误
+    ///
+    /// 文件无法读取或存在格式错误的行时返回 [`AppError::AliasLoadError`]。
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|error| {
+            AppError::AliasLoadError(format!("读取别名映射文件失败 {}: {error}", path.display()))
+        })?;
+        let is_csv = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+        let parsed = if is_csv {
+            Self::parse_csv(&content)
+        } else {
+            Self::parse_toml(&content)
+        };
+        parsed.map_err(|error| match error {
+            AppError::AliasLoadError(message) => {
+                AppError::AliasLoadError(format!("{}: {message}", path.display()))
+            }
+            other => other,
+        })
+    }
+
+    /// 解析 TOML 风格的 `别名 = 规范名` 映射。
+    ///
+    /// # 错误
+    ///
+    /// 某行缺少 `=` 或别名、规范名为空时返回 [`AppError::AliasLoadError`]。
+    pub fn parse_toml(content: &str) -> Result<Self> {
+        let mut aliases = Self::default();
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('[') {
+                continue;
+            }
+            let (alias, canonical) =
+                split_toml_line(line).ok_or_else(|| invalid_line(index, "应为 `别名 = 规范名`"))?;
+            aliases.insert(index, &alias, &canonical)?;
+        }
+        Ok(aliases)
+    }
+
+    /// 解析每行 `别名,规范名` 的 CSV 映射。
+    ///
+    /// # 错误
+    ///
+    /// 某行字段数不为 2 或别名、规范名为空时返回 [`AppError::AliasLoadError`]。
+    pub fn parse_csv(content: &str) -> Result<Self> {
+        let mut aliases = Self::default();
+        for (index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let fields = split_csv_line(line);
+            let [alias, canonical] = fields.as_slice() else {
+                return Err(invalid_line(index, "应为 `别名,规范名`"));
+            };
+            aliases.insert(index, alias, canonical)?;
+        }
+        Ok(aliases)
+    }
+
+    /// 查找别名对应的规范名（大小写与空白不敏感），未命中返回 `None`。
+    #[must_use]
+    pub fn canonical_name(&self, name: &str) -> Option<&str> {
+        self.canonical_by_key
+            .get(&alias_key(name))
+            .map(String::as_str)
+    }
+
+    /// 命中别名时返回规范名，否则原样返回输入名称。
+    #[must_use]
+    pub fn resolve<'a>(&'a self, name: &'a str) -> &'a str {
+        self.canonical_name(name).unwrap_or(name)
+    }
+
+    /// 映射条目数量
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.canonical_by_key.len()
+    }
+
+    /// 是否为空映射
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.canonical_by_key.is_empty()
+    }
+
+    fn insert(&mut self, index: usize, alias: &str, canonical: &str) -> Result<()> {
+        let (alias, canonical) = (alias.trim(), canonical.trim());
+        if alias.is_empty() || canonical.is_empty() {
+            return Err(invalid_line(index, "别名和规范名不能为空"));
+        }
+        self.canonical_by_key
+            .insert(alias_key(alias), canonical.to_string());
+        Ok(())
+    }
+}
+
+/// 别名查找键：去除所有空白并转为小写。
+#[must_use]
+pub fn alias_key(name: &str) -> String {
+    name.chars()
+        .filter(|ch| !ch.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn invalid_line(index: usize, message: &str) -> AppError {
+    AppError::AliasLoadError(format!("第 {} 行格式错误，{message}", index + 1))
+}
+
+fn split_toml_line(line: &str) -> Option<(String, String)> {
+    let (key, rest) = if let Some(quoted) = line.strip_prefix('"') {
+        let end = quoted.find('"')?;
+        let rest = quoted[end + 1..].trim_start().strip_prefix('=')?;
+        (quoted[..end].to_string(), rest)
+    } else {
+        let (key, rest) = line.split_once('=')?;
+        (key.trim().to_string(), rest)
+    };
+    Some((key, unquote(rest.trim()).to_string()))
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(ch),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_ignores_case_and_whitespace() {
+        let aliases = NameAliases::parse_toml("\"Sousou no Frieren\" = \"葬送的芙莉莲\"").unwrap();
+        assert_eq!(
+            aliases.canonical_name("SOUSOU NO FRIEREN"),
+            Some("葬送的芙莉莲")
+        );
+        assert_eq!(
+            aliases.canonical_name("sousounofrieren"),
+            Some("葬送的芙莉莲")
+        );
+        assert_eq!(
+            aliases.canonical_name("  Sousou  no\tFrieren "),
+            Some("葬送的芙莉莲")
+        );
+    }
+
+    #[test]
+    fn unmatched_name_is_kept() {
+        let aliases = NameAliases::parse_toml("Frieren = 葬送的芙莉莲").unwrap();
+        assert_eq!(aliases.canonical_name("Other Show"), None);
+        assert_eq!(aliases.resolve("Other Show"), "Other Show");
+    }
+
+    #[test]
+    fn toml_skips_comments_and_tables() {
+        let aliases = NameAliases::parse_toml(
+            "# 别名表\n[aliases]\n\nFrieren = \"葬送的芙莉莲\"\n\"Kusuriya = Hitorigoto\" = 药屋少女的呢喃\n",
+        )
+        .unwrap();
+        assert_eq!(aliases.len(), 2);
+        assert_eq!(aliases.resolve("frieren"), "葬送的芙莉莲");
+        assert_eq!(aliases.resolve("Kusuriya = Hitorigoto"), "药屋少女的呢喃");
+    }
+
+    #[test]
+    fn csv_supports_quoted_fields() {
+        let aliases = NameAliases::parse_csv(
+            "Frieren,葬送的芙莉莲\n\"Re:Zero, Season 2\",Re:从零开始的异世界生活\n",
+        )
+        .unwrap();
+        assert_eq!(aliases.resolve("frieren"), "葬送的芙莉莲");
+        assert_eq!(
+            aliases.resolve("re:zero, season 2"),
+            "Re:从零开始的异世界生活"
+        );
+    }
+
+    #[test]
+    fn malformed_lines_are_rejected() {
+        assert!(NameAliases::parse_toml("Frieren").is_err());
+        assert!(NameAliases::parse_toml("Frieren = ").is_err());
+        assert!(NameAliases::parse_csv("a,b,c").is_err());
+    }
+}
@@ -0,0 +1,260 @@
+//! 整理结束通知模块
+//!
+//! `--notify <URL>` 在整理结束后向 webhook POST 一份 JSON 摘要（需 `notify` feature），
+//! `--notify-desktop` 通过系统自带的命令（Linux 的 `notify-send`、macOS 的 `osascript`）
+//! 弹出桌面通知。通知失败只由调用方输出警告，不影响整理结果与退出码。
+//!
+//! # 示例
+//!
+//! ```
+//! use anime_organizer::notify::NotifySummary;
+//! use anime_organizer::OperationMode;
+//!
+//! let summary = NotifySummary {
+//!     source: "/downloads".to_string(),
+//!     targets: vec!["/anime".to_string()],
+//!     mode: OperationMode::Link,
+//!     dry_run: false,
+//!     succeeded: 12,
+//!     skipped: 1,
+//!     failed: 0,
+//!     error: None,
+//! };
+//! let payload = summary.webhook_payload();
+//! assert_eq!(payload["event"], "organize_finished");
+//! assert_eq!(payload["summary"]["succeeded"], 12);
+//! ```
+
+use crate::error::{AppError, Result};
+use crate::organizer::OperationMode;
+use serde::Serialize;
+use std::process::{Command, Stdio};
+
+/// 一次整理结束后的摘要
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NotifySummary {
+    /// 源路径
+    pub source: String,
+    /// 目标根目录（未指定时为空，即整理到源目录）
+    pub targets: Vec<String>,
+    /// 操作模式
+    pub mode: OperationMode,
+    /// 是否为预览模式
+    pub dry_run: bool,
+    /// 成功的文件数
+    pub succeeded: usize,
+    /// 目标已存在而跳过的文件数
+    pub skipped: usize,
+    /// 失败的文件数
+    pub failed: usize,
+    /// 整理中途出错（如目录不存在）时的错误信息
+    pub error: Option<String>,
+}
+
+impl NotifySummary {
+    /// 通知标题：按是否出错、是否有失败文件区分
+    #[must_use]
+    pub fn title(&self) -> &'static str {
+        if self.error.is_some() {
+            "aniorg 整理出错"
+        } else if self.failed > 0 {
+            "aniorg 整理完成（部分失败）"
+        } else {
+            "aniorg 整理完成"
+        }
+    }
+
+    /// 通知正文，如 `成功12个，已存在跳过1个，失败0个`
+    #[must_use]
+    pub fn message(&self) -> String {
+        let mut message = format!(
+            "{}成功{}个，已存在跳过{}个，失败{}个",
+            if self.dry_run { "[预览] " } else { "" },
+            self.succeeded,
+            self.skipped,
+            self.failed
+        );
+        if let Some(error) = &self.error {
+            message.push_str(&format!("；错误: {error}"));
+        }
+        message
+    }
+
+    /// webhook 的 JSON 请求体：`title`/`text` 便于聊天机器人直接展示，`summary` 为完整摘要
+    #[must_use]
+    pub fn webhook_payload(&self) -> serde_json::Value {
+        serde_json::json!({
+            "event": "organize_finished",
+            "title": self.title(),
+            "text": self.message(),
+            "summary": self,
+        })
+    }
+}
+
+/// 向 webhook POST JSON 摘要。
+///
+/// # 错误
+///
+/// 请求失败或响应状态码不是 2xx 时返回 `AppError::NotifyError`。
+#[cfg(feature = "notify")]
+pub fn post_webhook(url: &str, summary: &NotifySummary) -> Result<()> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .map_err(|error| AppError::NotifyError(format!("创建 HTTP 客户端失败: {error}")))?;
+    let response = client
+        .post(url)
+        .json(&summary.webhook_payload())
+        .send()
+        .map_err(|error| AppError::NotifyError(format!("请求 {url} 失败: {error}")))?;
+    if !response.status().is_success() {
+        return Err(AppError::NotifyError(format!(
+            "{url} 返回状态码 {}",
+            response.status()
+        )));
+    }
+    Ok(())
+}
+
+/// 未启用 `notify` feature 时无法发送 webhook。
+///
+/// # 错误
+///
+/// 总是返回 `AppError::NotifyError`。
+#[cfg(not(feature = "notify"))]
+pub fn post_webhook(url: &str, _summary: &NotifySummary) -> Result<()> {
+    Err(AppError::NotifyError(format!(
+        "未启用 notify 功能，无法发送到 {url}，请使用 --features notify 编译"
+    )))
+}
+
+/// 当前平台发送桌面通知的命令（程序名与参数）；不支持的平台返回 `None`。
+#[must_use]
+pub fn desktop_command(title: &str, message: &str) -> Option<Vec<String>> {
+    if cfg!(target_os = "macos") {
+        let quote = |text: &str| text.replace('\\', "\\\\").replace('"', "\\\"");
+        Some(vec![
+            "osascript".to_string(),
+            "-e".to_string(),
+            format!(
+                "display notification \"{}\" with title \"{}\"",
+                quote(message),
+                quote(title)
+            ),
+        ])
+    } else if cfg!(unix) {
+        Some(vec![
+            "notify-send".to_string(),
+            "--app-name=aniorg".to_string(),
+            title.to_string(),
+            message.to_string(),
+        ])
+    } else {
+        None
+    }
+}
+
+/// 发送桌面通知。
+///
+/// # 错误
+///
+/// 平台不支持、命令无法启动或退出码非零时返回 `AppError::NotifyError`。
+pub fn send_desktop(summary: &NotifySummary) -> Result<()> {
+    let args = desktop_command(summary.title(), &summary.message())
+        .ok_or_else(|| AppError::NotifyError("当前平台不支持桌面通知".to_string()))?;
+    let status = Command::new(&args[0])
+        .args(&args[1..])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map_err(|error| AppError::NotifyError(format!("无法启动 {}: {error}", args[0])))?;
+    if !status.success() {
+        return Err(AppError::NotifyError(format!(
+            "{} 退出状态 {status}",
+            args[0]
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary() -> NotifySummary {
+        NotifySummary {
+            source: "/downloads".to_string(),
+            targets: vec!["/anime".to_string(), "/backup".to_string()],
+            mode: OperationMode::Copy,
+            dry_run: false,
+            succeeded: 3,
+            skipped: 1,
+            failed: 2,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn payload_contains_counts_and_text() {
+        let payload = summary().webhook_payload();
+
+        assert_eq!(
+            payload,
+            serde_json::json!({
+                "event": "organize_finished",
+                "title": "aniorg 整理完成（部分失败）",
+                "text": "成功3个，已存在跳过1个，失败2个",
+                "summary": {
+                    "source": "/downloads",
+                    "targets": ["/anime", "/backup"],
+                    "mode": "copy",
+                    "dry_run": false,
+                    "succeeded": 3,
+                    "skipped": 1,
+                    "failed": 2,
+                    "error": null,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn error_and_dry_run_are_reflected_in_message() {
+        let summary = NotifySummary {
+            dry_run: true,
+            failed: 0,
+            error: Some("源目录不存在: /downloads".to_string()),
+            ..summary()
+        };
+
+        assert_eq!(summary.title(), "aniorg 整理出错");
+        assert_eq!(
+            summary.message(),
+            "[预览] 成功3个，已存在跳过1个，失败0个；错误: 源目录不存在: /downloads"
+        );
+        assert_eq!(
+            summary.webhook_payload()["summary"]["error"],
+            "源目录不存在: /downloads"
+        );
+    }
+
+    #[test]
+    fn clean_run_has_plain_title() {
+        let summary = NotifySummary {
+            failed: 0,
+            ..summary()
+        };
+        assert_eq!(summary.title(), "aniorg 整理完成");
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    #[test]
+    fn desktop_command_uses_notify_send() {
+        assert_eq!(
+            desktop_command("标题", "正文").unwrap(),
+            ["notify-send", "--app-name=aniorg", "标题", "正文"]
+        );
+    }
+}
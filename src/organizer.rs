@@ -14,20 +14,24 @@
 //! use anime_organizer::organizer::{FileOrganizer, OperationMode};
 //! use anime_organizer::parser::AnimeFileInfo;
 //!
-//! let info = AnimeFileInfo {
-//!     publisher: "ANi".to_string(),
-//!     anime_name: "测试".to_string(),
-//!     episode: "01".to_string(),
-//!     tags: "[1080P]".to_string(),
-//!     extension: ".mp4".to_string(),
-//!     original_path: "/downloads/[ANi] 测试 - 01 [1080P].mp4".to_string(),
-//! };
+//! let info = AnimeFileInfo::builder()
+//!     .publisher("ANi")
+//!     .anime_name("测试")
+//!     .episode("01")
+//!     .tags("[1080P]")
+//!     .extension(".mp4")
+//!     .original_path("/downloads/[ANi] 测试 - 01 [1080P].mp4")
+//!     .build();
 //!
 //! let result = FileOrganizer::organize(&info, "/anime", OperationMode::Copy, false, false);
 //! ```
 
 use crate::error::{AppError, Result};
+use crate::filesystem::{check_readable, hard_link_count, FileSystem, StdFileSystem};
 use crate::parser::AnimeFileInfo;
+use crate::report::RelativePaths;
+use crate::scan::is_system_metadata_file;
+use crate::tags::{filter_tags, normalize_tags, TagCategory};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::{Read, Seek, SeekFrom};
@@ -35,8 +39,11 @@ use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
 /// 文件操作模式
+///
+/// 命令行、环境变量与配置文件中的取值经 [`FromStr`](std::str::FromStr) 解析，
+/// 不区分大小写，并接受 `mv`、`cp`、`hardlink` 等常见别名。
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case", try_from = "String")]
 pub enum OperationMode {
     /// 移动文件
     #[value(name = "move")]
@@ -48,6 +55,9 @@ pub enum OperationMode {
     #[value(name = "link")]
     #[default]
     Link,
+    /// 按源与目标是否位于同一文件系统自动选择：同盘硬链接，跨盘复制（见 [`OperationMode::resolve`]）
+    #[value(name = "auto")]
+    Auto,
 }
 
 impl std::fmt::Display for OperationMode {
@@ -56,8 +66,318 @@ impl std::fmt::Display for OperationMode {
             Self::Move => write!(f, "move"),
             Self::Copy => write!(f, "copy"),
             Self::Link => write!(f, "link"),
+            Self::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+impl std::str::FromStr for OperationMode {
+    type Err = AppError;
+
+    /// 解析操作模式。
+    ///
+    /// ```
+    /// use anime_organizer::OperationMode;
+    ///
+    /// assert_eq!("hardlink".parse::<OperationMode>().unwrap(), OperationMode::Link);
+    /// assert!("symlink".parse::<OperationMode>().is_err());
+    /// ```
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "move" | "mv" => Ok(Self::Move),
+            "copy" | "cp" => Ok(Self::Copy),
+            "link" | "hardlink" | "hard-link" | "hard_link" | "ln" => Ok(Self::Link),
+            "auto" => Ok(Self::Auto),
+            _ => Err(AppError::InvalidOperationMode(value.to_string())),
+        }
+    }
+}
+
+impl OperationMode {
+    /// 将 `Auto` 解析为实际执行的模式：确认跨文件系统时复制，同盘或无法判断时硬链接；
+    /// 其余模式原样返回。
+    ///
+    /// ```
+    /// use anime_organizer::OperationMode;
+    ///
+    /// assert_eq!(OperationMode::Auto.resolve(Some(true)), OperationMode::Link);
+    /// assert_eq!(OperationMode::Auto.resolve(Some(false)), OperationMode::Copy);
+    /// assert_eq!(OperationMode::Move.resolve(Some(false)), OperationMode::Move);
+    /// ```
+    #[must_use]
+    pub fn resolve(self, same_filesystem: Option<bool>) -> Self {
+        match (self, same_filesystem) {
+            (Self::Auto, Some(false)) => Self::Copy,
+            (Self::Auto, _) => Self::Link,
+            (mode, _) => mode,
+        }
+    }
+}
+
+impl TryFrom<String> for OperationMode {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+/// 目标已存在时判断其与源文件是否"相同"（相同则跳过，不同则覆盖或备份）的策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SameFileCheck {
+    /// 大小相同且首尾各 64 KiB 内容一致
+    #[default]
+    Sample,
+    /// 仅比较大小
+    Size,
+    /// 同一设备上的同一 inode（即两者互为硬链接）；非 Unix 平台退回 `sample`
+    Inode,
+    /// 逐字节比较全部内容（见 [`crate::dedup::files_identical`]）
+    Hash,
+    /// 只要目标文件名已存在即视为相同
+    Name,
+}
+
+impl SameFileCheck {
+    /// 按策略判断已存在的 `target_path` 是否与 `source_path` 相同。
+    ///
+    /// # 错误
+    ///
+    /// 读取元数据或内容失败时返回 I/O 错误。
+    pub fn matches(self, source_path: &Path, target_path: &Path) -> std::io::Result<bool> {
+        match self {
+            Self::Sample => FileOrganizer::files_match_quick(source_path, target_path),
+            Self::Size => Ok(fs::metadata(source_path)?.len() == fs::metadata(target_path)?.len()),
+            #[cfg(unix)]
+            Self::Inode => {
+                use std::os::unix::fs::MetadataExt;
+                let (source, target) = (fs::metadata(source_path)?, fs::metadata(target_path)?);
+                Ok(source.dev() == target.dev() && source.ino() == target.ino())
+            }
+            #[cfg(not(unix))]
+            Self::Inode => FileOrganizer::files_match_quick(source_path, target_path),
+            Self::Hash => crate::dedup::files_identical(source_path, target_path),
+            Self::Name => Ok(true),
+        }
+    }
+}
+
+/// 目标已存在且与源文件不同时的处理策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictPolicy {
+    /// 总是用源文件覆盖目标（`backup` 时先备份）
+    #[default]
+    Overwrite,
+    /// 源文件修改时间晚于目标时覆盖，否则保留目标并跳过
+    KeepNewer,
+    /// 源文件大于目标时覆盖，否则保留目标并跳过
+    KeepLarger,
+}
+
+impl ConflictPolicy {
+    /// 按策略判断是否用 `source_path` 替换已存在的 `target_path`；两者相等时保留目标。
+    ///
+    /// # 错误
+    ///
+    /// 读取元数据失败时返回 I/O 错误。
+    pub fn should_replace(self, source_path: &Path, target_path: &Path) -> std::io::Result<bool> {
+        match self {
+            Self::Overwrite => Ok(true),
+            Self::KeepNewer => {
+                Ok(fs::metadata(source_path)?.modified()?
+                    > fs::metadata(target_path)?.modified()?)
+            }
+            Self::KeepLarger => {
+                Ok(fs::metadata(source_path)?.len() > fs::metadata(target_path)?.len())
+            }
+        }
+    }
+}
+
+/// `--with-metadata` 随视频搬运的元数据文件扩展名（小写，不带点）
+pub const METADATA_EXTENSIONS: &[&str] = &["nfo", "jpg", "jpeg", "png", "webp"];
+
+/// 目录级元数据文件名（不含扩展名），如 `poster.jpg`、`thumb.png`；
+/// 搬运时保留原名放到视频的目标目录
+pub const DIRECTORY_METADATA_STEMS: &[&str] = &[
+    "poster",
+    "thumb",
+    "fanart",
+    "folder",
+    "banner",
+    "landscape",
+    "clearlogo",
+];
+
+/// 单个文件的计划操作
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperationPlan {
+    /// 源文件路径
+    pub source: PathBuf,
+    /// 目标文件路径
+    pub target: PathBuf,
+}
+
+/// 实际执行的文件操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutedOp {
+    /// 已移动（含跨设备时的复制后删除）
+    Moved,
+    /// 已复制
+    Copied,
+    /// 已创建硬链接
+    Linked,
+}
+
+impl From<OperationMode> for ExecutedOp {
+    fn from(mode: OperationMode) -> Self {
+        match mode {
+            OperationMode::Move => Self::Moved,
+            OperationMode::Copy => Self::Copied,
+            OperationMode::Link => Self::Linked,
+            // 未经 [`OperationMode::resolve`] 解析时按首选的硬链接计
+            OperationMode::Auto => Self::Linked,
+        }
+    }
+}
+
+/// 文件未被整理的原因
+///
+/// 前六项在遍历源目录时判定，其余由 [`OperationOutcome::Skipped`] 返回；
+/// 统计汇总见 [`crate::report::SkipStats`]。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// 扩展名不在整理范围内
+    UnsupportedExtension,
+    /// 0 字节文件，通常是下载失败留下的占位文件（`--allow-empty` 时不跳过）
+    EmptyFile,
+    /// 文件名无法解析
+    UnrecognizedName,
+    /// 被 `--newer-than`、`--episodes` 等条件过滤
+    Filtered,
+    /// 与本次已处理文件内容相同（`--dedup-by-content`）
+    DuplicateContent,
+    /// 同一集已有优先级更高的扩展名版本（`--prefer-ext`）
+    NonPreferredExtension,
+    /// 上次已处理且未变动（`--state`）
+    Unchanged,
+    /// 源与目标是同一个文件
+    SameFile,
+    /// 目标已存在且内容与源一致
+    AlreadyExists,
+    /// 目标已存在且按 [`ConflictPolicy`] 比源文件更新或更大，予以保留
+    ExistingPreferred,
+}
+
+impl std::fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedExtension => write!(f, "扩展名不符"),
+            Self::EmptyFile => write!(f, "空文件"),
+            Self::UnrecognizedName => write!(f, "命名不匹配"),
+            Self::Filtered => write!(f, "被过滤"),
+            Self::DuplicateContent => write!(f, "内容重复"),
+            Self::NonPreferredExtension => write!(f, "已有优先扩展名版本"),
+            Self::Unchanged => write!(f, "上次已处理且未变动"),
+            Self::SameFile => write!(f, "源与目标为同一文件"),
+            Self::AlreadyExists => write!(f, "目标已存在且内容相同"),
+            Self::ExistingPreferred => write!(f, "已存在的目标较新或较大"),
+        }
+    }
+}
+
+/// 单次整理的结果，用于区分是否实际改动了文件
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationOutcome {
+    /// 已执行文件操作
+    Performed(ExecutedOp),
+    /// 无需操作
+    Skipped(SkipReason),
+    /// 预览模式，未执行
+    DryRun,
+}
+
+/// 整理到目录的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrganizedFile {
+    /// 视频文件的目标路径
+    pub target: PathBuf,
+    /// 视频文件的整理结果（外部字幕不参与判定）
+    pub outcome: OperationOutcome,
+}
+
+/// 单次整理的执行选项
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrganizeOptions {
+    /// 操作模式
+    pub mode: OperationMode,
+    /// 是否为预览模式
+    pub dry_run: bool,
+    /// 覆盖内容不同的已存在目标前是否先改名备份（见 [`FileOrganizer::backup_path`]）
+    pub backup: bool,
+    /// 目标文件名只保留这些类别的标签；`None` 表示保留源文件名
+    pub keep_tags: Option<Vec<TagCategory>>,
+    /// 移动完成后在源位置留下指向目标的符号链接（仅 move 模式生效）
+    pub leave_symlink: bool,
+    /// 跳过内容比较，直接覆盖已存在的目标且不备份（忽略 `backup`）
+    pub force: bool,
+    /// 目标文件重命名为 `{集数}{分隔符}{标签}{扩展名}`（见 [`AnimeFileInfo::target_filename_with`]）；
+    /// `None` 表示保留源文件名
+    pub name_separator: Option<String>,
+    /// 目标文件名模板（见 [`AnimeFileInfo::target_filename_from`]），优先于 `name_separator`
+    /// 与 `keep_tags`；`None` 表示不使用模板
+    pub filename_format: Option<String>,
+    /// 改名时区间集数（如 `01-12`）的渲染模板（见 [`AnimeFileInfo::episode_label_with`]）；
+    /// `None` 表示原样保留
+    pub episode_range_format: Option<String>,
+    /// 源文件旁存在 macOS AppleDouble 伴随文件 `._文件名` 时随视频一起搬运
+    pub keep_apple_double: bool,
+    /// 随视频搬运同目录的元数据文件（见 [`METADATA_EXTENSIONS`]）
+    pub with_metadata: bool,
+    /// 目标已存在时判断是否与源文件相同的策略
+    pub same_file_check: SameFileCheck,
+    /// 目标已存在且与源文件不同时是否覆盖的策略；`force` 时忽略
+    pub on_conflict: ConflictPolicy,
+    /// 建立硬链接后读取目标的链接数，确认不小于 2（见 [`hard_link_count`]）
+    pub verify_links: bool,
+    /// 实际操作后打开目标文件读取首字节，确认可读（见 [`check_readable`]）
+    pub verify_readable: bool,
+    /// 目标不可读时的重试次数，每次间隔 [`VERIFY_READABLE_RETRY_DELAY`]
+    pub verify_readable_retries: u32,
+    /// 目标文件名的最大字节数，超出时从尾部截断标签（见 [`FileOrganizer::truncate_filename_tags`]）；
+    /// `None` 表示不限制
+    pub max_filename_len: Option<usize>,
+    /// 预览输出中以相对路径显示源与目标；`None` 表示显示完整路径
+    pub relative_paths: Option<RelativePaths>,
+    /// 复制时临时文件的后缀（见 [`crate::cleanup::temp_file_path_with`]）；
+    /// `None` 表示默认的 [`crate::cleanup::TEMP_FILE_SUFFIX`]
+    pub temp_suffix: Option<String>,
+    /// 预览模式下不逐行输出计划的操作（`--quiet`）
+    pub quiet: bool,
+}
+
+impl OrganizeOptions {
+    /// 使用指定模式创建选项，其余保持默认
+    #[must_use]
+    pub fn new(mode: OperationMode) -> Self {
+        Self {
+            mode,
+            ..Self::default()
         }
     }
+
+    /// 实际使用的临时文件后缀
+    #[must_use]
+    pub fn temp_suffix(&self) -> &str {
+        self.temp_suffix
+            .as_deref()
+            .unwrap_or(crate::cleanup::TEMP_FILE_SUFFIX)
+    }
 }
 
 /// 文件整理器
@@ -80,7 +400,7 @@ impl FileOrganizer {
     ///
     /// # 返回值
     ///
-    /// 成功返回 `Ok(())`，失败返回相应的错误。
+    /// 成功返回 [`OperationOutcome`]，区分实际执行、无需操作与预览；失败返回相应的错误。
     ///
     /// # 错误
     ///
@@ -94,14 +414,14 @@ impl FileOrganizer {
     /// use anime_organizer::organizer::{FileOrganizer, OperationMode};
     /// use anime_organizer::parser::AnimeFileInfo;
     ///
-    /// let info = AnimeFileInfo {
-    ///     publisher: "ANi".to_string(),
-    ///     anime_name: "测试".to_string(),
-    ///     episode: "01".to_string(),
-    ///     tags: "[1080P]".to_string(),
-    ///     extension: ".mp4".to_string(),
-    ///     original_path: "/downloads/[ANi] 测试 - 01 [1080P].mp4".to_string(),
-    /// };
+    /// let info = AnimeFileInfo::builder()
+    ///     .publisher("ANi")
+    ///     .anime_name("测试")
+    ///     .episode("01")
+    ///     .tags("[1080P]")
+    ///     .extension(".mp4")
+    ///     .original_path("/downloads/[ANi] 测试 - 01 [1080P].mp4")
+    ///     .build();
     ///
     /// // 常规模式：`/anime/测试/[ANi] 测试 - 01 [1080P].mp4`
     /// FileOrganizer::organize(&info, "/anime", OperationMode::Copy, false, false)?;
@@ -116,7 +436,7 @@ impl FileOrganizer {
         mode: OperationMode,
         dry_run: bool,
         season_mode: bool,
-    ) -> Result<()> {
+    ) -> Result<OperationOutcome> {
         let target_dir = if season_mode {
             target_root
                 .as_ref()
@@ -125,7 +445,8 @@ impl FileOrganizer {
         } else {
             target_root.as_ref().join(&anime_file.anime_name)
         };
-        Self::organize_to_dir(anime_file, target_dir, mode, dry_run).map(|_| ())
+        Self::organize_to_dir(anime_file, target_dir, mode, dry_run)
+            .map(|organized| organized.outcome)
     }
 
     /// 将文件整理到指定目录，不额外附加动画名称目录。
@@ -134,54 +455,369 @@ impl FileOrganizer {
         target_dir: P,
         mode: OperationMode,
         dry_run: bool,
-    ) -> Result<PathBuf> {
+    ) -> Result<OrganizedFile> {
         let subtitles = Self::find_external_subtitles(Path::new(&anime_file.original_path));
-        Self::organize_to_dir_with_subtitles(anime_file, target_dir, mode, dry_run, &subtitles)
+        let options = OrganizeOptions {
+            dry_run,
+            ..OrganizeOptions::new(mode)
+        };
+        Self::organize_to_dir_with_subtitles(anime_file, target_dir, &options, &subtitles)
     }
 
     /// 使用已发现的字幕整理文件，供批处理避免重复扫描源目录。
     pub fn organize_to_dir_with_subtitles<P: AsRef<Path>>(
         anime_file: &AnimeFileInfo,
         target_dir: P,
-        mode: OperationMode,
-        dry_run: bool,
+        options: &OrganizeOptions,
+        subtitle_paths: &[PathBuf],
+    ) -> Result<OrganizedFile> {
+        Self::organize_to_dir_with_fs(
+            &StdFileSystem,
+            anime_file,
+            target_dir,
+            options,
+            subtitle_paths,
+        )
+    }
+
+    /// 与 [`FileOrganizer::organize_to_dir_with_subtitles`] 相同，但通过指定的
+    /// [`FileSystem`] 执行写操作，便于测试注入错误。
+    pub fn organize_to_dir_with_fs<P: AsRef<Path>>(
+        file_system: &dyn FileSystem,
+        anime_file: &AnimeFileInfo,
+        target_dir: P,
+        options: &OrganizeOptions,
         subtitle_paths: &[PathBuf],
-    ) -> Result<PathBuf> {
+    ) -> Result<OrganizedFile> {
         let target_dir = target_dir.as_ref();
+        let plans =
+            Self::plan_to_dir_with_subtitles(anime_file, target_dir, subtitle_paths, options)?;
+        let target = plans[0].target.clone();
+
+        if options.dry_run {
+            Self::print_dry_run(&plans, options);
+            return Ok(OrganizedFile {
+                target,
+                outcome: OperationOutcome::DryRun,
+            });
+        }
+
+        file_system.create_dir_all(target_dir)?;
+        let mut outcomes = Vec::with_capacity(plans.len());
+        for plan in &plans {
+            let outcome = Self::organize_path(file_system, &plan.source, &plan.target, options)?;
+            if options.leave_symlink && outcome == OperationOutcome::Performed(ExecutedOp::Moved) {
+                Self::leave_link_at_source(file_system, &plan.source, &plan.target);
+            }
+            if options.verify_links && outcome == OperationOutcome::Performed(ExecutedOp::Linked) {
+                Self::verify_hard_link(&plan.target)?;
+            }
+            if options.verify_readable && matches!(outcome, OperationOutcome::Performed(_)) {
+                Self::verify_readable(&plan.target, options.verify_readable_retries)?;
+            }
+            outcomes.push(outcome);
+        }
+
+        Ok(OrganizedFile {
+            target,
+            outcome: outcomes[0],
+        })
+    }
+
+    /// 预览模式下逐行输出计划的源与目标路径；`quiet` 时不输出。
+    fn print_dry_run(plans: &[OperationPlan], options: &OrganizeOptions) {
+        if options.quiet {
+            return;
+        }
+        for plan in plans {
+            match &options.relative_paths {
+                Some(paths) => println!(
+                    "[DRY-RUN] {} -> {}",
+                    paths.source(&plan.source),
+                    paths.target(&plan.target)
+                ),
+                None => println!(
+                    "[DRY-RUN] {} -> {}",
+                    plan.source.display(),
+                    plan.target.display()
+                ),
+            }
+        }
+    }
+
+    /// 计算视频及其字幕的源/目标路径，不执行任何文件操作。
+    ///
+    /// 返回的第一项总是视频文件本身，其后依次为外部字幕。
+    pub fn plan_to_dir_with_subtitles<P: AsRef<Path>>(
+        anime_file: &AnimeFileInfo,
+        target_dir: P,
+        subtitle_paths: &[PathBuf],
+        options: &OrganizeOptions,
+    ) -> Result<Vec<OperationPlan>> {
         let source_path = Path::new(&anime_file.original_path);
-        let target_filename = source_path
+        let source_filename = source_path
             .file_name()
             .ok_or_else(|| AppError::FileOperation {
                 path: source_path.to_path_buf(),
                 message: "源路径缺少文件名".to_string(),
-            })?;
-        let target_path = target_dir.join(target_filename);
-
-        if dry_run {
-            println!(
-                "[DRY-RUN] {} -> {}",
-                anime_file.original_path,
-                target_path.display()
-            );
-            for subtitle_path in subtitle_paths {
-                println!(
-                    "[DRY-RUN] {} -> {}",
-                    subtitle_path.display(),
-                    Self::subtitle_target_path(source_path, subtitle_path, &target_path).display(),
+            })?
+            .to_string_lossy();
+        let target_filename = match (
+            &options.filename_format,
+            &options.name_separator,
+            &options.keep_tags,
+        ) {
+            (Some(format), _, _) => {
+                let episode = options.episode_range_format.as_ref().map_or_else(
+                    || anime_file.episode.clone(),
+                    |range_format| anime_file.episode_label_with(range_format),
+                );
+                AnimeFileInfo {
+                    episode,
+                    ..anime_file.clone()
+                }
+                .target_filename_from(format)
+            }
+            (None, Some(separator), keep) => {
+                let tags = keep.as_ref().map_or_else(
+                    || anime_file.tags.clone(),
+                    |keep| filter_tags(&anime_file.tags, keep),
                 );
+                let info = AnimeFileInfo {
+                    tags,
+                    ..anime_file.clone()
+                };
+                match &options.episode_range_format {
+                    Some(range_format) => info.target_filename_with_range(separator, range_format),
+                    None => info.target_filename_with(separator),
+                }
+            }
+            (None, None, Some(keep)) => {
+                Self::filename_with_kept_tags(&source_filename, &anime_file.tags, keep)
+            }
+            (None, None, None) => source_filename.to_string(),
+        };
+        let target_filename = match options.max_filename_len {
+            Some(max_len) => Self::truncate_filename_tags(&target_filename, anime_file, max_len),
+            None => target_filename,
+        };
+        let target_path = target_dir.as_ref().join(target_filename);
+
+        let mut plans = vec![OperationPlan {
+            source: source_path.to_path_buf(),
+            target: target_path.clone(),
+        }];
+        plans.extend(subtitle_paths.iter().map(|subtitle_path| OperationPlan {
+            source: subtitle_path.clone(),
+            target: Self::subtitle_target_path(source_path, subtitle_path, &target_path),
+        }));
+        if options.keep_apple_double {
+            if let Some(plan) = Self::apple_double_plan(source_path, &target_path) {
+                plans.push(plan);
             }
-            return Ok(target_path);
         }
+        if options.with_metadata {
+            plans.extend(Self::metadata_plans(source_path, &target_path));
+        }
+        Ok(plans)
+    }
+
+    /// 视频所在目录中的元数据文件及其目标路径，按源路径排序。
+    ///
+    /// - 与视频同名（如 `视频.nfo`、`视频-thumb.jpg`）：改名时保持与目标视频的关联，
+    ///   规则同外部字幕
+    /// - 目录级文件（见 [`DIRECTORY_METADATA_STEMS`]，如 `poster.jpg`）：保留原名放到目标目录
+    fn metadata_plans(source_path: &Path, target_path: &Path) -> Vec<OperationPlan> {
+        let (Some(directory), Some(target_dir)) = (source_path.parent(), target_path.parent())
+        else {
+            return Vec::new();
+        };
+        let Some(video_stem) = source_path.file_stem().and_then(|value| value.to_str()) else {
+            return Vec::new();
+        };
+        let Ok(entries) = fs::read_dir(directory) else {
+            return Vec::new();
+        };
+        let mut plans = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_file()))
+            .map(|entry| entry.path())
+            .filter(|path| !is_system_metadata_file(path))
+            .filter_map(|path| {
+                let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+                if !METADATA_EXTENSIONS.contains(&extension.as_str()) {
+                    return None;
+                }
+                let stem = path.file_stem()?.to_str()?;
+                let target = match stem.strip_prefix(video_stem) {
+                    Some(suffix) if suffix.is_empty() || suffix.starts_with(['-', '.']) => {
+                        Self::subtitle_target_path(source_path, &path, target_path)
+                    }
+                    _ if DIRECTORY_METADATA_STEMS.contains(&stem.to_ascii_lowercase().as_str()) => {
+                        target_dir.join(path.file_name()?)
+                    }
+                    _ => return None,
+                };
+                Some(OperationPlan {
+                    source: path,
+                    target,
+                })
+            })
+            .collect::<Vec<_>>();
+        plans.sort_by(|left, right| left.source.cmp(&right.source));
+        plans
+    }
+
+    /// 执行一条已生成的计划操作，按需创建目标所在目录；`options.dry_run` 被忽略。
+    ///
+    /// 计划可能在生成后过期（见 [`crate::plan`]），执行前重新确认源文件仍存在。
+    ///
+    /// # 错误
+    ///
+    /// - `AppError::FileOperation` - 源文件已不存在
+    /// - 其余同 [`FileOrganizer::organize_to_dir_with_fs`]
+    pub fn execute_plan(
+        file_system: &dyn FileSystem,
+        plan: &OperationPlan,
+        options: &OrganizeOptions,
+    ) -> Result<OperationOutcome> {
+        if !plan.source.is_file() {
+            return Err(AppError::FileOperation {
+                path: plan.source.clone(),
+                message: "源文件已不存在".to_string(),
+            });
+        }
+        if let Some(parent) = plan.target.parent() {
+            file_system.create_dir_all(parent)?;
+        }
+        let outcome = Self::organize_path(file_system, &plan.source, &plan.target, options)?;
+        if options.leave_symlink && outcome == OperationOutcome::Performed(ExecutedOp::Moved) {
+            Self::leave_link_at_source(file_system, &plan.source, &plan.target);
+        }
+        if options.verify_links && outcome == OperationOutcome::Performed(ExecutedOp::Linked) {
+            Self::verify_hard_link(&plan.target)?;
+        }
+        if options.verify_readable && matches!(outcome, OperationOutcome::Performed(_)) {
+            Self::verify_readable(&plan.target, options.verify_readable_retries)?;
+        }
+        Ok(outcome)
+    }
+
+    /// 源文件旁的 AppleDouble 伴随文件 `._文件名` 及其目标路径；不存在时返回 `None`。
+    fn apple_double_plan(source_path: &Path, target_path: &Path) -> Option<OperationPlan> {
+        let companion = |path: &Path| {
+            let name = path.file_name()?.to_string_lossy();
+            Some(path.with_file_name(format!("._{name}")))
+        };
+        let source = companion(source_path).filter(|path| path.is_file())?;
+        Some(OperationPlan {
+            source,
+            target: companion(target_path)?,
+        })
+    }
+
+    /// 将文件名中的标签串替换为只含指定类别标签的版本，其余部分保持不变。
+    ///
+    /// ```
+    /// use anime_organizer::organizer::FileOrganizer;
+    /// use anime_organizer::tags::TagCategory;
+    ///
+    /// assert_eq!(
+    ///     FileOrganizer::filename_with_kept_tags(
+    ///         "[ANi] Test - 01 [1080P][Baha][WEB-DL][AAC AVC][CHT].mp4",
+    ///         "[1080P][Baha][WEB-DL][AAC AVC][CHT]",
+    ///         &[TagCategory::Resolution],
+    ///     ),
+    ///     "[ANi] Test - 01 [1080P].mp4"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn filename_with_kept_tags(filename: &str, tags: &str, keep: &[TagCategory]) -> String {
+        if tags.is_empty() {
+            return filename.to_string();
+        }
+        let Some((position, raw_len)) = filename
+            .rfind(tags)
+            .map(|position| (position, tags.len()))
+            .or_else(|| Self::raw_tags_span(filename, tags))
+        else {
+            return filename.to_string();
+        };
+        let before = filename[..position].trim_end();
+        let after = &filename[position + raw_len..];
+        let kept = filter_tags(tags, keep);
+        if kept.is_empty() {
+            format!("{before}{after}")
+        } else {
+            format!("{before} {kept}{after}")
+        }
+    }
 
-        fs::create_dir_all(target_dir)?;
-        Self::organize_path(source_path, &target_path, mode)?;
-        for subtitle_path in subtitle_paths {
-            let subtitle_target =
-                Self::subtitle_target_path(source_path, subtitle_path, &target_path);
-            Self::organize_path(subtitle_path, &subtitle_target, mode)?;
+    /// 文件名超过 `max_len` 字节时从尾部逐个去掉方括号标签，直到不超限。
+    ///
+    /// 集数与扩展名始终保留：开头的 `[发布组]` 与纯数字的集数段（全方括号格式）不会被去掉；
+    /// 去掉全部标签后仍超限时退回为 `集数.扩展名`（如 `01.mkv`）。
+    ///
+    /// ```
+    /// use anime_organizer::organizer::FileOrganizer;
+    /// use anime_organizer::parser::FilenameParser;
+    ///
+    /// let name = "[ANi] 测试 - 01 [1080P][Baha][WEB-DL][AAC AVC][CHT].mp4";
+    /// let info = FilenameParser::parse_str(name).unwrap();
+    /// assert_eq!(
+    ///     FileOrganizer::truncate_filename_tags(name, &info, 40),
+    ///     "[ANi] 测试 - 01 [1080P][Baha].mp4"
+    /// );
+    /// assert_eq!(FileOrganizer::truncate_filename_tags(name, &info, 20), "01.mp4");
+    /// ```
+    #[must_use]
+    pub fn truncate_filename_tags(
+        filename: &str,
+        anime_file: &AnimeFileInfo,
+        max_len: usize,
+    ) -> String {
+        if filename.len() <= max_len {
+            return filename.to_string();
+        }
+        let extension_len = anime_file.extension.len();
+        let (mut stem, extension) = match filename.len().checked_sub(extension_len) {
+            Some(split)
+                if filename.is_char_boundary(split)
+                    && filename[split..].eq_ignore_ascii_case(&anime_file.extension) =>
+            {
+                filename.split_at(split)
+            }
+            _ => (filename, ""),
+        };
+        while stem.len() + extension.len() > max_len {
+            let Some(open) = stem.strip_suffix(']').and_then(|inner| inner.rfind('[')) else {
+                break;
+            };
+            let segment = &stem[open + 1..stem.len() - 1];
+            if open == 0 || (!segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit())) {
+                break;
+            }
+            stem = stem[..open].trim_end();
         }
+        if stem.len() + extension.len() <= max_len {
+            format!("{stem}{extension}")
+        } else {
+            format!("{}{}", anime_file.episode, anime_file.extension)
+        }
+    }
 
-        Ok(target_path)
+    /// 在文件名中定位归一化前的标签串（如 `(1080p) x264`），返回起始位置与长度。
+    ///
+    /// 解析得到的标签已归一化为方括号形式，与原文件名不再逐字一致；
+    /// 从扩展名前向左逐个字符边界尝试，取第一个归一化后与 `tags` 相同的片段。
+    fn raw_tags_span(filename: &str, tags: &str) -> Option<(usize, usize)> {
+        let stem_end = filename.rfind('.').unwrap_or(filename.len());
+        let stem = &filename[..stem_end];
+        stem.char_indices()
+            .rev()
+            .map(|(index, _)| index)
+            .find(|&index| normalize_tags(&stem[index..]) == tags)
+            .map(|index| (index, stem_end - index))
     }
 
     /// 扫描一次批处理根目录中的所有外部字幕候选。
@@ -237,13 +873,14 @@ impl FileOrganizer {
     }
 
     fn is_external_subtitle(path: &Path) -> bool {
-        matches!(
-            path.extension()
-                .and_then(|value| value.to_str())
-                .map(|value| value.to_ascii_lowercase())
-                .as_deref(),
-            Some("srt" | "ass" | "ssa" | "vtt")
-        )
+        !is_system_metadata_file(path)
+            && matches!(
+                path.extension()
+                    .and_then(|value| value.to_str())
+                    .map(|value| value.to_ascii_lowercase())
+                    .as_deref(),
+                Some("srt" | "ass" | "ssa" | "vtt")
+            )
     }
 
     fn subtitle_target_path(
@@ -271,31 +908,225 @@ impl FileOrganizer {
         target_video.with_file_name(format!("{target_stem}{suffix}.{extension}"))
     }
 
-    fn organize_path(source_path: &Path, target_path: &Path, mode: OperationMode) -> Result<()> {
+    fn organize_path(
+        file_system: &dyn FileSystem,
+        source_path: &Path,
+        target_path: &Path,
+        options: &OrganizeOptions,
+    ) -> Result<OperationOutcome> {
+        let (backup, force) = (options.backup, options.force);
         if source_path == target_path
             || target_path.exists()
                 && fs::canonicalize(source_path)? == fs::canonicalize(target_path)?
         {
-            return Ok(());
+            return Ok(OperationOutcome::Skipped(SkipReason::SameFile));
         }
         if target_path.exists() {
-            if Self::files_match_quick(source_path, target_path)? {
-                return Ok(());
+            if !force && options.same_file_check.matches(source_path, target_path)? {
+                return Ok(OperationOutcome::Skipped(SkipReason::AlreadyExists));
+            }
+            if !force
+                && !options
+                    .on_conflict
+                    .should_replace(source_path, target_path)?
+            {
+                return Ok(OperationOutcome::Skipped(SkipReason::ExistingPreferred));
+            }
+            if backup && !force {
+                let backup_path = Self::backup_path(target_path, &Self::backup_timestamp());
+                file_system.rename(target_path, &backup_path)?;
+            } else {
+                Self::remove_existing_target(file_system, target_path)?;
             }
-            fs::remove_file(target_path)?;
         }
+        let mode = match options.mode {
+            OperationMode::Auto => {
+                let target_dir = target_path.parent().unwrap_or(target_path);
+                OperationMode::Auto.resolve(file_system.same_filesystem(source_path, target_dir))
+            }
+            mode => mode,
+        };
         match mode {
             OperationMode::Move => {
-                if fs::rename(source_path, target_path).is_err() {
-                    fs::copy(source_path, target_path)?;
-                    fs::remove_file(source_path)?;
+                if file_system.rename(source_path, target_path).is_err() {
+                    Self::copy_via_temp(
+                        file_system,
+                        source_path,
+                        target_path,
+                        options.temp_suffix(),
+                    )?;
+                    file_system.remove_file(source_path)?;
                 }
             }
             OperationMode::Copy => {
-                fs::copy(source_path, target_path)?;
+                Self::copy_via_temp(file_system, source_path, target_path, options.temp_suffix())?
+            }
+            OperationMode::Link | OperationMode::Auto => {
+                match Self::create_hard_link(file_system, source_path, target_path) {
+                    // 无法预先判断是否同盘时 auto 先尝试硬链接，失败再退回复制
+                    Err(AppError::CrossDeviceLink | AppError::HardLinkNotSupported)
+                        if options.mode == OperationMode::Auto =>
+                    {
+                        Self::copy_via_temp(
+                            file_system,
+                            source_path,
+                            target_path,
+                            options.temp_suffix(),
+                        )?;
+                        return Ok(OperationOutcome::Performed(ExecutedOp::Copied));
+                    }
+                    result => result?,
+                }
+            }
+        }
+        Ok(OperationOutcome::Performed(mode.into()))
+    }
+
+    /// `--verify-links`：确认新建的硬链接目标链接数不小于 2。
+    ///
+    /// 无法读取链接数的平台上不做校验。
+    ///
+    /// # 错误
+    ///
+    /// 链接数小于 2 时返回 `AppError::FileOperation`。
+    fn verify_hard_link(target_path: &Path) -> Result<()> {
+        match hard_link_count(target_path)? {
+            Some(count) if count < 2 => Err(AppError::FileOperation {
+                path: target_path.to_path_buf(),
+                message: format!("硬链接校验失败：链接数为 {count}，预期至少为 2"),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// `--verify-readable`：打开目标文件读取首字节，失败时按 `retries` 间隔重试。
+    ///
+    /// # 错误
+    ///
+    /// 重试用尽后仍不可读时返回 `AppError::FileOperation`。
+    fn verify_readable(target_path: &Path, retries: u32) -> Result<()> {
+        let mut attempt = 0;
+        loop {
+            match check_readable(target_path) {
+                Ok(()) => return Ok(()),
+                Err(_) if attempt < retries => {
+                    attempt += 1;
+                    std::thread::sleep(VERIFY_READABLE_RETRY_DELAY);
+                }
+                Err(error) => {
+                    return Err(AppError::FileOperation {
+                        path: target_path.to_path_buf(),
+                        message: format!("目标文件不可读（已重试 {retries} 次）: {error}"),
+                    })
+                }
+            }
+        }
+    }
+
+    /// 移动完成后在源位置创建指向目标的符号链接，便于 BT 客户端继续做种。
+    ///
+    /// 符号链接创建失败（如 Windows 缺少权限）时回退为硬链接；仍失败则仅输出警告，
+    /// 不影响已完成的移动。
+    fn leave_link_at_source(file_system: &dyn FileSystem, source_path: &Path, target_path: &Path) {
+        let link_target =
+            fs::canonicalize(target_path).unwrap_or_else(|_| target_path.to_path_buf());
+        let Err(symlink_error) = file_system.symlink(&link_target, source_path) else {
+            return;
+        };
+        if let Err(hard_link_error) = file_system.hard_link(target_path, source_path) {
+            eprintln!(
+                "警告：无法在源位置留下链接 {}：符号链接失败（{symlink_error}），硬链接失败（{hard_link_error}）",
+                source_path.display()
+            );
+        }
+    }
+
+    /// 先复制到同目录的临时文件再改名，避免中断时留下不完整的目标文件。
+    ///
+    /// 临时文件名见 [`crate::cleanup::temp_file_path_with`]，残留时可用 `aniorg clean` 清理。
+    fn copy_via_temp(
+        file_system: &dyn FileSystem,
+        source_path: &Path,
+        target_path: &Path,
+        temp_suffix: &str,
+    ) -> Result<()> {
+        let temp_path = crate::cleanup::temp_file_path_with(target_path, temp_suffix);
+        let result = file_system
+            .copy(source_path, &temp_path)
+            .and_then(|_| file_system.rename(&temp_path, target_path));
+        if let Err(error) = result {
+            let _ = file_system.remove_file(&temp_path);
+            return Err(error.into());
+        }
+        Ok(())
+    }
+
+    /// 将文件设为只读：Unix 去掉所有写权限位，Windows 设置 readonly 属性。
+    ///
+    /// 硬链接与源文件共享同一份数据与权限，对链接设置只读会同时影响源文件。
+    ///
+    /// # 错误
+    ///
+    /// - `AppError::Io` - 读取或修改文件权限失败
+    pub fn set_read_only(path: &Path) -> Result<()> {
+        let mut permissions = fs::metadata(path)?.permissions();
+        if !permissions.readonly() {
+            permissions.set_readonly(true);
+            fs::set_permissions(path, permissions)?;
+        }
+        Ok(())
+    }
+
+    /// 计算覆盖前备份文件的路径。
+    ///
+    /// 优先使用 `原名.bak`；已存在时改用 `原名.{timestamp}.bak`，
+    /// 仍冲突则依次尝试 `原名.{timestamp}-1.bak`、`原名.{timestamp}-2.bak` 等。
+    #[must_use]
+    pub fn backup_path(target_path: &Path, timestamp: &str) -> PathBuf {
+        let file_name = target_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let candidate = target_path.with_file_name(format!("{file_name}.bak"));
+        if !candidate.exists() {
+            return candidate;
+        }
+        let candidate = target_path.with_file_name(format!("{file_name}.{timestamp}.bak"));
+        if !candidate.exists() {
+            return candidate;
+        }
+        (1..)
+            .map(|index| target_path.with_file_name(format!("{file_name}.{timestamp}-{index}.bak")))
+            .find(|candidate| !candidate.exists())
+            .unwrap_or(candidate)
+    }
+
+    /// 备份时间戳（UTC），格式为 `YYYYMMDDHHMMSS`。
+    fn backup_timestamp() -> String {
+        let now = time::OffsetDateTime::now_utc();
+        format!(
+            "{:04}{:02}{:02}{:02}{:02}{:02}",
+            now.year(),
+            u8::from(now.month()),
+            now.day(),
+            now.hour(),
+            now.minute(),
+            now.second()
+        )
+    }
+
+    fn remove_existing_target(file_system: &dyn FileSystem, target_path: &Path) -> Result<()> {
+        // Windows 无法直接删除带 readonly 属性的文件，覆盖前先清除该属性。
+        #[cfg(windows)]
+        {
+            let mut permissions = fs::metadata(target_path)?.permissions();
+            if permissions.readonly() {
+                #[allow(clippy::permissions_set_readonly_false)]
+                permissions.set_readonly(false);
+                fs::set_permissions(target_path, permissions)?;
             }
-            OperationMode::Link => Self::create_hard_link(source_path, target_path)?,
         }
+        file_system.remove_file(target_path)?;
         Ok(())
     }
 
@@ -340,6 +1171,7 @@ impl FileOrganizer {
     ///
     /// # 参数
     ///
+    /// * `file_system` - 执行链接的文件系统
     /// * `source` - 源文件路径
     /// * `target` - 目标路径
     ///
@@ -347,74 +1179,941 @@ impl FileOrganizer {
     ///
     /// - `AppError::CrossDeviceLink` - 源和目标不在同一文件系统
     /// - `AppError::HardLinkNotSupported` - 当前系统不支持硬链接
-    fn create_hard_link<P: AsRef<Path>, Q: AsRef<Path>>(source: P, target: Q) -> Result<()> {
-        let result = fs::hard_link(source.as_ref(), target.as_ref());
-
-        match result {
-            Ok(()) => Ok(()),
-            Err(e) => {
-                // 检查是否为跨设备错误
-                let error_code = e.raw_os_error();
-
-                // Windows: ERROR_NOT_SAME_DEVICE = 17
-                // Unix: EXDEV = 18
-                if error_code == Some(17) || error_code == Some(18) {
-                    Err(AppError::CrossDeviceLink)
-                } else if e.kind() == std::io::ErrorKind::PermissionDenied {
-                    Err(AppError::HardLinkNotSupported)
-                } else {
-                    Err(AppError::Io(e))
-                }
-            }
+    fn create_hard_link(file_system: &dyn FileSystem, source: &Path, target: &Path) -> Result<()> {
+        file_system
+            .hard_link(source, target)
+            .map_err(Self::hard_link_error)
+    }
+
+    /// 将创建硬链接的 I/O 错误转换为更具体的 [`AppError`]。
+    fn hard_link_error(error: std::io::Error) -> AppError {
+        if is_cross_device_error(&error) {
+            AppError::CrossDeviceLink
+        } else if error.kind() == std::io::ErrorKind::PermissionDenied {
+            AppError::HardLinkNotSupported
+        } else {
+            AppError::Io(error)
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl FileOrganizer {
+    /// [`FileOrganizer::organize`] 的异步版本，文件操作通过 `tokio::fs` 执行，不阻塞运行时。
+    ///
+    /// 目标路径与结果与同步版本一致；扫描字幕、比较已存在目标等无异步接口的步骤
+    /// 放到 [`tokio::task::spawn_blocking`] 中执行。
+    ///
+    /// # 错误
+    ///
+    /// 同 [`FileOrganizer::organize`]。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use anime_organizer::organizer::{FileOrganizer, OperationMode};
+    /// use anime_organizer::parser::FilenameParser;
+    ///
+    /// # async fn run() -> anime_organizer::error::Result<()> {
+    /// let info = FilenameParser::parse_str("/downloads/[ANi] 测试 - 01 [1080P].mp4").unwrap();
+    /// FileOrganizer::organize_async(&info, "/anime", OperationMode::Copy, false, false).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn organize_async<P: AsRef<Path>>(
+        anime_file: &AnimeFileInfo,
+        target_root: P,
+        mode: OperationMode,
+        dry_run: bool,
+        season_mode: bool,
+    ) -> Result<OperationOutcome> {
+        let target_dir = if season_mode {
+            target_root
+                .as_ref()
+                .join(anime_file.series_name())
+                .join(anime_file.season_dir_name())
+        } else {
+            target_root.as_ref().join(&anime_file.anime_name)
+        };
+        let video_path = PathBuf::from(&anime_file.original_path);
+        let subtitles =
+            Self::blocking(move || Ok(Self::find_external_subtitles(&video_path))).await?;
+        let options = OrganizeOptions {
+            dry_run,
+            ..OrganizeOptions::new(mode)
+        };
+        Self::organize_to_dir_async(anime_file, target_dir, &options, &subtitles)
+            .await
+            .map(|organized| organized.outcome)
+    }
+
+    /// [`FileOrganizer::organize_to_dir_with_subtitles`] 的异步版本。
+    ///
+    /// # 错误
+    ///
+    /// 同 [`FileOrganizer::organize_to_dir_with_fs`]。
+    pub async fn organize_to_dir_async<P: AsRef<Path>>(
+        anime_file: &AnimeFileInfo,
+        target_dir: P,
+        options: &OrganizeOptions,
+        subtitle_paths: &[PathBuf],
+    ) -> Result<OrganizedFile> {
+        let target_dir = target_dir.as_ref().to_path_buf();
+        let plans = {
+            let (anime_file, target_dir) = (anime_file.clone(), target_dir.clone());
+            let (subtitle_paths, options) = (subtitle_paths.to_vec(), options.clone());
+            Self::blocking(move || {
+                Self::plan_to_dir_with_subtitles(
+                    &anime_file,
+                    &target_dir,
+                    &subtitle_paths,
+                    &options,
+                )
+            })
+            .await?
+        };
+        let target = plans[0].target.clone();
+
+        if options.dry_run {
+            Self::print_dry_run(&plans, options);
+            return Ok(OrganizedFile {
+                target,
+                outcome: OperationOutcome::DryRun,
+            });
+        }
+
+        tokio::fs::create_dir_all(&target_dir).await?;
+        let mut outcomes = Vec::with_capacity(plans.len());
+        for plan in &plans {
+            let outcome = Self::organize_path_async(&plan.source, &plan.target, options).await?;
+            if options.leave_symlink && outcome == OperationOutcome::Performed(ExecutedOp::Moved) {
+                let (source, target) = (plan.source.clone(), plan.target.clone());
+                Self::blocking(move || {
+                    Self::leave_link_at_source(&StdFileSystem, &source, &target);
+                    Ok(())
+                })
+                .await?;
+            }
+            if options.verify_links && outcome == OperationOutcome::Performed(ExecutedOp::Linked) {
+                let target = plan.target.clone();
+                Self::blocking(move || Self::verify_hard_link(&target)).await?;
+            }
+            if options.verify_readable && matches!(outcome, OperationOutcome::Performed(_)) {
+                Self::verify_readable_async(&plan.target, options.verify_readable_retries).await?;
+            }
+            outcomes.push(outcome);
+        }
+
+        Ok(OrganizedFile {
+            target,
+            outcome: outcomes[0],
+        })
+    }
+
+    /// [`FileOrganizer::organize_path`] 的异步版本，始终作用于真实文件系统。
+    async fn organize_path_async(
+        source_path: &Path,
+        target_path: &Path,
+        options: &OrganizeOptions,
+    ) -> Result<OperationOutcome> {
+        let (backup, force) = (options.backup, options.force);
+        let target_exists = tokio::fs::try_exists(target_path).await?;
+        if source_path == target_path
+            || target_exists
+                && tokio::fs::canonicalize(source_path).await?
+                    == tokio::fs::canonicalize(target_path).await?
+        {
+            return Ok(OperationOutcome::Skipped(SkipReason::SameFile));
+        }
+        if target_exists {
+            if !force {
+                let (source, target) = (source_path.to_path_buf(), target_path.to_path_buf());
+                let (same_file_check, on_conflict) = (options.same_file_check, options.on_conflict);
+                let skip = Self::blocking(move || {
+                    if same_file_check.matches(&source, &target)? {
+                        return Ok(Some(SkipReason::AlreadyExists));
+                    }
+                    Ok((!on_conflict.should_replace(&source, &target)?)
+                        .then_some(SkipReason::ExistingPreferred))
+                })
+                .await?;
+                if let Some(reason) = skip {
+                    return Ok(OperationOutcome::Skipped(reason));
+                }
+            }
+            if backup && !force {
+                let backup_path = Self::backup_path(target_path, &Self::backup_timestamp());
+                tokio::fs::rename(target_path, &backup_path).await?;
+            } else {
+                Self::remove_existing_target_async(target_path).await?;
+            }
+        }
+        let mode = match options.mode {
+            OperationMode::Auto => {
+                let (source, target_dir) = (
+                    source_path.to_path_buf(),
+                    target_path.parent().unwrap_or(target_path).to_path_buf(),
+                );
+                let same_filesystem =
+                    Self::blocking(move || Ok(StdFileSystem.same_filesystem(&source, &target_dir)))
+                        .await?;
+                OperationMode::Auto.resolve(same_filesystem)
+            }
+            mode => mode,
+        };
+        match mode {
+            OperationMode::Move => {
+                if tokio::fs::rename(source_path, target_path).await.is_err() {
+                    Self::copy_via_temp_async(source_path, target_path, options.temp_suffix())
+                        .await?;
+                    tokio::fs::remove_file(source_path).await?;
+                }
+            }
+            OperationMode::Copy => {
+                Self::copy_via_temp_async(source_path, target_path, options.temp_suffix()).await?
+            }
+            OperationMode::Link | OperationMode::Auto => {
+                match tokio::fs::hard_link(source_path, target_path)
+                    .await
+                    .map_err(Self::hard_link_error)
+                {
+                    Err(AppError::CrossDeviceLink | AppError::HardLinkNotSupported)
+                        if options.mode == OperationMode::Auto =>
+                    {
+                        Self::copy_via_temp_async(source_path, target_path, options.temp_suffix())
+                            .await?;
+                        return Ok(OperationOutcome::Performed(ExecutedOp::Copied));
+                    }
+                    result => result?,
+                }
+            }
+        }
+        Ok(OperationOutcome::Performed(mode.into()))
+    }
+
+    /// [`FileOrganizer::copy_via_temp`] 的异步版本。
+    async fn copy_via_temp_async(
+        source_path: &Path,
+        target_path: &Path,
+        temp_suffix: &str,
+    ) -> Result<()> {
+        let temp_path = crate::cleanup::temp_file_path_with(target_path, temp_suffix);
+        let result = match tokio::fs::copy(source_path, &temp_path).await {
+            Ok(_) => tokio::fs::rename(&temp_path, target_path).await,
+            Err(error) => Err(error),
+        };
+        if let Err(error) = result {
+            let _ = tokio::fs::remove_file(&temp_path).await;
+            return Err(error.into());
+        }
+        Ok(())
+    }
+
+    /// [`FileOrganizer::remove_existing_target`] 的异步版本。
+    async fn remove_existing_target_async(target_path: &Path) -> Result<()> {
+        // Windows 无法直接删除带 readonly 属性的文件，覆盖前先清除该属性。
+        #[cfg(windows)]
+        {
+            let mut permissions = tokio::fs::metadata(target_path).await?.permissions();
+            if permissions.readonly() {
+                #[allow(clippy::permissions_set_readonly_false)]
+                permissions.set_readonly(false);
+                tokio::fs::set_permissions(target_path, permissions).await?;
+            }
+        }
+        tokio::fs::remove_file(target_path).await?;
+        Ok(())
+    }
+
+    /// [`FileOrganizer::verify_readable`] 的异步版本，重试间隔使用 `tokio::time::sleep`。
+    async fn verify_readable_async(target_path: &Path, retries: u32) -> Result<()> {
+        use tokio::io::AsyncReadExt;
+
+        let mut attempt = 0;
+        loop {
+            let mut byte = [0u8; 1];
+            let result = match tokio::fs::File::open(target_path).await {
+                Ok(mut file) => file.read(&mut byte).await.map(|_| ()),
+                Err(error) => Err(error),
+            };
+            match result {
+                Ok(()) => return Ok(()),
+                Err(_) if attempt < retries => {
+                    attempt += 1;
+                    tokio::time::sleep(VERIFY_READABLE_RETRY_DELAY).await;
+                }
+                Err(error) => {
+                    return Err(AppError::FileOperation {
+                        path: target_path.to_path_buf(),
+                        message: format!("目标文件不可读（已重试 {retries} 次）: {error}"),
+                    })
+                }
+            }
+        }
+    }
+
+    /// 在阻塞线程池中执行没有异步接口的文件系统操作。
+    async fn blocking<T, F>(operation: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> Result<T> + Send + 'static,
+    {
+        tokio::task::spawn_blocking(operation)
+            .await
+            .map_err(|error| AppError::Io(std::io::Error::other(error)))?
+    }
+}
+
+/// `--verify-readable` 两次重试之间的等待时间
+pub const VERIFY_READABLE_RETRY_DELAY: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// 平台表示"跨设备"的原始错误码：Unix 为 `EXDEV`，Windows 为 `ERROR_NOT_SAME_DEVICE`
+#[cfg(unix)]
+const CROSS_DEVICE_OS_ERROR: Option<i32> = Some(libc::EXDEV);
+#[cfg(windows)]
+const CROSS_DEVICE_OS_ERROR: Option<i32> = Some(17);
+#[cfg(not(any(unix, windows)))]
+const CROSS_DEVICE_OS_ERROR: Option<i32> = None;
+
+/// 是否为源与目标不在同一文件系统导致的错误。
+///
+/// 优先按 [`std::io::ErrorKind::CrossesDevices`] 判断；自行构造、不带该分类的错误再按
+/// 当前平台的原始错误码兜底，不同平台的错误码互不混用（Unix 的 17 是 `EEXIST`）。
+fn is_cross_device_error(error: &std::io::Error) -> bool {
+    error.kind() == std::io::ErrorKind::CrossesDevices
+        || CROSS_DEVICE_OS_ERROR.is_some_and(|code| error.raw_os_error() == Some(code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::time::{Duration, SystemTime};
+    use tempfile::TempDir;
+
+    fn create_test_file(dir: &Path, filename: &str, content: &str) -> std::path::PathBuf {
+        let file_path = dir.join(filename);
+        let mut file = fs::File::create(&file_path).unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file_path
+    }
+
+    #[test]
+    fn operation_mode_accepts_aliases() {
+        for (value, mode) in [
+            ("move", OperationMode::Move),
+            ("mv", OperationMode::Move),
+            ("copy", OperationMode::Copy),
+            ("CP", OperationMode::Copy),
+            ("link", OperationMode::Link),
+            ("hardlink", OperationMode::Link),
+            (" Hard-Link ", OperationMode::Link),
+            ("ln", OperationMode::Link),
+        ] {
+            assert_eq!(value.parse::<OperationMode>().unwrap(), mode, "{value}");
+        }
+        let mode: OperationMode = serde_json::from_str(r#""hardlink""#).unwrap();
+        assert_eq!(mode, OperationMode::Link);
+        assert_eq!(
+            serde_json::to_string(&OperationMode::Link).unwrap(),
+            r#""link""#
+        );
+    }
+
+    #[test]
+    fn operation_mode_rejects_unknown_values_listing_choices() {
+        let error = "symlink".parse::<OperationMode>().unwrap_err();
+        assert!(matches!(error, AppError::InvalidOperationMode(ref value) if value == "symlink"));
+        let message = error.to_string();
+        for choice in ["move", "copy", "link", "hardlink"] {
+            assert!(message.contains(choice), "{message}");
+        }
+        assert!(serde_json::from_str::<OperationMode>(r#""symlink""#).is_err());
+    }
+
+    fn create_test_anime_info(source_path: &Path) -> AnimeFileInfo {
+        AnimeFileInfo {
+            publisher: "ANi".to_string(),
+            anime_name: "测试".to_string(),
+            episode: "01".to_string(),
+            tags: "[1080P]".to_string(),
+            extension: ".mp4".to_string(),
+            original_path: source_path.to_string_lossy().to_string(),
+            alt_titles: Vec::new(),
+            is_final: false,
+        }
+    }
+
+    /// 内存文件系统：按操作名与路径注入错误，记录文件内容。
+    #[derive(Default)]
+    struct MockFileSystem {
+        files: std::cell::RefCell<std::collections::HashMap<PathBuf, Vec<u8>>>,
+        failures: std::collections::HashMap<(&'static str, PathBuf), MockError>,
+        same_filesystem: Option<bool>,
+    }
+
+    #[derive(Clone, Copy)]
+    enum MockError {
+        Os(i32),
+        Kind(std::io::ErrorKind),
+    }
+
+    impl MockFileSystem {
+        fn with_file(path: &Path, content: &[u8]) -> Self {
+            let mock = Self::default();
+            mock.files
+                .borrow_mut()
+                .insert(path.to_path_buf(), content.to_vec());
+            mock
+        }
+
+        fn fail(mut self, operation: &'static str, path: &Path, error: MockError) -> Self {
+            self.failures.insert((operation, path.to_path_buf()), error);
+            self
+        }
+
+        fn on_filesystem(mut self, same_filesystem: Option<bool>) -> Self {
+            self.same_filesystem = same_filesystem;
+            self
+        }
+
+        fn check(&self, operation: &'static str, path: &Path) -> std::io::Result<()> {
+            match self.failures.get(&(operation, path.to_path_buf())) {
+                Some(MockError::Os(code)) => Err(std::io::Error::from_raw_os_error(*code)),
+                Some(MockError::Kind(kind)) => Err(std::io::Error::from(*kind)),
+                None => Ok(()),
+            }
+        }
+
+        fn content(&self, path: &Path) -> Option<Vec<u8>> {
+            self.files.borrow().get(path).cloned()
+        }
+
+        fn paths(&self) -> Vec<PathBuf> {
+            let mut paths = self.files.borrow().keys().cloned().collect::<Vec<_>>();
+            paths.sort();
+            paths
+        }
+    }
+
+    impl FileSystem for MockFileSystem {
+        fn copy(&self, from: &Path, to: &Path) -> std::io::Result<u64> {
+            self.check("copy", from)?;
+            let content = self
+                .content(from)
+                .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound))?;
+            let len = content.len() as u64;
+            self.files.borrow_mut().insert(to.to_path_buf(), content);
+            Ok(len)
+        }
+
+        fn rename(&self, from: &Path, to: &Path) -> std::io::Result<()> {
+            self.check("rename", from)?;
+            let content = self
+                .files
+                .borrow_mut()
+                .remove(from)
+                .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound))?;
+            self.files.borrow_mut().insert(to.to_path_buf(), content);
+            Ok(())
+        }
+
+        fn hard_link(&self, original: &Path, link: &Path) -> std::io::Result<()> {
+            self.check("hard_link", original)?;
+            let content = self
+                .content(original)
+                .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound))?;
+            self.files.borrow_mut().insert(link.to_path_buf(), content);
+            Ok(())
+        }
+
+        fn symlink(&self, original: &Path, link: &Path) -> std::io::Result<()> {
+            self.check("symlink", link)?;
+            let content = self
+                .content(original)
+                .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound))?;
+            self.files.borrow_mut().insert(link.to_path_buf(), content);
+            Ok(())
+        }
+
+        fn create_dir_all(&self, path: &Path) -> std::io::Result<()> {
+            self.check("create_dir_all", path)
+        }
+
+        fn remove_file(&self, path: &Path) -> std::io::Result<()> {
+            self.check("remove_file", path)?;
+            self.files
+                .borrow_mut()
+                .remove(path)
+                .map(|_| ())
+                .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound))
+        }
+
+        fn remove_dir(&self, path: &Path) -> std::io::Result<()> {
+            self.check("remove_dir", path)
+        }
+
+        fn same_filesystem(&self, _left: &Path, _right: &Path) -> Option<bool> {
+            self.same_filesystem
+        }
+    }
+
+    fn mock_paths() -> (PathBuf, PathBuf) {
+        let root = Path::new("aniorg-mock-fs-does-not-exist");
+        (
+            root.join("source").join("[ANi] 测试 - 01 [1080P].mp4"),
+            root.join("target").join("测试"),
+        )
+    }
+
+    fn organize_with_mock(
+        mock: &MockFileSystem,
+        source: &Path,
+        target_dir: &Path,
+        mode: OperationMode,
+    ) -> Result<OrganizedFile> {
+        FileOrganizer::organize_to_dir_with_fs(
+            mock,
+            &create_test_anime_info(source),
+            target_dir,
+            &OrganizeOptions::new(mode),
+            &[],
+        )
+    }
+
+    #[test]
+    fn auto_mode_links_on_same_filesystem() {
+        let (source, target_dir) = mock_paths();
+        let mock = MockFileSystem::with_file(&source, b"video").on_filesystem(Some(true));
+
+        let result = organize_with_mock(&mock, &source, &target_dir, OperationMode::Auto);
+
+        assert_eq!(
+            result.unwrap().outcome,
+            OperationOutcome::Performed(ExecutedOp::Linked)
+        );
+        assert_eq!(
+            mock.content(&target_dir.join(source.file_name().unwrap()))
+                .unwrap(),
+            b"video"
+        );
+    }
+
+    #[test]
+    fn auto_mode_copies_across_filesystems() {
+        let (source, target_dir) = mock_paths();
+        // 跨盘时不应尝试硬链接
+        let mock = MockFileSystem::with_file(&source, b"video")
+            .on_filesystem(Some(false))
+            .fail(
+                "hard_link",
+                &source,
+                MockError::Kind(std::io::ErrorKind::Other),
+            );
+
+        let result = organize_with_mock(&mock, &source, &target_dir, OperationMode::Auto);
+
+        assert_eq!(
+            result.unwrap().outcome,
+            OperationOutcome::Performed(ExecutedOp::Copied)
+        );
+        assert_eq!(
+            mock.content(&target_dir.join(source.file_name().unwrap()))
+                .unwrap(),
+            b"video"
+        );
+        assert!(mock.content(&source).is_some());
+    }
+
+    #[test]
+    fn auto_mode_falls_back_to_copy_when_link_crosses_devices() {
+        let (source, target_dir) = mock_paths();
+        let mock = MockFileSystem::with_file(&source, b"video").fail(
+            "hard_link",
+            &source,
+            MockError::Os(CROSS_DEVICE_OS_ERROR.unwrap()),
+        );
+
+        let result = organize_with_mock(&mock, &source, &target_dir, OperationMode::Auto);
+
+        assert_eq!(
+            result.unwrap().outcome,
+            OperationOutcome::Performed(ExecutedOp::Copied)
+        );
+        assert_eq!(
+            mock.content(&target_dir.join(source.file_name().unwrap()))
+                .unwrap(),
+            b"video"
+        );
+    }
+
+    #[test]
+    fn auto_mode_resolution() {
+        assert_eq!(OperationMode::Auto.resolve(Some(true)), OperationMode::Link);
+        assert_eq!(OperationMode::Auto.resolve(None), OperationMode::Link);
+        assert_eq!(
+            OperationMode::Auto.resolve(Some(false)),
+            OperationMode::Copy
+        );
+        for mode in [
+            OperationMode::Move,
+            OperationMode::Copy,
+            OperationMode::Link,
+        ] {
+            assert_eq!(mode.resolve(Some(false)), mode);
+        }
+        assert_eq!(
+            " AUTO ".parse::<OperationMode>().unwrap(),
+            OperationMode::Auto
+        );
+        assert_eq!(OperationMode::Auto.to_string(), "auto");
+    }
+
+    #[test]
+    fn mock_cross_device_link_maps_to_cross_device_error() {
+        let (source, target_dir) = mock_paths();
+        let mock = MockFileSystem::with_file(&source, b"video").fail(
+            "hard_link",
+            &source,
+            MockError::Os(CROSS_DEVICE_OS_ERROR.unwrap()),
+        );
+
+        let result = organize_with_mock(&mock, &source, &target_dir, OperationMode::Link);
+
+        assert!(matches!(result, Err(AppError::CrossDeviceLink)));
+        assert_eq!(mock.paths(), vec![source]);
+    }
+
+    #[test]
+    fn mock_crosses_devices_kind_maps_to_cross_device_error() {
+        let (source, target_dir) = mock_paths();
+        let mock = MockFileSystem::with_file(&source, b"video").fail(
+            "hard_link",
+            &source,
+            MockError::Kind(std::io::ErrorKind::CrossesDevices),
+        );
+
+        let result = organize_with_mock(&mock, &source, &target_dir, OperationMode::Link);
+
+        assert!(matches!(result, Err(AppError::CrossDeviceLink)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn mock_other_platform_error_codes_are_not_cross_device() {
+        let (source, target_dir) = mock_paths();
+        // Windows 的 ERROR_NOT_SAME_DEVICE 在 Unix 上是 EEXIST
+        let mock = MockFileSystem::with_file(&source, b"video").fail(
+            "hard_link",
+            &source,
+            MockError::Os(libc::EEXIST),
+        );
+
+        let result = organize_with_mock(&mock, &source, &target_dir, OperationMode::Link);
+
+        assert!(matches!(result, Err(AppError::Io(_))));
+    }
+
+    #[test]
+    fn mock_permission_denied_link_maps_to_not_supported() {
+        let (source, target_dir) = mock_paths();
+        let mock = MockFileSystem::with_file(&source, b"video").fail(
+            "hard_link",
+            &source,
+            MockError::Kind(std::io::ErrorKind::PermissionDenied),
+        );
+
+        let result = organize_with_mock(&mock, &source, &target_dir, OperationMode::Link);
+
+        assert!(matches!(result, Err(AppError::HardLinkNotSupported)));
+    }
+
+    #[test]
+    fn mock_other_link_errors_stay_io_errors() {
+        let (source, target_dir) = mock_paths();
+        let mock = MockFileSystem::with_file(&source, b"video").fail(
+            "hard_link",
+            &source,
+            MockError::Kind(std::io::ErrorKind::Other),
+        );
+
+        let result = organize_with_mock(&mock, &source, &target_dir, OperationMode::Link);
+
+        assert!(matches!(result, Err(AppError::Io(_))));
+    }
+
+    #[test]
+    fn mock_cross_device_move_falls_back_to_copy_and_delete() {
+        let (source, target_dir) = mock_paths();
+        let mock = MockFileSystem::with_file(&source, b"video").fail(
+            "rename",
+            &source,
+            MockError::Os(CROSS_DEVICE_OS_ERROR.unwrap()),
+        );
+
+        let organized =
+            organize_with_mock(&mock, &source, &target_dir, OperationMode::Move).unwrap();
+        let target = organized.target;
+
+        assert_eq!(
+            organized.outcome,
+            OperationOutcome::Performed(ExecutedOp::Moved)
+        );
+
+        assert_eq!(mock.content(&target).as_deref(), Some(&b"video"[..]));
+        assert_eq!(mock.paths(), vec![target]);
+    }
+
+    #[test]
+    fn mock_leave_symlink_falls_back_to_hard_link() {
+        let (source, target_dir) = mock_paths();
+        let mock = MockFileSystem::with_file(&source, b"video").fail(
+            "symlink",
+            &source,
+            MockError::Kind(std::io::ErrorKind::PermissionDenied),
+        );
+        let options = OrganizeOptions {
+            leave_symlink: true,
+            ..OrganizeOptions::new(OperationMode::Move)
+        };
+
+        let organized = FileOrganizer::organize_to_dir_with_fs(
+            &mock,
+            &create_test_anime_info(&source),
+            &target_dir,
+            &options,
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(mock.paths(), vec![source, organized.target]);
+    }
+
+    #[test]
+    fn mock_copy_writes_through_custom_temp_suffix() {
+        let (source, target_dir) = mock_paths();
+        let target = target_dir.join("[ANi] 测试 - 01 [1080P].mp4");
+        let temp = crate::cleanup::temp_file_path_with(&target, ".syncignore");
+        let mock = MockFileSystem::with_file(&source, b"video").fail(
+            "rename",
+            &temp,
+            MockError::Kind(std::io::ErrorKind::StorageFull),
+        );
+        let options = OrganizeOptions {
+            temp_suffix: Some(".syncignore".to_string()),
+            ..OrganizeOptions::new(OperationMode::Copy)
+        };
+
+        let result = FileOrganizer::organize_to_dir_with_fs(
+            &mock,
+            &create_test_anime_info(&source),
+            &target_dir,
+            &options,
+            &[],
+        );
+
+        // 仅当临时文件使用自定义后缀时才会命中注入的改名失败
+        assert!(matches!(result, Err(AppError::Io(_))));
+        assert_eq!(mock.paths(), vec![source]);
+    }
+
+    #[test]
+    fn mock_failed_copy_removes_temp_file() {
+        let (source, target_dir) = mock_paths();
+        let temp = crate::cleanup::temp_file_path(&target_dir.join("[ANi] 测试 - 01 [1080P].mp4"));
+        let mock = MockFileSystem::with_file(&source, b"video").fail(
+            "rename",
+            &temp,
+            MockError::Kind(std::io::ErrorKind::StorageFull),
+        );
+
+        let result = organize_with_mock(&mock, &source, &target_dir, OperationMode::Copy);
+
+        assert!(matches!(result, Err(AppError::Io(_))));
+        assert_eq!(mock.paths(), vec![source]);
+    }
+
+    #[test]
+    fn test_organize_move_mode() {
+        let source_dir = TempDir::new().unwrap();
+        let target_dir = TempDir::new().unwrap();
+
+        let source_file = create_test_file(source_dir.path(), "test.mp4", "test content");
+        let anime_info = create_test_anime_info(&source_file);
+
+        let result = FileOrganizer::organize(
+            &anime_info,
+            target_dir.path(),
+            OperationMode::Move,
+            false,
+            false,
+        );
+
+        assert_eq!(
+            result.unwrap(),
+            OperationOutcome::Performed(ExecutedOp::Moved)
+        );
+        let expected_path = target_dir.path().join("测试").join("test.mp4");
+        assert!(expected_path.exists());
+        assert!(!source_file.exists());
+        assert_eq!(fs::read_to_string(&expected_path).unwrap(), "test content");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn verify_links_accepts_real_hard_link() {
+        let source_dir = TempDir::new().unwrap();
+        let target_dir = TempDir::new().unwrap();
+        let source_file = create_test_file(source_dir.path(), "test.mp4", "test content");
+        let options = OrganizeOptions {
+            verify_links: true,
+            ..OrganizeOptions::new(OperationMode::Link)
+        };
+
+        let organized = FileOrganizer::organize_to_dir_with_subtitles(
+            &create_test_anime_info(&source_file),
+            target_dir.path(),
+            &options,
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            organized.outcome,
+            OperationOutcome::Performed(ExecutedOp::Linked)
+        );
+        assert_eq!(hard_link_count(&organized.target).unwrap(), Some(2));
+    }
+
+    /// 声称建立了硬链接、实际只复制了内容的文件系统
+    #[cfg(unix)]
+    struct CopyingLinkFileSystem;
+
+    impl FileSystem for CopyingLinkFileSystem {
+        fn copy(&self, from: &Path, to: &Path) -> std::io::Result<u64> {
+            StdFileSystem.copy(from, to)
+        }
+
+        fn rename(&self, from: &Path, to: &Path) -> std::io::Result<()> {
+            StdFileSystem.rename(from, to)
+        }
+
+        fn hard_link(&self, original: &Path, link: &Path) -> std::io::Result<()> {
+            StdFileSystem.copy(original, link).map(|_| ())
+        }
+
+        fn symlink(&self, original: &Path, link: &Path) -> std::io::Result<()> {
+            StdFileSystem.symlink(original, link)
+        }
+
+        fn create_dir_all(&self, path: &Path) -> std::io::Result<()> {
+            StdFileSystem.create_dir_all(path)
+        }
+
+        fn remove_file(&self, path: &Path) -> std::io::Result<()> {
+            StdFileSystem.remove_file(path)
+        }
+
+        fn remove_dir(&self, path: &Path) -> std::io::Result<()> {
+            StdFileSystem.remove_dir(path)
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::io::Write;
-    use tempfile::TempDir;
+    #[cfg(unix)]
+    #[test]
+    fn verify_links_rejects_single_link_target() {
+        let source_dir = TempDir::new().unwrap();
+        let target_dir = TempDir::new().unwrap();
+        let source_file = create_test_file(source_dir.path(), "test.mp4", "test content");
+        let anime_file = create_test_anime_info(&source_file);
+        let organize = |verify_links| {
+            FileOrganizer::organize_to_dir_with_fs(
+                &CopyingLinkFileSystem,
+                &anime_file,
+                target_dir.path().join(if verify_links { "a" } else { "b" }),
+                &OrganizeOptions {
+                    verify_links,
+                    ..OrganizeOptions::new(OperationMode::Link)
+                },
+                &[],
+            )
+        };
 
-    fn create_test_file(dir: &Path, filename: &str, content: &str) -> std::path::PathBuf {
-        let file_path = dir.join(filename);
-        let mut file = fs::File::create(&file_path).unwrap();
-        file.write_all(content.as_bytes()).unwrap();
-        file_path
+        assert!(organize(false).is_ok());
+        assert!(matches!(
+            organize(true),
+            Err(AppError::FileOperation { message, .. }) if message.contains("链接数为 1")
+        ));
     }
 
-    fn create_test_anime_info(source_path: &Path) -> AnimeFileInfo {
-        AnimeFileInfo {
-            publisher: "ANi".to_string(),
-            anime_name: "测试".to_string(),
-            episode: "01".to_string(),
-            tags: "[1080P]".to_string(),
-            extension: ".mp4".to_string(),
-            original_path: source_path.to_string_lossy().to_string(),
+    #[cfg(unix)]
+    #[test]
+    fn verify_readable_rejects_unreadable_target() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let source_dir = TempDir::new().unwrap();
+        let target_dir = TempDir::new().unwrap();
+        let source_file = create_test_file(source_dir.path(), "test.mp4", "test content");
+        // 硬链接与源共享 inode，去掉源的读权限即得到不可读的目标。
+        fs::set_permissions(&source_file, fs::Permissions::from_mode(0o000)).unwrap();
+        let organize = |verify_readable| {
+            FileOrganizer::organize_to_dir_with_subtitles(
+                &create_test_anime_info(&source_file),
+                target_dir
+                    .path()
+                    .join(if verify_readable { "a" } else { "b" }),
+                &OrganizeOptions {
+                    verify_readable,
+                    ..OrganizeOptions::new(OperationMode::Link)
+                },
+                &[],
+            )
+        };
+
+        assert!(organize(false).is_ok());
+        // 特权用户（如 root）会绕过权限位，此时目标仍可读。
+        if fs::File::open(&source_file).is_err() {
+            assert!(matches!(
+                organize(true),
+                Err(AppError::FileOperation { message, .. }) if message.contains("目标文件不可读")
+            ));
+        } else {
+            assert!(organize(true).is_ok());
         }
     }
 
+    #[cfg(unix)]
     #[test]
-    fn test_organize_move_mode() {
+    fn leave_symlink_points_source_at_moved_target() {
         let source_dir = TempDir::new().unwrap();
         let target_dir = TempDir::new().unwrap();
-
         let source_file = create_test_file(source_dir.path(), "test.mp4", "test content");
-        let anime_info = create_test_anime_info(&source_file);
+        let options = OrganizeOptions {
+            leave_symlink: true,
+            ..OrganizeOptions::new(OperationMode::Move)
+        };
 
-        let result = FileOrganizer::organize(
-            &anime_info,
+        let organized = FileOrganizer::organize_to_dir_with_subtitles(
+            &create_test_anime_info(&source_file),
             target_dir.path(),
-            OperationMode::Move,
-            false,
-            false,
-        );
+            &options,
+            &[],
+        )
+        .unwrap();
 
-        assert!(result.is_ok());
-        let expected_path = target_dir.path().join("测试").join("test.mp4");
-        assert!(expected_path.exists());
-        assert!(!source_file.exists());
-        assert_eq!(fs::read_to_string(&expected_path).unwrap(), "test content");
+        assert!(fs::symlink_metadata(&source_file)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        assert_eq!(
+            fs::read_link(&source_file).unwrap(),
+            fs::canonicalize(&organized.target).unwrap()
+        );
+        assert!(!fs::symlink_metadata(&organized.target)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        assert_eq!(fs::read_to_string(&source_file).unwrap(), "test content");
     }
 
     #[test]
@@ -433,7 +2132,10 @@ mod tests {
             false,
         );
 
-        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            OperationOutcome::Performed(ExecutedOp::Copied)
+        );
         let expected_path = target_dir.path().join("测试").join("test.mp4");
         assert!(expected_path.exists());
         assert!(source_file.exists());
@@ -458,6 +2160,82 @@ mod tests {
         assert!(!FileOrganizer::files_match_quick(&source, &target).unwrap());
     }
 
+    #[test]
+    fn same_file_checks_judge_same_and_different_targets() {
+        let directory = TempDir::new().unwrap();
+        let source = create_test_file(directory.path(), "source.mkv", "video");
+        let copy = create_test_file(directory.path(), "copy.mkv", "video");
+        let same_size = create_test_file(directory.path(), "same_size.mkv", "audio");
+        let longer = create_test_file(directory.path(), "longer.mkv", "video!");
+        let matches = |check: SameFileCheck, target: &Path| check.matches(&source, target).unwrap();
+
+        assert!(matches(SameFileCheck::Sample, &copy));
+        assert!(!matches(SameFileCheck::Sample, &same_size));
+        assert!(matches(SameFileCheck::Size, &same_size));
+        assert!(!matches(SameFileCheck::Size, &longer));
+        assert!(matches(SameFileCheck::Hash, &copy));
+        assert!(!matches(SameFileCheck::Hash, &same_size));
+        assert!(matches(SameFileCheck::Name, &longer));
+    }
+
+    #[test]
+    fn hash_check_detects_middle_only_changes() {
+        let directory = TempDir::new().unwrap();
+        let source = directory.path().join("source.mkv");
+        let target = directory.path().join("target.mkv");
+        let bytes = vec![b'a'; 256 * 1024];
+        fs::write(&source, &bytes).unwrap();
+        let mut changed = bytes;
+        changed[128 * 1024] = b'b';
+        fs::write(&target, changed).unwrap();
+
+        assert!(SameFileCheck::Sample.matches(&source, &target).unwrap());
+        assert!(!SameFileCheck::Hash.matches(&source, &target).unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn inode_check_requires_hard_link() {
+        let directory = TempDir::new().unwrap();
+        let source = create_test_file(directory.path(), "source.mkv", "video");
+        let copy = create_test_file(directory.path(), "copy.mkv", "video");
+        let link = directory.path().join("link.mkv");
+        fs::hard_link(&source, &link).unwrap();
+
+        assert!(SameFileCheck::Inode.matches(&source, &link).unwrap());
+        assert!(!SameFileCheck::Inode.matches(&source, &copy).unwrap());
+    }
+
+    #[test]
+    fn same_file_check_controls_skip_or_overwrite() {
+        let directory = TempDir::new().unwrap();
+        let source = create_test_file(directory.path(), "source.mkv", "new!!");
+        let target = create_test_file(directory.path(), "target.mkv", "old!!");
+        let organize = |same_file_check| {
+            FileOrganizer::organize_path(
+                &StdFileSystem,
+                &source,
+                &target,
+                &OrganizeOptions {
+                    same_file_check,
+                    ..OrganizeOptions::new(OperationMode::Copy)
+                },
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            organize(SameFileCheck::Size),
+            OperationOutcome::Skipped(SkipReason::AlreadyExists)
+        );
+        assert_eq!(fs::read_to_string(&target).unwrap(), "old!!");
+        assert_eq!(
+            organize(SameFileCheck::Hash),
+            OperationOutcome::Performed(ExecutedOp::Copied)
+        );
+        assert_eq!(fs::read_to_string(&target).unwrap(), "new!!");
+    }
+
     #[test]
     fn test_organize_copy_mode_includes_matching_external_subtitles() {
         let source_dir = TempDir::new().unwrap();
@@ -509,7 +2287,7 @@ mod tests {
             false,
         );
 
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), OperationOutcome::DryRun);
         assert!(source_file.exists());
         assert!(!target_dir.path().join("测试").exists());
     }
@@ -531,7 +2309,10 @@ mod tests {
             false,
         );
 
-        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            OperationOutcome::Performed(ExecutedOp::Copied)
+        );
         assert!(nested_target.join("测试").exists());
     }
 
@@ -556,7 +2337,10 @@ mod tests {
             false,
         );
 
-        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            OperationOutcome::Performed(ExecutedOp::Copied)
+        );
         let expected_path = target_dir.path().join("测试").join("test.mp4");
         assert_eq!(fs::read_to_string(&expected_path).unwrap(), "new content");
     }
@@ -567,7 +2351,7 @@ mod tests {
         let source_file = create_test_file(directory.path(), "test.mp4", "test content");
         let anime_info = create_test_anime_info(&source_file);
 
-        let target_path = FileOrganizer::organize_to_dir(
+        let organized = FileOrganizer::organize_to_dir(
             &anime_info,
             directory.path(),
             OperationMode::Copy,
@@ -575,8 +2359,480 @@ mod tests {
         )
         .unwrap();
 
-        assert_eq!(target_path, source_file);
-        assert_eq!(fs::read_to_string(target_path).unwrap(), "test content");
+        assert_eq!(organized.target, source_file);
+        assert_eq!(
+            organized.outcome,
+            OperationOutcome::Skipped(SkipReason::SameFile)
+        );
+        assert_eq!(
+            fs::read_to_string(organized.target).unwrap(),
+            "test content"
+        );
+    }
+
+    #[test]
+    fn set_read_only_makes_target_unwritable() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = create_test_file(temp_dir.path(), "target.mkv", "video");
+
+        FileOrganizer::set_read_only(&path).unwrap();
+
+        assert!(fs::metadata(&path).unwrap().permissions().readonly());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o222, 0, "write bits should be cleared: {mode:o}");
+        }
+        // 特权用户（如 root）会绕过权限位，此时仅校验权限本身。
+        let probe = create_test_file(temp_dir.path(), "probe", "");
+        let mut permissions = fs::metadata(&probe).unwrap().permissions();
+        permissions.set_readonly(true);
+        fs::set_permissions(&probe, permissions).unwrap();
+        if fs::OpenOptions::new().append(true).open(&probe).is_err() {
+            assert!(fs::OpenOptions::new().append(true).open(&path).is_err());
+        }
+    }
+
+    #[test]
+    fn read_only_target_can_be_overwritten_on_rerun() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = create_test_file(temp_dir.path(), "source.mkv", "new content");
+        let target = create_test_file(temp_dir.path(), "target.mkv", "old");
+        FileOrganizer::set_read_only(&target).unwrap();
+
+        FileOrganizer::organize_path(
+            &StdFileSystem,
+            &source,
+            &target,
+            &OrganizeOptions::new(OperationMode::Copy),
+        )
+        .unwrap();
+
+        assert_eq!(fs::read_to_string(&target).unwrap(), "new content");
+    }
+
+    #[test]
+    fn backup_keeps_overwritten_target() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = create_test_file(temp_dir.path(), "source.mkv", "new content");
+        let target = create_test_file(temp_dir.path(), "target.mkv", "old");
+
+        FileOrganizer::organize_path(
+            &StdFileSystem,
+            &source,
+            &target,
+            &OrganizeOptions {
+                backup: true,
+                ..OrganizeOptions::new(OperationMode::Copy)
+            },
+        )
+        .unwrap();
+
+        assert_eq!(fs::read_to_string(&target).unwrap(), "new content");
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("target.mkv.bak")).unwrap(),
+            "old"
+        );
+    }
+
+    #[test]
+    fn backup_skips_identical_target() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = create_test_file(temp_dir.path(), "source.mkv", "same");
+        let target = create_test_file(temp_dir.path(), "target.mkv", "same");
+
+        let outcome = FileOrganizer::organize_path(
+            &StdFileSystem,
+            &source,
+            &target,
+            &OrganizeOptions {
+                backup: true,
+                ..OrganizeOptions::new(OperationMode::Copy)
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            outcome,
+            OperationOutcome::Skipped(SkipReason::AlreadyExists)
+        );
+        assert!(!temp_dir.path().join("target.mkv.bak").exists());
+    }
+
+    #[test]
+    fn force_overwrites_without_backup_or_comparison() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = create_test_file(temp_dir.path(), "source.mkv", "new content");
+        let target = create_test_file(temp_dir.path(), "target.mkv", "old");
+
+        let outcome = FileOrganizer::organize_path(
+            &StdFileSystem,
+            &source,
+            &target,
+            &OrganizeOptions {
+                backup: true,
+                force: true,
+                ..OrganizeOptions::new(OperationMode::Copy)
+            },
+        )
+        .unwrap();
+
+        assert_eq!(outcome, OperationOutcome::Performed(ExecutedOp::Copied));
+        assert_eq!(fs::read_to_string(&target).unwrap(), "new content");
+        assert!(!temp_dir.path().join("target.mkv.bak").exists());
+
+        let identical = create_test_file(temp_dir.path(), "identical.mkv", "new content");
+        let outcome = FileOrganizer::organize_path(
+            &StdFileSystem,
+            &identical,
+            &target,
+            &OrganizeOptions {
+                force: true,
+                ..OrganizeOptions::new(OperationMode::Copy)
+            },
+        )
+        .unwrap();
+        assert_eq!(outcome, OperationOutcome::Performed(ExecutedOp::Copied));
+    }
+
+    #[test]
+    fn keep_newer_replaces_older_target_and_skips_older_source() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = create_test_file(temp_dir.path(), "source.mkv", "new content");
+        let target = create_test_file(temp_dir.path(), "target.mkv", "old");
+        let set_modified = |path: &Path, seconds: u64| {
+            fs::File::options()
+                .write(true)
+                .open(path)
+                .unwrap()
+                .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(seconds))
+                .unwrap();
+        };
+        let options = OrganizeOptions {
+            on_conflict: ConflictPolicy::KeepNewer,
+            ..OrganizeOptions::new(OperationMode::Copy)
+        };
+
+        set_modified(&source, 1_000);
+        set_modified(&target, 2_000);
+        let outcome =
+            FileOrganizer::organize_path(&StdFileSystem, &source, &target, &options).unwrap();
+        assert_eq!(
+            outcome,
+            OperationOutcome::Skipped(SkipReason::ExistingPreferred)
+        );
+        assert_eq!(fs::read_to_string(&target).unwrap(), "old");
+
+        set_modified(&source, 3_000);
+        let outcome =
+            FileOrganizer::organize_path(&StdFileSystem, &source, &target, &options).unwrap();
+        assert_eq!(outcome, OperationOutcome::Performed(ExecutedOp::Copied));
+        assert_eq!(fs::read_to_string(&target).unwrap(), "new content");
+    }
+
+    #[test]
+    fn keep_larger_keeps_larger_target() {
+        let temp_dir = TempDir::new().unwrap();
+        let small = create_test_file(temp_dir.path(), "small.mkv", "old");
+        let large = create_test_file(temp_dir.path(), "large.mkv", "larger content");
+        let target = create_test_file(temp_dir.path(), "target.mkv", "target");
+        let options = OrganizeOptions {
+            on_conflict: ConflictPolicy::KeepLarger,
+            ..OrganizeOptions::new(OperationMode::Copy)
+        };
+
+        let outcome =
+            FileOrganizer::organize_path(&StdFileSystem, &small, &target, &options).unwrap();
+        assert_eq!(
+            outcome,
+            OperationOutcome::Skipped(SkipReason::ExistingPreferred)
+        );
+        assert_eq!(fs::read_to_string(&target).unwrap(), "target");
+
+        let outcome =
+            FileOrganizer::organize_path(&StdFileSystem, &large, &target, &options).unwrap();
+        assert_eq!(outcome, OperationOutcome::Performed(ExecutedOp::Copied));
+        assert_eq!(fs::read_to_string(&target).unwrap(), "larger content");
+    }
+
+    #[test]
+    fn backup_path_increments_on_conflict() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("target.mkv");
+
+        assert_eq!(
+            FileOrganizer::backup_path(&target, "20240101120000"),
+            temp_dir.path().join("target.mkv.bak")
+        );
+        create_test_file(temp_dir.path(), "target.mkv.bak", "1");
+        assert_eq!(
+            FileOrganizer::backup_path(&target, "20240101120000"),
+            temp_dir.path().join("target.mkv.20240101120000.bak")
+        );
+        create_test_file(temp_dir.path(), "target.mkv.20240101120000.bak", "2");
+        assert_eq!(
+            FileOrganizer::backup_path(&target, "20240101120000"),
+            temp_dir.path().join("target.mkv.20240101120000-1.bak")
+        );
+        create_test_file(temp_dir.path(), "target.mkv.20240101120000-1.bak", "3");
+        assert_eq!(
+            FileOrganizer::backup_path(&target, "20240101120000"),
+            temp_dir.path().join("target.mkv.20240101120000-2.bak")
+        );
+    }
+
+    #[test]
+    fn keep_tags_renames_video_and_subtitle_targets() {
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let target_dir = temp_dir.path().join("target");
+        fs::create_dir_all(&source_dir).unwrap();
+        let video = create_test_file(
+            &source_dir,
+            "[ANi] Test - 01 [1080P][Baha][WEB-DL][AAC AVC][CHT].mp4",
+            "video",
+        );
+        let subtitle = create_test_file(
+            &source_dir,
+            "[ANi] Test - 01 [1080P][Baha][WEB-DL][AAC AVC][CHT].zh.ass",
+            "subtitle",
+        );
+        let info = crate::parser::FilenameParser::parse(&video).unwrap();
+        let options = OrganizeOptions {
+            keep_tags: Some(vec![TagCategory::Resolution, TagCategory::Lang]),
+            ..OrganizeOptions::new(OperationMode::Copy)
+        };
+
+        let target_path = FileOrganizer::organize_to_dir_with_subtitles(
+            &info,
+            &target_dir,
+            &options,
+            &[subtitle],
+        )
+        .unwrap()
+        .target;
+
+        assert_eq!(
+            target_path,
+            target_dir.join("[ANi] Test - 01 [1080P][CHT].mp4")
+        );
+        assert!(target_path.exists());
+        assert!(target_dir
+            .join("[ANi] Test - 01 [1080P][CHT].zh.ass")
+            .exists());
+    }
+
+    #[test]
+    fn episode_range_format_renames_range_targets_only() {
+        let plan_name = |episode: &str, episode_range_format: Option<&str>| {
+            let info = AnimeFileInfo::builder()
+                .anime_name("Test")
+                .episode(episode)
+                .tags("[1080P]")
+                .extension("mkv")
+                .original_path("/downloads/[ANi] Test [1080P].mkv")
+                .build();
+            let options = OrganizeOptions {
+                name_separator: Some(" ".to_string()),
+                episode_range_format: episode_range_format.map(str::to_string),
+                ..OrganizeOptions::new(OperationMode::Copy)
+            };
+            let plans =
+                FileOrganizer::plan_to_dir_with_subtitles(&info, "/anime/Test", &[], &options)
+                    .unwrap();
+            plans[0]
+                .target
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .into_owned()
+        };
+
+        assert_eq!(plan_name("01~12", None), "01~12 [1080P].mkv");
+        assert_eq!(
+            plan_name("01~12", Some("{start}-{end}")),
+            "01-12 [1080P].mkv"
+        );
+        assert_eq!(
+            plan_name("01-12", Some("{start}-{end}话")),
+            "01-12话 [1080P].mkv"
+        );
+        assert_eq!(plan_name("3", Some("{start}~{end}")), "03 [1080P].mkv");
+    }
+
+    #[test]
+    fn truncate_filename_tags_drops_trailing_tags_first() {
+        let name = "[ANi] Test - 01 [1080P][Baha][WEB-DL][AAC AVC][CHT].mkv";
+        let info = crate::parser::FilenameParser::parse_str(name).unwrap();
+
+        assert_eq!(
+            FileOrganizer::truncate_filename_tags(name, &info, 255),
+            name
+        );
+        let truncated = FileOrganizer::truncate_filename_tags(name, &info, 40);
+        assert_eq!(truncated, "[ANi] Test - 01 [1080P][Baha].mkv");
+        assert!(truncated.len() <= 40);
+        assert_eq!(
+            FileOrganizer::truncate_filename_tags(name, &info, 20),
+            "[ANi] Test - 01.mkv"
+        );
+        assert_eq!(
+            FileOrganizer::truncate_filename_tags(name, &info, 16),
+            "01.mkv"
+        );
+    }
+
+    #[test]
+    fn truncate_filename_tags_keeps_bracket_episode_and_extension() {
+        let name = "[Group][Test][01][1080P][HEVC 10bit][CHS].MKV";
+        let info = crate::parser::FilenameParser::parse_str(name).unwrap();
+
+        assert_eq!(
+            FileOrganizer::truncate_filename_tags(name, &info, 30),
+            "[Group][Test][01][1080P].MKV"
+        );
+        assert_eq!(
+            FileOrganizer::truncate_filename_tags(name, &info, 24),
+            "[Group][Test][01].MKV"
+        );
+        assert_eq!(
+            FileOrganizer::truncate_filename_tags(name, &info, 20),
+            "01.mkv"
+        );
+    }
+
+    #[test]
+    fn max_filename_len_truncates_video_and_subtitle_targets() {
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let target_dir = temp_dir.path().join("target");
+        fs::create_dir_all(&source_dir).unwrap();
+        let name = "[ANi] Test - 01 [1080P][Baha][WEB-DL][AAC AVC][CHT].mp4";
+        let video = create_test_file(&source_dir, name, "video");
+        let subtitle = create_test_file(
+            &source_dir,
+            "[ANi] Test - 01 [1080P][Baha][WEB-DL][AAC AVC][CHT].zh.ass",
+            "subtitle",
+        );
+        let info = crate::parser::FilenameParser::parse(&video).unwrap();
+        let options = OrganizeOptions {
+            max_filename_len: Some(40),
+            ..OrganizeOptions::new(OperationMode::Copy)
+        };
+
+        let target_path = FileOrganizer::organize_to_dir_with_subtitles(
+            &info,
+            &target_dir,
+            &options,
+            &[subtitle],
+        )
+        .unwrap()
+        .target;
+
+        assert_eq!(
+            target_path,
+            target_dir.join("[ANi] Test - 01 [1080P][Baha].mp4")
+        );
+        assert!(target_path.exists());
+        assert!(target_dir
+            .join("[ANi] Test - 01 [1080P][Baha].zh.ass")
+            .exists());
+    }
+
+    #[test]
+    fn with_metadata_carries_nfo_and_images_with_video() {
+        let temp_dir = TempDir::new().unwrap();
+        let source_dir = temp_dir.path().join("source");
+        let target_dir = temp_dir.path().join("target");
+        fs::create_dir_all(&source_dir).unwrap();
+        let video = create_test_file(&source_dir, "[ANi] Test - 01 [1080P].mkv", "video");
+        create_test_file(&source_dir, "[ANi] Test - 01 [1080P].nfo", "nfo");
+        create_test_file(&source_dir, "[ANi] Test - 01 [1080P]-thumb.JPG", "thumb");
+        create_test_file(&source_dir, "poster.jpg", "poster");
+        create_test_file(&source_dir, "[ANi] Test - 02 [1080P].nfo", "other");
+        create_test_file(&source_dir, "notes.txt", "notes");
+        create_test_file(&source_dir, "._poster.jpg", "apple double");
+        let info = crate::parser::FilenameParser::parse(&video).unwrap();
+        let options = OrganizeOptions {
+            with_metadata: true,
+            name_separator: Some(" ".to_string()),
+            ..OrganizeOptions::new(OperationMode::Copy)
+        };
+
+        let plans =
+            FileOrganizer::plan_to_dir_with_subtitles(&info, &target_dir, &[], &options).unwrap();
+        let targets = plans
+            .iter()
+            .map(|plan| {
+                plan.target
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            targets,
+            [
+                "01 [1080P].mkv",
+                "01 [1080P]-thumb.JPG",
+                "01 [1080P].nfo",
+                "poster.jpg"
+            ]
+        );
+
+        FileOrganizer::organize_to_dir_with_subtitles(&info, &target_dir, &options, &[]).unwrap();
+        assert_eq!(
+            fs::read_to_string(target_dir.join("01 [1080P].nfo")).unwrap(),
+            "nfo"
+        );
+        assert_eq!(
+            fs::read_to_string(target_dir.join("poster.jpg")).unwrap(),
+            "poster"
+        );
+        assert_eq!(fs::read_dir(&target_dir).unwrap().count(), 4);
+    }
+
+    #[test]
+    fn metadata_files_are_left_behind_by_default() {
+        let temp_dir = TempDir::new().unwrap();
+        let video = create_test_file(temp_dir.path(), "[ANi] Test - 01 [1080P].mkv", "video");
+        create_test_file(temp_dir.path(), "[ANi] Test - 01 [1080P].nfo", "nfo");
+        let info = crate::parser::FilenameParser::parse(&video).unwrap();
+
+        let plans = FileOrganizer::plan_to_dir_with_subtitles(
+            &info,
+            temp_dir.path().join("target"),
+            &[],
+            &OrganizeOptions::new(OperationMode::Copy),
+        )
+        .unwrap();
+        assert_eq!(plans.len(), 1);
+    }
+
+    #[test]
+    fn keep_tags_can_drop_every_tag() {
+        assert_eq!(
+            FileOrganizer::filename_with_kept_tags(
+                "[ANi] Test - 01 [1080P][CHT].mp4",
+                "[1080P][CHT]",
+                &[TagCategory::Codec],
+            ),
+            "[ANi] Test - 01.mp4"
+        );
+    }
+
+    #[test]
+    fn keep_tags_finds_unbracketed_tags() {
+        assert_eq!(
+            FileOrganizer::filename_with_kept_tags(
+                "[Sub] Test - 01 (1080p) x264 CHT.mkv",
+                "[1080p][x264][CHT]",
+                &[TagCategory::Resolution],
+            ),
+            "[Sub] Test - 01 [1080p].mkv"
+        );
     }
 
     #[test]
@@ -603,7 +2859,8 @@ mod tests {
 
         let target_path =
             FileOrganizer::organize_to_dir(&anime_info, &season_dir, OperationMode::Copy, false)
-                .unwrap();
+                .unwrap()
+                .target;
 
         assert_eq!(target_path, season_dir.join(filename));
         assert!(target_path.exists());
@@ -622,6 +2879,8 @@ mod tests {
             tags: "[1080P]".to_string(),
             extension: ".mp4".to_string(),
             original_path: source_file.to_string_lossy().to_string(),
+            alt_titles: Vec::new(),
+            is_final: false,
         };
 
         let result = FileOrganizer::organize(
@@ -632,7 +2891,10 @@ mod tests {
             true,
         );
 
-        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            OperationOutcome::Performed(ExecutedOp::Copied)
+        );
         let expected_path = target_dir
             .path()
             .join("Test Anime")
@@ -654,6 +2916,8 @@ mod tests {
             tags: "[1080P]".to_string(),
             extension: ".mp4".to_string(),
             original_path: source_file.to_string_lossy().to_string(),
+            alt_titles: Vec::new(),
+            is_final: false,
         };
 
         let result = FileOrganizer::organize(
@@ -664,7 +2928,10 @@ mod tests {
             false,
         );
 
-        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            OperationOutcome::Performed(ExecutedOp::Copied)
+        );
         let expected_path = target_dir.path().join("Test Anime 第2季").join("test.mp4");
         assert!(expected_path.exists());
     }
@@ -682,6 +2949,8 @@ mod tests {
             tags: "[1080P]".to_string(),
             extension: ".mp4".to_string(),
             original_path: source_file.to_string_lossy().to_string(),
+            alt_titles: Vec::new(),
+            is_final: false,
         };
 
         let result = FileOrganizer::organize(
@@ -692,7 +2961,10 @@ mod tests {
             true,
         );
 
-        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            OperationOutcome::Performed(ExecutedOp::Copied)
+        );
         let expected_path = target_dir
             .path()
             .join("異世界悠閒農家")
@@ -23,21 +23,65 @@
 //! }
 //! ```
 //!
+//! # 平台支持
+//!
+//! 解析逻辑只依赖 `regex` 与字符串处理，不访问文件系统；[`FilenameParser::parse_str`]
+//! 也不依赖 `std::path`。crate 整体仍依赖 SQLite 等原生库，尚未在 `wasm32-unknown-unknown`
+//! 等目标上构建。
+//!
 //! # 测试模块
 //!
 //! 测试位于 `tests/parser/` 目录，按发布组分类组织。
 
+use crate::tags::{classify_tag, normalize_tags, TagCategory};
 use regex::Regex;
-use std::path::Path;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
-static ANIME_FILE_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^\[(?P<publisher>[^\]]+)\]").expect("正则表达式编译失败"));
 static SEASON_EPISODE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\s*-\s*S(?P<season>\d{1,2})E(?P<episode>\d{1,4})\s")
+        .expect("季集信息正则表达式编译失败")
+});
+/// 中文序数集数：`第一話`、`第01集`、`第 5 话`（可省略 ` - `），或 ` - III`、` - 十二` 这类紧跟分隔符的数字词。
+/// 罗马数字只接受 II–XX 的多字母写法，` - X`、` - C` 这类单个字母不视为集数。
+static NUMERAL_EPISODE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(?P<title>.+?)\s+(?:(?:-\s+)?(?P<ordinal>第\s*(?:[0-9]+|[一二三四五六七八九十百零〇]+)\s*[話话集])|-\s+(?P<numeral>[一二三四五六七八九十百零〇]+|X?(?:I{2,3}|IV|VI{1,3}|IX)|X[IVX]))(?P<rest>\s*[\[(.].*)$",
+    )
+    .expect("数字词集数正则表达式编译失败")
+});
+/// 无空格的紧凑命名：`妖精的尾巴-01[1080p]`，番名与集数间仅有 `-`，集数后紧接标签或扩展名；
+/// 取最后一个满足条件的 `-`，集数为 1–3 位数字
+static COMPACT_EPISODE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<title>.*[^\s-])-(?P<episode>\d{1,3})(?P<rest>[\[.].*)$")
+        .expect("紧凑集数正则表达式编译失败")
+});
+
+/// 数字之后的完结标记候选：`01 END`、`12 [END]`、`12(完)`、`[12完]`、`12END`；
+/// 标记后须为分隔符或括号，`01 Ending` 这类单词不受影响。
+/// 该数字是否为集数由 [`strip_finale_marker`] 确认
+static FINALE_MARKER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(
-        r"(?i)^(?P<title>.+?)\s*-\s*S(?P<season>\d{1,2})E(?P<episode>\d{1,4})(?P<rest>\s.*)$",
+        r"\d\]?(?P<marker>\s*(?:[\[(（【]\s*(?:(?i:end)|完(?:结|結)?)\s*[\])）】]|(?i:end)|完(?:结|結)?))(?:[\s\[\]()（）【】._-]|$)",
     )
-    .expect("季集信息正则表达式编译失败")
+    .expect("完结标记正则表达式编译失败")
+});
+
+/// 番名或标签中括号包裹的年份：`(2024)`、`[2024]`、`（2024）`、`【2024】`
+static YEAR_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"[(\[（【](?P<year>19[5-9][0-9]|20[0-9]{2})[)\]）】]")
+        .expect("年份正则表达式编译失败")
+});
+
+/// 番名末尾括号内的季度标注：`(第二季)`、`（2nd Season）`、`(Season 2)`；
+/// 只认带季度字样的内容，`(2024)` 这类年份括号不受影响
+static BRACKETED_SEASON_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(?P<title>.+?)\s*[(（]\s*(?P<season>第\s*(?:\d{1,2}|[一二三四五六七八九十]+)\s*季|(?i:season\s*\d{1,2}|\d{1,2}(?:st|nd|rd|th)\s+season))\s*[)）]$",
+    )
+    .expect("括号季度正则表达式编译失败")
 });
 
 static SEASON_SUFFIX_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
@@ -64,8 +108,10 @@ static SEASON_SUFFIX_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
 
 /// 动漫文件信息结构体
 ///
-/// 包含从文件名中解析出的所有关键信息。
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// 包含从文件名中解析出的所有关键信息。后续版本可能新增字段，
+/// 在库外构造时请使用 [`AnimeFileInfo::builder`]。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct AnimeFileInfo {
     /// 发布组名称
     pub publisher: String,
@@ -79,15 +125,408 @@ pub struct AnimeFileInfo {
     pub extension: String,
     /// 原始文件路径
     pub original_path: String,
+    /// 番名中以 ` / ` 并列的其他语言标题（如 `动漫名 / Anime Name` 中的 `Anime Name`），按出现顺序
+    pub alt_titles: Vec<String>,
+    /// 集数后带完结标记（`END`、`[END]`、`完`），标记已从标签中剥离
+    pub is_final: bool,
 }
 
 impl AnimeFileInfo {
+    /// 创建链式构造器，用于在库中手动构造文件信息。
+    ///
+    /// ```
+    /// use anime_organizer::parser::{AnimeFileInfo, FilenameParser};
+    ///
+    /// let info = AnimeFileInfo::builder()
+    ///     .publisher("ANi")
+    ///     .anime_name("测试")
+    ///     .episode("1")
+    ///     .tags("[1080P]")
+    ///     .extension("MP4")
+    ///     .original_path("[ANi] 测试 - 01 [1080P].mp4")
+    ///     .build();
+    /// assert_eq!(info, FilenameParser::parse_str("[ANi] 测试 - 01 [1080P].mp4").unwrap());
+    /// assert_eq!(info.target_filename(), "01 [1080P].mp4");
+    /// ```
+    #[must_use]
+    pub fn builder() -> AnimeFileInfoBuilder {
+        AnimeFileInfoBuilder::default()
+    }
+
+    /// 以 `anime_name` 与 `alt_titles` 依次排列后的第 `index` 个标题（从 1 开始；刚解析的
+    /// 文件即文件名中的顺序）作为主标题，其余标题按原顺序存入 `alt_titles`；
+    /// 超出标题个数时保持不变并返回 `false`。
+    ///
+    /// ```
+    /// use anime_organizer::parser::FilenameParser;
+    ///
+    /// let mut info =
+    ///     FilenameParser::parse_str("[ANi] 葬送的芙莉莲 / Frieren - 01 [1080P].mp4").unwrap();
+    /// assert_eq!(info.anime_name, "葬送的芙莉莲");
+    /// assert!(info.select_primary_title(2));
+    /// assert_eq!(info.anime_name, "Frieren");
+    /// assert_eq!(info.alt_titles, ["葬送的芙莉莲"]);
+    /// ```
+    pub fn select_primary_title(&mut self, index: usize) -> bool {
+        if index == 0 || index > self.alt_titles.len() + 1 {
+            return false;
+        }
+        let mut titles = Vec::with_capacity(self.alt_titles.len() + 1);
+        titles.push(std::mem::take(&mut self.anime_name));
+        titles.append(&mut self.alt_titles);
+        self.anime_name = titles.remove(index - 1);
+        self.alt_titles = titles;
+        true
+    }
+
+    /// 按原始文件名恢复扩展名的大小写（解析时统一为小写），用于大小写敏感且需保留原样的场景。
+    ///
+    /// 原始路径不以该扩展名结尾（如扩展名写在 `[MKV]` 中）时保持不变并返回 `false`。
+    ///
+    /// ```
+    /// use anime_organizer::parser::FilenameParser;
+    ///
+    /// let mut info = FilenameParser::parse_str("[ANi] 测试 - 01 [1080P].MKV").unwrap();
+    /// assert_eq!(info.extension, ".mkv");
+    /// assert!(info.restore_extension_case());
+    /// assert_eq!(info.extension, ".MKV");
+    /// ```
+    pub fn restore_extension_case(&mut self) -> bool {
+        let Some(split) = self.original_path.len().checked_sub(self.extension.len()) else {
+            return false;
+        };
+        match self.original_path.get(split..) {
+            Some(original) if original.eq_ignore_ascii_case(&self.extension) => {
+                self.extension = original.to_string();
+                true
+            }
+            _ => false,
+        }
+    }
+
     /// 生成目标文件名
     ///
     /// 返回格式为 `{episode} {tags}{extension}` 的文件名。
     #[must_use]
     pub fn target_filename(&self) -> String {
-        format!("{} {}{}", self.episode, self.tags, self.extension)
+        self.target_filename_with(DEFAULT_NAME_SEPARATOR)
+    }
+
+    /// 以指定分隔符连接集数与标签生成目标文件名；没有标签时不输出分隔符。
+    ///
+    /// ```
+    /// use anime_organizer::parser::FilenameParser;
+    ///
+    /// let info = FilenameParser::parse_str("[ANi] 测试 - 01 [1080P].mp4").unwrap();
+    /// assert_eq!(info.target_filename_with("."), "01.[1080P].mp4");
+    /// assert_eq!(info.target_filename_with("_"), "01_[1080P].mp4");
+    /// ```
+    #[must_use]
+    pub fn target_filename_with(&self, separator: &str) -> String {
+        if self.tags.is_empty() {
+            format!("{}{}", self.episode, self.extension)
+        } else {
+            format!("{}{separator}{}{}", self.episode, self.tags, self.extension)
+        }
+    }
+
+    /// 与 [`target_filename_with`](Self::target_filename_with) 相同，但区间集数（如 `01-12`、`01~12`）
+    /// 按 `range_format` 渲染（见 [`episode_label_with`](Self::episode_label_with)）。
+    ///
+    /// ```
+    /// use anime_organizer::parser::AnimeFileInfo;
+    ///
+    /// let info = AnimeFileInfo::builder()
+    ///     .episode("01-12")
+    ///     .tags("[1080P]")
+    ///     .extension("mkv")
+    ///     .build();
+    /// assert_eq!(
+    ///     info.target_filename_with_range(" ", "{start}~{end}"),
+    ///     "01~12 [1080P].mkv"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn target_filename_with_range(&self, separator: &str, range_format: &str) -> String {
+        AnimeFileInfo {
+            episode: self.episode_label_with(range_format),
+            ..self.clone()
+        }
+        .target_filename_with(separator)
+    }
+
+    /// 按文件名模板生成目标文件名，模板渲染后追加扩展名：
+    ///
+    /// - `{anime}` 替换为不含季信息的系列名（见 [`series_name`](Self::series_name)）
+    /// - `{episode}` 替换为集数
+    /// - `{n}`、`{n:0W}` 替换为季号，语法同 [`render_season_dir`]；特殊集按第 0 季，
+    ///   无法识别季号时按第 1 季
+    ///
+    /// ```
+    /// use anime_organizer::parser::FilenameParser;
+    ///
+    /// let info = FilenameParser::parse_str("[ANi] 测试 第二季 - 05 [1080P].mkv").unwrap();
+    /// assert_eq!(
+    ///     info.target_filename_from("{anime} - s{n:02}e{episode}"),
+    ///     "测试 - s02e05.mkv"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn target_filename_from(&self, format: &str) -> String {
+        let season = if self.is_special() {
+            0
+        } else {
+            self.season_number().unwrap_or(1)
+        };
+        let name = format
+            .replace("{anime}", &self.series_name().replace(['/', '\\'], "_"))
+            .replace("{episode}", &self.episode);
+        format!("{}{}", render_season_dir(&name, season), self.extension)
+    }
+
+    /// 区间集数的起止集号，如 `01-12`、`01~12` 返回 `("01", "12")`；单集或其他写法返回 `None`。
+    #[must_use]
+    pub fn episode_range(&self) -> Option<(&str, &str)> {
+        let (start, end) = self.episode.split_once(['-', '~'])?;
+        let is_number = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+        (is_number(start) && is_number(end)).then_some((start, end))
+    }
+
+    /// 按模板渲染集数标签：区间集数替换 `range_format` 中的 `{start}`、`{end}`，
+    /// 单集、合并多集与特殊集原样返回。
+    ///
+    /// ```
+    /// use anime_organizer::parser::AnimeFileInfo;
+    ///
+    /// let range = AnimeFileInfo::builder().episode("01~12").build();
+    /// assert_eq!(range.episode_label_with("{start}-{end}话"), "01-12话");
+    ///
+    /// let single = AnimeFileInfo::builder().episode("5").build();
+    /// assert_eq!(single.episode_label_with("{start}-{end}话"), "05");
+    /// ```
+    #[must_use]
+    pub fn episode_label_with(&self, range_format: &str) -> String {
+        match self.episode_range() {
+            Some((start, end)) => range_format.replace("{start}", start).replace("{end}", end),
+            None => self.episode.clone(),
+        }
+    }
+
+    /// 返回相对于媒体库根目录的目标路径 `动漫名/目标文件名`，文件名见
+    /// [`target_filename`](Self::target_filename)。
+    ///
+    /// 只做路径计算，不访问文件系统，也不处理目标已存在等冲突，供自行管理文件操作的调用方复用。
+    ///
+    /// ```
+    /// use anime_organizer::parser::FilenameParser;
+    /// use std::path::Path;
+    ///
+    /// let info = FilenameParser::parse_str("[ANi] 测试 - 01 [1080P].mp4").unwrap();
+    /// assert_eq!(info.target_relative_path(), Path::new("测试/01 [1080P].mp4"));
+    /// ```
+    #[must_use]
+    pub fn target_relative_path(&self) -> PathBuf {
+        PathBuf::from(&self.anime_name).join(self.target_filename())
+    }
+
+    /// 带季目录层的相对目标路径 `系列名/季目录/目标文件名`，与季模式的目录布局一致；
+    /// 季目录按 `season_format` 渲染（见 [`season_dir_name_with`](Self::season_dir_name_with)）。
+    ///
+    /// ```
+    /// use anime_organizer::parser::{FilenameParser, DEFAULT_SEASON_FORMAT};
+    /// use std::path::Path;
+    ///
+    /// let info = FilenameParser::parse_str("[ANi] 测试 第二季 - 01 [1080P].mp4").unwrap();
+    /// assert_eq!(
+    ///     info.target_relative_path_with_season(DEFAULT_SEASON_FORMAT),
+    ///     Path::new("测试/Season 2/01 [1080P].mp4")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn target_relative_path_with_season(&self, season_format: &str) -> PathBuf {
+        PathBuf::from(self.series_name())
+            .join(self.season_dir_name_with(season_format))
+            .join(self.target_filename())
+    }
+
+    /// 返回文件包含的全部集号。
+    ///
+    /// 合并多集（如 `01+02`）返回每一集，单集返回一项，小数集或特殊集返回空列表。
+    #[must_use]
+    pub fn episode_numbers(&self) -> Vec<u32> {
+        self.episode
+            .split('+')
+            .map(|part| part.trim().parse::<u32>())
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .unwrap_or_default()
+    }
+
+    /// 是否为合并多集文件（如 `01+02`）。
+    #[must_use]
+    pub fn is_multi_episode(&self) -> bool {
+        self.episode_numbers().len() > 1
+    }
+
+    /// 对集数的整数部分加上偏移量并重新格式化，用于跨季连续编号的资源。
+    ///
+    /// - 整数集补齐为两位（`13` 偏移 `-12` 得到 `01`）
+    /// - 小数集保留小数部分（`13.5` 偏移 `-12` 得到 `1.5`）
+    /// - 合并多集逐集偏移（`13+14` 偏移 `-12` 得到 `01+02`）
+    /// - 分段放送保留段落字母（`13A` 偏移 `-12` 得到 `01A`）
+    /// - 无法识别为数字的特殊集（如 `SP`）保持不变
+    ///
+    /// # 错误
+    ///
+    /// - `AppError::ParseError` - 偏移后集数为零或负数，此时集数保持不变
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use anime_organizer::parser::FilenameParser;
+    ///
+    /// let mut info = FilenameParser::parse_str("[ANi] Test - 13 [1080P].mp4").unwrap();
+    /// info.apply_episode_offset(-12)?;
+    /// assert_eq!(info.episode, "01");
+    /// # Ok::<(), anime_organizer::error::AppError>(())
+    /// ```
+    pub fn apply_episode_offset(&mut self, offset: i64) -> crate::error::Result<()> {
+        if offset == 0 {
+            return Ok(());
+        }
+        let mut parts = Vec::new();
+        for part in self.episode.split('+') {
+            let (part, suffix) = match split_episode_part(part) {
+                Some((number, _)) => (number, &part[number.len()..]),
+                None => (part, ""),
+            };
+            let (integer, fraction) = match part.split_once('.') {
+                Some((integer, fraction)) => (integer, Some(fraction)),
+                None => (part, None),
+            };
+            let (Ok(value), true) = (
+                integer.parse::<i64>(),
+                fraction.is_none_or(|fraction| fraction.bytes().all(|b| b.is_ascii_digit())),
+            ) else {
+                return Ok(());
+            };
+            let shifted = value + offset;
+            let is_zero = shifted == 0 && fraction.is_none_or(|f| f.bytes().all(|b| b == b'0'));
+            if shifted < 0 || is_zero {
+                return Err(crate::error::AppError::ParseError(format!(
+                    "集数 {} 偏移 {offset} 后为 {shifted}，不是有效集数",
+                    self.episode
+                )));
+            }
+            parts.push(match fraction {
+                Some(fraction) => format!("{shifted}.{fraction}"),
+                None => format!("{shifted:02}{suffix}"),
+            });
+        }
+        self.episode = parts.join("+");
+        Ok(())
+    }
+
+    /// 根据番名或标签中的中文关键字判断集的类型（剧场版、总集篇、特别篇）。
+    ///
+    /// ```
+    /// use anime_organizer::parser::{EpisodeKind, FilenameParser};
+    ///
+    /// let info = FilenameParser::parse_str("[ANi] 劇場版 測試 - 電影 [1080P].mp4").unwrap();
+    /// assert_eq!(info.episode_kind(), EpisodeKind::Movie);
+    /// let info = FilenameParser::parse_str("[Sub] 测试 - 13 [总集篇][1080P].mkv").unwrap();
+    /// assert_eq!(info.episode_kind(), EpisodeKind::Recap);
+    /// ```
+    #[must_use]
+    pub fn episode_kind(&self) -> EpisodeKind {
+        EpisodeKind::detect(&self.anime_name)
+            .or_else(|| EpisodeKind::detect(&self.tags))
+            .unwrap_or_default()
+    }
+
+    /// 是否为特殊集：特别篇（见 [`EpisodeKind::Special`]）或小数集（如 `12.5`）。
+    ///
+    /// ```
+    /// use anime_organizer::parser::FilenameParser;
+    ///
+    /// assert!(FilenameParser::parse_str("[ANi] 测试 - 12.5 [1080P].mp4").unwrap().is_special());
+    /// assert!(!FilenameParser::parse_str("[ANi] 测试 - 12 [1080P].mp4").unwrap().is_special());
+    /// ```
+    #[must_use]
+    pub fn is_special(&self) -> bool {
+        self.episode_kind() == EpisodeKind::Special
+            || self
+                .episode
+                .split_once('.')
+                .is_some_and(|(integer, fraction)| {
+                    !integer.is_empty()
+                        && integer.bytes().all(|b| b.is_ascii_digit())
+                        && fraction.bytes().all(|b| b.is_ascii_digit())
+                        && fraction.bytes().any(|b| b != b'0')
+                })
+    }
+
+    /// 返回剔除类型关键字后的番名；剔除后为空时返回原番名。
+    ///
+    /// ```
+    /// use anime_organizer::parser::FilenameParser;
+    ///
+    /// let info = FilenameParser::parse_str("[ANi] 劇場版 測試 - 電影 [1080P].mp4").unwrap();
+    /// assert_eq!(info.anime_name_without_kind_keyword(), "測試");
+    /// ```
+    #[must_use]
+    pub fn anime_name_without_kind_keyword(&self) -> String {
+        let mut name = self.anime_name.clone();
+        for (keyword, _) in EPISODE_KIND_KEYWORDS {
+            name = name.replace(keyword, " ");
+        }
+        let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+        if name.is_empty() {
+            self.anime_name.clone()
+        } else {
+            name
+        }
+    }
+
+    /// 返回番名的 slug：小写，空白与 `-`、`_`、`.` 转为单个连字符，去掉其余标点符号，
+    /// 中日文字符保留。适合用作 URL 或文件系统中的安全标识符。
+    ///
+    /// ```
+    /// use anime_organizer::parser::FilenameParser;
+    ///
+    /// let info = FilenameParser::parse_str("[ANi] Re:Zero Season 3 - 01 [1080P].mp4").unwrap();
+    /// assert_eq!(info.slug(), "rezero-season-3");
+    /// ```
+    #[must_use]
+    pub fn slug(&self) -> String {
+        self.slug_with(SlugCjk::Keep)
+    }
+
+    /// 按指定的中日文字符处理方式生成 slug，其余规则同 [`slug`](Self::slug)。
+    ///
+    /// [`SlugCjk::Drop`] 只保留 ASCII 字母数字，纯中文番名会得到空字符串。
+    #[must_use]
+    pub fn slug_with(&self, cjk: SlugCjk) -> String {
+        let mut slug = String::with_capacity(self.anime_name.len());
+        let mut pending_separator = false;
+        for c in self.anime_name.chars() {
+            if c.is_whitespace() || matches!(c, '-' | '_' | '.') {
+                pending_separator = true;
+                continue;
+            }
+            let keep = match cjk {
+                SlugCjk::Keep => c.is_alphanumeric(),
+                SlugCjk::Drop => c.is_ascii_alphanumeric(),
+            };
+            if !keep {
+                continue;
+            }
+            if pending_separator && !slug.is_empty() {
+                slug.push('-');
+            }
+            pending_separator = false;
+            slug.extend(c.to_lowercase());
+        }
+        slug
     }
 
     /// 返回系列主标题，不包含可识别的季后缀。
@@ -96,6 +535,25 @@ impl AnimeFileInfo {
         split_series_and_season(&self.anime_name).0
     }
 
+    /// 返回番名或标签中括号包裹的年份（如 `Test (2024)`、`[2024]`），番名优先；没有时返回 `None`。
+    ///
+    /// ```
+    /// use anime_organizer::parser::FilenameParser;
+    ///
+    /// let info = FilenameParser::parse_str("[ANi] Test (2024) - 01 [1080P].mkv").unwrap();
+    /// assert_eq!(info.year(), Some(2024));
+    /// let info = FilenameParser::parse_str("[ANi] Test - 01 [1080P].mkv").unwrap();
+    /// assert_eq!(info.year(), None);
+    /// ```
+    #[must_use]
+    pub fn year(&self) -> Option<u32> {
+        [&self.anime_name, &self.tags].into_iter().find_map(|text| {
+            YEAR_REGEX
+                .captures(text)
+                .and_then(|captures| captures["year"].parse().ok())
+        })
+    }
+
     /// 返回从文件名中识别出的季号。
     #[must_use]
     pub fn season_number(&self) -> Option<u32> {
@@ -105,7 +563,618 @@ impl AnimeFileInfo {
     /// 返回用于季目录的名称，无法识别时回落到 `Season 1`。
     #[must_use]
     pub fn season_dir_name(&self) -> String {
-        format!("Season {}", self.season_number().unwrap_or(1))
+        self.season_dir_name_with(DEFAULT_SEASON_FORMAT)
+    }
+
+    /// 按模板返回季目录名称，模板语法见 [`render_season_dir`]。
+    ///
+    /// 文件名中没有可识别的季信息时按第 1 季渲染。
+    #[must_use]
+    pub fn season_dir_name_with(&self, format: &str) -> String {
+        render_season_dir(format, self.season_number().unwrap_or(1))
+    }
+}
+
+/// [`AnimeFileInfo`] 的链式构造器，见 [`AnimeFileInfo::builder`]。
+#[derive(Debug, Clone, Default)]
+pub struct AnimeFileInfoBuilder {
+    info: AnimeFileInfo,
+}
+
+impl AnimeFileInfoBuilder {
+    /// 设置发布组名称
+    #[must_use]
+    pub fn publisher(mut self, publisher: impl Into<String>) -> Self {
+        self.info.publisher = publisher.into();
+        self
+    }
+
+    /// 设置动漫名称
+    #[must_use]
+    pub fn anime_name(mut self, anime_name: impl Into<String>) -> Self {
+        self.info.anime_name = anime_name.into();
+        self
+    }
+
+    /// 设置集数；纯数字集数与解析器一致补齐为两位（`1` → `01`）
+    #[must_use]
+    pub fn episode(mut self, episode: impl Into<String>) -> Self {
+        let episode = episode.into();
+        self.info.episode = match episode.parse::<u32>() {
+            Ok(number) if episode.bytes().all(|b| b.is_ascii_digit()) => format!("{number:02}"),
+            _ => episode,
+        };
+        self
+    }
+
+    /// 设置标签串（如 `[1080P][CHT]`），默认为空
+    #[must_use]
+    pub fn tags(mut self, tags: impl Into<String>) -> Self {
+        self.info.tags = tags.into();
+        self
+    }
+
+    /// 设置扩展名；统一为带前导点的小写形式（`MKV` → `.mkv`）
+    #[must_use]
+    pub fn extension(mut self, extension: impl AsRef<str>) -> Self {
+        let extension = extension.as_ref().to_lowercase();
+        self.info.extension = if extension.is_empty() || extension.starts_with('.') {
+            extension
+        } else {
+            format!(".{extension}")
+        };
+        self
+    }
+
+    /// 设置原始文件路径
+    #[must_use]
+    pub fn original_path(mut self, original_path: impl AsRef<Path>) -> Self {
+        self.info.original_path = original_path.as_ref().to_string_lossy().into_owned();
+        self
+    }
+
+    /// 设置其他语言标题，默认为空
+    #[must_use]
+    pub fn alt_titles<I, S>(mut self, alt_titles: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.info.alt_titles = alt_titles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 标记为带完结标记的最后一集，默认为 `false`
+    #[must_use]
+    pub fn is_final(mut self, is_final: bool) -> Self {
+        self.info.is_final = is_final;
+        self
+    }
+
+    /// 生成 [`AnimeFileInfo`]；未设置的字段为空字符串
+    #[must_use]
+    pub fn build(self) -> AnimeFileInfo {
+        self.info
+    }
+}
+
+/// 集的类型
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum EpisodeKind {
+    /// 普通正片
+    #[default]
+    Regular,
+    /// 剧场版
+    Movie,
+    /// 总集篇
+    Recap,
+    /// 特别篇
+    Special,
+}
+
+/// 生成 slug 时中日文等非 ASCII 字符的处理方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SlugCjk {
+    /// 原样保留（英文字母仍转为小写）
+    #[default]
+    Keep,
+    /// 全部去掉，只保留 ASCII 字母数字
+    Drop,
+}
+
+/// 类型关键字（简繁体）；同时出现多个时取列表中靠前者
+const EPISODE_KIND_KEYWORDS: &[(&str, EpisodeKind)] = &[
+    ("剧场版", EpisodeKind::Movie),
+    ("劇場版", EpisodeKind::Movie),
+    ("总集篇", EpisodeKind::Recap),
+    ("總集篇", EpisodeKind::Recap),
+    ("特别篇", EpisodeKind::Special),
+    ("特別篇", EpisodeKind::Special),
+];
+
+impl EpisodeKind {
+    /// 在文本中查找类型关键字
+    fn detect(text: &str) -> Option<Self> {
+        EPISODE_KIND_KEYWORDS
+            .iter()
+            .find(|(keyword, _)| text.contains(keyword))
+            .map(|(_, kind)| *kind)
+    }
+
+    /// 整理时使用的子目录名；普通正片返回 `None`。
+    ///
+    /// 特别篇与小数集同属特殊集，目录名可配置（见 [`DEFAULT_SPECIALS_DIR`]），因此也返回 `None`。
+    #[must_use]
+    pub fn dir_name(self) -> Option<&'static str> {
+        match self {
+            Self::Regular | Self::Special => None,
+            Self::Movie => Some("剧场版"),
+            Self::Recap => Some("总集篇"),
+        }
+    }
+}
+
+/// 默认特殊集目录名，见 [`AnimeFileInfo::is_special`]
+pub const DEFAULT_SPECIALS_DIR: &str = "Specials";
+
+/// 默认季目录模板
+pub const DEFAULT_SEASON_FORMAT: &str = "Season {n}";
+
+/// 目标文件名中集数与标签之间的默认分隔符
+pub const DEFAULT_NAME_SEPARATOR: &str = " ";
+
+/// 区间集数的默认渲染模板，见 [`AnimeFileInfo::episode_label_with`]
+pub const DEFAULT_EPISODE_RANGE_FORMAT: &str = "{start}-{end}";
+
+/// 发布组名归一化时默认去除的后缀，见 [`normalize_publisher`]
+pub const DEFAULT_PUBLISHER_SUFFIXES: &[&str] =
+    &["Raws", "Raw", "字幕组", "字幕組", "字幕社", "Subs", "Sub"];
+
+/// 按模板渲染季目录名。
+///
+/// - `{n}` 替换为季号
+/// - `{n:0W}` 替换为补零到 `W` 位的季号，如 `{n:02}`
+///
+/// # 示例
+///
+/// ```
+/// use anime_organizer::parser::render_season_dir;
+///
+/// assert_eq!(render_season_dir("Season {n}", 2), "Season 2");
+/// assert_eq!(render_season_dir("S{n:02}", 2), "S02");
+/// assert_eq!(render_season_dir("第{n}季", 2), "第2季");
+/// ```
+#[must_use]
+pub fn render_season_dir(format: &str, season: u32) -> String {
+    let mut output = String::new();
+    let mut rest = format;
+    while let Some(start) = rest.find("{n") {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match parse_season_placeholder(after) {
+            Some((width, consumed)) => {
+                output.push_str(&format!("{season:0width$}"));
+                rest = &after[consumed..];
+            }
+            None => {
+                output.push_str("{n");
+                rest = after;
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/// 标题大小写规范化时保持全大写的缩写（含罗马数字季号）
+const TITLE_CASE_ACRONYMS: &[&str] = &[
+    "OVA", "OAD", "ONA", "TV", "SP", "PV", "CM", "NCOP", "NCED", "BD", "DVD", "II", "III", "IV",
+    "VI", "VII", "VIII", "IX",
+];
+
+/// 标题大小写规范化时除首尾词外保持小写的虚词
+const TITLE_CASE_SMALL_WORDS: &[&str] = &[
+    "a", "an", "the", "and", "but", "or", "nor", "of", "in", "on", "at", "to", "for", "by", "with",
+    "vs",
+];
+
+/// 将英文番名规范化为标题大小写，如 `the final season` → `The Final Season`。
+///
+/// 每个单词首字母大写、其余小写（`Re:zero` 这类以标点分隔的部分也各自首字母大写）；
+/// 已知缩写（OVA、TV、罗马数字等）全大写，冠词、介词等虚词除首尾的英文单词外小写。
+/// 不含 ASCII 字母的单词（如中文、日文）保持不变，单词间的空白也原样保留。
+///
+/// # 示例
+///
+/// ```
+/// use anime_organizer::parser::title_case;
+///
+/// assert_eq!(title_case("attack on titan the final season"), "Attack on Titan the Final Season");
+/// assert_eq!(title_case("re:zero ova"), "Re:Zero OVA");
+/// assert_eq!(title_case("葬送的芙莉莲"), "葬送的芙莉莲");
+/// ```
+#[must_use]
+pub fn title_case(name: &str) -> String {
+    let words: Vec<&str> = name.split(' ').collect();
+    // 首尾词按英文单词计算，使 `进击的巨人 the final season` 中的 `the` 也视为首词
+    let is_english = |word: &&str| word.chars().any(|c| c.is_ascii_alphabetic());
+    let first = words.iter().position(is_english).unwrap_or(0);
+    let last = words.iter().rposition(is_english).unwrap_or(0);
+    words
+        .iter()
+        .enumerate()
+        .map(|(index, word)| {
+            if !is_english(word) {
+                return (*word).to_string();
+            }
+            let core = word
+                .trim_matches(|c: char| !c.is_alphanumeric())
+                .to_ascii_lowercase();
+            if TITLE_CASE_ACRONYMS
+                .iter()
+                .any(|acronym| acronym.eq_ignore_ascii_case(&core))
+            {
+                return word.to_uppercase();
+            }
+            if index != first && index != last && TITLE_CASE_SMALL_WORDS.contains(&core.as_str()) {
+                return word.to_lowercase();
+            }
+            capitalize_word(word)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 字母前一个字符不是字母数字（或撇号）时大写，其余字母小写。
+fn capitalize_word(word: &str) -> String {
+    let mut output = String::with_capacity(word.len());
+    let mut previous: Option<char> = None;
+    for c in word.chars() {
+        let starts_part = previous.is_none_or(|p| !p.is_alphanumeric() && p != '\'');
+        if starts_part {
+            output.extend(c.to_uppercase());
+        } else {
+            output.extend(c.to_lowercase());
+        }
+        previous = Some(c);
+    }
+    output
+}
+
+/// 去除发布组名末尾的常见后缀，如 `ANi-Raws` → `ANi`、`Group字幕组` → `Group`。
+///
+/// 后缀按列表顺序反复匹配（忽略 ASCII 大小写），去除后再剔除末尾的空格、`-`、`_`、`.`；
+/// 以 ASCII 字母数字开头的后缀要求前面是分隔符，避免把 `SweetSub` 截成 `Sweet`。
+/// 去除后为空时保留原名。
+///
+/// # 示例
+///
+/// ```
+/// use anime_organizer::parser::{normalize_publisher, DEFAULT_PUBLISHER_SUFFIXES};
+///
+/// assert_eq!(normalize_publisher("ANi-Raws", DEFAULT_PUBLISHER_SUFFIXES), "ANi");
+/// assert_eq!(normalize_publisher("Group字幕组", DEFAULT_PUBLISHER_SUFFIXES), "Group");
+/// assert_eq!(normalize_publisher("SweetSub", DEFAULT_PUBLISHER_SUFFIXES), "SweetSub");
+/// ```
+#[must_use]
+pub fn normalize_publisher<S: AsRef<str>>(publisher: &str, suffixes: &[S]) -> String {
+    let is_separator = |c: char| c.is_whitespace() || matches!(c, '-' | '_' | '.');
+    let mut name = publisher.trim();
+    'strip: loop {
+        for suffix in suffixes {
+            let suffix = suffix.as_ref();
+            let Some(split) = name.len().checked_sub(suffix.len()) else {
+                continue;
+            };
+            if suffix.is_empty()
+                || !name.is_char_boundary(split)
+                || !name[split..].eq_ignore_ascii_case(suffix)
+            {
+                continue;
+            }
+            let stem = &name[..split];
+            let needs_separator = suffix.starts_with(|c: char| c.is_ascii_alphanumeric());
+            if needs_separator && !stem.ends_with(is_separator) {
+                continue;
+            }
+            let stem = stem.trim_end_matches(is_separator);
+            if stem.is_empty() {
+                continue;
+            }
+            name = stem;
+            continue 'strip;
+        }
+        break;
+    }
+    name.to_string()
+}
+
+/// 校验特殊集目录名：必须是单级目录名，不能为空、`.`、`..` 或包含路径分隔符。
+///
+/// # 错误
+///
+/// - `AppError::ParseError` - 目录名无效
+pub fn validate_specials_dir(name: &str) -> crate::error::Result<()> {
+    if name.trim().is_empty() || name == "." || name == ".." || name.contains(['/', '\\']) {
+        return Err(crate::error::AppError::ParseError(format!(
+            "无效的特殊集目录名 {name:?}: 必须是单级目录名"
+        )));
+    }
+    Ok(())
+}
+
+/// 校验目标文件名中集数与标签之间的分隔符：不能包含路径分隔符或字母数字。
+///
+/// # 错误
+///
+/// - `AppError::ParseError` - 分隔符无效
+pub fn validate_name_separator(separator: &str) -> crate::error::Result<()> {
+    if separator.contains(['/', '\\']) || separator.chars().any(char::is_alphanumeric) {
+        return Err(crate::error::AppError::ParseError(format!(
+            "无效的文件名分隔符 {separator:?}: 不能包含路径分隔符或字母数字"
+        )));
+    }
+    Ok(())
+}
+
+/// 校验区间集数模板：必须同时包含 `{start}` 与 `{end}`，且不能包含路径分隔符。
+///
+/// # 错误
+///
+/// - `AppError::ParseError` - 模板无效
+pub fn validate_episode_range_format(format: &str) -> crate::error::Result<()> {
+    if format.contains(['/', '\\']) || !format.contains("{start}") || !format.contains("{end}") {
+        return Err(crate::error::AppError::ParseError(format!(
+            "无效的区间集数模板 {format:?}: 必须包含 {{start}} 与 {{end}}，且不能包含路径分隔符"
+        )));
+    }
+    Ok(())
+}
+
+/// 校验季目录模板：必须包含 `{n}` 或 `{n:0W}`，且不能包含路径分隔符。
+///
+/// # 错误
+///
+/// - `AppError::ParseError` - 模板无效
+pub fn validate_season_format(format: &str) -> crate::error::Result<()> {
+    let invalid = |reason: &str| {
+        Err(crate::error::AppError::ParseError(format!(
+            "无效的季目录模板 {format:?}: {reason}"
+        )))
+    };
+    if format.contains(['/', '\\']) {
+        return invalid("不能包含路径分隔符");
+    }
+    let mut has_placeholder = false;
+    let mut rest = format;
+    while let Some(start) = rest.find("{n") {
+        let after = &rest[start + 2..];
+        let Some((_, consumed)) = parse_season_placeholder(after) else {
+            return invalid("占位符应为 {n} 或 {n:02} 形式");
+        };
+        has_placeholder = true;
+        rest = &after[consumed..];
+    }
+    if !has_placeholder {
+        return invalid("缺少季号占位符 {n}");
+    }
+    if render_season_dir(format, 1).trim().is_empty() {
+        return invalid("渲染结果为空");
+    }
+    Ok(())
+}
+
+/// 解析 `{n` 之后的占位符剩余部分，返回 `(补零宽度, 消耗的字节数)`。
+fn parse_season_placeholder(after: &str) -> Option<(usize, usize)> {
+    if after.starts_with('}') {
+        return Some((0, 1));
+    }
+    let spec = after.strip_prefix(":0")?;
+    let end = spec.find('}')?;
+    let width = spec[..end]
+        .parse::<usize>()
+        .ok()
+        .filter(|width| *width <= 9)?;
+    Some((width, 2 + end + 1))
+}
+
+/// 按集数比较两个文件，供缺集检查、播放列表和去重等场景统一排序。
+///
+/// - 数字集数按数值比较，`1`、`01`、`001` 视为相同
+/// - 小数集（如 `12.5`）排在相邻整数集之间
+/// - 分段放送（如 `13A`、`13B`）排在同号整数集之后，按字母（忽略大小写）排序
+/// - 区间（如 `01-12`、`01~12`）与合并多集（如 `01+02`）按起点比较，起点相同时按终点比较
+/// - 无法识别为数字的特殊集（如 `SP`、`OVA2`）排在所有数字集之后，
+///   同前缀的特殊集按尾部编号排序
+///
+/// # 示例
+///
+/// ```
+/// use anime_organizer::parser::{compare_episodes, FilenameParser};
+/// use std::cmp::Ordering;
+///
+/// let a = FilenameParser::parse("[ANi] Test - 02 [1080P].mp4").unwrap();
+/// let b = FilenameParser::parse("[ANi] Test - 10 [1080P].mp4").unwrap();
+/// assert_eq!(compare_episodes(&a, &b), Ordering::Less);
+/// ```
+#[must_use]
+pub fn compare_episodes(a: &AnimeFileInfo, b: &AnimeFileInfo) -> Ordering {
+    EpisodeOrderKey::new(&a.episode).cmp(&EpisodeOrderKey::new(&b.episode))
+}
+
+#[derive(Debug)]
+enum EpisodeOrderKey<'a> {
+    Numeric {
+        start: f64,
+        end: f64,
+        /// 分段放送的段落字母（`13A` 中的 `A`，统一为大写）
+        part: Option<char>,
+    },
+    Special {
+        prefix: String,
+        number: Option<f64>,
+        raw: &'a str,
+    },
+}
+
+impl<'a> EpisodeOrderKey<'a> {
+    fn new(episode: &'a str) -> Self {
+        let episode = episode.trim();
+        if let Some(value) = parse_episode_value(episode) {
+            return Self::Numeric {
+                start: value,
+                end: value,
+                part: None,
+            };
+        }
+        if let Some((number, part)) = split_episode_part(episode) {
+            if let Some(value) = parse_episode_value(number) {
+                return Self::Numeric {
+                    start: value,
+                    end: value,
+                    part: Some(part.to_ascii_uppercase()),
+                };
+            }
+        }
+        if let (Some(start), Some(end)) = (
+            episode.split('+').next().and_then(parse_episode_value),
+            episode.rsplit('+').next().and_then(parse_episode_value),
+        ) {
+            if episode.contains('+') {
+                return Self::Numeric {
+                    start,
+                    end,
+                    part: None,
+                };
+            }
+        }
+        if let Some((start, end)) = episode.split_once(['-', '~']) {
+            if let (Some(start), Some(end)) = (parse_episode_value(start), parse_episode_value(end))
+            {
+                return Self::Numeric {
+                    start,
+                    end,
+                    part: None,
+                };
+            }
+        }
+
+        let digits_at = episode
+            .find(|ch: char| ch.is_ascii_digit())
+            .unwrap_or(episode.len());
+        let (prefix, number) = episode.split_at(digits_at);
+        Self::Special {
+            prefix: prefix.trim().to_uppercase(),
+            number: parse_episode_value(number),
+            raw: episode,
+        }
+    }
+}
+
+/// 拆分分段放送的集数：`13A` → (`13`, `A`)；不是“数字 + 单个字母”时返回 `None`。
+fn split_episode_part(episode: &str) -> Option<(&str, char)> {
+    let part = episode.chars().next_back()?;
+    let number = &episode[..episode.len() - part.len_utf8()];
+    (part.is_ascii_alphabetic() && !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()))
+        .then_some((number, part))
+}
+
+fn parse_episode_value(raw: &str) -> Option<f64> {
+    raw.trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+impl PartialEq for EpisodeOrderKey<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for EpisodeOrderKey<'_> {}
+
+impl PartialOrd for EpisodeOrderKey<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EpisodeOrderKey<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (
+                Self::Numeric { start, end, part },
+                Self::Numeric {
+                    start: other_start,
+                    end: other_end,
+                    part: other_part,
+                },
+            ) => start
+                .total_cmp(other_start)
+                .then_with(|| end.total_cmp(other_end))
+                .then_with(|| part.cmp(other_part)),
+            (Self::Numeric { .. }, Self::Special { .. }) => Ordering::Less,
+            (Self::Special { .. }, Self::Numeric { .. }) => Ordering::Greater,
+            (
+                Self::Special {
+                    prefix,
+                    number,
+                    raw,
+                },
+                Self::Special {
+                    prefix: other_prefix,
+                    number: other_number,
+                    raw: other_raw,
+                },
+            ) => prefix
+                .cmp(other_prefix)
+                .then_with(|| match (number, other_number) {
+                    (Some(left), Some(right)) => left.total_cmp(right),
+                    (None, Some(_)) => Ordering::Less,
+                    (Some(_), None) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                })
+                .then_with(|| raw.cmp(other_raw)),
+        }
+    }
+}
+
+/// 多语言标题之间的分隔符；两侧须有空格，以免误拆 `Fate/stay night` 这类番名。
+/// 文件名中不能出现 `/`，落盘的文件常改用全角 `／`。
+const ALT_TITLE_SEPARATORS: &[&str] = &[" / ", " ／ "];
+
+/// 按 ` / `（或全角 ` ／ `）拆分并列的多语言标题，返回第一个标题与其余标题。
+///
+/// ```
+/// use anime_organizer::parser::split_alt_titles;
+///
+/// assert_eq!(
+///     split_alt_titles("葬送的芙莉莲 / 葬送のフリーレン / Frieren"),
+///     (
+///         "葬送的芙莉莲".to_string(),
+///         vec!["葬送のフリーレン".to_string(), "Frieren".to_string()]
+///     )
+/// );
+/// assert_eq!(split_alt_titles("Fate/stay night"), ("Fate/stay night".to_string(), vec![]));
+/// ```
+#[must_use]
+pub fn split_alt_titles(name: &str) -> (String, Vec<String>) {
+    let mut titles = vec![name];
+    for separator in ALT_TITLE_SEPARATORS {
+        titles = titles
+            .into_iter()
+            .flat_map(|title| title.split(separator))
+            .collect();
+    }
+    let mut titles = titles
+        .into_iter()
+        .map(str::trim)
+        .filter(|title| !title.is_empty())
+        .map(str::to_string);
+    match titles.next() {
+        Some(primary) => (primary, titles.collect()),
+        None => (name.to_string(), Vec::new()),
     }
 }
 
@@ -133,6 +1202,49 @@ pub fn split_series_and_season(name: &str) -> (String, Option<u32>) {
     (trimmed.to_string(), None)
 }
 
+/// 剥离紧跟集数的完结标记（见 [`FINALE_MARKER_REGEX`]），返回剥离后的文件名与原始标记；
+/// 没有标记时不分配。
+///
+/// 依次尝试每个候选标记：`episode_end` 给出剥离后文件名中集数结束（或集数之后剩余部分开始）的
+/// 位置，与标记之间只隔空白或 `]` 时才剥离，番名或标签中的 `86 End`、`[x264][END]` 不受影响。
+fn strip_finale_marker(
+    filename: &str,
+    episode_end: impl Fn(&str) -> Option<usize>,
+) -> (Cow<'_, str>, Option<&str>) {
+    for marker in FINALE_MARKER_REGEX
+        .captures_iter(filename)
+        .filter_map(|captures| captures.name("marker"))
+    {
+        let stripped = format!(
+            "{}{}",
+            &filename[..marker.start()],
+            &filename[marker.end()..]
+        );
+        let follows_episode = episode_end(&stripped).is_some_and(|end| {
+            stripped
+                .get(marker.start().min(end)..marker.start().max(end))
+                .is_some_and(|gap| gap.chars().all(|c| c.is_whitespace() || c == ']'))
+        });
+        if follows_episode {
+            return (Cow::Owned(stripped), Some(marker.as_str().trim()));
+        }
+    }
+    (Cow::Borrowed(filename), None)
+}
+
+/// 把番名末尾括号内的季度标注改写为 ` Season N` 后缀（与 `S01E02` 标记的番名一致），
+/// 使 [`split_series_and_season`] 能取出季号；没有括号季度标注时原样返回。
+fn normalize_bracketed_season(name: String) -> String {
+    let Some(captures) = BRACKETED_SEASON_REGEX.captures(&name) else {
+        return name;
+    };
+    let season = split_series_and_season(&format!("Title {}", &captures["season"])).1;
+    match season {
+        Some(season) => format!("{} Season {season}", captures["title"].trim()),
+        None => name,
+    }
+}
+
 fn parse_season_number(raw: &str) -> Option<u32> {
     let normalized = raw.trim();
 
@@ -161,12 +1273,162 @@ fn parse_season_number(raw: &str) -> Option<u32> {
     }
 }
 
+/// 宽松规则按 `-`、`_`、`.`、空格与括号切分出的片段及其起始位置
+fn loose_tokens(body: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    for (index, c) in body.char_indices().chain([(body.len(), ' ')]) {
+        if matches!(c, '-' | '_' | '.' | ' ' | '[' | ']' | '(' | ')') {
+            if index > start {
+                tokens.push((start, &body[start..index]));
+            }
+            start = index + c.len_utf8();
+        }
+    }
+    tokens
+}
+
+/// 宽松规则的集数：最后一个可作为集数的片段的下标与数字部分（见 [`loose_episode_digits`]）
+fn loose_episode<'a>(tokens: &[(usize, &'a str)]) -> Option<(usize, &'a str)> {
+    tokens
+        .iter()
+        .enumerate()
+        .rev()
+        .find_map(|(index, &(_, token))| Some((index, loose_episode_digits(token)?)))
+}
+
+/// 宽松规则中可作为集数的片段：1–3 位数字，可带 `v2` 版本号；
+/// 排除 `480`、`720` 这类省略了 `p` 的分辨率。
+fn loose_episode_digits(token: &str) -> Option<&str> {
+    let digits = match token.find(['v', 'V']) {
+        Some(index)
+            if index + 1 < token.len()
+                && token[index + 1..].bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            &token[..index]
+        }
+        Some(_) => return None,
+        None => token,
+    };
+    ((1..=3).contains(&digits.len())
+        && digits.bytes().all(|b| b.is_ascii_digit())
+        && !matches!(digits, "480" | "540" | "576" | "720"))
+    .then_some(digits)
+}
+
+/// 宽松规则中标志番名结束的片段：年份或可归类的画质、片源、编码、语言标签
+fn is_loose_tag(token: &str) -> bool {
+    let is_year = token.len() == 4
+        && token
+            .parse::<u32>()
+            .is_ok_and(|year| (1950..=2099).contains(&year));
+    is_year || classify_tag(token) != TagCategory::Other
+}
+
+/// 零宽字符与 BOM：`U+200B`–`U+200D`、`U+2060`、`U+FEFF`
+fn is_invisible_char(c: char) -> bool {
+    matches!(c, '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}')
+}
+
+/// 去除零宽字符与 BOM，并把各类空白（含全角空格、不换行空格）规范为单个半角空格、去掉首尾空白。
+///
+/// 不含这些字符时原样返回，不重新分配。
+fn clean_invisible(text: String) -> String {
+    let needs_cleaning = text.chars().any(is_invisible_char)
+        || text.chars().any(|c| c.is_whitespace() && c != ' ')
+        || text.contains("  ")
+        || text.trim() != text;
+    if !needs_cleaning {
+        return text;
+    }
+    text.chars()
+        .filter(|&c| !is_invisible_char(c))
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 将集数统一转换为补零的阿拉伯数字。
+///
+/// 支持阿拉伯数字、中文数字（`十二`、`一百零五`）、罗马数字（`III`）以及
+/// `第一話`/`第12话`/`第三集` 形式；无法识别时返回 `None`。
+///
+/// # 示例
+///
+/// ```
+/// use anime_organizer::parser::parse_episode_number;
+///
+/// assert_eq!(parse_episode_number("第一話").as_deref(), Some("01"));
+/// assert_eq!(parse_episode_number("第 5 话").as_deref(), Some("05"));
+/// assert_eq!(parse_episode_number("III").as_deref(), Some("03"));
+/// assert_eq!(parse_episode_number("十二").as_deref(), Some("12"));
+/// assert_eq!(parse_episode_number("Final"), None);
+/// ```
+#[must_use]
+pub fn parse_episode_number(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    let inner = raw
+        .strip_prefix('第')
+        .and_then(|rest| rest.strip_suffix(['話', '话', '集']))
+        .map_or(raw, str::trim);
+    let number = if !inner.is_empty() && inner.bytes().all(|byte| byte.is_ascii_digit()) {
+        inner.parse::<u32>().ok()?
+    } else {
+        parse_roman_numeral(inner).or_else(|| parse_cjk_number(inner))?
+    };
+    Some(format!("{number:02}"))
+}
+
+/// 解析规范写法的大写罗马数字（如 `IV` 而非 `IIII`）。
+fn parse_roman_numeral(raw: &str) -> Option<u32> {
+    const NUMERALS: [(u32, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    let mut rest = raw;
+    let mut total = 0;
+    for (value, symbol) in NUMERALS {
+        // 规范写法中 M/C/X/I 最多连续三次，其余符号最多一次。
+        let max_repeat = if symbol.len() == 1 && "MCXI".contains(symbol) {
+            3
+        } else {
+            1
+        };
+        let mut repeat = 0;
+        while repeat < max_repeat {
+            let Some(stripped) = rest.strip_prefix(symbol) else {
+                break;
+            };
+            rest = stripped;
+            total += value;
+            repeat += 1;
+        }
+    }
+    (rest.is_empty() && total > 0).then_some(total)
+}
+
 fn parse_cjk_number(raw: &str) -> Option<u32> {
+    if raw.is_empty() {
+        return None;
+    }
     let mut total = 0;
     let mut current = 0;
 
     for ch in raw.chars() {
         match ch {
+            '零' | '〇' => {}
             '一' => current += 1,
             '二' => current += 2,
             '三' => current += 3,
@@ -180,6 +1442,10 @@ fn parse_cjk_number(raw: &str) -> Option<u32> {
                 total += if current == 0 { 10 } else { current * 10 };
                 current = 0;
             }
+            '百' => {
+                total += if current == 0 { 100 } else { current * 100 };
+                current = 0;
+            }
             _ => return None,
         }
     }
@@ -187,6 +1453,69 @@ fn parse_cjk_number(raw: &str) -> Option<u32> {
     Some(total + current)
 }
 
+/// 番名与集数的匹配结果，`*_raw` 为文件名中的原始片段
+struct AnimeEpisodeMatch<'a> {
+    anime_name: String,
+    anime_raw: &'a str,
+    episode: String,
+    episode_raw: &'a str,
+    /// 番名与集数之间的标签（全方括号格式中如 `[WebRip]`），其余标签在 `rest` 中
+    leading_tags: &'a str,
+    rest: &'a str,
+}
+
+impl AnimeEpisodeMatch<'_> {
+    /// 番名与集数之间的标签和 `rest` 中解析出的标签合并后的原始标签串
+    fn tags(&self, tags: &str) -> String {
+        if self.leading_tags.is_empty() {
+            tags.to_string()
+        } else {
+            format!("{}{tags}", self.leading_tags)
+        }
+    }
+}
+
+/// 单个字段的调试信息
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldTrace {
+    /// 字段名：`publisher`、`anime`、`episode`、`tags`、`ext`
+    pub name: &'static str,
+    /// 文件名中匹配到的原始片段
+    pub raw: String,
+    /// 处理后的值（与 [`AnimeFileInfo`] 中一致）
+    pub value: String,
+}
+
+/// [`FilenameParser::debug_parse`] 的结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseTrace {
+    /// 已成功匹配的字段，按解析顺序排列
+    pub fields: Vec<FieldTrace>,
+    /// 解析中断的位置与原因；`None` 表示解析成功
+    pub failure: Option<String>,
+}
+
+impl ParseTrace {
+    /// 按字段名查找
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&FieldTrace> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    fn push(&mut self, name: &'static str, raw: &str, value: impl Into<String>) {
+        self.fields.push(FieldTrace {
+            name,
+            raw: raw.to_string(),
+            value: value.into(),
+        });
+    }
+
+    fn fail(mut self, reason: String) -> Self {
+        self.failure = Some(reason);
+        self
+    }
+}
+
 /// 文件名解析器
 ///
 /// 使用正则表达式解析符合特定格式的动漫文件名。
@@ -194,51 +1523,285 @@ pub struct FilenameParser;
 
 impl FilenameParser {
     /// 解析文件路径，提取动漫文件信息
+    ///
+    /// 先从路径中取出文件名，再交给 [`FilenameParser::parse_str`] 解析；
+    /// `original_path` 保留完整路径。
     #[must_use]
     pub fn parse<P: AsRef<Path>>(file_path: P) -> Option<AnimeFileInfo> {
         let path = file_path.as_ref();
         let filename = path.to_str()?;
 
         let filename = if filename.starts_with('[') {
-            filename.to_string()
+            filename
         } else if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+            name
+        } else {
+            filename
+        };
+
+        Self::parse_with_original_path(filename, || path.to_string_lossy().into_owned())
+    }
+
+    /// 解析纯文件名字符串，不访问文件系统也不依赖 `std::path`。
+    ///
+    /// 适用于只有文件名、没有对应文件的场景（如在线测试命名）；
+    /// 返回值的 `original_path` 即传入的字符串。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use anime_organizer::parser::FilenameParser;
+    ///
+    /// let info = FilenameParser::parse_str("[ANi] 测试 - 01 [1080P].mp4").unwrap();
+    /// assert_eq!(info.anime_name, "测试");
+    /// assert_eq!(info.original_path, "[ANi] 测试 - 01 [1080P].mp4");
+    /// ```
+    #[must_use]
+    pub fn parse_str(filename: &str) -> Option<AnimeFileInfo> {
+        Self::parse_with_original_path(filename, || filename.to_string())
+    }
+
+    /// 先按标准规则解析，失败时再用 [`FilenameParser::parse_loose_str`] 的宽松规则兜底。
+    ///
+    /// 与 [`FilenameParser::parse`] 一样只取路径中的文件名解析。
+    #[must_use]
+    pub fn parse_loose<P: AsRef<Path>>(file_path: P) -> Option<AnimeFileInfo> {
+        let path = file_path.as_ref();
+        Self::parse(path).or_else(|| {
+            let filename = path.file_name()?.to_str()?;
+            let mut info = Self::parse_loose_heuristic(filename)?;
+            info.original_path = path.to_string_lossy().into_owned();
+            Some(info)
+        })
+    }
+
+    /// 先按标准规则解析，失败时启发式地从分隔串中提取集数（best-effort）。
+    ///
+    /// 针对画质与集数黏连的命名，如 `Anime-1080p-01.mkv`：按 `-`、`_`、`.`、空格与括号切分，
+    /// 取最后一个独立的纯数字片段（可带 `v2` 版本号）作为集数。分辨率（`1080p`、`720`）、
+    /// 年份（`2024`）与编码等标签不会被当作集数；番名取第一个标签或集数之前的部分，
+    /// 其后的片段都作为标签。开头的 `[发布组]` 可省略。
+    ///
+    /// 该规则只是尽力而为，番名以数字结尾等情况仍可能误判，因此默认不启用，
+    /// 由 `--loose-episode` 显式开启。
+    ///
+    /// ```
+    /// use anime_organizer::parser::FilenameParser;
+    ///
+    /// assert!(FilenameParser::parse_str("Anime-1080p-01.mkv").is_none());
+    ///
+    /// let info = FilenameParser::parse_loose_str("Anime-1080p-01.mkv").unwrap();
+    /// assert_eq!(info.anime_name, "Anime");
+    /// assert_eq!(info.episode, "01");
+    /// assert_eq!(info.tags, "[1080p]");
+    /// ```
+    #[must_use]
+    pub fn parse_loose_str(filename: &str) -> Option<AnimeFileInfo> {
+        Self::parse_str(filename).or_else(|| Self::parse_loose_heuristic(filename))
+    }
+
+    fn parse_loose_heuristic(filename: &str) -> Option<AnimeFileInfo> {
+        let (stripped, finale_marker) = strip_finale_marker(filename, Self::loose_episode_end);
+        let (stem, extension) = stripped.trim_matches(is_invisible_char).rsplit_once('.')?;
+        if !Self::looks_like_extension(extension) {
+            return None;
+        }
+        let (publisher, body) = Self::split_publisher(stem).unwrap_or(("", stem));
+
+        let tokens = loose_tokens(body);
+        let (episode_index, episode_raw) = loose_episode(&tokens)?;
+        let name_end = tokens
+            .iter()
+            .position(|&(_, token)| is_loose_tag(token))
+            .map_or(episode_index, |index| index.min(episode_index));
+        let name = body[..tokens[name_end].0].trim_matches(['-', '_', '.', ' ', '[', '(']);
+        if name.is_empty() {
+            return None;
+        }
+        let anime_name = if name.contains(' ') {
             name.to_string()
         } else {
-            filename.to_string()
+            name.replace(['_', '.'], " ")
         };
+        let tags: String = tokens[name_end..]
+            .iter()
+            .enumerate()
+            .filter(|&(offset, _)| name_end + offset != episode_index)
+            .map(|(_, &(_, token))| format!("[{token}]"))
+            .collect();
 
-        let caps = ANIME_FILE_REGEX.captures(&filename)?;
-        let publisher = caps.name("publisher")?.as_str().trim().to_string();
-        let publisher_end = caps.get(0)?.end();
+        let (anime_name, alt_titles) = split_alt_titles(&clean_invisible(anime_name));
+        let anime_name = normalize_bracketed_season(anime_name);
+        Some(AnimeFileInfo {
+            publisher: publisher.trim().to_string(),
+            anime_name,
+            episode: format!("{episode_raw:0>2}"),
+            tags: clean_invisible(tags),
+            extension: format!(".{}", extension.to_lowercase()),
+            original_path: filename.to_string(),
+            alt_titles,
+            is_final: finale_marker.is_some(),
+        })
+    }
 
-        let after_publisher = &filename[publisher_end..];
+    /// 宽松规则选中的集数片段在文件名中的结束位置，供 [`strip_finale_marker`] 使用
+    fn loose_episode_end(filename: &str) -> Option<usize> {
+        let trimmed = filename.trim_start_matches(is_invisible_char);
+        let (stem, _) = trimmed
+            .trim_end_matches(is_invisible_char)
+            .rsplit_once('.')?;
+        let body = Self::split_publisher(stem).map_or(stem, |(_, body)| body);
+        let tokens = loose_tokens(body);
+        let (episode_index, _) = loose_episode(&tokens)?;
+        let (start, token) = tokens[episode_index];
+        Some(filename.len() - trimmed.len() + stem.len() - body.len() + start + token.len())
+    }
 
-        let (anime_name, episode, after_episode) = Self::parse_anime_episode(after_publisher)?;
+    /// 集数之后剩余部分在文件名中的起点，供 [`strip_finale_marker`] 使用
+    fn episode_rest_start(filename: &str) -> Option<usize> {
+        let (_, after_publisher) = Self::split_publisher(filename)?;
+        let matched = Self::parse_anime_episode(after_publisher)?;
+        Some(filename.trim_end_matches(is_invisible_char).len() - matched.rest.len())
+    }
 
-        let (tags, extension) = Self::parse_tags_and_ext(after_episode)?;
+    /// 全程使用切片，解析成功后才分配各字段，失败的文件名不产生分配。
+    fn parse_with_original_path(
+        filename: &str,
+        original_path: impl FnOnce() -> String,
+    ) -> Option<AnimeFileInfo> {
+        let (filename, finale_marker) = strip_finale_marker(filename, Self::episode_rest_start);
+        let (publisher, after_publisher) = Self::split_publisher(&filename)?;
+        let matched = Self::parse_anime_episode(after_publisher)?;
+        let (tags, extension) =
+            Self::parse_tags_and_ext(Self::strip_episode_separator(matched.rest))?;
+        let tags = matched.tags(tags);
 
+        let (anime_name, alt_titles) = split_alt_titles(&clean_invisible(matched.anime_name));
+        let anime_name = normalize_bracketed_season(anime_name);
         Some(AnimeFileInfo {
-            publisher,
+            publisher: publisher.trim().to_string(),
             anime_name,
-            episode,
-            tags,
+            episode: matched.episode,
+            tags: normalize_tags(&clean_invisible(tags)),
             extension,
-            original_path: path.to_string_lossy().to_string(),
+            original_path: original_path(),
+            alt_titles,
+            is_final: finale_marker.is_some(),
         })
     }
 
-    fn parse_anime_episode(input: &str) -> Option<(String, String, &str)> {
+    /// 拆出开头 `[发布组]` 中的发布组与其后的剩余部分。
+    ///
+    /// 发布组为第一对方括号内的全部内容，可含空格、圆括号与成对的内层方括号
+    /// （如 `[Group [Raws]]`）；内层方括号不成对时退回到第一个 `]` 结束。
+    /// 文件名首尾的 BOM、零宽空格等不可见字符先行去除。
+    fn split_publisher(filename: &str) -> Option<(&str, &str)> {
+        let inner = filename.trim_matches(is_invisible_char).strip_prefix('[')?;
+        let mut depth = 0usize;
+        let matching = inner.char_indices().find_map(|(index, c)| match c {
+            '[' => {
+                depth += 1;
+                None
+            }
+            ']' if depth == 0 => Some(index),
+            ']' => {
+                depth -= 1;
+                None
+            }
+            _ => None,
+        });
+        let end = matching
+            .or_else(|| inner.find(']'))
+            .filter(|&end| end > 0)?;
+        Some((&inner[..end], &inner[end + 1..]))
+    }
+
+    /// 按 [`FilenameParser::parse_str`] 的步骤解析，记录每个字段的原始片段与处理后的值。
+    ///
+    /// 解析失败时 `failure` 说明在哪一步断开，`fields` 保留此前已匹配的字段。
+    ///
+    /// ```
+    /// use anime_organizer::parser::FilenameParser;
+    ///
+    /// let trace = FilenameParser::debug_parse("[ANi] 测试 - 1 (1080p).MP4");
+    /// assert!(trace.failure.is_none());
+    /// let episode = trace.field("episode").unwrap();
+    /// assert_eq!((episode.raw.as_str(), episode.value.as_str()), ("1", "01"));
+    ///
+    /// let trace = FilenameParser::debug_parse("[ANi] 测试.mp4");
+    /// assert_eq!(trace.fields.len(), 1);
+    /// assert!(trace.failure.unwrap().contains("集数"));
+    /// ```
+    #[must_use]
+    pub fn debug_parse(filename: &str) -> ParseTrace {
+        let mut trace = ParseTrace::default();
+        let (filename, finale_marker) = strip_finale_marker(filename, Self::episode_rest_start);
+        let Some((publisher, after_publisher)) = Self::split_publisher(&filename) else {
+            return trace.fail(format!("publisher: {filename:?} 不以 `[发布组]` 开头"));
+        };
+        trace.push("publisher", publisher, publisher.trim());
+
+        let Some(matched) = Self::parse_anime_episode(after_publisher) else {
+            return trace.fail(format!(
+                "anime/episode: 在 {after_publisher:?} 中未找到集数（如 ` - 01`、`S01E02`、`第一話`、`[01]`）"
+            ));
+        };
+        trace.push(
+            "anime",
+            matched.anime_raw,
+            normalize_bracketed_season(clean_invisible(matched.anime_name.clone())),
+        );
+        trace.push("episode", matched.episode_raw, matched.episode.clone());
+
+        let rest = Self::strip_episode_separator(matched.rest);
+        let Some((tags, extension)) = Self::parse_tags_and_ext(rest) else {
+            return trace.fail(format!("ext: 在 {rest:?} 中未找到支持的扩展名"));
+        };
+        let raw_extension = rest.trim()[tags.len()..].trim();
+        let tags = matched.tags(tags);
+        trace.push(
+            "tags",
+            &tags,
+            normalize_tags(&clean_invisible(tags.clone())),
+        );
+        trace.push("ext", raw_extension, extension);
+        if let Some(marker) = finale_marker {
+            trace.push("final", marker, "true");
+        }
+        trace
+    }
+
+    fn parse_anime_episode(input: &str) -> Option<AnimeEpisodeMatch<'_>> {
         let input = input.trim_start();
-        if let Some(captures) = SEASON_EPISODE_REGEX.captures(input) {
-            let title = captures.name("title")?.as_str().trim();
+        // 捕获组匹配开销较大，先确认存在 `S` + 数字再交给正则。
+        let has_season_marker = input
+            .as_bytes()
+            .windows(2)
+            .any(|pair| matches!(pair[0], b'S' | b's') && pair[1].is_ascii_digit());
+        // 只匹配 ` - S01E02 ` 标记本身：番名取标记之前的部分，避免长路径上的
+        // 惰性前缀回溯。
+        if let Some(captures) = has_season_marker
+            .then(|| {
+                SEASON_EPISODE_REGEX
+                    .captures_iter(input)
+                    .find(|captures| captures.get(0).is_some_and(|marker| marker.start() > 0))
+            })
+            .flatten()
+        {
+            let marker = captures.get(0)?;
+            let title = &input[..marker.start()];
             let season = captures.name("season")?.as_str().parse::<u32>().ok()?;
-            let episode = captures.name("episode")?.as_str().parse::<u32>().ok()?;
-            let rest = captures.name("rest")?.as_str().trim_start();
-            return Some((
-                format!("{title} Season {season}"),
-                format!("{episode:02}"),
+            let episode_raw = captures.name("episode")?.as_str();
+            let episode = episode_raw.parse::<u32>().ok()?;
+            let rest = input[marker.end()..].trim_start();
+            return Some(AnimeEpisodeMatch {
+                anime_name: format!("{} Season {season}", title.trim()),
+                anime_raw: title,
+                episode: format!("{episode:02}"),
+                episode_raw,
+                leading_tags: "",
                 rest,
-            ));
+            });
         }
         let bytes = input.as_bytes();
 
@@ -275,6 +1838,29 @@ impl FilenameParser {
 
                             break;
                         }
+                        // 合并多集：`01+02`，与区间写法 `01-02` 区分
+                        while num_end + 1 < bytes.len()
+                            && bytes[num_end] == b'+'
+                            && bytes[num_end + 1].is_ascii_digit()
+                        {
+                            num_end += 1;
+                            while num_end < bytes.len() && bytes[num_end].is_ascii_digit() {
+                                num_end += 1;
+                            }
+                        }
+                        // 分段放送：`13A`、`13B`，集数后紧跟单个字母；
+                        // 排除 `1080p`、`4K` 这类画质标记
+                        if bytes[num_start..num_end].iter().all(u8::is_ascii_digit)
+                            && num_end < bytes.len()
+                            && bytes[num_end].is_ascii_alphabetic()
+                            && !matches!(bytes[num_end].to_ascii_lowercase(), b'p' | b'k')
+                            && bytes
+                                .get(num_end + 1)
+                                .copied()
+                                .is_none_or(|next| matches!(next, b' ' | b'[' | b'-' | b'.' | b'('))
+                        {
+                            num_end += 1;
+                        }
                         let after_digits = if num_end < bytes.len() {
                             bytes[num_end]
                         } else {
@@ -297,8 +1883,17 @@ impl FilenameParser {
 
         if let Some((dash_pos, digit_start, digit_end)) = episode_info {
             let episode_raw = std::str::from_utf8(&bytes[digit_start..digit_end]).ok()?;
-            let episode = if episode_raw.contains('.') {
+            let episode = if episode_raw.contains('+') {
+                episode_raw
+                    .split('+')
+                    .map(|part| format!("{part:0>2}"))
+                    .collect::<Vec<_>>()
+                    .join("+")
+            } else if episode_raw.contains('.') {
                 episode_raw.to_string()
+            } else if let Some(number) = episode_raw.strip_suffix(|c: char| c.is_ascii_alphabetic())
+            {
+                format!("{number:0>2}{}", &episode_raw[number.len()..])
             } else {
                 format!("{:0>2}", episode_raw)
             };
@@ -306,7 +1901,14 @@ impl FilenameParser {
             let anime_name = input[..dash_pos].trim().to_string();
             let after_episode = std::str::from_utf8(&bytes[digit_end..]).ok()?.trim_start();
 
-            return Some((anime_name, episode, after_episode));
+            return Some(AnimeEpisodeMatch {
+                anime_name,
+                anime_raw: &input[..dash_pos],
+                episode,
+                episode_raw,
+                leading_tags: "",
+                rest: after_episode,
+            });
         }
 
         for marker in [" - 電影", " - 电影", " - Movie"] {
@@ -315,12 +1917,40 @@ impl FilenameParser {
                 if after_marker.starts_with('[') || after_marker.starts_with('.') {
                     let anime_name = input[..marker_pos].trim().to_string();
                     if !anime_name.is_empty() {
-                        return Some((anime_name, "01".to_string(), after_marker));
+                        return Some(AnimeEpisodeMatch {
+                            anime_name,
+                            anime_raw: &input[..marker_pos],
+                            episode: "01".to_string(),
+                            episode_raw: marker.trim_start_matches([' ', '-']),
+                            leading_tags: "",
+                            rest: after_marker,
+                        });
                     }
                 }
             }
         }
 
+        if let Some(captures) = NUMERAL_EPISODE_REGEX.captures(input) {
+            let raw = captures
+                .name("ordinal")
+                .or_else(|| captures.name("numeral"))?;
+            let episode = parse_episode_number(raw.as_str())?;
+            let title = captures.name("title")?.as_str();
+            let rest = captures.name("rest")?.as_str().trim_start();
+            return Some(AnimeEpisodeMatch {
+                anime_name: title.trim().to_string(),
+                anime_raw: title,
+                episode,
+                episode_raw: raw.as_str(),
+                leading_tags: "",
+                rest,
+            });
+        }
+
+        if let Some(matched) = Self::parse_bracket_segments(input) {
+            return Some(matched);
+        }
+
         // Try to find "[XX]" pattern (dmhy.org format with episode in brackets)
         // Look for last occurrence of "[" followed by digits and "]"
         for i in (0..bytes.len()).rev() {
@@ -339,7 +1969,14 @@ impl FilenameParser {
                                 let anime_name = input[..j - 1].trim().to_string();
                                 let after_episode =
                                     std::str::from_utf8(&bytes[i + 1..]).ok()?.trim_start();
-                                return Some((anime_name, episode, after_episode));
+                                return Some(AnimeEpisodeMatch {
+                                    anime_name,
+                                    anime_raw: &input[..j - 1],
+                                    episode,
+                                    episode_raw: episode_str,
+                                    leading_tags: "",
+                                    rest: after_episode,
+                                });
                             }
                         }
                     }
@@ -347,10 +1984,92 @@ impl FilenameParser {
             }
         }
 
-        None
+        Self::parse_compact(input)
+    }
+
+    /// 无空格的紧凑命名：`妖精的尾巴-01[1080p].mp4`（发布组已由调用方拆出）。
+    ///
+    /// 作为最后的规则，仅在带空格的各种写法都不匹配时使用；
+    /// `480`、`720` 这类省略了 `p` 的分辨率不视为集数。番名以画质或编码结尾时
+    /// （`Show-1080p-03`）不是紧凑命名，交给宽松规则处理。
+    fn parse_compact(input: &str) -> Option<AnimeEpisodeMatch<'_>> {
+        let captures = COMPACT_EPISODE_REGEX.captures(input)?;
+        let title = captures.name("title")?.as_str();
+        let last_token = title.rsplit([' ', '-', '_', '.']).next().unwrap_or(title);
+        if matches!(
+            classify_tag(last_token),
+            TagCategory::Resolution | TagCategory::Codec
+        ) {
+            return None;
+        }
+        let episode_raw = loose_episode_digits(captures.name("episode")?.as_str())?;
+        Some(AnimeEpisodeMatch {
+            anime_name: title.trim().to_string(),
+            anime_raw: title,
+            episode: format!("{episode_raw:0>2}"),
+            episode_raw,
+            leading_tags: "",
+            rest: captures.name("rest")?.as_str(),
+        })
+    }
+
+    /// 全方括号格式：`[番名][01][1080p]`（发布组已由调用方拆出）。
+    ///
+    /// 从开头起连续的方括号段中，第一段为番名，其后第一个纯数字段为集数，
+    /// 其余段（集数前后均可）为标签。开头不是方括号或没有纯数字段时返回 `None`，
+    /// 交给后续规则处理。
+    fn parse_bracket_segments(input: &str) -> Option<AnimeEpisodeMatch<'_>> {
+        let mut segments: Vec<(usize, usize)> = Vec::new();
+        let mut pos = 0;
+        loop {
+            let rest = &input[pos..];
+            let trimmed = rest.trim_start();
+            if !trimmed.starts_with('[') {
+                break;
+            }
+            let open = pos + rest.len() - trimmed.len();
+            let close = open + trimmed.find(']')?;
+            segments.push((open, close + 1));
+            pos = close + 1;
+        }
+        let content = |(open, end): (usize, usize)| &input[open + 1..end - 1];
+
+        let &name_segment = segments.first()?;
+        let anime_raw = content(name_segment);
+        if anime_raw.trim().is_empty() || anime_raw.trim().bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let episode_index = segments.iter().skip(1).position(|&segment| {
+            let content = content(segment);
+            !content.is_empty()
+                && content.bytes().all(|b| b.is_ascii_digit())
+                && content
+                    .parse::<u32>()
+                    .is_ok_and(|number| (1..=9999).contains(&number))
+        })? + 1;
+        let episode_segment = segments[episode_index];
+        let episode_raw = content(episode_segment);
+
+        Some(AnimeEpisodeMatch {
+            anime_name: anime_raw.trim().to_string(),
+            anime_raw,
+            episode: format!("{episode_raw:0>2}"),
+            episode_raw,
+            leading_tags: input[name_segment.1..episode_segment.0].trim(),
+            rest: input[episode_segment.1..].trim_start(),
+        })
+    }
+
+    /// 去掉集数与标签之间多余的破折号，如 `Anime - 01 - [1080p].mkv` 中集数后的 ` - `；
+    /// 全角破折号 `–`、`—` 同样视为分隔。
+    fn strip_episode_separator(rest: &str) -> &str {
+        let trimmed = rest.trim_start();
+        trimmed
+            .strip_prefix(['-', '–', '—'])
+            .map_or(trimmed, str::trim_start)
     }
 
-    fn parse_tags_and_ext(input: &str) -> Option<(String, String)> {
+    fn parse_tags_and_ext(input: &str) -> Option<(&str, String)> {
         let input = input.trim();
         if input.is_empty() {
             return None;
@@ -365,7 +2084,7 @@ impl FilenameParser {
                     if let Some(open_pos) = open_stack.pop() {
                         let bracket_content = &input[open_pos + 1..i];
                         if Self::looks_like_extension(bracket_content) {
-                            let tags = input[..open_pos].trim().to_string();
+                            let tags = input[..open_pos].trim();
                             return Some((tags, format!(".{}", bracket_content.to_lowercase())));
                         }
                     }
@@ -376,7 +2095,7 @@ impl FilenameParser {
         if let Some(dot_pos) = input.rfind('.') {
             let ext = input[dot_pos + 1..].to_lowercase();
             if Self::looks_like_extension(&ext) {
-                let tags = input[..dot_pos].trim().to_string();
+                let tags = input[..dot_pos].trim();
                 return Some((tags, format!(".{}", ext)));
             }
         }
@@ -400,12 +2119,12 @@ impl FilenameParser {
                                 .unwrap_or(bracket_start);
                             let content = &input[bracket_start + 1..bracket_end];
                             if Self::looks_like_extension(content) {
-                                let tags = input[..bracket_start].trim().to_string();
+                                let tags = input[..bracket_start].trim();
                                 return Some((tags, format!(".{}", content.to_lowercase())));
                             }
                         }
                     }
-                    let tags = before_dot.trim().to_string();
+                    let tags = before_dot.trim();
                     return Some((tags, format!(".{}", ext.to_lowercase())));
                 }
             }
@@ -425,7 +2144,7 @@ impl FilenameParser {
                     .unwrap_or(bracket_start);
                 let content = &input[bracket_start + 1..bracket_end];
                 if Self::looks_like_extension(content) {
-                    let tags = input[..bracket_start].trim().to_string();
+                    let tags = input[..bracket_start].trim();
                     return Some((tags, format!(".{}", content.to_lowercase())));
                 }
             }
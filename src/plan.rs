@@ -0,0 +1,407 @@
+//! 整理计划模块
+//!
+//! `--dry-run --plan-out plan.json` 把预览得到的 [`OperationPlan`] 列表连同操作模式及目标冲突
+//! 相关选项（见 [`PlanOptions`]）写成 JSON，
+//! 审核（或拷贝到另一台机器）后由 `aniorg apply plan.json` 执行。计划中的路径原样保存，
+//! 执行时逐条重新确认源文件仍存在，已不存在的条目报告失败但不影响其余条目；
+//! `aniorg apply --atomic` 则在任一条目失败时撤销已完成的条目。
+//!
+//! # 示例
+//!
+//! ```
+//! use anime_organizer::organizer::{OperationMode, OperationPlan};
+//! use anime_organizer::plan::OrganizePlan;
+//!
+//! let plan = OrganizePlan::new(
+//!     OperationMode::Copy,
+//!     vec![OperationPlan {
+//!         source: "/downloads/[ANi] 测试 - 01 [1080P].mp4".into(),
+//!         target: "/anime/测试/[ANi] 测试 - 01 [1080P].mp4".into(),
+//!     }],
+//! );
+//! let json = plan.to_json()?;
+//! assert_eq!(OrganizePlan::from_json(&json)?, plan);
+//! # Ok::<(), anime_organizer::AppError>(())
+//! ```
+
+use crate::error::{AppError, Result};
+use crate::filesystem::FileSystem;
+use crate::organizer::{
+    ConflictPolicy, FileOrganizer, OperationMode, OperationOutcome, OperationPlan, OrganizeOptions,
+    SameFileCheck,
+};
+use crate::transaction;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+/// 当前计划文件格式版本，格式不兼容地变化时递增
+pub const PLAN_VERSION: u32 = 2;
+
+/// 预览时选定、执行计划时仍需沿用的目标冲突处理选项（对应 [`OrganizeOptions`] 的同名字段）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanOptions {
+    /// 覆盖内容不同的已存在目标前先改名备份
+    pub backup: bool,
+    /// 跳过内容比较，直接覆盖已存在的目标且不备份
+    pub force: bool,
+    /// 目标已存在时判断是否与源文件相同的策略
+    pub same_file_check: SameFileCheck,
+    /// 目标已存在且与源文件不同时是否覆盖的策略
+    pub on_conflict: ConflictPolicy,
+}
+
+impl PlanOptions {
+    /// 以 `mode` 及这些选项构造执行计划所用的 [`OrganizeOptions`]
+    #[must_use]
+    pub fn to_organize_options(self, mode: OperationMode) -> OrganizeOptions {
+        OrganizeOptions {
+            backup: self.backup,
+            force: self.force,
+            same_file_check: self.same_file_check,
+            on_conflict: self.on_conflict,
+            ..OrganizeOptions::new(mode)
+        }
+    }
+}
+
+/// 可序列化的整理计划
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrganizePlan {
+    /// 计划文件格式版本，见 [`PLAN_VERSION`]
+    pub version: u32,
+    /// 操作模式
+    pub mode: OperationMode,
+    /// 目标冲突处理选项
+    pub options: PlanOptions,
+    /// 按执行顺序排列的文件操作（视频在前，其后为其字幕等伴随文件）
+    pub operations: Vec<OperationPlan>,
+}
+
+impl OrganizePlan {
+    /// 使用当前格式版本及默认冲突选项创建计划
+    #[must_use]
+    pub fn new(mode: OperationMode, operations: Vec<OperationPlan>) -> Self {
+        Self {
+            version: PLAN_VERSION,
+            mode,
+            options: PlanOptions::default(),
+            operations,
+        }
+    }
+
+    /// 设置执行计划时沿用的目标冲突处理选项
+    #[must_use]
+    pub fn with_options(mut self, options: PlanOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// 序列化为带缩进的 JSON，便于人工审核。
+    ///
+    /// # 错误
+    ///
+    /// 序列化失败时返回 [`AppError::PlanError`]。
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|error| AppError::PlanError(format!("序列化失败: {error}")))
+    }
+
+    /// 从 JSON 解析计划。
+    ///
+    /// # 错误
+    ///
+    /// JSON 格式错误或版本不受支持时返回 [`AppError::PlanError`]。
+    pub fn from_json(json: &str) -> Result<Self> {
+        let plan: Self = serde_json::from_str(json)
+            .map_err(|error| AppError::PlanError(format!("解析失败: {error}")))?;
+        if plan.version != PLAN_VERSION {
+            return Err(AppError::PlanError(format!(
+                "不支持的计划版本 {}（当前为 {PLAN_VERSION}）",
+                plan.version
+            )));
+        }
+        Ok(plan)
+    }
+
+    /// 写入计划文件。
+    ///
+    /// # 错误
+    ///
+    /// - `AppError::PlanError` - 序列化失败
+    /// - `AppError::Io` - 写入文件失败
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_json()? + "\n")?;
+        Ok(())
+    }
+
+    /// 读取计划文件。
+    ///
+    /// # 错误
+    ///
+    /// 文件无法读取或内容无效时返回 [`AppError::PlanError`]。
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = fs::read_to_string(path).map_err(|error| {
+            AppError::PlanError(format!("读取计划文件失败 {}: {error}", path.display()))
+        })?;
+        Self::from_json(&json).map_err(|error| match error {
+            AppError::PlanError(message) => {
+                AppError::PlanError(format!("{}: {message}", path.display()))
+            }
+            other => other,
+        })
+    }
+
+    /// 按当前文件系统状态预估执行计划的影响，见 [`PlanEstimate::from_operations`]。
+    #[must_use]
+    pub fn estimate(&self) -> PlanEstimate {
+        PlanEstimate::from_operations(&self.operations)
+    }
+
+    /// 依次执行计划中的全部操作，返回与 [`operations`](Self::operations) 一一对应的结果。
+    ///
+    /// 单条失败（包括源文件已不存在）不会中断后续操作。
+    pub fn apply(&self, file_system: &dyn FileSystem) -> Vec<Result<OperationOutcome>> {
+        let options = self.options.to_organize_options(self.mode);
+        self.operations
+            .iter()
+            .map(|operation| FileOrganizer::execute_plan(file_system, operation, &options))
+            .collect()
+    }
+
+    /// 以事务方式执行计划（见 [`crate::transaction`]）：任一操作失败时撤销此前已完成的操作。
+    ///
+    /// # 错误
+    ///
+    /// 任一操作失败时返回 [`AppError::TransactionRolledBack`]。
+    pub fn apply_atomic(&self, file_system: &dyn FileSystem) -> Result<Vec<OperationOutcome>> {
+        let options = self.options.to_organize_options(self.mode);
+        transaction::execute_all(file_system, &self.operations, &options)
+    }
+}
+
+/// 执行计划前的影响预估，dry-run 结束时输出
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlanEstimate {
+    /// 需要新建的目录数（含多级缺失目录中的每一级）
+    pub new_dirs: usize,
+    /// 计划操作的文件数（含字幕等伴随文件）
+    pub files: usize,
+    /// 目标已存在的文件数：内容相同时跳过，否则按冲突策略覆盖
+    pub existing_targets: usize,
+}
+
+impl PlanEstimate {
+    /// 按当前文件系统状态聚合计划操作。
+    ///
+    /// 每个目标向上逐级查找尚不存在的目录，多个目标共用的目录只计一次；
+    /// 同一目标出现多次时也只计一个已存在目标。
+    #[must_use]
+    pub fn from_operations(operations: &[OperationPlan]) -> Self {
+        let mut new_dirs = BTreeSet::new();
+        let mut existing_targets = BTreeSet::new();
+        for operation in operations {
+            if operation.target.exists() {
+                existing_targets.insert(operation.target.as_path());
+            }
+            for dir in operation.target.ancestors().skip(1) {
+                if dir.as_os_str().is_empty() || dir.exists() || !new_dirs.insert(dir) {
+                    break;
+                }
+            }
+        }
+        Self {
+            new_dirs: new_dirs.len(),
+            files: operations.len(),
+            existing_targets: existing_targets.len(),
+        }
+    }
+
+    /// 面向用户的摘要，如 `预计操作：将创建 2 个新目录，复制 3 个文件，其中 1 个目标已存在（内容相同则跳过，否则覆盖）`
+    #[must_use]
+    pub fn describe(&self, mode: OperationMode) -> String {
+        let verb = match mode {
+            OperationMode::Move => "移动",
+            OperationMode::Copy => "复制",
+            OperationMode::Link => "硬链接",
+            OperationMode::Auto => "硬链接（跨盘时复制）",
+        };
+        let mut message = format!(
+            "预计操作：将创建 {} 个新目录，{verb} {} 个文件",
+            self.new_dirs, self.files
+        );
+        if self.existing_targets > 0 {
+            message.push_str(&format!(
+                "，其中 {} 个目标已存在（内容相同则跳过，否则覆盖）",
+                self.existing_targets
+            ));
+        }
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filesystem::StdFileSystem;
+    use crate::organizer::{ExecutedOp, SkipReason};
+
+    fn operation(dir: &Path, name: &str) -> OperationPlan {
+        OperationPlan {
+            source: dir.join("source").join(name),
+            target: dir.join("target").join("Test Show").join(name),
+        }
+    }
+
+    #[test]
+    fn estimate_counts_new_dirs_files_and_existing_targets() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("target");
+        fs::create_dir_all(target.join("Existing Show")).unwrap();
+        fs::write(target.join("Existing Show").join("01.mkv"), b"old").unwrap();
+        let into = |relative: &str| OperationPlan {
+            source: dir.path().join("source").join("video.mkv"),
+            target: target.join(relative),
+        };
+        let operations = [
+            into("Test Show/Season 1/01.mkv"),
+            into("Test Show/Season 1/01.ass"),
+            into("Test Show/Season 2/01.mkv"),
+            into("Existing Show/01.mkv"),
+            into("Existing Show/02.mkv"),
+        ];
+
+        let estimate = PlanEstimate::from_operations(&operations);
+
+        // Test Show、Season 1、Season 2 三个目录缺失，Existing Show 已存在。
+        assert_eq!(
+            estimate,
+            PlanEstimate {
+                new_dirs: 3,
+                files: 5,
+                existing_targets: 1,
+            }
+        );
+        assert_eq!(
+            estimate.describe(OperationMode::Copy),
+            "预计操作：将创建 3 个新目录，复制 5 个文件，其中 1 个目标已存在（内容相同则跳过，否则覆盖）"
+        );
+    }
+
+    #[test]
+    fn estimate_of_empty_plan_is_zero() {
+        let plan = OrganizePlan::new(OperationMode::Move, Vec::new());
+        assert_eq!(plan.estimate(), PlanEstimate::default());
+        assert_eq!(
+            plan.estimate().describe(OperationMode::Move),
+            "预计操作：将创建 0 个新目录，移动 0 个文件"
+        );
+    }
+
+    #[test]
+    fn plan_round_trips_through_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let plan = OrganizePlan::new(
+            OperationMode::Move,
+            vec![
+                operation(dir.path(), "[ANi] Test Show - 01 [1080P].mkv"),
+                operation(dir.path(), "[ANi] Test Show - 01 [1080P].ass"),
+            ],
+        );
+        let path = dir.path().join("plan.json");
+        plan.save(&path).unwrap();
+
+        assert_eq!(OrganizePlan::load(&path).unwrap(), plan);
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json["mode"], "move");
+        assert_eq!(json["operations"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn unsupported_version_and_invalid_json_are_rejected() {
+        let mut plan = OrganizePlan::new(OperationMode::Copy, Vec::new());
+        plan.version = PLAN_VERSION + 1;
+        let json = serde_json::to_string(&plan).unwrap();
+
+        assert!(matches!(
+            OrganizePlan::from_json(&json),
+            Err(AppError::PlanError(_))
+        ));
+        assert!(matches!(
+            OrganizePlan::from_json("not json"),
+            Err(AppError::PlanError(_))
+        ));
+        assert!(matches!(
+            OrganizePlan::load(Path::new("/nonexistent/plan.json")),
+            Err(AppError::PlanError(_))
+        ));
+    }
+
+    #[test]
+    fn apply_reports_missing_source_and_continues() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("source")).unwrap();
+        let missing = operation(dir.path(), "[ANi] Test Show - 01 [1080P].mkv");
+        let present = operation(dir.path(), "[ANi] Test Show - 02 [1080P].mkv");
+        fs::write(&present.source, b"video").unwrap();
+        let plan = OrganizePlan::new(OperationMode::Copy, vec![missing.clone(), present.clone()]);
+
+        let results = plan.apply(&StdFileSystem);
+
+        assert!(matches!(
+            &results[0],
+            Err(AppError::FileOperation { path, .. }) if *path == missing.source
+        ));
+        assert!(!missing.target.exists());
+        assert_eq!(
+            results[1].as_ref().unwrap(),
+            &OperationOutcome::Performed(ExecutedOp::Copied)
+        );
+        assert_eq!(fs::read(&present.target).unwrap(), b"video");
+        assert!(present.source.exists());
+    }
+
+    #[test]
+    fn conflict_options_survive_round_trip_and_apply() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("source")).unwrap();
+        let operation = operation(dir.path(), "[ANi] Test Show - 01 [1080P].mkv");
+        fs::write(&operation.source, b"new").unwrap();
+        fs::create_dir_all(operation.target.parent().unwrap()).unwrap();
+        fs::write(&operation.target, b"larger old").unwrap();
+        let options = PlanOptions {
+            backup: true,
+            on_conflict: ConflictPolicy::KeepLarger,
+            ..PlanOptions::default()
+        };
+        let path = dir.path().join("plan.json");
+        OrganizePlan::new(OperationMode::Copy, vec![operation.clone()])
+            .with_options(options)
+            .save(&path)
+            .unwrap();
+
+        let plan = OrganizePlan::load(&path).unwrap();
+        assert_eq!(plan.options, options);
+        let results = plan.apply(&StdFileSystem);
+
+        assert!(matches!(results[0], Ok(OperationOutcome::Skipped(_))));
+        assert_eq!(fs::read(&operation.target).unwrap(), b"larger old");
+    }
+
+    #[test]
+    fn applying_twice_skips_existing_targets() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("source")).unwrap();
+        let operation = operation(dir.path(), "[ANi] Test Show - 01 [1080P].mkv");
+        fs::write(&operation.source, b"video").unwrap();
+        let plan = OrganizePlan::new(OperationMode::Copy, vec![operation]);
+
+        assert!(plan.apply(&StdFileSystem)[0].is_ok());
+        assert_eq!(
+            plan.apply(&StdFileSystem)[0].as_ref().unwrap(),
+            &OperationOutcome::Skipped(SkipReason::AlreadyExists)
+        );
+    }
+}
@@ -0,0 +1,201 @@
+//! 播放列表生成模块
+//!
+//! 为整理后的番剧目录生成按集数排序的 `.m3u` 播放列表。
+//!
+//! ## 目录结构
+//!
+//! ```text
+//! Anime Name/
+//! ├── Anime Name.m3u
+//! ├── [ANi] Anime Name - 01 [1080P].mkv
+//! └── [ANi] Anime Name - 02 [1080P].mkv
+//! ```
+//!
+//! 列表中的路径相对于番剧目录，并统一使用 `/` 作为分隔符。
+
+use std::cmp::Ordering;
+use std::fmt::Write as FmtWrite;
+use std::path::{Component, Path, PathBuf};
+
+use crate::error::{AppError, Result};
+use crate::parser::{compare_episodes, AnimeFileInfo};
+
+/// 播放列表条目
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaylistEntry {
+    /// 解析出的文件信息
+    pub info: AnimeFileInfo,
+    /// 整理后的目标文件路径
+    pub path: PathBuf,
+}
+
+impl PlaylistEntry {
+    /// 创建播放列表条目
+    #[must_use]
+    pub fn new(info: AnimeFileInfo, path: PathBuf) -> Self {
+        Self { info, path }
+    }
+}
+
+/// 按季号和集数排序条目，集数顺序见 [`compare_episodes`]。
+pub fn sort_entries(entries: &mut [PlaylistEntry]) {
+    entries.sort_by(compare_entries);
+}
+
+fn compare_entries(left: &PlaylistEntry, right: &PlaylistEntry) -> Ordering {
+    let left_season = left.info.season_number().unwrap_or(1);
+    let right_season = right.info.season_number().unwrap_or(1);
+    left_season
+        .cmp(&right_season)
+        .then_with(|| compare_episodes(&left.info, &right.info))
+        .then_with(|| left.path.cmp(&right.path))
+}
+
+/// 渲染播放列表内容，条目路径相对于 `root`。
+///
+/// 调用前应先使用 [`sort_entries`] 排序。
+#[must_use]
+pub fn render_m3u(root: &Path, entries: &[PlaylistEntry]) -> String {
+    let mut output = String::from("#EXTM3U\n");
+    for entry in entries {
+        let relative = entry
+            .path
+            .strip_prefix(root)
+            .map(normalized_relative_path)
+            .unwrap_or_else(|_| entry.path.to_string_lossy().to_string());
+        let _ = writeln!(
+            output,
+            "#EXTINF:-1,{} - {}",
+            entry.info.anime_name, entry.info.episode
+        );
+        let _ = writeln!(output, "{relative}");
+    }
+    output
+}
+
+/// 在 `root` 下写入 `{title}.m3u`，返回播放列表路径。
+///
+/// # 错误
+///
+/// - `AppError::FileOperation` - 写入播放列表失败
+pub fn write_m3u(root: &Path, title: &str, entries: &mut [PlaylistEntry]) -> Result<PathBuf> {
+    sort_entries(entries);
+    let path = root.join(format!("{title}.m3u"));
+    std::fs::write(&path, render_m3u(root, entries)).map_err(|e| AppError::FileOperation {
+        path: path.clone(),
+        message: format!("写入播放列表失败: {e}"),
+    })?;
+    Ok(path)
+}
+
+fn normalized_relative_path(path: &Path) -> String {
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(value) => Some(value.to_string_lossy().to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(anime_name: &str, episode: &str, path: &str) -> PlaylistEntry {
+        PlaylistEntry::new(
+            AnimeFileInfo {
+                publisher: "ANi".to_string(),
+                anime_name: anime_name.to_string(),
+                episode: episode.to_string(),
+                tags: "[1080P]".to_string(),
+                extension: ".mkv".to_string(),
+                original_path: path.to_string(),
+                alt_titles: Vec::new(),
+                is_final: false,
+            },
+            PathBuf::from(path),
+        )
+    }
+
+    fn episodes(entries: &[PlaylistEntry]) -> Vec<&str> {
+        entries
+            .iter()
+            .map(|entry| entry.info.episode.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn sorts_numeric_episodes_by_value() {
+        let mut entries = vec![
+            entry("Test", "10", "Test/10.mkv"),
+            entry("Test", "02", "Test/02.mkv"),
+            entry("Test", "1", "Test/1.mkv"),
+        ];
+        sort_entries(&mut entries);
+        assert_eq!(episodes(&entries), ["1", "02", "10"]);
+    }
+
+    #[test]
+    fn sorts_decimal_episodes_between_neighbours() {
+        let mut entries = vec![
+            entry("Test", "13", "Test/13.mkv"),
+            entry("Test", "12.5", "Test/12.5.mkv"),
+            entry("Test", "12", "Test/12.mkv"),
+        ];
+        sort_entries(&mut entries);
+        assert_eq!(episodes(&entries), ["12", "12.5", "13"]);
+    }
+
+    #[test]
+    fn sorts_special_episodes_last() {
+        let mut entries = vec![
+            entry("Test", "SP", "Test/SP.mkv"),
+            entry("Test", "OVA", "Test/OVA.mkv"),
+            entry("Test", "02", "Test/02.mkv"),
+            entry("Test", "01", "Test/01.mkv"),
+        ];
+        sort_entries(&mut entries);
+        assert_eq!(episodes(&entries), ["01", "02", "OVA", "SP"]);
+    }
+
+    #[test]
+    fn sorts_seasons_before_episodes() {
+        let mut entries = vec![
+            entry("Test 第2季", "01", "Test/Season 2/01.mkv"),
+            entry("Test", "12", "Test/Season 1/12.mkv"),
+        ];
+        sort_entries(&mut entries);
+        assert_eq!(episodes(&entries), ["12", "01"]);
+    }
+
+    #[test]
+    fn renders_relative_paths() {
+        let root = Path::new("library").join("Test");
+        let entries = vec![PlaylistEntry::new(
+            entry("Test", "01", "unused").info,
+            root.join("Season 1").join("[ANi] Test - 01.mkv"),
+        )];
+        assert_eq!(
+            render_m3u(&root, &entries),
+            "#EXTM3U\n#EXTINF:-1,Test - 01\nSeason 1/[ANi] Test - 01.mkv\n"
+        );
+    }
+
+    #[test]
+    fn writes_playlist_into_anime_directory() {
+        let directory = tempfile::tempdir().unwrap();
+        let root = directory.path().join("Test");
+        std::fs::create_dir(&root).unwrap();
+        let mut entries = vec![
+            PlaylistEntry::new(entry("Test", "02", "").info, root.join("02.mkv")),
+            PlaylistEntry::new(entry("Test", "01", "").info, root.join("01.mkv")),
+        ];
+
+        let path = write_m3u(&root, "Test", &mut entries).unwrap();
+
+        assert_eq!(path, root.join("Test.m3u"));
+        let content = std::fs::read_to_string(path).unwrap();
+        assert!(content.find("01.mkv").unwrap() < content.find("02.mkv").unwrap());
+    }
+}
@@ -0,0 +1,160 @@
+//! 集数重编号模块
+//!
+//! `--renumber` 将同一番剧的集数按原顺序重排为从 01 开始的连续编号，例如
+//! `02 03 05` 变为 `01 02 03`。只有纯整数集数参与重排；`12.5`、`01+02`、
+//! `電影` 等无法确定位置的集数保持不变。同一集的多个版本得到相同的新集数。
+
+use crate::parser::AnimeFileInfo;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// 一条重编号映射（仅记录集数实际改变的条目）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EpisodeRenumber {
+    /// 动漫名称
+    pub anime_name: String,
+    /// 原集数
+    pub from: String,
+    /// 新集数
+    pub to: String,
+}
+
+/// 按 `anime_name` 分组重编号，原地改写集数并返回映射（按番名、原集数排序）。
+///
+/// ```
+/// use anime_organizer::parser::FilenameParser;
+/// use anime_organizer::renumber::renumber_episodes;
+///
+/// let mut files = ["03", "05", "02"].map(|episode| {
+///     FilenameParser::parse_str(&format!("[ANi] Test - {episode} [1080P].mp4")).unwrap()
+/// });
+/// let mapping = renumber_episodes(&mut files);
+/// assert_eq!(files.map(|file| file.episode), ["02", "03", "01"]);
+/// assert_eq!(mapping.len(), 3);
+/// ```
+pub fn renumber_episodes(files: &mut [AnimeFileInfo]) -> Vec<EpisodeRenumber> {
+    let mut episodes: BTreeMap<String, BTreeSet<u32>> = BTreeMap::new();
+    for file in files.iter() {
+        if let Some(number) = integer_episode(&file.episode) {
+            episodes
+                .entry(file.anime_name.clone())
+                .or_default()
+                .insert(number);
+        }
+    }
+
+    let mut mapping = Vec::new();
+    let mut renumbered: BTreeMap<(String, u32), String> = BTreeMap::new();
+    for (anime_name, numbers) in episodes {
+        for (index, from) in numbers.into_iter().enumerate() {
+            let to = format!("{:02}", index + 1);
+            if to != format!("{from:02}") {
+                mapping.push(EpisodeRenumber {
+                    anime_name: anime_name.clone(),
+                    from: format!("{from:02}"),
+                    to: to.clone(),
+                });
+            }
+            renumbered.insert((anime_name.clone(), from), to);
+        }
+    }
+
+    for file in files.iter_mut() {
+        if let Some(number) = integer_episode(&file.episode) {
+            if let Some(to) = renumbered.get(&(file.anime_name.clone(), number)) {
+                file.episode.clone_from(to);
+            }
+        }
+    }
+    mapping
+}
+
+fn integer_episode(episode: &str) -> Option<u32> {
+    if episode.is_empty() || !episode.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    episode.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(anime_name: &str, episode: &str) -> AnimeFileInfo {
+        AnimeFileInfo {
+            publisher: "ANi".to_string(),
+            anime_name: anime_name.to_string(),
+            episode: episode.to_string(),
+            tags: String::new(),
+            extension: ".mkv".to_string(),
+            original_path: format!("{anime_name} - {episode}.mkv"),
+            alt_titles: Vec::new(),
+            is_final: false,
+        }
+    }
+
+    fn episodes(files: &[AnimeFileInfo]) -> Vec<&str> {
+        files.iter().map(|file| file.episode.as_str()).collect()
+    }
+
+    #[test]
+    fn gaps_are_closed_in_original_order() {
+        let mut files = vec![file("A", "05"), file("A", "02"), file("A", "03")];
+        let mapping = renumber_episodes(&mut files);
+
+        assert_eq!(episodes(&files), ["03", "01", "02"]);
+        assert_eq!(
+            mapping,
+            [("02", "01"), ("03", "02"), ("05", "03")]
+                .map(|(from, to)| EpisodeRenumber {
+                    anime_name: "A".to_string(),
+                    from: from.to_string(),
+                    to: to.to_string(),
+                })
+                .to_vec()
+        );
+    }
+
+    #[test]
+    fn anime_are_renumbered_independently() {
+        let mut files = vec![
+            file("A", "10"),
+            file("B", "01"),
+            file("A", "12"),
+            file("B", "02"),
+        ];
+        let mapping = renumber_episodes(&mut files);
+
+        assert_eq!(episodes(&files), ["01", "01", "02", "02"]);
+        assert!(mapping.iter().all(|entry| entry.anime_name == "A"));
+        assert_eq!(mapping.len(), 2);
+    }
+
+    #[test]
+    fn versions_of_same_episode_share_new_number() {
+        let mut files = vec![file("A", "04"), file("A", "04"), file("A", "07")];
+        renumber_episodes(&mut files);
+
+        assert_eq!(episodes(&files), ["01", "01", "02"]);
+    }
+
+    #[test]
+    fn non_integer_episodes_are_left_unchanged() {
+        let mut files = vec![
+            file("A", "03"),
+            file("A", "03.5"),
+            file("A", "05+06"),
+            file("A", "電影"),
+            file("A", "08"),
+        ];
+        renumber_episodes(&mut files);
+
+        assert_eq!(episodes(&files), ["01", "03.5", "05+06", "電影", "02"]);
+    }
+
+    #[test]
+    fn continuous_episodes_produce_no_mapping() {
+        let mut files = vec![file("A", "01"), file("A", "02")];
+        assert!(renumber_episodes(&mut files).is_empty());
+        assert_eq!(episodes(&files), ["01", "02"]);
+    }
+}
@@ -0,0 +1,957 @@
+//! 整理结果报告模块
+//!
+//! 将每个文件的整理结果转换为结构化记录，便于导出 CSV、NDJSON 等格式供表格或脚本消费，
+//! 并提供按番剧分组的统计汇总。
+//!
+//! # 示例
+//!
+//! ```
+//! use anime_organizer::report::{RecordStatus, ReportRecord, CSV_HEADER};
+//! use anime_organizer::OperationMode;
+//!
+//! let record = ReportRecord {
+//!     source: "/downloads/a, b.mkv".to_string(),
+//!     target: "/anime/A/a, b.mkv".to_string(),
+//!     anime_name: "A".to_string(),
+//!     episode: "01".to_string(),
+//!     mode: OperationMode::Copy,
+//!     status: RecordStatus::DryRun,
+//! };
+//! assert_eq!(CSV_HEADER, "source,target,anime,episode,mode,status");
+//! assert_eq!(
+//!     record.to_csv_row(),
+//!     "\"/downloads/a, b.mkv\",\"/anime/A/a, b.mkv\",A,01,copy,dry_run"
+//! );
+//! ```
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::organizer::{OperationMode, SkipReason};
+
+/// CSV 表头
+pub const CSV_HEADER: &str = "source,target,anime,episode,mode,status";
+
+/// 单条记录的处理状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordStatus {
+    /// 预览模式，仅计划未执行
+    DryRun,
+    /// 执行成功
+    Success,
+    /// 目标已存在且内容相同，未执行
+    Skipped,
+    /// 执行失败
+    Failed,
+}
+
+impl std::fmt::Display for RecordStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DryRun => write!(f, "dry_run"),
+            Self::Success => write!(f, "success"),
+            Self::Skipped => write!(f, "skipped"),
+            Self::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+/// 单个文件的整理记录
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportRecord {
+    /// 源文件路径
+    pub source: String,
+    /// 目标文件路径
+    pub target: String,
+    /// 动漫名称
+    pub anime_name: String,
+    /// 集数
+    pub episode: String,
+    /// 操作模式
+    pub mode: OperationMode,
+    /// 处理状态
+    pub status: RecordStatus,
+}
+
+impl ReportRecord {
+    /// 转换为一行 CSV（不含换行符），列顺序与 [`CSV_HEADER`] 一致。
+    #[must_use]
+    pub fn to_csv_row(&self) -> String {
+        let mode = self.mode.to_string();
+        let status = self.status.to_string();
+        csv_row(&[
+            &self.source,
+            &self.target,
+            &self.anime_name,
+            &self.episode,
+            &mode,
+            &status,
+        ])
+    }
+
+    /// 转换为一行 JSON（不含换行符），字段名与结构体一致，供 NDJSON 逐行输出。
+    #[must_use]
+    pub fn to_json_line(&self) -> String {
+        serde_json::to_string(self).expect("整理记录只含字符串与枚举，序列化不会失败")
+    }
+
+    /// 将 [`to_json_line`](Self::to_json_line) 的结果作为一行写入并立即 flush。
+    ///
+    /// 整行在一次加锁内写完，多线程共用同一个 `Mutex` 包装的输出时各行不会交错。
+    ///
+    /// # 错误
+    ///
+    /// 写入或 flush 失败时返回 IO 错误。
+    pub fn write_json_line<W: Write>(&self, writer: &Mutex<W>) -> io::Result<()> {
+        let line = self.to_json_line();
+        let mut writer = writer.lock().unwrap_or_else(PoisonError::into_inner);
+        writeln!(writer, "{line}")?;
+        writer.flush()
+    }
+}
+
+/// 单部番剧的整理统计
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnimeSummary {
+    /// 动漫名称
+    pub anime_name: String,
+    /// 成功集数
+    pub succeeded: usize,
+    /// 失败集数
+    pub failed: usize,
+    /// 本组全部文件（含失败）的总字节数
+    pub total_bytes: u64,
+}
+
+/// 按番剧名汇总每个文件的整理结果与大小，按番名排序。
+///
+/// `outcomes` 的每一项为 `(番名, 是否成功, 文件字节数)`。
+///
+/// # 示例
+///
+/// ```
+/// use anime_organizer::report::summarize_by_anime;
+///
+/// let summary = summarize_by_anime([("B", true, 100), ("A", true, 10), ("B", false, 50)]);
+/// assert_eq!(summary[0].anime_name, "A");
+/// assert_eq!((summary[1].succeeded, summary[1].failed), (1, 1));
+/// assert_eq!(summary[1].total_bytes, 150);
+/// ```
+#[must_use]
+pub fn summarize_by_anime<'a, I>(outcomes: I) -> Vec<AnimeSummary>
+where
+    I: IntoIterator<Item = (&'a str, bool, u64)>,
+{
+    let mut groups: BTreeMap<&str, AnimeSummary> = BTreeMap::new();
+    for (anime_name, success, bytes) in outcomes {
+        let summary = groups.entry(anime_name).or_insert_with(|| AnimeSummary {
+            anime_name: anime_name.to_string(),
+            succeeded: 0,
+            failed: 0,
+            total_bytes: 0,
+        });
+        if success {
+            summary.succeeded += 1;
+        } else {
+            summary.failed += 1;
+        }
+        summary.total_bytes += bytes;
+    }
+    groups.into_values().collect()
+}
+
+/// 将字节数格式化为人类可读的大小（1024 进制，保留一位小数）。
+///
+/// ```
+/// use anime_organizer::report::format_size;
+///
+/// assert_eq!(format_size(512), "512 B");
+/// assert_eq!(format_size(1536), "1.5 KB");
+/// assert_eq!(format_size(3 * 1024 * 1024 * 1024), "3.0 GB");
+/// ```
+#[must_use]
+pub fn format_size(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        format!("{:.1} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+/// 一次整理的文件数统计，`Display` 输出结束时的中文摘要行
+///
+/// ```
+/// use anime_organizer::report::OrganizeSummary;
+///
+/// let summary = OrganizeSummary {
+///     processed: 5,
+///     succeeded: 3,
+///     skipped: 1,
+///     failed: 1,
+/// };
+/// assert_eq!(
+///     summary.to_string(),
+///     "处理完成：总计5个文件，成功3个，已存在跳过1个，失败1个"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrganizeSummary {
+    /// 解析成功、进入整理流程的文件数
+    pub processed: usize,
+    /// 整理成功的文件数
+    pub succeeded: usize,
+    /// 目标已存在且内容相同、无需操作的文件数
+    pub skipped: usize,
+    /// 整理失败的文件数
+    pub failed: usize,
+}
+
+impl std::fmt::Display for OrganizeSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "处理完成：总计{}个文件，成功{}个，已存在跳过{}个，失败{}个",
+            self.processed, self.succeeded, self.skipped, self.failed
+        )
+    }
+}
+
+/// 一次整理实际传输的数据量与耗时，结束时据此输出平均吞吐
+///
+/// ```
+/// use anime_organizer::report::TransferStats;
+/// use std::time::Duration;
+///
+/// let stats = TransferStats {
+///     bytes: 300 * 1024 * 1024,
+///     elapsed: Duration::from_secs(4),
+/// };
+/// assert_eq!(stats.bytes_per_second(), Some(75.0 * 1024.0 * 1024.0));
+/// assert_eq!(stats.render(), "耗时 4秒，共传输 300.0 MB，平均吞吐 75.0 MB/s");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransferStats {
+    /// 实际执行了移动、复制或硬链接的文件总字节数
+    pub bytes: u64,
+    /// 从开始整理到结束的耗时
+    pub elapsed: Duration,
+}
+
+impl TransferStats {
+    /// 平均吞吐（字节/秒）；耗时为 0 时无法计算，返回 `None`
+    #[must_use]
+    pub fn bytes_per_second(&self) -> Option<f64> {
+        let seconds = self.elapsed.as_secs_f64();
+        (seconds > 0.0).then(|| self.bytes as f64 / seconds)
+    }
+
+    /// 面向用户的摘要，如 `耗时 3分05秒，共传输 1.5 GB，平均吞吐 8.3 MB/s`
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut message = format!(
+            "耗时 {}，共传输 {}",
+            format_duration(self.elapsed),
+            format_size(self.bytes)
+        );
+        if let Some(rate) = self.bytes_per_second() {
+            message.push_str(&format!("，平均吞吐 {:.1} MB/s", rate / (1024.0 * 1024.0)));
+        }
+        message
+    }
+}
+
+/// 估算复制耗时时默认采用的吞吐（100 MiB/s），可用 `--throughput` 覆盖
+pub const DEFAULT_COPY_THROUGHPUT: u64 = 100 * 1024 * 1024;
+
+/// 预览模式下估算的执行耗时
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeEstimate {
+    /// 硬链接或同盘移动只改目录项，几乎即时完成
+    Instant,
+    /// 需要复制数据时按吞吐估算的耗时
+    Copy(Duration),
+}
+
+/// 按操作模式、总字节数与吞吐（字节/秒）估算执行耗时。
+///
+/// link 以及源与目标位于同一文件系统的 move 视为即时；copy 与跨盘 move 按
+/// `总字节数 / 吞吐` 估算。吞吐为 0 时按 1 字节/秒处理。
+///
+/// ```
+/// use anime_organizer::report::{estimate_time, TimeEstimate};
+/// use anime_organizer::OperationMode;
+/// use std::time::Duration;
+///
+/// let gib = 1024 * 1024 * 1024;
+/// assert_eq!(
+///     estimate_time(OperationMode::Copy, 10 * gib, 100 * 1024 * 1024, true),
+///     TimeEstimate::Copy(Duration::from_secs(102) + Duration::from_millis(400))
+/// );
+/// assert_eq!(estimate_time(OperationMode::Move, gib, 1, true), TimeEstimate::Instant);
+/// ```
+#[must_use]
+pub fn estimate_time(
+    mode: OperationMode,
+    total_bytes: u64,
+    bytes_per_second: u64,
+    same_device: bool,
+) -> TimeEstimate {
+    match mode {
+        OperationMode::Link => TimeEstimate::Instant,
+        OperationMode::Move | OperationMode::Auto if same_device => TimeEstimate::Instant,
+        OperationMode::Move | OperationMode::Copy | OperationMode::Auto => TimeEstimate::Copy(
+            Duration::from_secs_f64(total_bytes as f64 / bytes_per_second.max(1) as f64),
+        ),
+    }
+}
+
+/// 将时长格式化为 `1小时02分03秒`、`3分05秒`、`45秒` 形式，不足一秒的部分向上取整。
+///
+/// ```
+/// use anime_organizer::report::format_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(format_duration(Duration::from_millis(300)), "1秒");
+/// assert_eq!(format_duration(Duration::from_secs(185)), "3分05秒");
+/// assert_eq!(format_duration(Duration::from_secs(3723)), "1小时02分03秒");
+/// ```
+#[must_use]
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs() + u64::from(duration.subsec_nanos() > 0);
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}小时{minutes:02}分{seconds:02}秒")
+    } else if minutes > 0 {
+        format!("{minutes}分{seconds:02}秒")
+    } else {
+        format!("{seconds}秒")
+    }
+}
+
+/// 按原因统计的跳过文件数
+///
+/// # 示例
+///
+/// ```
+/// use anime_organizer::report::SkipStats;
+/// use anime_organizer::SkipReason;
+///
+/// let mut stats = SkipStats::default();
+/// stats.record(SkipReason::UnrecognizedName);
+/// stats.record(SkipReason::UnsupportedExtension);
+/// stats.record(SkipReason::UnsupportedExtension);
+/// assert_eq!(stats.count(SkipReason::UnsupportedExtension), 2);
+/// assert_eq!(stats.render(), "跳过明细：扩展名不符 2 个，命名不匹配 1 个");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SkipStats {
+    counts: BTreeMap<SkipReason, usize>,
+}
+
+impl SkipStats {
+    /// 记录一个被跳过的文件
+    pub fn record(&mut self, reason: SkipReason) {
+        *self.counts.entry(reason).or_default() += 1;
+    }
+
+    /// 某一原因的跳过数
+    #[must_use]
+    pub fn count(&self, reason: SkipReason) -> usize {
+        self.counts.get(&reason).copied().unwrap_or_default()
+    }
+
+    /// 跳过总数
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// 是否没有跳过任何文件
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// 渲染为一行按原因分类的说明，按 [`SkipReason`] 声明顺序排列。
+    #[must_use]
+    pub fn render(&self) -> String {
+        let parts = self
+            .counts
+            .iter()
+            .map(|(reason, count)| format!("{reason} {count} 个"))
+            .collect::<Vec<_>>();
+        format!("跳过明细：{}", parts.join("，"))
+    }
+}
+
+/// 将按番剧汇总渲染为对齐的文本表格（末尾不含换行符）。
+#[must_use]
+pub fn render_anime_summary(summaries: &[AnimeSummary]) -> String {
+    const HEADERS: [&str; 4] = ["番名", "成功", "失败", "大小"];
+    let rows = summaries
+        .iter()
+        .map(|summary| {
+            [
+                summary.anime_name.clone(),
+                summary.succeeded.to_string(),
+                summary.failed.to_string(),
+                format_size(summary.total_bytes),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let mut widths = HEADERS.map(display_width);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(display_width(cell));
+        }
+    }
+
+    let render_row = |cells: [&str; 4]| {
+        cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| {
+                let padding = width - display_width(cell);
+                format!("{cell}{}", " ".repeat(padding))
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+    let mut lines = vec![render_row(HEADERS)];
+    lines.extend(
+        rows.iter()
+            .map(|row| row.each_ref().map(String::as_str))
+            .map(render_row),
+    );
+    lines.join("\n")
+}
+
+/// 终端显示宽度：CJK 等全角字符按 2 列计算。
+fn display_width(text: &str) -> usize {
+    text.chars().map(|ch| if is_wide(ch) { 2 } else { 1 }).sum()
+}
+
+fn is_wide(ch: char) -> bool {
+    matches!(
+        ch as u32,
+        0x1100..=0x115F
+            | 0x2E80..=0x303E
+            | 0x3041..=0x33FF
+            | 0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xA000..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFE30..=0xFE4F
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6
+    )
+}
+
+/// `--relative`：预览与报告中显示相对路径的基准
+///
+/// 源目录与目标目录有共同上级目录时，两侧都相对该目录显示，仍能看出文件来自哪一侧；
+/// 没有共同上级（如位于不同盘符，或只共享文件系统根目录）时，源路径相对源目录、
+/// 目标路径相对目标目录显示。不在基准目录下的路径原样显示。
+///
+/// ```
+/// use anime_organizer::report::RelativePaths;
+/// use std::path::Path;
+///
+/// let paths = RelativePaths::new(Path::new("/data/downloads"), Path::new("/data/anime"));
+/// assert_eq!(
+///     paths.source(Path::new("/data/downloads/a.mkv")),
+///     Path::new("downloads/a.mkv").to_string_lossy()
+/// );
+/// assert_eq!(
+///     paths.target(Path::new("/data/anime/A/a.mkv")),
+///     Path::new("anime/A/a.mkv").to_string_lossy()
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RelativePaths {
+    source_base: PathBuf,
+    target_base: PathBuf,
+}
+
+impl RelativePaths {
+    /// 根据源目录与目标目录确定基准；源为单个文件时以其所在目录为准
+    #[must_use]
+    pub fn new(source_root: &Path, target_root: &Path) -> Self {
+        let source_root = if source_root.is_file() {
+            source_root.parent().unwrap_or(source_root)
+        } else {
+            source_root
+        };
+        match common_ancestor(source_root, target_root) {
+            Some(base) => Self {
+                source_base: base.clone(),
+                target_base: base,
+            },
+            None => Self {
+                source_base: source_root.to_path_buf(),
+                target_base: target_root.to_path_buf(),
+            },
+        }
+    }
+
+    /// 源路径的显示形式
+    #[must_use]
+    pub fn source(&self, path: &Path) -> String {
+        relative_display(path, &self.source_base)
+    }
+
+    /// 目标路径的显示形式
+    #[must_use]
+    pub fn target(&self, path: &Path) -> String {
+        relative_display(path, &self.target_base)
+    }
+}
+
+/// 两个路径最长的共同上级目录；只有根目录或盘符相同时返回 `None`
+fn common_ancestor(a: &Path, b: &Path) -> Option<PathBuf> {
+    let mut common = PathBuf::new();
+    let mut named = 0;
+    for (left, right) in a.components().zip(b.components()) {
+        if left != right {
+            break;
+        }
+        if matches!(left, Component::Normal(_)) {
+            named += 1;
+        }
+        common.push(left);
+    }
+    (named > 0).then_some(common)
+}
+
+fn relative_display(path: &Path, base: &Path) -> String {
+    match path.strip_prefix(base) {
+        Ok(relative) if relative.as_os_str().is_empty() => path
+            .file_name()
+            .map_or_else(|| path.to_string_lossy(), |name| name.to_string_lossy())
+            .into_owned(),
+        Ok(relative) => relative.to_string_lossy().into_owned(),
+        Err(_) => path.to_string_lossy().into_owned(),
+    }
+}
+
+/// 将若干字段拼接为一行 CSV（RFC 4180）。
+#[must_use]
+pub fn csv_row(fields: &[&str]) -> String {
+    fields
+        .iter()
+        .map(|field| csv_escape(field))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// 转义单个 CSV 字段。
+///
+/// 字段包含逗号、双引号、换行或首尾空白时使用双引号包裹，内部双引号写作 `""`。
+#[must_use]
+pub fn csv_escape(field: &str) -> Cow<'_, str> {
+    let needs_quotes = field.contains([',', '"', '\n', '\r'])
+        || field.starts_with(char::is_whitespace)
+        || field.ends_with(char::is_whitespace);
+    if needs_quotes {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// 写出路径列表文件：每行一个路径，供 `--failures-out` 导出、`--from-file` 读回。
+///
+/// # 错误
+///
+/// 创建或写入文件失败时返回 IO 错误。
+pub fn write_path_list(path: &Path, paths: &[PathBuf]) -> io::Result<()> {
+    let mut content = String::new();
+    for entry in paths {
+        content.push_str(&entry.to_string_lossy());
+        content.push('\n');
+    }
+    std::fs::write(path, content)
+}
+
+/// 读取路径列表文件：每行一个路径，跳过空行，兼容 CRLF 换行。
+///
+/// # 错误
+///
+/// 读取文件失败时返回 IO 错误。
+pub fn read_path_list(path: &Path) -> io::Result<Vec<PathBuf>> {
+    Ok(std::fs::read_to_string(path)?
+        .lines()
+        .map(|line| line.trim_end_matches('\r'))
+        .filter(|line| !line.trim().is_empty())
+        .map(PathBuf::from)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transfer_stats_throughput() {
+        let stats = |bytes, millis| TransferStats {
+            bytes,
+            elapsed: Duration::from_millis(millis),
+        };
+        let mib = 1024 * 1024;
+
+        assert_eq!(
+            stats(10 * mib, 2000).bytes_per_second(),
+            Some(5.0 * mib as f64)
+        );
+        assert_eq!(stats(mib, 250).bytes_per_second(), Some(4.0 * mib as f64));
+        assert_eq!(stats(0, 1000).bytes_per_second(), Some(0.0));
+        assert_eq!(stats(mib, 0).bytes_per_second(), None);
+    }
+
+    #[test]
+    fn transfer_stats_render() {
+        let stats = TransferStats {
+            bytes: 1536 * 1024 * 1024,
+            elapsed: Duration::from_secs(185),
+        };
+        assert_eq!(
+            stats.render(),
+            "耗时 3分05秒，共传输 1.5 GB，平均吞吐 8.3 MB/s"
+        );
+        assert_eq!(TransferStats::default().render(), "耗时 0秒，共传输 0 B");
+    }
+
+    #[test]
+    fn relative_paths_use_common_ancestor() {
+        let paths = RelativePaths::new(Path::new("/mnt/nas/dl"), Path::new("/mnt/nas/media/anime"));
+        assert_eq!(
+            paths.source(Path::new("/mnt/nas/dl/sub/a.mkv")),
+            Path::new("dl/sub/a.mkv").to_string_lossy()
+        );
+        assert_eq!(
+            paths.target(Path::new("/mnt/nas/media/anime/A/a.mkv")),
+            Path::new("media/anime/A/a.mkv").to_string_lossy()
+        );
+    }
+
+    #[test]
+    fn relative_paths_without_common_ancestor_use_own_roots() {
+        let paths = RelativePaths::new(Path::new("/downloads"), Path::new("/anime"));
+        assert_eq!(paths.source(Path::new("/downloads/a.mkv")), "a.mkv");
+        assert_eq!(
+            paths.target(Path::new("/anime/A/a.mkv")),
+            Path::new("A/a.mkv").to_string_lossy()
+        );
+
+        let paths = RelativePaths::new(Path::new("downloads"), Path::new("/anime"));
+        assert_eq!(paths.source(Path::new("downloads/a.mkv")), "a.mkv");
+    }
+
+    #[test]
+    fn relative_paths_keep_outside_paths_and_root_file() {
+        let paths = RelativePaths::new(Path::new("/data/dl"), Path::new("/data/anime"));
+        assert_eq!(paths.source(Path::new("/tmp/x.mkv")), "/tmp/x.mkv");
+
+        let paths = RelativePaths::new(Path::new("/downloads"), Path::new("/downloads"));
+        assert_eq!(paths.source(Path::new("/downloads")), "downloads");
+    }
+
+    #[test]
+    fn plain_fields_are_not_quoted() {
+        assert_eq!(
+            csv_escape("[ANi] Test - 01 [1080P].mkv"),
+            "[ANi] Test - 01 [1080P].mkv"
+        );
+        assert_eq!(csv_row(&["a", "b", ""]), "a,b,");
+    }
+
+    #[test]
+    fn commas_are_quoted() {
+        assert_eq!(csv_escape("Re:Zero, Season 2"), "\"Re:Zero, Season 2\"");
+    }
+
+    #[test]
+    fn quotes_are_doubled() {
+        assert_eq!(csv_escape("The \"Best\" Show"), "\"The \"\"Best\"\" Show\"");
+    }
+
+    #[test]
+    fn newlines_and_edge_whitespace_are_quoted() {
+        assert_eq!(csv_escape("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(csv_escape(" padded"), "\" padded\"");
+    }
+
+    #[test]
+    fn summary_groups_outcomes_by_anime() {
+        let summary = summarize_by_anime([
+            ("葬送的芙莉莲", true, 0),
+            ("Test", false, 0),
+            ("葬送的芙莉莲", true, 0),
+            ("Test", true, 0),
+        ]);
+        assert_eq!(
+            summary,
+            vec![
+                AnimeSummary {
+                    anime_name: "Test".to_string(),
+                    succeeded: 1,
+                    failed: 1,
+                    total_bytes: 0,
+                },
+                AnimeSummary {
+                    anime_name: "葬送的芙莉莲".to_string(),
+                    succeeded: 2,
+                    failed: 0,
+                    total_bytes: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn summary_accumulates_sizes_per_anime() {
+        const GIB: u64 = 1024 * 1024 * 1024;
+        let summary = summarize_by_anime([
+            ("A", true, GIB),
+            ("B", true, 700),
+            ("A", true, GIB / 2),
+            ("A", false, GIB / 2),
+            ("B", true, 400),
+        ]);
+
+        assert_eq!(
+            summary
+                .iter()
+                .map(|summary| (summary.anime_name.as_str(), summary.total_bytes))
+                .collect::<Vec<_>>(),
+            [("A", 2 * GIB), ("B", 1100)]
+        );
+        assert_eq!(format_size(summary[0].total_bytes), "2.0 GB");
+        assert_eq!(format_size(summary[1].total_bytes), "1.1 KB");
+    }
+
+    #[test]
+    fn format_size_picks_unit_by_threshold() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1024), "1.0 KB");
+        assert_eq!(format_size(5 * 1024 * 1024 + 512 * 1024), "5.5 MB");
+        assert_eq!(format_size(1024 * 1024 * 1024), "1.0 GB");
+    }
+
+    #[test]
+    fn copy_estimate_divides_size_by_throughput() {
+        const MIB: u64 = 1024 * 1024;
+
+        assert_eq!(
+            estimate_time(OperationMode::Copy, 600 * MIB, 10 * MIB, true),
+            TimeEstimate::Copy(Duration::from_secs(60))
+        );
+        assert_eq!(
+            estimate_time(OperationMode::Move, 50 * MIB, 100 * MIB, false),
+            TimeEstimate::Copy(Duration::from_millis(500))
+        );
+        assert_eq!(
+            estimate_time(OperationMode::Copy, 0, 100 * MIB, true),
+            TimeEstimate::Copy(Duration::ZERO)
+        );
+        assert_eq!(
+            estimate_time(OperationMode::Copy, 10, 0, true),
+            TimeEstimate::Copy(Duration::from_secs(10))
+        );
+    }
+
+    #[test]
+    fn link_and_same_device_move_are_instant() {
+        assert_eq!(
+            estimate_time(OperationMode::Link, u64::MAX, 1, false),
+            TimeEstimate::Instant
+        );
+        assert_eq!(
+            estimate_time(OperationMode::Move, u64::MAX, 1, true),
+            TimeEstimate::Instant
+        );
+    }
+
+    #[test]
+    fn format_duration_rounds_up_and_pads() {
+        assert_eq!(format_duration(Duration::ZERO), "0秒");
+        assert_eq!(format_duration(Duration::from_secs(59)), "59秒");
+        assert_eq!(format_duration(Duration::from_secs(60)), "1分00秒");
+        assert_eq!(format_duration(Duration::from_millis(59_001)), "1分00秒");
+        assert_eq!(
+            format_duration(Duration::from_secs(36_000)),
+            "10小时00分00秒"
+        );
+    }
+
+    #[test]
+    fn summary_serializes_to_json() {
+        let summary = summarize_by_anime([("Test", true, 2048)]);
+        assert_eq!(
+            serde_json::to_string(&summary).unwrap(),
+            r#"[{"anime_name":"Test","succeeded":1,"failed":0,"total_bytes":2048}]"#
+        );
+    }
+
+    #[test]
+    fn summary_table_aligns_wide_characters() {
+        let table = render_anime_summary(&summarize_by_anime([
+            ("芙莉莲", true, 3 * 1024 * 1024),
+            ("Test", false, 10),
+        ]));
+        assert_eq!(
+            table,
+            "番名    成功  失败  大小\nTest    0     1     10 B\n芙莉莲  1     0     3.0 MB"
+        );
+    }
+
+    #[test]
+    fn skip_stats_count_each_reason() {
+        let mut stats = SkipStats::default();
+        for reason in [
+            SkipReason::AlreadyExists,
+            SkipReason::Filtered,
+            SkipReason::UnsupportedExtension,
+            SkipReason::Filtered,
+            SkipReason::DuplicateContent,
+        ] {
+            stats.record(reason);
+        }
+
+        assert_eq!(stats.count(SkipReason::Filtered), 2);
+        assert_eq!(stats.count(SkipReason::UnrecognizedName), 0);
+        assert_eq!(stats.total(), 5);
+        assert_eq!(
+            stats.render(),
+            "跳过明细：扩展名不符 1 个，被过滤 2 个，内容重复 1 个，目标已存在且内容相同 1 个"
+        );
+    }
+
+    #[test]
+    fn empty_skip_stats() {
+        let stats = SkipStats::default();
+        assert!(stats.is_empty());
+        assert_eq!(stats.total(), 0);
+    }
+
+    #[test]
+    fn record_row_follows_header_order() {
+        let record = ReportRecord {
+            source: "C:\\Downloads\\\"quoted\", file.mkv".to_string(),
+            target: "E:\\Anime\\Test\\file.mkv".to_string(),
+            anime_name: "Test, Anime".to_string(),
+            episode: "12.5".to_string(),
+            mode: OperationMode::Link,
+            status: RecordStatus::Failed,
+        };
+        assert_eq!(
+            record.to_csv_row(),
+            "\"C:\\Downloads\\\"\"quoted\"\", file.mkv\",E:\\Anime\\Test\\file.mkv,\"Test, Anime\",12.5,link,failed"
+        );
+    }
+
+    #[test]
+    fn json_line_is_one_record_per_line() {
+        let record = ReportRecord {
+            source: "/downloads/a\nb.mkv".to_string(),
+            target: "/anime/A/a.mkv".to_string(),
+            anime_name: "A".to_string(),
+            episode: "01".to_string(),
+            mode: OperationMode::Copy,
+            status: RecordStatus::Success,
+        };
+        assert_eq!(
+            record.to_json_line(),
+            r#"{"source":"/downloads/a\nb.mkv","target":"/anime/A/a.mkv","anime_name":"A","episode":"01","mode":"copy","status":"success"}"#
+        );
+    }
+
+    #[test]
+    fn concurrent_json_lines_do_not_interleave() {
+        let output = Mutex::new(Vec::new());
+        std::thread::scope(|scope| {
+            for thread in 0..8 {
+                let output = &output;
+                scope.spawn(move || {
+                    for episode in 0..50 {
+                        let record = ReportRecord {
+                            source: format!("/downloads/{thread}-{episode}.mkv"),
+                            target: format!("/anime/A/{thread}-{episode}.mkv"),
+                            anime_name: "A".to_string(),
+                            episode: format!("{episode:02}"),
+                            mode: OperationMode::Link,
+                            status: RecordStatus::Success,
+                        };
+                        record.write_json_line(output).unwrap();
+                    }
+                });
+            }
+        });
+
+        let output = String::from_utf8(output.into_inner().unwrap()).unwrap();
+        let records: Vec<ReportRecord> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 8 * 50);
+    }
+
+    #[test]
+    fn organize_summary_display_matches_run_summary_line() {
+        assert_eq!(
+            OrganizeSummary::default().to_string(),
+            "处理完成：总计0个文件，成功0个，已存在跳过0个，失败0个"
+        );
+        let summary = OrganizeSummary {
+            processed: 12,
+            succeeded: 9,
+            skipped: 2,
+            failed: 1,
+        };
+        assert_eq!(
+            format!("{summary}"),
+            "处理完成：总计12个文件，成功9个，已存在跳过2个，失败1个"
+        );
+    }
+
+    #[test]
+    fn path_list_round_trips_and_skips_blank_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let list = dir.path().join("failures.txt");
+        let paths = vec![
+            PathBuf::from("/downloads/[ANi] A - 01 [1080P].mkv"),
+            PathBuf::from("/downloads/sub dir/B - 02.mp4"),
+        ];
+
+        write_path_list(&list, &paths).unwrap();
+        assert_eq!(read_path_list(&list).unwrap(), paths);
+
+        std::fs::write(&list, "/downloads/a.mkv\r\n\r\n  \n/downloads/b.mkv").unwrap();
+        assert_eq!(
+            read_path_list(&list).unwrap(),
+            [
+                PathBuf::from("/downloads/a.mkv"),
+                PathBuf::from("/downloads/b.mkv")
+            ]
+        );
+    }
+}
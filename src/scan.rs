@@ -0,0 +1,1001 @@
+//! 目录扫描模块
+//!
+//! 使用 rayon 线程池并行枚举源目录中的文件，并在枚举阶段直接按扩展名过滤，
+//! 以减少网络文件系统上逐目录串行 `read_dir` 的等待时间。
+//!
+//! # 示例
+//!
+//! ```no_run
+//! use anime_organizer::scan::{collect_files, ScanOptions};
+//! use std::collections::HashSet;
+//!
+//! let extensions: HashSet<String> = [".mkv".to_string()].into_iter().collect();
+//! let files = collect_files("/downloads", &ScanOptions::with_extensions(extensions));
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use crate::time_filter::modified_since;
+
+/// 默认最大扫描线程数
+const MAX_DEFAULT_THREADS: usize = 8;
+
+/// 目录扫描选项
+#[derive(Debug, Clone)]
+pub struct ScanOptions {
+    /// 仅保留这些扩展名（小写、带点，如 `.mkv`）；`None` 表示不过滤
+    pub extensions: Option<HashSet<String>>,
+    /// 工作线程数，`0` 表示按 CPU 核数自动选择
+    pub threads: usize,
+    /// 是否按路径排序结果，保证多次运行输出一致
+    pub sorted: bool,
+    /// 仅保留修改时间不早于该时刻的文件；`None` 表示不过滤
+    pub modified_after: Option<SystemTime>,
+    /// 是否跟随符号链接；指回祖先目录的链接环会被跳过
+    pub follow_symlinks: bool,
+    /// 是否包含隐藏文件/目录与系统目录（见 [`is_hidden_or_system_name`]）
+    pub include_hidden: bool,
+    /// 跳过这些目录及其子树；按扫描时拼出的路径（`root.join(..)`）逐项比较，
+    /// 通常由 [`nested_target_dir`] 计算
+    pub excluded_dirs: Vec<PathBuf>,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            extensions: None,
+            threads: 0,
+            sorted: true,
+            modified_after: None,
+            follow_symlinks: false,
+            include_hidden: false,
+            excluded_dirs: Vec::new(),
+        }
+    }
+}
+
+impl ScanOptions {
+    /// 创建仅保留指定扩展名的扫描选项
+    #[must_use]
+    pub fn with_extensions(extensions: HashSet<String>) -> Self {
+        Self {
+            extensions: Some(extensions),
+            ..Self::default()
+        }
+    }
+
+    fn thread_count(&self) -> usize {
+        if self.threads > 0 {
+            return self.threads;
+        }
+        std::thread::available_parallelism()
+            .map_or(1, |count| count.get())
+            .min(MAX_DEFAULT_THREADS)
+    }
+}
+
+/// 判断文件扩展名（忽略大小写）是否与给定集合中的任一项匹配，集合项可含通配符，
+/// 见 [`extension_matches`]。以 `!` 开头的项为排除模式，与之匹配的扩展名一律不匹配。
+///
+/// ```
+/// use anime_organizer::scan::has_extension;
+/// use std::collections::HashSet;
+/// use std::path::Path;
+///
+/// let extensions: HashSet<String> = [".mp*".to_string(), "!.mpg".to_string()].into();
+/// assert!(has_extension(Path::new("a.MP4"), &extensions));
+/// assert!(!has_extension(Path::new("a.mpg"), &extensions));
+/// ```
+#[must_use]
+pub fn has_extension(path: &Path, extensions: &HashSet<String>) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| {
+            let ext = format!(".{}", ext.to_lowercase());
+            let mut included = extensions.contains(&ext);
+            for pattern in extensions {
+                match pattern.strip_prefix('!') {
+                    Some(excluded) if extension_matches(excluded, &ext) => return false,
+                    Some(_) => {}
+                    None => included = included || extension_matches(pattern, &ext),
+                }
+            }
+            included
+        })
+        .unwrap_or(false)
+}
+
+/// 判断扩展名是否匹配模式：`*` 匹配任意多个字符，`?` 匹配单个字符，其余字符按原样比较。
+/// 两者都应为小写、带点的形式。
+///
+/// ```
+/// use anime_organizer::scan::extension_matches;
+///
+/// assert!(extension_matches(".mp*", ".mp4"));
+/// assert!(extension_matches(".mp*", ".mpg"));
+/// assert!(extension_matches(".m?v", ".mkv"));
+/// assert!(!extension_matches(".mp*", ".mkv"));
+/// assert!(extension_matches(".mkv", ".mkv"));
+/// ```
+#[must_use]
+pub fn extension_matches(pattern: &str, extension: &str) -> bool {
+    wildcard_match(pattern, extension)
+}
+
+/// 是否为 macOS 生成的元数据文件：AppleDouble 伴随文件 `._文件名` 与 `.DS_Store`。
+///
+/// ```
+/// use anime_organizer::scan::is_system_metadata_file;
+/// use std::path::Path;
+///
+/// assert!(is_system_metadata_file(Path::new("/dl/._[ANi] Test - 01 [1080P].mkv")));
+/// assert!(is_system_metadata_file(Path::new("/dl/.DS_Store")));
+/// assert!(!is_system_metadata_file(Path::new("/dl/[ANi] Test - 01 [1080P].mkv")));
+/// ```
+#[must_use]
+pub fn is_system_metadata_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with("._") || name == ".DS_Store")
+}
+
+/// 遍历时默认跳过的系统目录（不区分大小写）：回收站、NAS 缩略图与快照目录等。
+pub const SYSTEM_DIRECTORY_NAMES: &[&str] = &[
+    "$RECYCLE.BIN",
+    "System Volume Information",
+    "lost+found",
+    "@eaDir",
+    "#recycle",
+    "#snapshot",
+];
+
+/// 名称是否表示隐藏项（`.` 开头，如 `.git`、`.Trash-1000`）或已知系统目录。
+///
+/// ```
+/// use anime_organizer::scan::is_hidden_or_system_name;
+///
+/// assert!(is_hidden_or_system_name(".git"));
+/// assert!(is_hidden_or_system_name("$Recycle.Bin"));
+/// assert!(!is_hidden_or_system_name("Season 1"));
+/// ```
+#[must_use]
+pub fn is_hidden_or_system_name(name: &str) -> bool {
+    name.starts_with('.')
+        || SYSTEM_DIRECTORY_NAMES
+            .iter()
+            .any(|system| system.eq_ignore_ascii_case(name))
+}
+
+/// 目录项是否应作为隐藏项跳过；Windows 上还会检查隐藏与系统属性。
+fn is_hidden_entry(entry: &fs::DirEntry) -> bool {
+    if is_hidden_or_system_name(&entry.file_name().to_string_lossy()) {
+        return true;
+    }
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::MetadataExt;
+        const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+        const FILE_ATTRIBUTE_SYSTEM: u32 = 0x4;
+        if let Ok(metadata) = entry.metadata() {
+            return metadata.file_attributes() & (FILE_ATTRIBUTE_HIDDEN | FILE_ATTRIBUTE_SYSTEM)
+                != 0;
+        }
+    }
+    false
+}
+
+/// 递归收集 `root` 下的所有普通文件。
+///
+/// 默认与 `WalkDir` 一致：不跟随符号链接，无法读取的目录会被跳过。
+/// 启用 [`ScanOptions::follow_symlinks`] 后与 `WalkDir::follow_links` 一致：
+/// 指回祖先目录的符号链接（链接环）会被跳过并输出警告，而不是无限遍历。
+/// macOS 元数据文件（见 [`is_system_metadata_file`]）总是被跳过；除非启用
+/// [`ScanOptions::include_hidden`]，`root` 之下的隐藏项与系统目录也会被跳过。
+/// 若 `root` 本身是文件，则只返回该文件（仍会应用扩展名过滤）。
+#[must_use]
+pub fn collect_files<P: AsRef<Path>>(root: P, options: &ScanOptions) -> Vec<PathBuf> {
+    let root = root.as_ref();
+    let matches = |path: &Path| {
+        !is_system_metadata_file(path)
+            && options
+                .extensions
+                .as_ref()
+                .is_none_or(|extensions| has_extension(path, extensions))
+            && options
+                .modified_after
+                .is_none_or(|cutoff| modified_since(path, cutoff))
+    };
+
+    let Ok(metadata) = fs::metadata(root) else {
+        return Vec::new();
+    };
+    if metadata.is_file() {
+        return if matches(root) {
+            vec![root.to_path_buf()]
+        } else {
+            Vec::new()
+        };
+    }
+
+    let ancestors = if options.follow_symlinks {
+        directory_id(root).into_iter().collect()
+    } else {
+        Vec::new()
+    };
+    let root = PendingDirectory {
+        path: root.to_path_buf(),
+        ancestors,
+    };
+    let found = Mutex::new(Vec::new());
+    // 工作线程中的 panic 会在全部任务结束后由 `scope` 传回调用方
+    let scan = || rayon::scope(|scope| scan_directory(scope, root, &matches, options, &found));
+    match rayon::ThreadPoolBuilder::new()
+        .num_threads(options.thread_count())
+        .build()
+    {
+        Ok(pool) => pool.install(scan),
+        Err(_) => scan(),
+    }
+    let mut files = found.into_inner().unwrap_or_else(|e| e.into_inner());
+
+    if options.sorted {
+        files.sort();
+    }
+    files
+}
+
+/// 路径中是否含通配符 `*` 或 `?`。
+///
+/// 方括号不视为通配符：番剧文件名几乎都带 `[发布组]`，按字面匹配更符合直觉。
+/// 只检查普通路径层级，Windows 的 `\\?\C:\` 等路径前缀不算通配符。
+///
+/// ```
+/// use anime_organizer::scan::is_glob_pattern;
+/// use std::path::Path;
+///
+/// assert!(is_glob_pattern(Path::new("Downloads/*/[ANi]*.mkv")));
+/// assert!(!is_glob_pattern(Path::new("Downloads/[ANi] Test - 01 [1080P].mkv")));
+/// ```
+#[must_use]
+pub fn is_glob_pattern(path: &Path) -> bool {
+    path.components()
+        .any(|component| is_glob_component(&component))
+}
+
+const GLOB_METACHARACTERS: [char; 2] = ['*', '?'];
+
+fn is_glob_component(component: &Component<'_>) -> bool {
+    matches!(component, Component::Normal(name) if name.to_string_lossy().contains(GLOB_METACHARACTERS))
+}
+
+/// 通配符路径中第一个含通配符的层级之前的目录，即展开时的起点；
+/// 第一层即含通配符时返回 `.`。
+///
+/// ```
+/// use anime_organizer::scan::glob_base;
+/// use std::path::Path;
+///
+/// assert_eq!(glob_base(Path::new("Downloads/*/[ANi]*.mkv")), Path::new("Downloads"));
+/// assert_eq!(glob_base(Path::new("*.mkv")), Path::new("."));
+/// ```
+#[must_use]
+pub fn glob_base(pattern: &Path) -> PathBuf {
+    let base = glob_prefix(pattern);
+    if base.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        base
+    }
+}
+
+fn glob_prefix(pattern: &Path) -> PathBuf {
+    pattern
+        .components()
+        .take_while(|component| !is_glob_component(component))
+        .collect()
+}
+
+/// 按通配符路径展开匹配的普通文件，结果按路径排序。
+///
+/// 每一层分别匹配：`*` 匹配任意个字符、`?` 匹配单个字符，均不跨越 `/`；其余字符
+/// （含方括号）按字面比较。除非启用 [`ScanOptions::include_hidden`] 或该层模式本身以 `.`
+/// 开头，通配符不匹配隐藏项与系统目录；macOS 元数据文件与 [`ScanOptions::excluded_dirs`]
+/// 之下的文件总是被跳过。扩展名等其他过滤由调用方完成。
+#[must_use]
+pub fn expand_glob(pattern: &Path, options: &ScanOptions) -> Vec<PathBuf> {
+    let prefix = glob_prefix(pattern);
+    let components = pattern
+        .components()
+        .skip(prefix.components().count())
+        .collect::<Vec<_>>();
+    let mut matched = vec![prefix];
+    for component in &components {
+        let is_glob = is_glob_component(component);
+        let component = component.as_os_str().to_string_lossy();
+        let mut next = Vec::new();
+        for directory in &matched {
+            if !is_glob {
+                next.push(directory.join(component.as_ref()));
+                continue;
+            }
+            let listing = if directory.as_os_str().is_empty() {
+                Path::new(".")
+            } else {
+                directory.as_path()
+            };
+            let Ok(entries) = fs::read_dir(listing) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let name = entry.file_name();
+                let name_lossy = name.to_string_lossy();
+                let hidden = !options.include_hidden
+                    && !component.starts_with('.')
+                    && is_hidden_or_system_name(&name_lossy);
+                if !hidden && wildcard_match(&component, &name_lossy) {
+                    next.push(directory.join(&name));
+                }
+            }
+        }
+        matched = next;
+    }
+    let mut files = matched
+        .into_iter()
+        .filter(|path| {
+            path.is_file()
+                && !is_system_metadata_file(path)
+                && !options
+                    .excluded_dirs
+                    .iter()
+                    .any(|excluded| path.starts_with(excluded))
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// 单层通配符匹配：`*` 匹配任意个字符，`?` 匹配单个字符。
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+    let (mut p, mut n) = (0, 0);
+    // 最近一个 `*` 的位置及其当前匹配到的名称位置，失配时回溯到这里多吞一个字符。
+    let mut backtrack = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// 目标目录位于源目录之内（且不相同）时，返回以 `source` 为前缀拼出的目标路径，
+/// 供 [`ScanOptions::excluded_dirs`] 跳过已整理的文件；否则返回 `None`。
+///
+/// 两者按规范化路径比较，因此相对路径、`..` 与符号链接不影响判断；任一路径不存在时返回 `None`。
+/// 源与目标相同时无法排除整个目标，已在目标位置的文件由整理时的同一文件检查跳过。
+#[must_use]
+pub fn nested_target_dir(source: &Path, target: &Path) -> Option<PathBuf> {
+    let source_canonical = fs::canonicalize(source).ok()?;
+    let target_canonical = fs::canonicalize(target).ok()?;
+    let relative = target_canonical.strip_prefix(&source_canonical).ok()?;
+    if relative.as_os_str().is_empty() {
+        return None;
+    }
+    Some(source.join(relative))
+}
+
+/// 源文件的处理顺序
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortKey {
+    /// 按文件名
+    #[default]
+    Name,
+    /// 按文件大小从小到大
+    Size,
+    /// 按修改时间从旧到新
+    Mtime,
+}
+
+/// 按排序键原地排序文件，键相同时依次按文件名、完整路径排序，保证多次运行顺序一致。
+///
+/// 无法读取大小或修改时间的文件按 0 / `UNIX_EPOCH` 计，排在最前。
+///
+/// ```
+/// use anime_organizer::scan::{sort_files, SortKey};
+/// use std::path::PathBuf;
+///
+/// let mut files = vec![PathBuf::from("b/01.mkv"), PathBuf::from("a/02.mkv")];
+/// sort_files(&mut files, SortKey::Name);
+/// assert_eq!(files, [PathBuf::from("b/01.mkv"), PathBuf::from("a/02.mkv")]);
+/// ```
+pub fn sort_files(files: &mut [PathBuf], key: SortKey) {
+    files.sort_by_cached_key(|path| {
+        let metadata = match key {
+            SortKey::Name => None,
+            SortKey::Size | SortKey::Mtime => fs::metadata(path).ok(),
+        };
+        let size = match key {
+            SortKey::Size => metadata.as_ref().map_or(0, fs::Metadata::len),
+            _ => 0,
+        };
+        let modified = match key {
+            SortKey::Mtime => metadata
+                .and_then(|metadata| metadata.modified().ok())
+                .unwrap_or(SystemTime::UNIX_EPOCH),
+            _ => SystemTime::UNIX_EPOCH,
+        };
+        (
+            size,
+            modified,
+            path.file_name().map(ToOwned::to_owned),
+            path.clone(),
+        )
+    });
+}
+
+/// 待读取的目录；跟随符号链接时附带从根到该目录的目录身份链，用于检测链接环
+struct PendingDirectory {
+    path: PathBuf,
+    ancestors: Vec<DirectoryId>,
+}
+
+/// 读取一个目录，并为每个子目录派生一个任务
+fn scan_directory<'s>(
+    scope: &rayon::Scope<'s>,
+    directory: PendingDirectory,
+    matches: &'s (dyn Fn(&Path) -> bool + Sync),
+    options: &'s ScanOptions,
+    found: &'s Mutex<Vec<PathBuf>>,
+) {
+    let mut files = Vec::new();
+    let subdirectories = read_directory(&directory, matches, options, &mut files);
+    found
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .extend(files);
+    for subdirectory in subdirectories {
+        scope.spawn(move |scope| scan_directory(scope, subdirectory, matches, options, found));
+    }
+}
+
+fn read_directory(
+    directory: &PendingDirectory,
+    matches: &dyn Fn(&Path) -> bool,
+    options: &ScanOptions,
+    found: &mut Vec<PathBuf>,
+) -> Vec<PendingDirectory> {
+    let follow_symlinks = options.follow_symlinks;
+    let Ok(entries) = fs::read_dir(&directory.path) else {
+        return Vec::new();
+    };
+    let mut subdirectories = Vec::new();
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        if !options.include_hidden && is_hidden_entry(&entry) {
+            continue;
+        }
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        let path = entry.path();
+        let (is_dir, is_file) = if follow_symlinks && file_type.is_symlink() {
+            match fs::metadata(&path) {
+                Ok(metadata) => (metadata.is_dir(), metadata.is_file()),
+                // 悬空链接
+                Err(_) => continue,
+            }
+        } else {
+            (file_type.is_dir(), file_type.is_file())
+        };
+        if is_dir {
+            if options.excluded_dirs.contains(&path) {
+                continue;
+            }
+            let mut ancestors = Vec::new();
+            if follow_symlinks {
+                let Some(id) = directory_id(&path) else {
+                    continue;
+                };
+                if directory.ancestors.contains(&id) {
+                    eprintln!("警告：跳过符号链接环 {}", path.display());
+                    continue;
+                }
+                ancestors.clone_from(&directory.ancestors);
+                ancestors.push(id);
+            }
+            subdirectories.push(PendingDirectory { path, ancestors });
+        } else if is_file && matches(&path) {
+            found.push(path);
+        }
+    }
+    subdirectories
+}
+
+/// 目录身份：Unix 上为（设备号, inode），其他平台退化为规范化路径
+#[cfg(unix)]
+type DirectoryId = (u64, u64);
+#[cfg(not(unix))]
+type DirectoryId = PathBuf;
+
+#[cfg(unix)]
+fn directory_id(path: &Path) -> Option<DirectoryId> {
+    use std::os::unix::fs::MetadataExt;
+    fs::metadata(path)
+        .ok()
+        .map(|metadata| (metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn directory_id(path: &Path) -> Option<DirectoryId> {
+    fs::canonicalize(path).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use walkdir::WalkDir;
+
+    fn create_tree(root: &Path) {
+        for directory in ["a", "a/b", "a/b/c", "d", "e/f"] {
+            fs::create_dir_all(root.join(directory)).unwrap();
+        }
+        for file in [
+            "root.mkv",
+            "a/01.mkv",
+            "a/01.ass",
+            "a/b/02.MP4",
+            "a/b/c/03.mkv",
+            "a/b/c/notes.txt",
+            "d/04.avi",
+            "e/f/05.mkv",
+        ] {
+            fs::write(root.join(file), b"data").unwrap();
+        }
+    }
+
+    fn walkdir_files(root: &Path, extensions: Option<&HashSet<String>>) -> Vec<PathBuf> {
+        let mut files = WalkDir::new(root)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .map(|entry| entry.into_path())
+            .filter(|path| extensions.is_none_or(|extensions| has_extension(path, extensions)))
+            .collect::<Vec<_>>();
+        files.sort();
+        files
+    }
+
+    fn extensions(values: &[&str]) -> HashSet<String> {
+        values.iter().map(|value| (*value).to_string()).collect()
+    }
+
+    #[test]
+    fn parallel_scan_matches_single_threaded_walkdir() {
+        let directory = tempfile::tempdir().unwrap();
+        create_tree(directory.path());
+
+        for threads in [1, 2, 8] {
+            let options = ScanOptions {
+                threads,
+                ..ScanOptions::default()
+            };
+            assert_eq!(
+                collect_files(directory.path(), &options),
+                walkdir_files(directory.path(), None),
+                "threads = {threads}"
+            );
+        }
+    }
+
+    #[test]
+    fn extension_filter_is_applied_during_scan() {
+        let directory = tempfile::tempdir().unwrap();
+        create_tree(directory.path());
+        let filter = extensions(&[".mkv", ".mp4"]);
+
+        let files = collect_files(
+            directory.path(),
+            &ScanOptions::with_extensions(filter.clone()),
+        );
+
+        assert_eq!(files, walkdir_files(directory.path(), Some(&filter)));
+        assert_eq!(files.len(), 5);
+    }
+
+    #[test]
+    fn wildcard_extensions_match_similar_extensions() {
+        let filter = extensions(&[".mp*", ".m?v"]);
+
+        for file in ["01.mp4", "02.MPG", "03.mpeg", "04.mkv", "05.m4v"] {
+            assert!(has_extension(Path::new(file), &filter), "{file}");
+        }
+        for file in ["06.avi", "07.mkv.part", "08.m2ts", "09"] {
+            assert!(!has_extension(Path::new(file), &filter), "{file}");
+        }
+    }
+
+    #[test]
+    fn exact_extensions_match_only_themselves() {
+        let filter = extensions(&[".mkv"]);
+
+        assert!(has_extension(Path::new("01.MKV"), &filter));
+        assert!(!has_extension(Path::new("02.mk"), &filter));
+        assert!(!has_extension(Path::new("03.mkvx"), &filter));
+        assert!(!extension_matches(".mkv", ".mp4"));
+    }
+
+    #[test]
+    fn sorted_scan_is_deterministic() {
+        let directory = tempfile::tempdir().unwrap();
+        create_tree(directory.path());
+        let options = ScanOptions {
+            threads: 4,
+            ..ScanOptions::default()
+        };
+
+        let first = collect_files(directory.path(), &options);
+        let second = collect_files(directory.path(), &options);
+
+        assert_eq!(first, second);
+        assert!(first.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn modified_after_skips_older_files() {
+        let directory = tempfile::tempdir().unwrap();
+        let old = directory.path().join("old.mkv");
+        let new = directory.path().join("new.mkv");
+        fs::write(&old, b"data").unwrap();
+        fs::write(&new, b"data").unwrap();
+        let now = SystemTime::now();
+        fs::File::options()
+            .write(true)
+            .open(&old)
+            .unwrap()
+            .set_modified(now - std::time::Duration::from_secs(10 * 86_400))
+            .unwrap();
+        let options = ScanOptions {
+            modified_after: Some(now - std::time::Duration::from_secs(86_400)),
+            ..ScanOptions::default()
+        };
+
+        assert_eq!(collect_files(directory.path(), &options), vec![new]);
+    }
+
+    #[test]
+    fn macos_metadata_files_are_skipped() {
+        let directory = tempfile::tempdir().unwrap();
+        let video = directory.path().join("01.mkv");
+        fs::write(&video, b"data").unwrap();
+        fs::write(directory.path().join("._01.mkv"), b"meta").unwrap();
+        fs::write(directory.path().join(".DS_Store"), b"meta").unwrap();
+
+        assert_eq!(
+            collect_files(directory.path(), &ScanOptions::default()),
+            vec![video]
+        );
+    }
+
+    #[test]
+    fn hidden_and_system_names_are_recognized() {
+        for name in [
+            ".git",
+            ".Trash",
+            ".Trash-1000",
+            ".hidden.mkv",
+            "$RECYCLE.BIN",
+            "$Recycle.Bin",
+            "System Volume Information",
+            "lost+found",
+            "@eaDir",
+            "#recycle",
+            "#snapshot",
+        ] {
+            assert!(is_hidden_or_system_name(name), "{name}");
+        }
+        for name in ["Season 1", "01.mkv", "recycle", "Test Show (2024)"] {
+            assert!(!is_hidden_or_system_name(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn hidden_entries_are_skipped_unless_included() {
+        let directory = tempfile::tempdir().unwrap();
+        let video = directory.path().join("show/01.mkv");
+        fs::create_dir_all(directory.path().join("show")).unwrap();
+        fs::write(&video, b"data").unwrap();
+        for hidden in [".git", ".Trash-1000", "$RECYCLE.BIN", "@eaDir"] {
+            fs::create_dir_all(directory.path().join(hidden)).unwrap();
+            fs::write(directory.path().join(hidden).join("02.mkv"), b"data").unwrap();
+        }
+        fs::write(directory.path().join("show/.03.mkv"), b"data").unwrap();
+
+        assert_eq!(
+            collect_files(directory.path(), &ScanOptions::default()),
+            vec![video]
+        );
+        let options = ScanOptions {
+            include_hidden: true,
+            ..ScanOptions::default()
+        };
+        assert_eq!(collect_files(directory.path(), &options).len(), 6);
+    }
+
+    #[test]
+    fn hidden_root_is_still_scanned() {
+        let directory = tempfile::tempdir().unwrap();
+        let root = directory.path().join(".downloads");
+        fs::create_dir(&root).unwrap();
+        fs::write(root.join("01.mkv"), b"data").unwrap();
+
+        assert_eq!(
+            collect_files(&root, &ScanOptions::default()),
+            vec![root.join("01.mkv")]
+        );
+    }
+
+    #[test]
+    fn file_root_returns_itself() {
+        let directory = tempfile::tempdir().unwrap();
+        let file = directory.path().join("01.mkv");
+        fs::write(&file, b"data").unwrap();
+
+        assert_eq!(
+            collect_files(&file, &ScanOptions::with_extensions(extensions(&[".mkv"]))),
+            vec![file.clone()]
+        );
+        assert!(
+            collect_files(&file, &ScanOptions::with_extensions(extensions(&[".mp4"]))).is_empty()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn follow_symlinks_skips_loops() {
+        use std::os::unix::fs::symlink;
+
+        let directory = tempfile::tempdir().unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        create_tree(directory.path());
+        fs::write(outside.path().join("06.mkv"), b"data").unwrap();
+        symlink(directory.path(), directory.path().join("a/b/loop")).unwrap();
+        symlink(directory.path().join("a/b"), directory.path().join("d/b")).unwrap();
+        symlink(outside.path(), directory.path().join("linked")).unwrap();
+        symlink(
+            directory.path().join("missing"),
+            directory.path().join("dangling"),
+        )
+        .unwrap();
+
+        let mut expected = WalkDir::new(directory.path())
+            .follow_links(true)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .map(|entry| entry.into_path())
+            .collect::<Vec<_>>();
+        expected.sort();
+        assert!(expected.contains(&directory.path().join("linked/06.mkv")));
+        assert!(expected.contains(&directory.path().join("d/b/c/03.mkv")));
+
+        for threads in [1, 4] {
+            let options = ScanOptions {
+                threads,
+                follow_symlinks: true,
+                ..ScanOptions::default()
+            };
+            assert_eq!(
+                collect_files(directory.path(), &options),
+                expected,
+                "threads = {threads}"
+            );
+        }
+        assert_eq!(
+            collect_files(directory.path(), &ScanOptions::default()),
+            walkdir_files(directory.path(), None)
+        );
+    }
+
+    #[test]
+    fn missing_root_returns_empty() {
+        let directory = tempfile::tempdir().unwrap();
+        assert!(
+            collect_files(directory.path().join("missing"), &ScanOptions::default()).is_empty()
+        );
+    }
+
+    fn names(files: &[PathBuf]) -> Vec<String> {
+        files
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn sort_by_name_ignores_directories() {
+        let mut files = ["z/02.mkv", "a/03.mkv", "m/01.mkv", "b/01.mkv"]
+            .map(PathBuf::from)
+            .to_vec();
+        sort_files(&mut files, SortKey::Name);
+
+        assert_eq!(
+            files,
+            ["b/01.mkv", "m/01.mkv", "z/02.mkv", "a/03.mkv"].map(PathBuf::from)
+        );
+    }
+
+    #[test]
+    fn sort_by_size_is_ascending_with_name_tiebreak() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut files = [("c.mkv", 3), ("a.mkv", 10), ("b.mkv", 3), ("d.mkv", 1)]
+            .map(|(name, size)| {
+                let path = directory.path().join(name);
+                fs::write(&path, vec![0u8; size]).unwrap();
+                path
+            })
+            .to_vec();
+        sort_files(&mut files, SortKey::Size);
+
+        assert_eq!(names(&files), ["d.mkv", "b.mkv", "c.mkv", "a.mkv"]);
+    }
+
+    #[test]
+    fn sort_by_mtime_puts_oldest_first() {
+        let directory = tempfile::tempdir().unwrap();
+        let now = SystemTime::now();
+        let mut files = [("new.mkv", 0), ("old.mkv", 20), ("mid.mkv", 10)]
+            .map(|(name, days_ago)| {
+                let path = directory.path().join(name);
+                fs::write(&path, b"data").unwrap();
+                fs::File::options()
+                    .write(true)
+                    .open(&path)
+                    .unwrap()
+                    .set_modified(now - std::time::Duration::from_secs(days_ago * 86_400))
+                    .unwrap();
+                path
+            })
+            .to_vec();
+        sort_files(&mut files, SortKey::Mtime);
+
+        assert_eq!(names(&files), ["old.mkv", "mid.mkv", "new.mkv"]);
+    }
+
+    #[test]
+    fn unreadable_metadata_sorts_first() {
+        let directory = tempfile::tempdir().unwrap();
+        let existing = directory.path().join("a.mkv");
+        fs::write(&existing, b"data").unwrap();
+        let missing = directory.path().join("b.mkv");
+
+        for key in [SortKey::Size, SortKey::Mtime] {
+            let mut files = vec![existing.clone(), missing.clone()];
+            sort_files(&mut files, key);
+            assert_eq!(files, [missing.clone(), existing.clone()], "{key:?}");
+        }
+    }
+
+    #[test]
+    fn nested_target_dir_only_for_strict_subdirectory() {
+        let directory = tempfile::tempdir().unwrap();
+        let source = directory.path().join("source");
+        let library = source.join("library");
+        fs::create_dir_all(&library).unwrap();
+        let outside = directory.path().join("outside");
+        fs::create_dir(&outside).unwrap();
+
+        assert_eq!(nested_target_dir(&source, &library), Some(library.clone()));
+        assert_eq!(
+            nested_target_dir(&source, &source.join("library/../library")),
+            Some(library.clone())
+        );
+        assert_eq!(nested_target_dir(&source, &source), None);
+        assert_eq!(nested_target_dir(&source, &outside), None);
+        assert_eq!(nested_target_dir(&library, &source), None);
+        assert_eq!(nested_target_dir(&source, &source.join("missing")), None);
+    }
+
+    #[test]
+    fn excluded_dirs_are_not_scanned() {
+        let directory = tempfile::tempdir().unwrap();
+        create_tree(directory.path());
+        let options = ScanOptions {
+            excluded_dirs: vec![directory.path().join("a/b")],
+            ..ScanOptions::default()
+        };
+
+        let files = collect_files(directory.path(), &options);
+
+        assert!(files
+            .iter()
+            .all(|path| !path.starts_with(directory.path().join("a/b"))));
+        assert!(files.contains(&directory.path().join("d/04.avi")));
+    }
+
+    #[test]
+    fn wildcard_matches_star_and_question_mark_literally_elsewhere() {
+        assert!(wildcard_match("[ANi]*.mkv", "[ANi] Test - 01 [1080P].mkv"));
+        assert!(wildcard_match("*", ""));
+        assert!(wildcard_match("*01*", "[ANi] Test - 01 [1080P].mkv"));
+        assert!(wildcard_match("第?集.mp4", "第5集.mp4"));
+        assert!(wildcard_match("a*b*c", "aXbYbZc"));
+        assert!(!wildcard_match("[ANi]*.mkv", "[ANi] Test - 01 [1080P].mp4"));
+        assert!(!wildcard_match("[ANi]*.mkv", "A Test.mkv"));
+        assert!(!wildcard_match("?", ""));
+        assert!(!wildcard_match("a*b", "aXbY"));
+    }
+
+    #[test]
+    fn glob_expands_matching_files_per_component() {
+        let directory = tempfile::tempdir().unwrap();
+        let root = directory.path();
+        for file in [
+            "show1/[ANi] A - 01.mkv",
+            "show1/[ANi] A - 01.ass",
+            "show1/[Other] A - 01.mkv",
+            "show2/[ANi] B - 02.mkv",
+            "show2/nested/[ANi] C - 03.mkv",
+            ".hidden/[ANi] D - 04.mkv",
+            "[ANi] E - 05.mkv",
+        ] {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, b"data").unwrap();
+        }
+        let pattern = root.join("*").join("[ANi]*.mkv");
+
+        assert_eq!(glob_base(&pattern), root);
+        assert_eq!(
+            expand_glob(&pattern, &ScanOptions::default()),
+            vec![
+                root.join("show1/[ANi] A - 01.mkv"),
+                root.join("show2/[ANi] B - 02.mkv"),
+            ]
+        );
+        let with_hidden = ScanOptions {
+            include_hidden: true,
+            ..ScanOptions::default()
+        };
+        assert_eq!(expand_glob(&pattern, &with_hidden).len(), 3);
+        assert!(expand_glob(&root.join("*").join("*.avi"), &ScanOptions::default()).is_empty());
+    }
+
+    /// `fs::canonicalize` 在 Windows 上返回 `\\?\C:\...` 形式的路径，前缀中的 `?` 不是通配符
+    #[cfg(windows)]
+    #[test]
+    fn glob_ignores_verbatim_path_prefix() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = fs::canonicalize(dir.path()).unwrap();
+        assert!(root.to_string_lossy().starts_with(r"\\?\"));
+        let file = root.join("[ANi] A - 01.mkv");
+        fs::write(&file, b"data").unwrap();
+
+        assert!(!is_glob_pattern(&file));
+        let pattern = root.join("*.mkv");
+        assert!(is_glob_pattern(&pattern));
+        assert_eq!(glob_base(&pattern), root);
+        assert_eq!(expand_glob(&pattern, &ScanOptions::default()), vec![file]);
+    }
+}
@@ -0,0 +1,230 @@
+//! 增量整理状态模块
+//!
+//! `--state state.json` 记录每个已成功处理的源文件路径及其修改时间与大小，
+//! 下次运行时跳过路径、修改时间和大小都未变的文件，而不必逐个检查目标。
+//! 状态文件不存在时视为空状态；预览模式不写入状态。
+//!
+//! # 示例
+//!
+//! ```no_run
+//! use anime_organizer::state::{FileStamp, ProcessedState};
+//! use std::path::Path;
+//!
+//! let state_file = Path::new("state.json");
+//! let mut state = ProcessedState::load(state_file)?;
+//! let source = Path::new("/downloads/[ANi] 测试 - 01 [1080P].mp4");
+//! if !state.is_unchanged(source) {
+//!     let stamp = FileStamp::of(source)?;
+//!     // ……整理该文件……
+//!     state.record(source, stamp);
+//! }
+//! state.save(state_file)?;
+//! # Ok::<(), anime_organizer::AppError>(())
+//! ```
+
+use crate::error::{AppError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+/// 当前状态文件格式版本，格式不兼容地变化时递增
+pub const STATE_VERSION: u32 = 1;
+
+/// 已处理文件的指纹：修改时间与大小
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileStamp {
+    /// 修改时间距 Unix 纪元的纳秒数
+    pub modified_ns: u128,
+    /// 文件大小（字节）
+    pub size: u64,
+}
+
+impl FileStamp {
+    /// 读取文件当前的指纹。
+    ///
+    /// # 错误
+    ///
+    /// 读取元数据失败时返回 I/O 错误。
+    pub fn of(path: &Path) -> std::io::Result<Self> {
+        let metadata = fs::metadata(path)?;
+        let modified_ns = metadata
+            .modified()?
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos());
+        Ok(Self {
+            modified_ns,
+            size: metadata.len(),
+        })
+    }
+}
+
+/// 已处理文件的状态
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessedState {
+    /// 状态文件格式版本，见 [`STATE_VERSION`]
+    pub version: u32,
+    /// 源文件路径到处理时指纹的映射
+    pub files: BTreeMap<String, FileStamp>,
+}
+
+impl Default for ProcessedState {
+    fn default() -> Self {
+        Self {
+            version: STATE_VERSION,
+            files: BTreeMap::new(),
+        }
+    }
+}
+
+impl ProcessedState {
+    /// 读取状态文件；文件不存在时返回空状态。
+    ///
+    /// # 错误
+    ///
+    /// 文件无法读取、内容无效或版本不受支持时返回 [`AppError::StateError`]。
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = match fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Self::default())
+            }
+            Err(error) => {
+                return Err(AppError::StateError(format!(
+                    "读取状态文件失败 {}: {error}",
+                    path.display()
+                )))
+            }
+        };
+        let state: Self = serde_json::from_str(&json).map_err(|error| {
+            AppError::StateError(format!("{}: 解析失败: {error}", path.display()))
+        })?;
+        if state.version != STATE_VERSION {
+            return Err(AppError::StateError(format!(
+                "{}: 不支持的状态文件版本 {}（当前为 {STATE_VERSION}）",
+                path.display(),
+                state.version
+            )));
+        }
+        Ok(state)
+    }
+
+    /// 写入状态文件。
+    ///
+    /// # 错误
+    ///
+    /// - `AppError::StateError` - 序列化失败
+    /// - `AppError::Io` - 写入文件失败
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|error| AppError::StateError(format!("序列化失败: {error}")))?;
+        fs::write(path, json + "\n")?;
+        Ok(())
+    }
+
+    /// 文件上次已处理且修改时间与大小均未变化。
+    ///
+    /// 未记录或无法读取元数据的文件返回 `false`，交给正常流程处理。
+    #[must_use]
+    pub fn is_unchanged(&self, path: &Path) -> bool {
+        self.files
+            .get(path.to_string_lossy().as_ref())
+            .is_some_and(|stamp| FileStamp::of(path).is_ok_and(|current| current == *stamp))
+    }
+
+    /// 记录文件已处理。
+    ///
+    /// 指纹应在整理前通过 [`FileStamp::of`] 读取，移动模式下源文件整理后已不存在。
+    pub fn record(&mut self, path: &Path, stamp: FileStamp) {
+        self.files
+            .insert(path.to_string_lossy().into_owned(), stamp);
+    }
+
+    /// 已记录的文件数
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// 是否没有任何记录
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+
+    fn set_modified(path: &Path, seconds: u64) {
+        fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(seconds))
+            .unwrap();
+    }
+
+    #[test]
+    fn recorded_file_is_unchanged_after_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("[ANi] 测试 - 01 [1080P].mkv");
+        let state_file = dir.path().join("state.json");
+        fs::write(&source, b"video").unwrap();
+
+        let mut state = ProcessedState::load(&state_file).unwrap();
+        assert!(state.is_empty());
+        assert!(!state.is_unchanged(&source));
+        state.record(&source, FileStamp::of(&source).unwrap());
+        state.save(&state_file).unwrap();
+
+        let state = ProcessedState::load(&state_file).unwrap();
+        assert_eq!(state.len(), 1);
+        assert!(state.is_unchanged(&source));
+    }
+
+    #[test]
+    fn modified_or_resized_file_is_processed_again() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("[ANi] 测试 - 01 [1080P].mkv");
+        fs::write(&source, b"video").unwrap();
+        set_modified(&source, 1_000);
+        let mut state = ProcessedState::default();
+        state.record(&source, FileStamp::of(&source).unwrap());
+
+        set_modified(&source, 2_000);
+        assert!(!state.is_unchanged(&source));
+
+        state.record(&source, FileStamp::of(&source).unwrap());
+        fs::write(&source, b"longer video").unwrap();
+        set_modified(&source, 2_000);
+        assert!(!state.is_unchanged(&source));
+    }
+
+    #[test]
+    fn missing_source_is_not_unchanged() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("gone.mkv");
+        fs::write(&source, b"video").unwrap();
+        let mut state = ProcessedState::default();
+        state.record(&source, FileStamp::of(&source).unwrap());
+        fs::remove_file(&source).unwrap();
+
+        assert!(!state.is_unchanged(&source));
+    }
+
+    #[test]
+    fn invalid_state_file_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_file = dir.path().join("state.json");
+        fs::write(&state_file, "{\"version\": 99, \"files\": {}}").unwrap();
+
+        assert!(matches!(
+            ProcessedState::load(&state_file),
+            Err(AppError::StateError(_))
+        ));
+    }
+}
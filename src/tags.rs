@@ -0,0 +1,259 @@
+//! 标签解析模块
+//!
+//! 将文件名中的标签串（如 `[1080P][Baha][WEB-DL][AAC AVC][CHT]`）拆分为单个标签，
+//! 并按类别归类，供整理时只保留部分标签。
+//!
+//! # 示例
+//!
+//! ```
+//! use anime_organizer::tags::{filter_tags, TagCategory};
+//!
+//! let tags = "[1080P][Baha][WEB-DL][AAC AVC][CHT]";
+//! assert_eq!(filter_tags(tags, &[TagCategory::Resolution]), "[1080P]");
+//! assert_eq!(
+//!     filter_tags(tags, &[TagCategory::Resolution, TagCategory::Lang]),
+//!     "[1080P][CHT]"
+//! );
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// 标签类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagCategory {
+    /// 分辨率，如 `1080P`、`4K`
+    Resolution,
+    /// 片源，如 `Baha`、`WEB-DL`、`BDRip`
+    Source,
+    /// 视频/音频编码，如 `AAC AVC`、`HEVC`、`10bit`
+    Codec,
+    /// 字幕语言，如 `CHT`、`简繁内封`
+    Lang,
+    /// 无法归类的其他标签
+    Other,
+}
+
+const SOURCE_TAGS: &[&str] = &[
+    "BAHA", "WEB-DL", "WEBDL", "WEBRIP", "WEB", "BDRIP", "BD", "BLURAY", "BLU-RAY", "DVD",
+    "DVDRIP", "TVRIP", "HDTV", "CR", "B-GLOBAL", "NETFLIX", "NF", "AMZN", "ABEMA", "BILIBILI",
+    "VIU", "DSNP",
+];
+
+const CODEC_MARKERS: &[&str] = &[
+    "AVC", "HEVC", "H264", "H.264", "H265", "H.265", "X264", "X265", "AV1", "AAC", "FLAC", "AC3",
+    "EAC3", "OPUS", "DTS", "10BIT", "8BIT", "MA10P", "HI10P",
+];
+
+const LANG_TAGS: &[&str] = &[
+    "CHT", "CHS", "GB", "BIG5", "JP", "JPN", "ENG", "EN", "SC", "TC", "JPSC", "JPTC", "CHT&JP",
+    "CHS&JP", "GB&JP", "BIG5&JP",
+];
+
+const LANG_MARKERS: &[&str] = &[
+    "简", "繁", "簡", "中字", "中文", "日字", "双语", "雙語", "字幕",
+];
+
+/// 判断单个标签（不含方括号）的类别。
+#[must_use]
+pub fn classify_tag(tag: &str) -> TagCategory {
+    let upper = tag.trim().to_uppercase();
+    if is_resolution(&upper) {
+        TagCategory::Resolution
+    } else if SOURCE_TAGS.contains(&upper.as_str()) {
+        TagCategory::Source
+    } else if upper
+        .split([' ', '_', '-', '+'])
+        .any(|part| CODEC_MARKERS.contains(&part))
+    {
+        TagCategory::Codec
+    } else if LANG_TAGS.contains(&upper.as_str())
+        || LANG_MARKERS.iter().any(|marker| upper.contains(marker))
+    {
+        TagCategory::Lang
+    } else {
+        TagCategory::Other
+    }
+}
+
+fn is_resolution(upper: &str) -> bool {
+    if matches!(upper, "4K" | "2K" | "8K" | "UHD" | "FHD") {
+        return true;
+    }
+    if let Some(digits) = upper.strip_suffix('P').or_else(|| upper.strip_suffix('I')) {
+        return (3..=4).contains(&digits.len()) && digits.bytes().all(|b| b.is_ascii_digit());
+    }
+    upper.split_once('X').is_some_and(|(width, height)| {
+        [width, height]
+            .iter()
+            .all(|part| (3..=4).contains(&part.len()) && part.bytes().all(|b| b.is_ascii_digit()))
+    })
+}
+
+/// 拆分标签串，返回每个标签的原始片段（方括号、圆括号标签保留括号）。
+///
+/// 括号外的文本按空白拆分为独立标签。
+#[must_use]
+pub fn split_tags(tags: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut rest = tags.trim();
+    while !rest.is_empty() {
+        let close = match rest.chars().next() {
+            Some('[') => Some(']'),
+            Some('(') => Some(')'),
+            _ => None,
+        };
+        let end = match close {
+            Some(close) => rest.find(close).map_or(rest.len(), |index| index + 1),
+            None => rest
+                .find(|ch: char| ch == '[' || ch == '(' || ch.is_whitespace())
+                .unwrap_or(rest.len()),
+        };
+        parts.push(&rest[..end]);
+        rest = rest[end..].trim_start();
+    }
+    parts
+}
+
+/// 将标签串归一化为连续的方括号标签。
+///
+/// 圆括号标签 `(1080p)` 与裸标签 `1080p x264` 都改写为方括号形式，
+/// 仅由分隔符组成的片段（如 `-`）会被丢弃。
+///
+/// ```
+/// use anime_organizer::tags::normalize_tags;
+///
+/// assert_eq!(normalize_tags("[1080P][CHT]"), "[1080P][CHT]");
+/// assert_eq!(normalize_tags("(1080p) x264"), "[1080p][x264]");
+/// ```
+#[must_use]
+pub fn normalize_tags(tags: &str) -> String {
+    if is_normalized(tags) {
+        return tags.to_string();
+    }
+    split_tags(tags)
+        .into_iter()
+        .map(tag_content)
+        .filter(|content| !content.trim_matches(['-', '_', '+', '~']).is_empty())
+        .map(|content| format!("[{content}]"))
+        .collect()
+}
+
+/// 是否已是连续的方括号标签（常见的发布组命名），可跳过拆分与重新拼接。
+fn is_normalized(tags: &str) -> bool {
+    let mut rest = tags;
+    while !rest.is_empty() {
+        let Some(inner) = rest.strip_prefix('[') else {
+            return false;
+        };
+        let Some(end) = inner.find(']') else {
+            return false;
+        };
+        let content = &inner[..end];
+        if content.contains('[')
+            || content.trim() != content
+            || content.trim_matches(['-', '_', '+', '~']).is_empty()
+        {
+            return false;
+        }
+        rest = &inner[end + 1..];
+    }
+    true
+}
+
+/// 去掉单个标签片段外层的括号。
+fn tag_content(part: &str) -> &str {
+    part.strip_prefix('[')
+        .map(|inner| inner.strip_suffix(']').unwrap_or(inner))
+        .or_else(|| {
+            part.strip_prefix('(')
+                .map(|inner| inner.strip_suffix(')').unwrap_or(inner))
+        })
+        .unwrap_or(part)
+        .trim()
+}
+
+/// 只保留指定类别的标签，按原顺序拼接。
+#[must_use]
+pub fn filter_tags(tags: &str, keep: &[TagCategory]) -> String {
+    let mut output = String::new();
+    for part in split_tags(tags) {
+        if !keep.contains(&classify_tag(tag_content(part))) {
+            continue;
+        }
+        let adjacent_brackets = part.starts_with('[') && output.ends_with(']');
+        if !output.is_empty() && !adjacent_brackets {
+            output.push(' ');
+        }
+        output.push_str(part);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANI_TAGS: &str = "[1080P][Baha][WEB-DL][AAC AVC][CHT]";
+
+    #[test]
+    fn classifies_common_tags() {
+        assert_eq!(classify_tag("1080P"), TagCategory::Resolution);
+        assert_eq!(classify_tag("1920x1080"), TagCategory::Resolution);
+        assert_eq!(classify_tag("Baha"), TagCategory::Source);
+        assert_eq!(classify_tag("WEB-DL"), TagCategory::Source);
+        assert_eq!(classify_tag("AAC AVC"), TagCategory::Codec);
+        assert_eq!(classify_tag("HEVC-10bit"), TagCategory::Codec);
+        assert_eq!(classify_tag("CHT"), TagCategory::Lang);
+        assert_eq!(classify_tag("简繁内封"), TagCategory::Lang);
+        assert_eq!(classify_tag("V2"), TagCategory::Other);
+    }
+
+    #[test]
+    fn splits_bracketed_and_bare_tags() {
+        assert_eq!(
+            split_tags("[1080P][Baha] v2 [CHT]"),
+            ["[1080P]", "[Baha]", "v2", "[CHT]"]
+        );
+    }
+
+    #[test]
+    fn splits_parenthesized_tags() {
+        assert_eq!(
+            split_tags("(1080p)(WEB-DL) x264[CHT]"),
+            ["(1080p)", "(WEB-DL)", "x264", "[CHT]"]
+        );
+    }
+
+    #[test]
+    fn normalizes_parenthesized_and_bare_tags() {
+        assert_eq!(normalize_tags("(1080p)"), "[1080p]");
+        assert_eq!(normalize_tags("1080p"), "[1080p]");
+        assert_eq!(normalize_tags("- 1080p x264 [CHT]"), "[1080p][x264][CHT]");
+        assert_eq!(normalize_tags(ANI_TAGS), ANI_TAGS);
+        assert_eq!(normalize_tags(""), "");
+        assert_eq!(normalize_tags("[ 1080P ][-]"), "[1080P]");
+    }
+
+    #[test]
+    fn keeps_only_resolution() {
+        assert_eq!(filter_tags(ANI_TAGS, &[TagCategory::Resolution]), "[1080P]");
+    }
+
+    #[test]
+    fn keeps_only_language() {
+        assert_eq!(filter_tags(ANI_TAGS, &[TagCategory::Lang]), "[CHT]");
+    }
+
+    #[test]
+    fn keeps_all_categories() {
+        let all = [
+            TagCategory::Resolution,
+            TagCategory::Source,
+            TagCategory::Codec,
+            TagCategory::Lang,
+            TagCategory::Other,
+        ];
+        assert_eq!(filter_tags(ANI_TAGS, &all), ANI_TAGS);
+    }
+}
@@ -0,0 +1,218 @@
+//! 目标根目录模板模块
+//!
+//! `--target` 含 `{...}` 占位符时视为模板，每个文件解析后按其字段渲染出实际的目标根目录，
+//! 如 `/library/{year}/{anime}` 把不同年份的番剧分到不同的库。
+//!
+//! 支持的占位符：
+//!
+//! - `{year}` - 番名或标签中括号包裹的年份（见 [`AnimeFileInfo::year`]）
+//! - `{publisher}` - 发布组
+//! - `{anime}` - 番剧目录本身，只能作为最后一级出现；省略时同样在根目录下建番剧目录
+//!
+//! 字段缺失（如文件名中没有年份）时该级目录回退为 [`MISSING_FIELD_DIR`]。
+//!
+//! # 示例
+//!
+//! ```
+//! use anime_organizer::parser::FilenameParser;
+//! use anime_organizer::target_template::TargetTemplate;
+//! use std::path::Path;
+//!
+//! let template = TargetTemplate::parse("/library/{year}/{anime}")?;
+//! assert_eq!(template.base(), Path::new("/library"));
+//!
+//! let info = FilenameParser::parse_str("[ANi] Test (2024) - 01 [1080P].mkv").unwrap();
+//! assert_eq!(template.render(&info), Path::new("/library/2024"));
+//! # Ok::<(), anime_organizer::error::AppError>(())
+//! ```
+
+use crate::error::{AppError, Result};
+use crate::parser::AnimeFileInfo;
+use std::path::{Path, PathBuf};
+
+/// 模板字段缺失时使用的目录名
+pub const MISSING_FIELD_DIR: &str = "Unknown";
+
+/// 解析后的目标根目录模板
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetTemplate {
+    /// 第一个含占位符的层级之前的固定部分
+    base: PathBuf,
+    /// 其后各级目录的模板，已去掉末尾的 `{anime}`
+    components: Vec<String>,
+}
+
+impl TargetTemplate {
+    /// 判断 `--target` 是否为模板（含 `{` 占位符）。
+    #[must_use]
+    pub fn is_template(path: &Path) -> bool {
+        path.to_string_lossy().contains('{')
+    }
+
+    /// 解析模板。
+    ///
+    /// # 错误
+    ///
+    /// - `AppError::ParseError` - 含未知占位符、花括号不成对，或 `{anime}` 不在最后一级
+    pub fn parse(template: &str) -> Result<Self> {
+        let invalid = |reason: &str| {
+            Err(AppError::ParseError(format!(
+                "无效的目标模板 {template:?}: {reason}"
+            )))
+        };
+        let path = Path::new(template);
+        let mut base = PathBuf::new();
+        let mut components = Vec::new();
+        for component in path.components() {
+            let text = component.as_os_str().to_string_lossy();
+            if components.is_empty() && !text.contains(['{', '}']) {
+                base.push(component);
+                continue;
+            }
+            if let Err(reason) = validate_component(&text) {
+                return invalid(&reason);
+            }
+            components.push(text.into_owned());
+        }
+        match components.iter().position(|text| text.contains("{anime}")) {
+            Some(index) if index + 1 == components.len() && components[index] == "{anime}" => {
+                components.pop();
+            }
+            Some(_) => return invalid("{anime} 只能单独作为最后一级目录"),
+            None => {}
+        }
+        Ok(Self { base, components })
+    }
+
+    /// 模板中固定不变的前缀目录，用作扫描排除与相对路径的基准
+    #[must_use]
+    pub fn base(&self) -> &Path {
+        &self.base
+    }
+
+    /// 按文件信息渲染目标根目录，番剧目录由整理流程在其下创建。
+    #[must_use]
+    pub fn render(&self, info: &AnimeFileInfo) -> PathBuf {
+        let mut root = self.base.clone();
+        for component in &self.components {
+            root.push(render_component(component, info));
+        }
+        root
+    }
+}
+
+/// 校验单级目录模板中的占位符。
+fn validate_component(component: &str) -> std::result::Result<(), String> {
+    let mut rest = component;
+    while let Some(start) = rest.find('{') {
+        if rest[..start].contains('}') {
+            return Err("花括号不成对".to_string());
+        }
+        let Some(end) = rest[start..].find('}') else {
+            return Err("花括号不成对".to_string());
+        };
+        let name = &rest[start + 1..start + end];
+        if !matches!(name, "year" | "publisher" | "anime") {
+            return Err(format!(
+                "未知占位符 {{{name}}}，可用 {{year}}、{{publisher}}、{{anime}}"
+            ));
+        }
+        rest = &rest[start + end + 1..];
+    }
+    if rest.contains('}') {
+        return Err("花括号不成对".to_string());
+    }
+    Ok(())
+}
+
+/// 渲染单级目录；任一占位符缺失值时整级回退为 [`MISSING_FIELD_DIR`]。
+fn render_component(component: &str, info: &AnimeFileInfo) -> String {
+    let year = info.year().map(|year| year.to_string());
+    let publisher = Some(info.publisher.trim()).filter(|publisher| !publisher.is_empty());
+    let mut rendered = component.to_string();
+    for (placeholder, value) in [
+        ("{year}", year.as_deref()),
+        ("{publisher}", publisher),
+        ("{anime}", Some(info.anime_name.as_str())),
+    ] {
+        if !rendered.contains(placeholder) {
+            continue;
+        }
+        let Some(value) = value else {
+            return MISSING_FIELD_DIR.to_string();
+        };
+        rendered = rendered.replace(placeholder, &value.replace(['/', '\\'], "_"));
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::FilenameParser;
+
+    fn info(filename: &str) -> AnimeFileInfo {
+        FilenameParser::parse_str(filename).unwrap()
+    }
+
+    #[test]
+    fn files_are_routed_by_year() {
+        let template = TargetTemplate::parse("/library/{year}/{anime}").unwrap();
+
+        assert_eq!(
+            template.render(&info("[ANi] Test (2023) - 01 [1080P].mkv")),
+            Path::new("/library/2023")
+        );
+        assert_eq!(
+            template.render(&info("[ANi] Other - 01 [2024][1080P].mkv")),
+            Path::new("/library/2024")
+        );
+    }
+
+    #[test]
+    fn missing_year_falls_back_to_unknown_dir() {
+        let template = TargetTemplate::parse("/library/{year}").unwrap();
+
+        assert_eq!(
+            template.render(&info("[ANi] Test - 01 [1080P].mkv")),
+            Path::new("/library").join(MISSING_FIELD_DIR)
+        );
+    }
+
+    #[test]
+    fn mixed_component_renders_all_fields() {
+        let template = TargetTemplate::parse("/library/{publisher}-{year}").unwrap();
+
+        assert_eq!(
+            template.render(&info("[ANi] Test (2024) - 01 [1080P].mkv")),
+            Path::new("/library/ANi-2024")
+        );
+    }
+
+    #[test]
+    fn base_stops_at_first_placeholder() {
+        let template = TargetTemplate::parse("/mnt/media/anime/{year}").unwrap();
+        assert_eq!(template.base(), Path::new("/mnt/media/anime"));
+        assert!(TargetTemplate::is_template(Path::new("/library/{year}")));
+        assert!(!TargetTemplate::is_template(Path::new("/library/2024")));
+    }
+
+    #[test]
+    fn invalid_templates_are_rejected() {
+        for template in [
+            "/library/{season}",
+            "/library/{year",
+            "/library/year}",
+            "/library/{anime}/{year}",
+            "/library/{anime} Extra",
+        ] {
+            assert!(
+                matches!(
+                    TargetTemplate::parse(template),
+                    Err(AppError::ParseError(_))
+                ),
+                "{template}"
+            );
+        }
+    }
+}
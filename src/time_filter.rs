@@ -0,0 +1,209 @@
+//! 按修改时间过滤模块
+//!
+//! 解析 `--newer-than` 的取值：相对时长（如 `7d`、`12h`）或绝对日期（如 `2024-01-01`，
+//! 按本地时区的当天零点计算），并换算为文件修改时间的下限。
+//!
+//! # 示例
+//!
+//! ```
+//! use anime_organizer::time_filter::NewerThan;
+//! use std::time::{Duration, SystemTime};
+//!
+//! let now = SystemTime::UNIX_EPOCH + Duration::from_secs(30 * 86_400);
+//! let newer_than: NewerThan = "7d".parse()?;
+//! assert_eq!(
+//!     newer_than.cutoff(now),
+//!     SystemTime::UNIX_EPOCH + Duration::from_secs(23 * 86_400)
+//! );
+//! # Ok::<(), anime_organizer::AppError>(())
+//! ```
+
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use time::{Date, Month};
+
+use crate::error::{AppError, Result};
+
+/// 修改时间下限
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewerThan {
+    /// 距当前时间的相对时长
+    Relative(Duration),
+    /// 本地时区某一天的零点
+    Since(Date),
+}
+
+impl FromStr for NewerThan {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let value = value.trim();
+        if let Some(duration) = parse_relative(value) {
+            return Ok(Self::Relative(duration));
+        }
+        if let Some(date) = parse_date(value) {
+            return Ok(Self::Since(date));
+        }
+        Err(AppError::ParseError(format!(
+            "无效的时间阈值 {value:?}，应为相对时长（如 7d、12h、30m、2w）或日期（如 2024-01-01）"
+        )))
+    }
+}
+
+impl NewerThan {
+    /// 以本地时区换算修改时间下限。
+    #[must_use]
+    pub fn cutoff(&self, now: SystemTime) -> SystemTime {
+        match self {
+            Self::Relative(duration) => {
+                now.checked_sub(*duration).unwrap_or(SystemTime::UNIX_EPOCH)
+            }
+            Self::Since(date) => {
+                let utc_midnight = date.midnight().assume_utc().unix_timestamp();
+                self.cutoff_with_offset(now, local_utc_offset_seconds(utc_midnight))
+            }
+        }
+    }
+
+    /// 以指定的 UTC 偏移（秒，东区为正）换算修改时间下限。
+    #[must_use]
+    pub fn cutoff_with_offset(&self, now: SystemTime, utc_offset_seconds: i64) -> SystemTime {
+        match self {
+            Self::Relative(_) => self.cutoff(now),
+            Self::Since(date) => {
+                let timestamp = date.midnight().assume_utc().unix_timestamp() - utc_offset_seconds;
+                match u64::try_from(timestamp) {
+                    Ok(seconds) => SystemTime::UNIX_EPOCH + Duration::from_secs(seconds),
+                    Err(_) => SystemTime::UNIX_EPOCH,
+                }
+            }
+        }
+    }
+}
+
+/// 文件修改时间是否不早于 `cutoff`；无法读取修改时间时视为不满足。
+#[must_use]
+pub fn modified_since(path: &std::path::Path, cutoff: SystemTime) -> bool {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .is_ok_and(|modified| modified >= cutoff)
+}
+
+fn parse_relative(value: &str) -> Option<Duration> {
+    let split = value.find(|ch: char| !ch.is_ascii_digit())?;
+    let (amount, unit) = value.split_at(split);
+    let amount = amount.parse::<u64>().ok()?;
+    let unit_seconds = match unit.to_ascii_lowercase().as_str() {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return None,
+    };
+    amount.checked_mul(unit_seconds).map(Duration::from_secs)
+}
+
+fn parse_date(value: &str) -> Option<Date> {
+    let mut parts = value.splitn(3, '-');
+    let year = parts.next()?.parse::<i32>().ok()?;
+    let month = parts.next()?.parse::<u8>().ok()?;
+    let day = parts.next()?.parse::<u8>().ok()?;
+    Date::from_calendar_date(year, Month::try_from(month).ok()?, day).ok()
+}
+
+/// 本地时区在 `timestamp` 时刻相对 UTC 的偏移（秒）。
+#[cfg(unix)]
+fn local_utc_offset_seconds(timestamp: i64) -> i64 {
+    let time = timestamp as libc::time_t;
+    // SAFETY: localtime_r 是可重入版本，只读取 `time` 并写入调用方提供的 `tm`。
+    let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+    let result = unsafe { libc::localtime_r(&time, &mut tm) };
+    if result.is_null() {
+        0
+    } else {
+        tm.tm_gmtoff as i64
+    }
+}
+
+/// 非 Unix 平台暂无本地时区来源，按 UTC 处理。
+#[cfg(not(unix))]
+fn local_utc_offset_seconds(_timestamp: i64) -> i64 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(seconds: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(seconds)
+    }
+
+    #[test]
+    fn parses_relative_durations() {
+        assert_eq!(
+            "7d".parse::<NewerThan>().unwrap(),
+            NewerThan::Relative(Duration::from_secs(7 * 86_400))
+        );
+        assert_eq!(
+            "12H".parse::<NewerThan>().unwrap(),
+            NewerThan::Relative(Duration::from_secs(12 * 3_600))
+        );
+        assert_eq!(
+            "2w".parse::<NewerThan>().unwrap(),
+            NewerThan::Relative(Duration::from_secs(14 * 86_400))
+        );
+        assert_eq!(
+            "30m".parse::<NewerThan>().unwrap(),
+            NewerThan::Relative(Duration::from_secs(1_800))
+        );
+    }
+
+    #[test]
+    fn parses_absolute_dates() {
+        assert_eq!(
+            "2024-01-01".parse::<NewerThan>().unwrap(),
+            NewerThan::Since(Date::from_calendar_date(2024, Month::January, 1).unwrap())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        for value in ["", "7", "d", "7y", "2024-13-01", "2024-02-30", "yesterday"] {
+            assert!(value.parse::<NewerThan>().is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn relative_cutoff_subtracts_from_now() {
+        let newer_than = "1h".parse::<NewerThan>().unwrap();
+        assert_eq!(newer_than.cutoff(at(7_200)), at(3_600));
+    }
+
+    #[test]
+    fn date_cutoff_uses_local_midnight() {
+        let newer_than = "2024-01-01".parse::<NewerThan>().unwrap();
+        let utc_midnight = 1_704_067_200;
+        assert_eq!(newer_than.cutoff_with_offset(at(0), 0), at(utc_midnight));
+        // UTC+8 的零点是 UTC 前一天 16:00。
+        assert_eq!(
+            newer_than.cutoff_with_offset(at(0), 8 * 3_600),
+            at(utc_midnight - 8 * 3_600)
+        );
+    }
+
+    #[test]
+    fn modified_since_compares_mtime() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("a.mkv");
+        std::fs::write(&path, b"video").unwrap();
+        assert!(modified_since(&path, at(0)));
+        assert!(!modified_since(
+            &path,
+            SystemTime::now() + Duration::from_secs(3_600)
+        ));
+        assert!(!modified_since(&directory.path().join("missing"), at(0)));
+    }
+}
@@ -0,0 +1,476 @@
+//! 可撤销的整理事务
+//!
+//! [`Transaction`] 逐条执行 [`OperationPlan`] 并记录实际完成的操作；中途失败时调用
+//! [`Transaction::rollback`] 按相反顺序撤销：移动过的文件搬回源位置，复制或硬链接产生的
+//! 目标文件被删除，事务中新建的目标目录在变空后一并删除。[`execute_all`] 把"全部成功或
+//! 全部撤销"封装为一次调用，`aniorg apply --atomic` 即基于它实现。
+//!
+//! 按冲突策略被覆盖的已有目标在操作前以硬链接（不支持时复制）保留一份，撤销时放回原处，
+//! 事务确认后删除；`backup` 时操作中生成的 `.bak` 备份（见 [`FileOrganizer::backup_path`]）
+//! 在撤销时一并删除。在操作已完成、随后的校验（如 `verify_readable`）才失败的条目不在撤销范围内。
+//!
+//! # 示例
+//!
+//! ```no_run
+//! use anime_organizer::filesystem::StdFileSystem;
+//! use anime_organizer::organizer::{OperationMode, OperationPlan, OrganizeOptions};
+//! use anime_organizer::transaction;
+//!
+//! let operations = vec![OperationPlan {
+//!     source: "/downloads/[ANi] 测试 - 01 [1080P].mp4".into(),
+//!     target: "/anime/测试/[ANi] 测试 - 01 [1080P].mp4".into(),
+//! }];
+//! let options = OrganizeOptions::new(OperationMode::Move);
+//! let outcomes = transaction::execute_all(&StdFileSystem, &operations, &options)?;
+//! # Ok::<(), anime_organizer::AppError>(())
+//! ```
+
+use crate::error::{AppError, Result};
+use crate::filesystem::FileSystem;
+use crate::organizer::{
+    ExecutedOp, FileOrganizer, OperationOutcome, OperationPlan, OrganizeOptions,
+};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 记录已完成操作、可整体撤销的整理事务
+pub struct Transaction<'a> {
+    file_system: &'a dyn FileSystem,
+    options: &'a OrganizeOptions,
+    /// 按执行顺序记录的已完成操作
+    completed: Vec<Completed>,
+    /// 事务中新建的目录，父目录在前
+    created_dirs: Vec<PathBuf>,
+}
+
+/// 事务中已完成的一个操作
+struct Completed {
+    plan: OperationPlan,
+    op: ExecutedOp,
+    /// 操作前已存在、可能被覆盖的目标的保留副本
+    replaced: Option<PathBuf>,
+    /// 操作中为被覆盖的目标新建的备份
+    backup: Option<PathBuf>,
+}
+
+impl<'a> Transaction<'a> {
+    /// 创建空事务
+    #[must_use]
+    pub fn new(file_system: &'a dyn FileSystem, options: &'a OrganizeOptions) -> Self {
+        Self {
+            file_system,
+            options,
+            completed: Vec::new(),
+            created_dirs: Vec::new(),
+        }
+    }
+
+    /// 执行单个操作（见 [`FileOrganizer::execute_plan`]），实际执行的操作记入事务。
+    ///
+    /// # 错误
+    ///
+    /// 返回操作本身的错误；事务状态保持不变，由调用方决定是否 [`rollback`](Self::rollback)。
+    pub fn execute(&mut self, plan: &OperationPlan) -> Result<OperationOutcome> {
+        let missing_dirs = plan
+            .target
+            .parent()
+            .map(missing_ancestors)
+            .unwrap_or_default();
+        let replaced = self.keep_existing_target(&plan.target)?;
+        let backups_before = replaced
+            .as_ref()
+            .filter(|_| self.options.backup)
+            .map(|_| backups_of(&plan.target));
+        let result = FileOrganizer::execute_plan(self.file_system, plan, self.options);
+        // 目录可能在操作失败前已创建，无论成败都只记录确实新建出来的部分
+        self.created_dirs
+            .extend(missing_dirs.into_iter().filter(|dir| dir.is_dir()));
+        match (result, replaced) {
+            (Ok(OperationOutcome::Performed(op)), replaced) => {
+                let backup = backups_before.and_then(|before| {
+                    backups_of(&plan.target)
+                        .into_iter()
+                        .find(|backup| !before.contains(backup))
+                });
+                self.completed.push(Completed {
+                    plan: plan.clone(),
+                    op,
+                    replaced,
+                    backup,
+                });
+                Ok(OperationOutcome::Performed(op))
+            }
+            (result, Some(kept)) => {
+                // 失败时已有目标可能已被删除，放回原处；否则保留副本已无用
+                if plan.target.symlink_metadata().is_err() {
+                    let _ = self.file_system.rename(&kept, &plan.target);
+                } else {
+                    let _ = self.file_system.remove_file(&kept);
+                }
+                result
+            }
+            (result, None) => result,
+        }
+    }
+
+    /// 目标已存在时在同目录下为其保留一份（优先硬链接，不额外占用空间），供撤销时放回
+    fn keep_existing_target(&self, target: &Path) -> Result<Option<PathBuf>> {
+        if !target
+            .symlink_metadata()
+            .is_ok_and(|metadata| !metadata.is_dir())
+        {
+            return Ok(None);
+        }
+        let name = target.file_name().unwrap_or_default().to_string_lossy();
+        let kept = target.with_file_name(format!(".{name}.aniorg-rollback"));
+        let _ = self.file_system.remove_file(&kept);
+        if self.file_system.hard_link(target, &kept).is_err() {
+            self.file_system.copy(target, &kept)?;
+        }
+        Ok(Some(kept))
+    }
+
+    /// 已完成（将被撤销）的操作数
+    #[must_use]
+    pub fn completed(&self) -> usize {
+        self.completed.len()
+    }
+
+    /// 确认事务，保留全部已完成的操作，返回其数量
+    pub fn commit(self) -> usize {
+        for kept in self
+            .completed
+            .iter()
+            .filter_map(|done| done.replaced.as_ref())
+        {
+            let _ = self.file_system.remove_file(kept);
+        }
+        self.completed.len()
+    }
+
+    /// 按相反顺序撤销全部已完成的操作，返回撤销失败的错误；
+    /// 单个撤销失败不会中断其余撤销。
+    pub fn rollback(self) -> Vec<AppError> {
+        let mut errors = Vec::new();
+        for done in self.completed.iter().rev() {
+            if let Err(error) = self.undo(done) {
+                errors.push(error);
+            }
+        }
+        // 新建目录中若还有其他文件（如事务外写入的）则删除失败，保留即可
+        for dir in self.created_dirs.iter().rev() {
+            let _ = self.file_system.remove_dir(dir);
+        }
+        errors
+    }
+
+    fn undo(&self, done: &Completed) -> Result<()> {
+        let plan = &done.plan;
+        let failed = |error: std::io::Error| AppError::FileOperation {
+            path: plan.target.clone(),
+            message: format!("撤销失败: {error}"),
+        };
+        match done.op {
+            ExecutedOp::Moved => {
+                // `--leave-symlink` 在源位置留下的链接需先移除
+                if plan
+                    .source
+                    .symlink_metadata()
+                    .is_ok_and(|metadata| metadata.is_symlink())
+                {
+                    self.file_system.remove_file(&plan.source).map_err(failed)?;
+                }
+                if self.file_system.rename(&plan.target, &plan.source).is_err() {
+                    // 跨设备移动无法直接改名回去，改为复制后删除
+                    self.file_system
+                        .copy(&plan.target, &plan.source)
+                        .map_err(failed)?;
+                    self.file_system.remove_file(&plan.target).map_err(failed)?;
+                }
+            }
+            ExecutedOp::Copied | ExecutedOp::Linked => {
+                self.file_system.remove_file(&plan.target).map_err(failed)?;
+            }
+        }
+        if let Some(kept) = &done.replaced {
+            self.file_system
+                .rename(kept, &plan.target)
+                .map_err(failed)?;
+        }
+        if let Some(backup) = &done.backup {
+            self.file_system.remove_file(backup).map_err(failed)?;
+        }
+        Ok(())
+    }
+}
+
+/// 在一个事务中依次执行全部操作：任一操作失败时撤销此前已完成的操作并停止。
+///
+/// # 错误
+///
+/// 任一操作失败时返回 [`AppError::TransactionRolledBack`]，其中包含原始错误与撤销结果。
+pub fn execute_all(
+    file_system: &dyn FileSystem,
+    operations: &[OperationPlan],
+    options: &OrganizeOptions,
+) -> Result<Vec<OperationOutcome>> {
+    let mut transaction = Transaction::new(file_system, options);
+    let mut outcomes = Vec::with_capacity(operations.len());
+    for operation in operations {
+        match transaction.execute(operation) {
+            Ok(outcome) => outcomes.push(outcome),
+            Err(error) => {
+                let rolled_back = transaction.completed();
+                return Err(AppError::TransactionRolledBack {
+                    error: Box::new(error),
+                    rolled_back,
+                    rollback_errors: transaction.rollback(),
+                });
+            }
+        }
+    }
+    transaction.commit();
+    Ok(outcomes)
+}
+
+/// `target` 旁已有的备份文件 `原名.bak`、`原名.{timestamp}.bak` 等（见 [`FileOrganizer::backup_path`]）
+fn backups_of(target: &Path) -> HashSet<PathBuf> {
+    let (Some(dir), Some(name)) = (target.parent(), target.file_name()) else {
+        return HashSet::new();
+    };
+    let prefix = format!("{}.", name.to_string_lossy());
+    fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name().is_some_and(|file_name| {
+                let file_name = file_name.to_string_lossy();
+                file_name.starts_with(&prefix) && file_name.ends_with(".bak")
+            })
+        })
+        .collect()
+}
+
+/// `dir` 及其祖先中尚不存在的目录，父目录在前
+fn missing_ancestors(dir: &Path) -> Vec<PathBuf> {
+    let mut missing: Vec<PathBuf> = dir
+        .ancestors()
+        .take_while(|ancestor| !ancestor.as_os_str().is_empty() && !ancestor.exists())
+        .map(Path::to_path_buf)
+        .collect();
+    missing.reverse();
+    missing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filesystem::StdFileSystem;
+    use crate::organizer::OperationMode;
+
+    const NAMES: [&str; 3] = [
+        "[ANi] Test Show - 01 [1080P].mkv",
+        "[ANi] Test Show - 02 [1080P].mkv",
+        "[ANi] Test Show - 03 [1080P].mkv",
+    ];
+
+    /// 在 `source/` 中写入三集，返回指向 `target/Test Show/` 的操作；`missing` 指定的那一集不写入
+    fn fixture(root: &Path, missing: Option<usize>) -> Vec<OperationPlan> {
+        let source = root.join("source");
+        fs::create_dir_all(&source).unwrap();
+        NAMES
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                if missing != Some(index) {
+                    fs::write(source.join(name), format!("episode {index}")).unwrap();
+                }
+                OperationPlan {
+                    source: source.join(name),
+                    target: root.join("target").join("Test Show").join(name),
+                }
+            })
+            .collect()
+    }
+
+    fn source_listing(root: &Path) -> Vec<(String, String)> {
+        let mut files: Vec<_> = fs::read_dir(root.join("source"))
+            .unwrap()
+            .map(|entry| {
+                let path = entry.unwrap().path();
+                (
+                    path.file_name().unwrap().to_string_lossy().into_owned(),
+                    fs::read_to_string(&path).unwrap(),
+                )
+            })
+            .collect();
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn failure_midway_moves_files_back_to_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let operations = fixture(dir.path(), Some(2));
+        let before = source_listing(dir.path());
+        let options = OrganizeOptions::new(OperationMode::Move);
+
+        let error = execute_all(&StdFileSystem, &operations, &options).unwrap_err();
+
+        assert!(matches!(
+            error,
+            AppError::TransactionRolledBack { rolled_back: 2, ref rollback_errors, .. }
+                if rollback_errors.is_empty()
+        ));
+        assert_eq!(source_listing(dir.path()), before);
+        assert!(!dir.path().join("target").exists());
+    }
+
+    #[test]
+    fn failure_midway_removes_copied_and_linked_targets() {
+        for mode in [OperationMode::Copy, OperationMode::Link] {
+            let dir = tempfile::tempdir().unwrap();
+            fs::create_dir_all(dir.path().join("target")).unwrap();
+            let operations = fixture(dir.path(), Some(1));
+            let before = source_listing(dir.path());
+
+            let result = execute_all(&StdFileSystem, &operations, &OrganizeOptions::new(mode));
+
+            assert!(
+                matches!(
+                    result,
+                    Err(AppError::TransactionRolledBack { rolled_back: 1, .. })
+                ),
+                "{mode}"
+            );
+            assert_eq!(source_listing(dir.path()), before);
+            // 事务前已存在的目录保留，新建的子目录被删除
+            assert!(dir.path().join("target").is_dir());
+            assert!(!dir.path().join("target").join("Test Show").exists());
+        }
+    }
+
+    #[test]
+    fn success_keeps_all_operations() {
+        let dir = tempfile::tempdir().unwrap();
+        let operations = fixture(dir.path(), None);
+        let options = OrganizeOptions::new(OperationMode::Move);
+
+        let outcomes = execute_all(&StdFileSystem, &operations, &options).unwrap();
+
+        assert_eq!(
+            outcomes,
+            vec![OperationOutcome::Performed(ExecutedOp::Moved); NAMES.len()]
+        );
+        assert!(source_listing(dir.path()).is_empty());
+        for operation in &operations {
+            assert!(operation.target.is_file());
+        }
+    }
+
+    #[test]
+    fn rollback_restores_overwritten_targets() {
+        for mode in [
+            OperationMode::Move,
+            OperationMode::Copy,
+            OperationMode::Link,
+        ] {
+            let dir = tempfile::tempdir().unwrap();
+            let operations = fixture(dir.path(), Some(1));
+            let existing = &operations[0].target;
+            fs::create_dir_all(existing.parent().unwrap()).unwrap();
+            fs::write(existing, "old version").unwrap();
+            let before = source_listing(dir.path());
+            let options = OrganizeOptions {
+                force: true,
+                ..OrganizeOptions::new(mode)
+            };
+
+            let result = execute_all(&StdFileSystem, &operations, &options);
+
+            assert!(
+                matches!(
+                    result,
+                    Err(AppError::TransactionRolledBack { rolled_back: 1, ref rollback_errors, .. })
+                        if rollback_errors.is_empty()
+                ),
+                "{mode}"
+            );
+            assert_eq!(source_listing(dir.path()), before, "{mode}");
+            assert_eq!(
+                fs::read_to_string(existing).unwrap(),
+                "old version",
+                "{mode}"
+            );
+            assert_eq!(fs::read_dir(existing.parent().unwrap()).unwrap().count(), 1);
+        }
+    }
+
+    #[test]
+    fn rollback_removes_backups_of_overwritten_targets() {
+        let dir = tempfile::tempdir().unwrap();
+        let operations = fixture(dir.path(), Some(1));
+        let existing = &operations[0].target;
+        fs::create_dir_all(existing.parent().unwrap()).unwrap();
+        fs::write(existing, "old version").unwrap();
+        let options = OrganizeOptions {
+            backup: true,
+            ..OrganizeOptions::new(OperationMode::Copy)
+        };
+
+        let result = execute_all(&StdFileSystem, &operations, &options);
+
+        assert!(matches!(
+            result,
+            Err(AppError::TransactionRolledBack { rolled_back: 1, ref rollback_errors, .. })
+                if rollback_errors.is_empty()
+        ));
+        assert_eq!(fs::read_to_string(existing).unwrap(), "old version");
+        assert_eq!(fs::read_dir(existing.parent().unwrap()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn commit_discards_kept_copies_of_overwritten_targets() {
+        let dir = tempfile::tempdir().unwrap();
+        let operations = fixture(dir.path(), None);
+        let existing = &operations[0].target;
+        fs::create_dir_all(existing.parent().unwrap()).unwrap();
+        fs::write(existing, "old version").unwrap();
+        let options = OrganizeOptions {
+            force: true,
+            ..OrganizeOptions::new(OperationMode::Copy)
+        };
+
+        execute_all(&StdFileSystem, &operations, &options).unwrap();
+
+        assert_eq!(fs::read_to_string(existing).unwrap(), "episode 0");
+        assert_eq!(
+            fs::read_dir(existing.parent().unwrap()).unwrap().count(),
+            NAMES.len()
+        );
+    }
+
+    #[test]
+    fn rollback_leaves_skipped_targets_alone() {
+        let dir = tempfile::tempdir().unwrap();
+        let operations = fixture(dir.path(), None);
+        let existing = &operations[0];
+        fs::create_dir_all(existing.target.parent().unwrap()).unwrap();
+        fs::copy(&existing.source, &existing.target).unwrap();
+        let options = OrganizeOptions::new(OperationMode::Copy);
+        let mut transaction = Transaction::new(&StdFileSystem, &options);
+
+        assert!(matches!(
+            transaction.execute(existing).unwrap(),
+            OperationOutcome::Skipped(_)
+        ));
+        transaction.execute(&operations[1]).unwrap();
+        assert_eq!(transaction.completed(), 1);
+        assert!(transaction.rollback().is_empty());
+
+        assert!(existing.target.is_file());
+        assert!(!operations[1].target.exists());
+    }
+}
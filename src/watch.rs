@@ -0,0 +1,450 @@
+//! 目录监听模块
+//!
+//! [`DirectoryWatcher`] 根据系统文件事件（Linux 上为 inotify）检测源目录中新落地的文件，
+//! 系统不支持事件时（如部分网络文件系统）退回定时轮询。文件大小与修改时间需在 `settle`
+//! 时长内保持不变才视为写入完成；写入过程中的多次变化只会推迟就绪时间，相当于去抖。
+
+use crate::error::{AppError, Result};
+use crate::scan::{self, ScanOptions};
+use ::notify::{Config, Event, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant, SystemTime};
+
+/// 尚未写完（或刚写完、仍在等待稳定）的文件
+#[derive(Debug, Clone)]
+struct PendingFile {
+    size: u64,
+    modified: Option<SystemTime>,
+    changed_at: Instant,
+}
+
+/// 跟踪新文件直到其大小稳定
+///
+/// ```
+/// use anime_organizer::watch::SettleTracker;
+/// use std::path::PathBuf;
+/// use std::time::{Duration, Instant};
+///
+/// let mut tracker = SettleTracker::new(Duration::from_secs(2));
+/// let start = Instant::now();
+/// let path = PathBuf::from("a.mkv");
+/// tracker.observe(path.clone(), 100, None, start);
+/// assert!(tracker.take_settled(start + Duration::from_secs(1)).is_empty());
+/// assert_eq!(tracker.take_settled(start + Duration::from_secs(2)), vec![path]);
+/// ```
+#[derive(Debug)]
+pub struct SettleTracker {
+    settle: Duration,
+    known: HashSet<PathBuf>,
+    pending: HashMap<PathBuf, PendingFile>,
+}
+
+impl SettleTracker {
+    /// 创建跟踪器，`settle` 为判定写入完成所需的静默时长
+    #[must_use]
+    pub fn new(settle: Duration) -> Self {
+        Self {
+            settle,
+            known: HashSet::new(),
+            pending: HashMap::new(),
+        }
+    }
+
+    /// 将文件记为已处理，之后不再触发（用于启动时已存在的文件）
+    pub fn mark_known(&mut self, paths: impl IntoIterator<Item = PathBuf>) {
+        for path in paths {
+            self.pending.remove(&path);
+            self.known.insert(path);
+        }
+    }
+
+    /// 记录一次对文件的观察；大小或修改时间变化时重新计时
+    pub fn observe(
+        &mut self,
+        path: PathBuf,
+        size: u64,
+        modified: Option<SystemTime>,
+        now: Instant,
+    ) {
+        if self.known.contains(&path) {
+            return;
+        }
+        match self.pending.get_mut(&path) {
+            Some(pending) if pending.size == size && pending.modified == modified => {}
+            Some(pending) => {
+                pending.size = size;
+                pending.modified = modified;
+                pending.changed_at = now;
+            }
+            None => {
+                self.pending.insert(
+                    path,
+                    PendingFile {
+                        size,
+                        modified,
+                        changed_at: now,
+                    },
+                );
+            }
+        }
+    }
+
+    /// 取出已稳定的文件（按路径排序），并将其记为已处理
+    pub fn take_settled(&mut self, now: Instant) -> Vec<PathBuf> {
+        let mut settled = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| now.duration_since(pending.changed_at) >= self.settle)
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        settled.sort();
+        self.mark_known(settled.iter().cloned());
+        settled
+    }
+
+    /// 忘记已不存在的文件，使同名文件再次出现时重新触发
+    pub fn retain_present(&mut self, present: &HashSet<PathBuf>) {
+        self.known.retain(|path| present.contains(path));
+        self.pending.retain(|path, _| present.contains(path));
+    }
+
+    /// 忘记 `path` 及其下的所有文件（文件被删除或移走、目录被删除时）
+    pub fn forget(&mut self, path: &Path) {
+        self.known.retain(|known| !known.starts_with(path));
+        self.pending.retain(|pending, _| !pending.starts_with(path));
+    }
+
+    /// 最早可能有文件稳定的时刻；没有等待中的文件时返回 `None`
+    #[must_use]
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending
+            .values()
+            .map(|pending| pending.changed_at + self.settle)
+            .min()
+    }
+
+    /// 正在等待稳定的文件数
+    #[must_use]
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// 扫描 `root` 一次并返回本轮已稳定的新文件
+    pub fn poll(&mut self, root: &Path, options: &ScanOptions, now: Instant) -> Vec<PathBuf> {
+        let files = scan::collect_files(root, options);
+        let present = files.iter().cloned().collect::<HashSet<_>>();
+        self.retain_present(&present);
+        for path in files {
+            // 扫描与读取元数据之间文件可能已被移走，下一轮会自然忘记。
+            if let Ok(metadata) = fs::metadata(&path) {
+                self.observe(path, metadata.len(), metadata.modified().ok(), now);
+            }
+        }
+        self.take_settled(now)
+    }
+}
+
+/// 由文件事件驱动的目录监听器，用 [`SettleTracker`] 对新文件去抖
+///
+/// 启动时已存在的文件不会触发；新文件（含整个移入的目录中的文件）按 `options`
+/// 过滤扩展名、隐藏项与排除目录后等待稳定。
+pub struct DirectoryWatcher {
+    root: PathBuf,
+    options: ScanOptions,
+    tracker: SettleTracker,
+    events: Receiver<::notify::Result<Event>>,
+    _watcher: Box<dyn Watcher + Send>,
+}
+
+impl DirectoryWatcher {
+    /// 开始监听 `root`；无法使用系统文件事件时改为每隔 `poll_interval` 轮询一次。
+    ///
+    /// # 错误
+    ///
+    /// 事件监听与轮询都无法启动时返回 [`AppError::WatchError`]。
+    pub fn new(
+        root: &Path,
+        options: ScanOptions,
+        settle: Duration,
+        poll_interval: Duration,
+    ) -> Result<Self> {
+        let (sender, events) = mpsc::channel();
+        let watcher: Box<dyn Watcher + Send> =
+            match watch_with::<RecommendedWatcher>(root, sender.clone(), Config::default()) {
+                Ok(watcher) => Box::new(watcher),
+                Err(error) => {
+                    eprintln!(
+                        "警告：无法使用文件事件监听（{error}），改为每 {poll_interval:?} 轮询"
+                    );
+                    let config = Config::default().with_poll_interval(poll_interval);
+                    Box::new(
+                        watch_with::<PollWatcher>(root, sender, config)
+                            .map_err(|error| AppError::WatchError(error.to_string()))?,
+                    )
+                }
+            };
+        // 先建立监听再记录已有文件，避免两者之间落地的文件被漏掉
+        let mut tracker = SettleTracker::new(settle);
+        tracker.mark_known(scan::collect_files(root, &options));
+        Ok(Self {
+            root: root.to_path_buf(),
+            options,
+            tracker,
+            events,
+            _watcher: watcher,
+        })
+    }
+
+    /// 阻塞直到有新文件稳定，返回这些文件（按路径排序）。
+    ///
+    /// # 错误
+    ///
+    /// 监听意外中止时返回 [`AppError::WatchError`]。
+    pub fn next_settled(&mut self) -> Result<Vec<PathBuf>> {
+        loop {
+            let received = match self.tracker.next_deadline() {
+                Some(deadline) => self
+                    .events
+                    .recv_timeout(deadline.saturating_duration_since(Instant::now())),
+                None => self
+                    .events
+                    .recv()
+                    .map_err(|_| RecvTimeoutError::Disconnected),
+            };
+            match received {
+                Ok(event) => {
+                    self.handle(event);
+                    while let Ok(event) = self.events.try_recv() {
+                        self.handle(event);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => self.refresh_pending(),
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err(AppError::WatchError("文件事件通道已关闭".to_string()));
+                }
+            }
+            let settled = self.tracker.take_settled(Instant::now());
+            if !settled.is_empty() {
+                return Ok(settled);
+            }
+        }
+    }
+
+    fn handle(&mut self, event: ::notify::Result<Event>) {
+        let event = match event {
+            Ok(event) => event,
+            Err(error) => {
+                eprintln!("警告：文件事件出错: {error}");
+                return;
+            }
+        };
+        // 读取不改变文件；扫描移入的目录本身也会产生访问事件
+        if event.kind.is_access() {
+            return;
+        }
+        let now = Instant::now();
+        for path in event.paths {
+            if self.is_excluded(&path) {
+                continue;
+            }
+            match fs::metadata(&path) {
+                // 整个移入的目录不会逐个报告其中的文件
+                Ok(metadata) if metadata.is_dir() => {
+                    for file in scan::collect_files(&path, &self.options) {
+                        self.observe(file, now);
+                    }
+                }
+                Ok(metadata) => {
+                    if self.accepts(&path) {
+                        self.tracker
+                            .observe(path, metadata.len(), metadata.modified().ok(), now);
+                    }
+                }
+                // 已删除或移走
+                Err(_) => self.tracker.forget(&path),
+            }
+        }
+    }
+
+    /// 等待期满时重新读取等待中的文件：没有事件（如网络文件系统）的变化同样推迟就绪
+    fn refresh_pending(&mut self) {
+        let now = Instant::now();
+        let pending = self.tracker.pending.keys().cloned().collect::<Vec<_>>();
+        for path in pending {
+            self.observe(path, now);
+        }
+    }
+
+    fn observe(&mut self, path: PathBuf, now: Instant) {
+        match fs::metadata(&path) {
+            Ok(metadata) => {
+                self.tracker
+                    .observe(path, metadata.len(), metadata.modified().ok(), now);
+            }
+            Err(_) => self.tracker.forget(&path),
+        }
+    }
+
+    /// 是否位于隐藏项、系统目录或排除目录之下（与 [`scan::collect_files`] 的规则一致）
+    fn is_excluded(&self, path: &Path) -> bool {
+        let Ok(relative) = path.strip_prefix(&self.root) else {
+            return true;
+        };
+        self.options
+            .excluded_dirs
+            .iter()
+            .any(|dir| path.starts_with(dir))
+            || !self.options.include_hidden
+                && relative.components().any(|component| {
+                    scan::is_hidden_or_system_name(&component.as_os_str().to_string_lossy())
+                })
+    }
+
+    fn accepts(&self, path: &Path) -> bool {
+        !scan::is_system_metadata_file(path)
+            && self
+                .options
+                .extensions
+                .as_ref()
+                .is_none_or(|extensions| scan::has_extension(path, extensions))
+    }
+}
+
+fn watch_with<W: Watcher>(
+    root: &Path,
+    sender: mpsc::Sender<::notify::Result<Event>>,
+    config: Config,
+) -> ::notify::Result<W> {
+    let mut watcher = W::new(sender, config)?;
+    watcher.watch(root, RecursiveMode::Recursive)?;
+    Ok(watcher)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SETTLE: Duration = Duration::from_secs(2);
+
+    #[test]
+    fn growing_file_is_not_settled() {
+        let mut tracker = SettleTracker::new(SETTLE);
+        let start = Instant::now();
+        let path = PathBuf::from("a.mkv");
+
+        tracker.observe(path.clone(), 10, None, start);
+        tracker.observe(path.clone(), 20, None, start + Duration::from_secs(1));
+        assert!(tracker
+            .take_settled(start + Duration::from_secs(2))
+            .is_empty());
+        assert_eq!(
+            tracker.take_settled(start + Duration::from_secs(3)),
+            vec![path]
+        );
+    }
+
+    #[test]
+    fn settled_file_triggers_once() {
+        let mut tracker = SettleTracker::new(SETTLE);
+        let start = Instant::now();
+        let path = PathBuf::from("a.mkv");
+
+        tracker.observe(path.clone(), 10, None, start);
+        assert_eq!(tracker.take_settled(start + SETTLE), vec![path.clone()]);
+        tracker.observe(path, 10, None, start + SETTLE);
+        assert!(tracker.take_settled(start + SETTLE * 2).is_empty());
+        assert_eq!(tracker.pending_len(), 0);
+    }
+
+    #[test]
+    fn known_files_are_ignored_until_removed() {
+        let mut tracker = SettleTracker::new(SETTLE);
+        let start = Instant::now();
+        let path = PathBuf::from("a.mkv");
+        tracker.mark_known([path.clone()]);
+
+        tracker.observe(path.clone(), 10, None, start);
+        assert!(tracker.take_settled(start + SETTLE).is_empty());
+
+        tracker.retain_present(&HashSet::new());
+        tracker.observe(path.clone(), 10, None, start + SETTLE);
+        assert_eq!(tracker.take_settled(start + SETTLE * 2), vec![path]);
+    }
+
+    #[test]
+    fn poll_reports_new_files_after_settle() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("old.mkv"), b"old").unwrap();
+        let options = ScanOptions::default();
+        let mut tracker = SettleTracker::new(SETTLE);
+        tracker.mark_known(scan::collect_files(dir.path(), &options));
+
+        let start = Instant::now();
+        let new = dir.path().join("new.mkv");
+        fs::write(&new, b"new").unwrap();
+        assert!(tracker.poll(dir.path(), &options, start).is_empty());
+        assert_eq!(
+            tracker.poll(dir.path(), &options, start + SETTLE),
+            vec![new]
+        );
+    }
+
+    #[test]
+    fn next_deadline_follows_latest_change() {
+        let mut tracker = SettleTracker::new(SETTLE);
+        let start = Instant::now();
+        assert_eq!(tracker.next_deadline(), None);
+
+        tracker.observe(PathBuf::from("a.mkv"), 10, None, start);
+        tracker.observe(PathBuf::from("b.mkv"), 10, None, start + SETTLE);
+        assert_eq!(tracker.next_deadline(), Some(start + SETTLE));
+
+        tracker.forget(Path::new("a.mkv"));
+        assert_eq!(tracker.next_deadline(), Some(start + SETTLE * 2));
+    }
+
+    fn watcher(root: &Path) -> DirectoryWatcher {
+        let options = ScanOptions::with_extensions(HashSet::from([".mkv".to_string()]));
+        DirectoryWatcher::new(
+            root,
+            options,
+            Duration::from_millis(200),
+            Duration::from_millis(50),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn watcher_reports_new_files_from_events() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("old.mkv"), b"old").unwrap();
+        let mut watcher = watcher(dir.path());
+
+        fs::write(dir.path().join(".hidden.mkv"), b"hidden").unwrap();
+        fs::write(dir.path().join("notes.txt"), b"text").unwrap();
+        let new = dir.path().join("new.mkv");
+        fs::write(&new, b"new").unwrap();
+
+        assert_eq!(watcher.next_settled().unwrap(), vec![new]);
+    }
+
+    #[test]
+    fn watcher_reports_files_in_moved_in_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let staging = tempfile::tempdir_in(dir.path().parent().unwrap()).unwrap();
+        let season = staging.path().join("Season 1");
+        fs::create_dir(&season).unwrap();
+        fs::write(season.join("01.mkv"), b"video").unwrap();
+        let mut watcher = watcher(dir.path());
+
+        fs::rename(&season, dir.path().join("Season 1")).unwrap();
+
+        assert_eq!(
+            watcher.next_settled().unwrap(),
+            vec![dir.path().join("Season 1").join("01.mkv")]
+        );
+    }
+}
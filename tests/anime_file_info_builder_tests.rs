@@ -0,0 +1,66 @@
+//! AnimeFileInfo builder tests
+//!
+//! Covers `AnimeFileInfo::builder` defaults, the fields it sets and equivalence with parser output.
+
+use anime_organizer::parser::{AnimeFileInfo, FilenameParser};
+
+#[test]
+fn test_builder_sets_every_field() {
+    let built = AnimeFileInfo::builder()
+        .publisher("LoliHouse")
+        .anime_name("Test Show")
+        .episode("11")
+        .tags("[WebRip 1080p HEVC-10bit AAC]")
+        .extension(".mkv")
+        .original_path("/downloads/a.mkv")
+        .build();
+
+    assert_eq!(built.publisher, "LoliHouse");
+    assert_eq!(built.anime_name, "Test Show");
+    assert_eq!(built.episode, "11");
+    assert_eq!(built.tags, "[WebRip 1080p HEVC-10bit AAC]");
+    assert_eq!(built.extension, ".mkv");
+    assert_eq!(built.original_path, "/downloads/a.mkv");
+    assert!(built.alt_titles.is_empty());
+    assert!(!built.is_final);
+}
+
+#[test]
+fn test_builder_matches_parser_output() {
+    let filename = "[ANi] 妖怪旅館營業中 貳 - 07 [1080P][Baha][WEB-DL][AAC AVC][CHT].mp4";
+    let built = AnimeFileInfo::builder()
+        .publisher("ANi")
+        .anime_name("妖怪旅館營業中 貳")
+        .episode("7")
+        .tags("[1080P][Baha][WEB-DL][AAC AVC][CHT]")
+        .extension("MP4")
+        .original_path(filename)
+        .build();
+
+    assert_eq!(built, FilenameParser::parse_str(filename).unwrap());
+}
+
+#[test]
+fn test_builder_defaults() {
+    let built = AnimeFileInfo::builder()
+        .anime_name("Test")
+        .episode("03")
+        .extension("mkv")
+        .build();
+
+    assert_eq!(built.publisher, "");
+    assert_eq!(built.tags, "");
+    assert_eq!(built.original_path, "");
+    assert_eq!(built.extension, ".mkv");
+    assert_eq!(built.target_filename(), "03.mkv");
+}
+
+#[test]
+fn test_builder_keeps_non_integer_episodes() {
+    for episode in ["12.5", "01+02", "SP", "電影"] {
+        assert_eq!(
+            AnimeFileInfo::builder().episode(episode).build().episode,
+            episode
+        );
+    }
+}
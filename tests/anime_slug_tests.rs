@@ -0,0 +1,62 @@
+//! Anime name slug tests
+//!
+//! Covers `AnimeFileInfo::slug` for English, Chinese and Japanese titles, with and without CJK characters.
+
+use anime_organizer::parser::{AnimeFileInfo, FilenameParser, SlugCjk};
+
+fn info(anime_name: &str) -> AnimeFileInfo {
+    AnimeFileInfo::builder().anime_name(anime_name).build()
+}
+
+#[test]
+fn test_english_names() {
+    for (name, slug) in [
+        ("Test Show", "test-show"),
+        (
+            "Re:Zero kara Hajimeru Isekai Seikatsu",
+            "rezero-kara-hajimeru-isekai-seikatsu",
+        ),
+        ("Spy x Family Season 2", "spy-x-family-season-2"),
+        ("Oshi no Ko!!", "oshi-no-ko"),
+        ("  Dr. STONE -- NEW_WORLD  ", "dr-stone-new-world"),
+        ("Fate/Zero (2011)", "fatezero-2011"),
+    ] {
+        assert_eq!(info(name).slug(), slug, "{name}");
+    }
+}
+
+#[test]
+fn test_cjk_names_are_kept() {
+    for (name, slug) in [
+        ("妖怪旅館營業中 貳", "妖怪旅館營業中-貳"),
+        (
+            "关于我转生变成史莱姆这档事 第三季",
+            "关于我转生变成史莱姆这档事-第三季",
+        ),
+        ("【我推的孩子】", "我推的孩子"),
+        ("ぼっち・ざ・ろっく！", "ぼっちざろっく"),
+        ("BanG Dream! 少女乐团派对", "bang-dream-少女乐团派对"),
+    ] {
+        assert_eq!(info(name).slug(), slug, "{name}");
+    }
+}
+
+#[test]
+fn test_cjk_names_can_be_dropped() {
+    assert_eq!(
+        info("BanG Dream! 少女乐团派对").slug_with(SlugCjk::Drop),
+        "bang-dream"
+    );
+    assert_eq!(
+        info("86 －エイティシックス－").slug_with(SlugCjk::Drop),
+        "86"
+    );
+    assert_eq!(info("妖怪旅館營業中").slug_with(SlugCjk::Drop), "");
+}
+
+#[test]
+fn test_slug_from_parsed_filename() {
+    let info = FilenameParser::parse_str("[ANi] 妖怪旅館營業中 貳 - 07 [1080P].mp4").unwrap();
+    assert_eq!(info.slug(), "妖怪旅館營業中-貳");
+    assert_eq!(info.slug_with(SlugCjk::Keep), info.slug());
+}
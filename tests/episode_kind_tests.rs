@@ -0,0 +1,99 @@
+//! Episode kind tests
+//!
+//! Covers detection of `剧场版`/`总集篇`/`特别篇` keywords and stripping them from the anime name.
+
+use anime_organizer::parser::{validate_specials_dir, EpisodeKind, FilenameParser};
+
+#[test]
+fn test_keywords_in_anime_name() {
+    for (filename, kind, stripped) in [
+        (
+            "[ANi] 劇場版 關於我轉生變成史萊姆這檔事 蒼海之淚篇 - 電影 [1080P][Baha].mp4",
+            EpisodeKind::Movie,
+            "關於我轉生變成史萊姆這檔事 蒼海之淚篇",
+        ),
+        (
+            "[Sub] 测试 剧场版 - 01 [1080P].mkv",
+            EpisodeKind::Movie,
+            "测试",
+        ),
+        (
+            "[Sub] 测试 总集篇 - 01 [1080P].mkv",
+            EpisodeKind::Recap,
+            "测试",
+        ),
+        (
+            "[Sub] 測試 總集篇 - 01 [1080P].mkv",
+            EpisodeKind::Recap,
+            "測試",
+        ),
+        (
+            "[Sub] 测试特别篇 - 02 [1080P].mkv",
+            EpisodeKind::Special,
+            "测试",
+        ),
+        (
+            "[Sub] 測試 特別篇 - 02 [1080P].mkv",
+            EpisodeKind::Special,
+            "測試",
+        ),
+    ] {
+        let info = FilenameParser::parse(filename).unwrap();
+        assert_eq!(info.episode_kind(), kind, "{filename}");
+        assert_eq!(
+            info.anime_name_without_kind_keyword(),
+            stripped,
+            "{filename}"
+        );
+    }
+}
+
+#[test]
+fn test_keywords_in_tags() {
+    let info = FilenameParser::parse("[Sub] 测试 - 13 [特别篇][1080P].mkv").unwrap();
+    assert_eq!(info.episode_kind(), EpisodeKind::Special);
+    assert_eq!(info.anime_name_without_kind_keyword(), "测试");
+}
+
+#[test]
+fn test_regular_episode_and_keyword_only_title() {
+    let info = FilenameParser::parse("[ANi] 测试 - 01 [1080P].mp4").unwrap();
+    assert_eq!(info.episode_kind(), EpisodeKind::Regular);
+    assert_eq!(EpisodeKind::Regular.dir_name(), None);
+
+    let info = FilenameParser::parse("[Sub] 剧场版 - 01 [1080P].mkv").unwrap();
+    assert_eq!(info.episode_kind(), EpisodeKind::Movie);
+    assert_eq!(info.anime_name_without_kind_keyword(), "剧场版");
+}
+
+#[test]
+fn test_kind_dir_names() {
+    assert_eq!(EpisodeKind::Movie.dir_name(), Some("剧场版"));
+    assert_eq!(EpisodeKind::Recap.dir_name(), Some("总集篇"));
+    assert_eq!(EpisodeKind::Special.dir_name(), None);
+}
+
+#[test]
+fn test_special_episodes() {
+    for (filename, special) in [
+        ("[ANi] 测试 - 12.5 [1080P].mp4", true),
+        ("[Sub] 测试 特别篇 - 01 [1080P].mkv", true),
+        ("[Sub] 测试 - 13 [特別篇][1080P].mkv", true),
+        ("[ANi] 测试 - 12 [1080P].mp4", false),
+        ("[Sub] 测试 总集篇 - 01 [1080P].mkv", false),
+        ("[Group] Anime - 01+02 [1080p].mkv", false),
+    ] {
+        let info = FilenameParser::parse(filename).unwrap();
+        assert_eq!(info.is_special(), special, "{filename}");
+    }
+}
+
+#[test]
+fn test_validate_specials_dir() {
+    for name in ["Specials", "特典", "SP", "Season 00"] {
+        assert!(validate_specials_dir(name).is_ok(), "{name}");
+    }
+    for name in ["", " ", ".", "..", "a/b", "a\\b"] {
+        assert!(validate_specials_dir(name).is_err(), "{name}");
+    }
+}
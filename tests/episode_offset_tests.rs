@@ -0,0 +1,59 @@
+//! Episode offset tests
+//!
+//! Covers `AnimeFileInfo::apply_episode_offset` for positive, negative and invalid offsets.
+
+use anime_organizer::parser::FilenameParser;
+
+fn shifted(filename: &str, offset: i64) -> Result<String, String> {
+    let mut info = FilenameParser::parse_str(filename).unwrap();
+    info.apply_episode_offset(offset)
+        .map(|()| info.episode)
+        .map_err(|error| error.to_string())
+}
+
+#[test]
+fn test_negative_offset_restarts_season_numbering() {
+    assert_eq!(shifted("[ANi] Test - 13 [1080P].mp4", -12).unwrap(), "01");
+    assert_eq!(shifted("[ANi] Test - 24 [1080P].mp4", -12).unwrap(), "12");
+}
+
+#[test]
+fn test_positive_offset() {
+    assert_eq!(shifted("[ANi] Test - 01 [1080P].mp4", 12).unwrap(), "13");
+    assert_eq!(shifted("[ANi] Test - 99 [1080P].mp4", 1).unwrap(), "100");
+}
+
+#[test]
+fn test_offset_keeps_decimal_part() {
+    assert_eq!(
+        shifted("[ANi] Test - 13.5 [1080P].mp4", -12).unwrap(),
+        "1.5"
+    );
+}
+
+#[test]
+fn test_offset_shifts_each_merged_episode() {
+    assert_eq!(
+        shifted("[Group] Anime - 13+14 [1080p].mkv", -12).unwrap(),
+        "01+02"
+    );
+}
+
+#[test]
+fn test_offset_to_zero_or_negative_is_rejected() {
+    let error = shifted("[ANi] Test - 12 [1080P].mp4", -12).unwrap_err();
+    assert!(error.contains("12"), "{error}");
+    assert!(shifted("[ANi] Test - 05 [1080P].mp4", -12).is_err());
+}
+
+#[test]
+fn test_rejected_offset_leaves_episode_unchanged() {
+    let mut info = FilenameParser::parse_str("[ANi] Test - 05 [1080P].mp4").unwrap();
+    assert!(info.apply_episode_offset(-12).is_err());
+    assert_eq!(info.episode, "05");
+}
+
+#[test]
+fn test_zero_offset_is_noop() {
+    assert_eq!(shifted("[ANi] Test - 5 [1080P].mp4", 0).unwrap(), "05");
+}
@@ -0,0 +1,93 @@
+//! Episode ordering tests
+//!
+//! Covers `compare_episodes` across zero padding, decimal episodes, ranges and specials.
+
+use anime_organizer::parser::{compare_episodes, AnimeFileInfo};
+use std::cmp::Ordering;
+
+fn info(episode: &str) -> AnimeFileInfo {
+    let mut info = AnimeFileInfo::builder()
+        .publisher("ANi")
+        .anime_name("Test")
+        .extension(".mkv")
+        .original_path(format!("[ANi] Test - {episode}.mkv"))
+        .build();
+    // The builder pads numeric episodes; keep the raw value to cover unpadded input.
+    info.episode = episode.to_string();
+    info
+}
+
+fn sorted(episodes: &[&str]) -> Vec<String> {
+    let mut files = episodes
+        .iter()
+        .map(|episode| info(episode))
+        .collect::<Vec<_>>();
+    files.sort_by(compare_episodes);
+    files.into_iter().map(|file| file.episode).collect()
+}
+
+#[test]
+fn test_zero_padding_compares_equal() {
+    assert_eq!(compare_episodes(&info("1"), &info("01")), Ordering::Equal);
+    assert_eq!(compare_episodes(&info("001"), &info("01")), Ordering::Equal);
+}
+
+#[test]
+fn test_numeric_order_is_not_lexicographic() {
+    assert_eq!(sorted(&["10", "2", "01"]), ["01", "2", "10"]);
+    assert_eq!(sorted(&["100", "99"]), ["99", "100"]);
+}
+
+#[test]
+fn test_decimal_episode_between_neighbours() {
+    assert_eq!(sorted(&["13", "12.5", "12"]), ["12", "12.5", "13"]);
+}
+
+#[test]
+fn test_range_compares_by_start_then_end() {
+    assert_eq!(sorted(&["13-24", "01-12", "05"]), ["01-12", "05", "13-24"]);
+    assert_eq!(sorted(&["01-13", "01-12"]), ["01-12", "01-13"]);
+    assert_eq!(sorted(&["02", "01~12"]), ["01~12", "02"]);
+}
+
+#[test]
+fn test_range_start_equal_to_single_episode_sorts_after() {
+    assert_eq!(
+        compare_episodes(&info("01-12"), &info("01")),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn test_specials_sort_after_numeric() {
+    assert_eq!(
+        sorted(&["SP", "24", "OVA", "01"]),
+        ["01", "24", "OVA", "SP"]
+    );
+}
+
+#[test]
+fn test_specials_with_same_prefix_sort_by_number() {
+    assert_eq!(sorted(&["SP10", "SP2", "sp1"]), ["sp1", "SP2", "SP10"]);
+    assert_eq!(sorted(&["SP1", "SP"]), ["SP", "SP1"]);
+}
+
+#[test]
+fn test_mixed_episode_kinds() {
+    assert_eq!(
+        sorted(&["OVA", "12.5", "01-02", "SP2", "3", "12", "SP1"]),
+        ["01-02", "3", "12", "12.5", "OVA", "SP1", "SP2"]
+    );
+}
+
+#[test]
+fn test_split_part_episodes_sort_by_number_then_letter() {
+    assert_eq!(
+        sorted(&["14", "13B", "13", "13A", "05a", "12"]),
+        ["05a", "12", "13", "13A", "13B", "14"]
+    );
+    assert_eq!(
+        compare_episodes(&info("13a"), &info("13A")),
+        Ordering::Equal
+    );
+}
@@ -0,0 +1,61 @@
+//! 区间集数渲染测试
+//!
+//! 区间集数（`01-12`、`01~12`）按模板渲染连接符与后缀，单集、合并多集与特殊集不受影响。
+
+use anime_organizer::parser::{
+    validate_episode_range_format, AnimeFileInfo, DEFAULT_EPISODE_RANGE_FORMAT,
+};
+
+fn info(episode: &str) -> AnimeFileInfo {
+    AnimeFileInfo::builder()
+        .anime_name("测试")
+        .episode(episode)
+        .tags("[1080P]")
+        .extension("mkv")
+        .build()
+}
+
+#[test]
+fn range_is_detected_with_either_joiner() {
+    assert_eq!(info("01-12").episode_range(), Some(("01", "12")));
+    assert_eq!(info("01~12").episode_range(), Some(("01", "12")));
+    assert_eq!(info("01").episode_range(), None);
+    assert_eq!(info("01+02").episode_range(), None);
+    assert_eq!(info("SP-1").episode_range(), None);
+}
+
+#[test]
+fn range_is_rendered_with_custom_joiners() {
+    let range = info("01-12");
+    assert_eq!(
+        range.episode_label_with(DEFAULT_EPISODE_RANGE_FORMAT),
+        "01-12"
+    );
+    assert_eq!(range.episode_label_with("{start}~{end}"), "01~12");
+    assert_eq!(range.episode_label_with("{start}-{end}话"), "01-12话");
+    assert_eq!(range.episode_label_with("第{start}至{end}集"), "第01至12集");
+    assert_eq!(
+        info("01~12").target_filename_with_range(".", DEFAULT_EPISODE_RANGE_FORMAT),
+        "01-12.[1080P].mkv"
+    );
+}
+
+#[test]
+fn single_episodes_are_not_affected() {
+    for episode in ["05", "12.5", "13a", "01+02", "SP"] {
+        let info = info(episode);
+        assert_eq!(info.episode_label_with("{start}~{end}"), info.episode);
+        assert_eq!(
+            info.target_filename_with_range(" ", "{start}~{end}"),
+            info.target_filename()
+        );
+    }
+}
+
+#[test]
+fn invalid_range_formats_are_rejected() {
+    assert!(validate_episode_range_format("{start}~{end}").is_ok());
+    for format in ["{start}", "{end}", "~", "{start}/{end}", "{start}\\{end}"] {
+        assert!(validate_episode_range_format(format).is_err(), "{format}");
+    }
+}
@@ -562,3 +562,22 @@ fn records_store_metadata_genres_external_ids_and_artwork() {
         .unwrap();
     assert_eq!(artwork_count, 1);
 }
+
+#[test]
+fn custom_season_directory_names_are_recognized() {
+    let dir = tempfile::tempdir().unwrap();
+    let target = dir.path();
+    for (season_dir, expected) in [("Season 2", 2), ("S02", 2), ("第3季", 3)] {
+        let directory = target.join("Season Show").join(season_dir);
+        fs::create_dir_all(&directory).unwrap();
+        let video = directory.join("[ANi] Season Show - 01 [1080P].mkv");
+        fs::write(&video, b"video").unwrap();
+
+        let record = LibraryIndexRecord::from_target_path(target, &video)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(record.series_title, "Season Show", "{season_dir}");
+        assert_eq!(record.season, expected, "{season_dir}");
+    }
+}
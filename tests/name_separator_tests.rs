@@ -0,0 +1,60 @@
+//! Target filename separator tests
+//!
+//! Covers `target_filename_with`, separator validation and reading the
+//! renamed files back into the library index.
+
+use anime_organizer::parser::{validate_name_separator, FilenameParser};
+use anime_organizer::LibraryIndexRecord;
+use std::fs;
+
+#[test]
+fn test_default_separator_is_space() {
+    let info = FilenameParser::parse_str("[ANi] Test - 01 [1080P].mp4").unwrap();
+    assert_eq!(info.target_filename(), "01 [1080P].mp4");
+    assert_eq!(info.target_filename_with(" "), info.target_filename());
+}
+
+#[test]
+fn test_custom_separators() {
+    let info = FilenameParser::parse_str("[ANi] Test - 01 [1080P][CHT].mkv").unwrap();
+    assert_eq!(info.target_filename_with("."), "01.[1080P][CHT].mkv");
+    assert_eq!(info.target_filename_with("_"), "01_[1080P][CHT].mkv");
+    assert_eq!(info.target_filename_with(" - "), "01 - [1080P][CHT].mkv");
+    assert_eq!(info.target_filename_with(""), "01[1080P][CHT].mkv");
+}
+
+#[test]
+fn test_empty_tags_omit_separator() {
+    let mut info = FilenameParser::parse_str("[ANi] Test - 01 [1080P].mkv").unwrap();
+    info.tags.clear();
+    assert_eq!(info.target_filename(), "01.mkv");
+    assert_eq!(info.target_filename_with("_"), "01.mkv");
+}
+
+#[test]
+fn test_invalid_separators_rejected() {
+    assert!(validate_name_separator(".").is_ok());
+    assert!(validate_name_separator(" - ").is_ok());
+    assert!(validate_name_separator("/").is_err());
+    assert!(validate_name_separator("\\").is_err());
+    assert!(validate_name_separator("x").is_err());
+}
+
+#[test]
+fn test_library_index_reads_separated_filenames() {
+    let dir = tempfile::tempdir().unwrap();
+    let series = dir.path().join("Test Show");
+    fs::create_dir_all(&series).unwrap();
+    for (name, episode) in [
+        ("03.[1080P].mkv", 3.0),
+        ("04_[1080P].mkv", 4.0),
+        ("12.5.[1080P].mkv", 12.5),
+    ] {
+        let video = series.join(name);
+        fs::write(&video, b"video").unwrap();
+        let record = LibraryIndexRecord::from_target_path(dir.path(), &video)
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.episode, episode, "{name}");
+    }
+}
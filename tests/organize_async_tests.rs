@@ -0,0 +1,162 @@
+//! 异步整理测试
+//!
+//! `FileOrganizer::organize_async` 与同步的 `FileOrganizer::organize` 在相同目录结构上
+//! 应得到一致的结果与目标文件布局。
+
+#![cfg(feature = "async")]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anime_organizer::organizer::{
+    FileOrganizer, OperationMode, OperationOutcome, OrganizeOptions, SkipReason,
+};
+use anime_organizer::parser::{AnimeFileInfo, FilenameParser};
+use tempfile::TempDir;
+use walkdir::WalkDir;
+
+const EPISODE: &str = "[ANi] Test Show - 01 [1080P].mkv";
+
+/// 在新的临时目录下创建 `source/` 中的剧集与字幕，返回临时目录与解析结果。
+fn fixture() -> (TempDir, AnimeFileInfo) {
+    let root = TempDir::new().unwrap();
+    let source = root.path().join("source");
+    fs::create_dir_all(&source).unwrap();
+    fs::write(source.join(EPISODE), b"video").unwrap();
+    fs::write(
+        source.join("[ANi] Test Show - 01 [1080P].zh.ass"),
+        b"subtitle",
+    )
+    .unwrap();
+    let info = FilenameParser::parse(source.join(EPISODE)).unwrap();
+    (root, info)
+}
+
+/// 目录下所有文件的相对路径与内容，按路径排序。
+fn snapshot(root: &Path) -> Vec<(PathBuf, Vec<u8>)> {
+    let mut files: Vec<_> = WalkDir::new(root)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| {
+            (
+                entry.path().strip_prefix(root).unwrap().to_path_buf(),
+                fs::read(entry.path()).unwrap(),
+            )
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+async fn assert_same_as_sync(mode: OperationMode, dry_run: bool, season_mode: bool) {
+    let (sync_root, sync_info) = fixture();
+    let (async_root, async_info) = fixture();
+
+    let sync_outcome = FileOrganizer::organize(
+        &sync_info,
+        sync_root.path().join("target"),
+        mode,
+        dry_run,
+        season_mode,
+    )
+    .unwrap();
+    let async_outcome = FileOrganizer::organize_async(
+        &async_info,
+        async_root.path().join("target"),
+        mode,
+        dry_run,
+        season_mode,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(async_outcome, sync_outcome, "{mode} dry_run={dry_run}");
+    assert_eq!(snapshot(async_root.path()), snapshot(sync_root.path()));
+}
+
+#[tokio::test]
+async fn matches_sync_for_every_mode() {
+    for mode in [
+        OperationMode::Copy,
+        OperationMode::Move,
+        OperationMode::Link,
+    ] {
+        assert_same_as_sync(mode, false, false).await;
+    }
+}
+
+#[tokio::test]
+async fn matches_sync_in_season_mode_and_dry_run() {
+    assert_same_as_sync(OperationMode::Copy, false, true).await;
+    assert_same_as_sync(OperationMode::Move, true, false).await;
+}
+
+#[tokio::test]
+async fn moves_subtitles_with_video() {
+    let (root, info) = fixture();
+    let target = root.path().join("target");
+
+    let outcome = FileOrganizer::organize_async(&info, &target, OperationMode::Move, false, false)
+        .await
+        .unwrap();
+
+    assert!(matches!(outcome, OperationOutcome::Performed(_)));
+    assert_eq!(
+        fs::read(target.join("Test Show").join(EPISODE)).unwrap(),
+        b"video"
+    );
+    assert!(target
+        .join("Test Show")
+        .join("[ANi] Test Show - 01 [1080P].zh.ass")
+        .exists());
+    assert!(!root.path().join("source").join(EPISODE).exists());
+}
+
+#[tokio::test]
+async fn skips_and_overwrites_existing_targets_like_sync() {
+    let (root, info) = fixture();
+    let target_dir = root.path().join("target").join("Test Show");
+    fs::create_dir_all(&target_dir).unwrap();
+    let options = OrganizeOptions::new(OperationMode::Copy);
+
+    fs::write(target_dir.join(EPISODE), b"video").unwrap();
+    let identical = FileOrganizer::organize_to_dir_async(&info, &target_dir, &options, &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        identical.outcome,
+        FileOrganizer::organize_to_dir_with_subtitles(&info, &target_dir, &options, &[])
+            .unwrap()
+            .outcome
+    );
+    assert_eq!(
+        identical.outcome,
+        OperationOutcome::Skipped(SkipReason::AlreadyExists)
+    );
+
+    fs::write(target_dir.join(EPISODE), b"stale content").unwrap();
+    let replaced = FileOrganizer::organize_to_dir_async(&info, &target_dir, &options, &[])
+        .await
+        .unwrap();
+    assert!(matches!(replaced.outcome, OperationOutcome::Performed(_)));
+    assert_eq!(replaced.target, target_dir.join(EPISODE));
+    assert_eq!(fs::read(&replaced.target).unwrap(), b"video");
+}
+
+#[tokio::test]
+async fn reports_missing_source() {
+    let (root, info) = fixture();
+    fs::remove_file(root.path().join("source").join(EPISODE)).unwrap();
+
+    let result = FileOrganizer::organize_async(
+        &info,
+        root.path().join("target"),
+        OperationMode::Copy,
+        false,
+        false,
+    )
+    .await;
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,2627 @@
+//! 整理模式命令行集成测试
+//!
+//! 通过真实的 `aniorg` 可执行文件验证参数来源与进程输出。
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+use std::time::{Duration, SystemTime};
+
+const EPISODE: &str = "[ANi] Test Show - 01 [1080P].mkv";
+
+fn aniorg() -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aniorg"));
+    for (key, _) in std::env::vars_os() {
+        if key.to_string_lossy().starts_with("ANIORG_") {
+            command.env_remove(key);
+        }
+    }
+    command
+}
+
+fn run(command: &mut Command) -> Output {
+    command.output().unwrap()
+}
+
+fn write_episode(source: &Path) {
+    fs::write(source.join(EPISODE), b"video").unwrap();
+}
+
+fn organized(target: &Path) -> bool {
+    target.join("Test Show").join(EPISODE).exists()
+}
+
+#[test]
+fn environment_variables_provide_organize_defaults() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .env("ANIORG_SOURCE", source.path())
+        .env("ANIORG_TARGET", target.path())
+        .env("ANIORG_MODE", "copy"));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    assert!(source.path().join(EPISODE).exists());
+}
+
+#[cfg(feature = "daemon")]
+#[test]
+fn daemon_rejects_organize_arguments_but_not_environment_defaults() {
+    let data = tempfile::tempdir().unwrap();
+    let daemon = || {
+        let mut command = aniorg();
+        command
+            .env("HOME", data.path())
+            .env("XDG_DATA_HOME", data.path())
+            .arg("--daemon")
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::piped());
+        command
+    };
+    let conflict = "--daemon cannot be combined with organize arguments";
+
+    let output = run(daemon().args(["--mode", "copy"]));
+    assert!(!output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains(conflict));
+
+    // 环境变量只是默认值；守护进程启动后（或端口被占用而退出时）都不应报参数冲突
+    let mut child = daemon().env("ANIORG_MODE", "copy").spawn().unwrap();
+    std::thread::sleep(Duration::from_millis(500));
+    if child.try_wait().unwrap().is_none() {
+        child.kill().unwrap();
+    }
+    let output = child.wait_with_output().unwrap();
+    assert!(
+        !String::from_utf8_lossy(&output.stderr).contains(conflict),
+        "{output:?}"
+    );
+}
+
+#[test]
+fn command_line_arguments_override_environment_variables() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .env("ANIORG_SOURCE", source.path().join("missing"))
+        .env("ANIORG_TARGET", target.path().join("missing"))
+        .env("ANIORG_MODE", "move")
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    assert!(
+        source.path().join(EPISODE).exists(),
+        "--mode copy should win over ANIORG_MODE=move"
+    );
+}
+
+#[test]
+fn invalid_environment_mode_is_rejected() {
+    let source = tempfile::tempdir().unwrap();
+
+    let output = run(aniorg()
+        .env("ANIORG_MODE", "teleport")
+        .arg("--source")
+        .arg(source.path()));
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("teleport"));
+}
+
+#[test]
+fn dry_run_csv_output_lists_planned_operations() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--dry-run", "--output", "csv"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines = stdout.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "source,target,anime,episode,mode,status");
+    assert_eq!(lines.len(), 2, "{stdout}");
+    assert!(lines[1].ends_with(",Test Show,01,copy,dry_run"), "{stdout}");
+    assert!(lines[1].contains(EPISODE));
+    assert!(!organized(target.path()));
+}
+
+#[test]
+fn ndjson_output_prints_one_json_record_per_file() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    fs::write(
+        source.path().join("[ANi] Test Show - 02 [1080P].mkv"),
+        b"video",
+    )
+    .unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--output", "ndjson"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let records = stdout
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(records.len(), 2, "{stdout}");
+    let mut episodes = records
+        .iter()
+        .map(|record| {
+            assert_eq!(record["anime_name"], "Test Show");
+            assert_eq!(record["mode"], "copy");
+            assert_eq!(record["status"], "success");
+            record["episode"].as_str().unwrap().to_string()
+        })
+        .collect::<Vec<_>>();
+    episodes.sort();
+    assert_eq!(episodes, ["01", "02"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("处理完成"));
+}
+
+#[test]
+fn read_only_marks_copied_files_read_only() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--read-only"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let organized_path = target.path().join("Test Show").join(EPISODE);
+    assert!(fs::metadata(organized_path)
+        .unwrap()
+        .permissions()
+        .readonly());
+    assert!(!fs::metadata(source.path().join(EPISODE))
+        .unwrap()
+        .permissions()
+        .readonly());
+}
+
+#[test]
+fn clean_subcommand_removes_only_tool_temp_files() {
+    let target = tempfile::tempdir().unwrap();
+    let temp = target.path().join(".aniorg-tmp-123-01.mkv.aniorg-tmp");
+    let user_part = target.path().join("01.mkv.part");
+    fs::write(&temp, b"partial").unwrap();
+    fs::write(&user_part, b"user").unwrap();
+
+    let output = run(aniorg().arg("clean").arg(target.path()).arg("--dry-run"));
+    assert!(output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stdout).contains(".aniorg-tmp-123-01.mkv.aniorg-tmp"));
+    assert!(temp.exists());
+
+    let output = run(aniorg().arg("clean").arg(target.path()));
+    assert!(output.status.success(), "{output:?}");
+    assert!(!temp.exists());
+    assert!(user_part.exists());
+}
+
+#[test]
+fn clean_subcommand_accepts_custom_temp_suffix() {
+    let target = tempfile::tempdir().unwrap();
+    let temp = target.path().join(".aniorg-tmp-123-01.mkv.syncignore");
+    let default_temp = target.path().join(".aniorg-tmp-123-02.mkv.aniorg-tmp");
+    fs::write(&temp, b"partial").unwrap();
+    fs::write(&default_temp, b"partial").unwrap();
+
+    let output = run(aniorg()
+        .arg("clean")
+        .arg(target.path())
+        .args(["--temp-suffix", ".syncignore"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(!temp.exists());
+    assert!(default_temp.exists());
+}
+
+#[test]
+fn copy_with_custom_temp_suffix_leaves_no_temp_files() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--temp-suffix", ".syncignore"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let files: Vec<_> = fs::read_dir(target.path().join("Test Show"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(files, [EPISODE]);
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .args(["--temp-suffix", "a/b"]));
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("无效的临时文件后缀"));
+}
+
+#[test]
+fn keep_tags_limits_target_filename_tags() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    fs::write(
+        source
+            .path()
+            .join("[ANi] Test Show - 02 [1080P][Baha][WEB-DL][AAC AVC][CHT].mp4"),
+        b"video",
+    )
+    .unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--keep-tags", "resolution,lang"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(target
+        .path()
+        .join("Test Show")
+        .join("[ANi] Test Show - 02 [1080P][CHT].mp4")
+        .exists());
+}
+
+#[test]
+fn exclude_ext_skips_listed_extensions() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let mp4 = "[ANi] Test Show - 02 [1080P].mp4";
+    fs::write(source.path().join(mp4), b"video").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--exclude-ext", "MKV"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(!organized(target.path()));
+    assert!(target.path().join("Test Show").join(mp4).exists());
+}
+
+#[test]
+fn prefer_ext_organizes_only_preferred_version() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let mp4 = "[ANi] Test Show - 01 [1080P].mp4";
+    let other = "[ANi] Test Show - 02 [1080P].mp4";
+    fs::write(source.path().join(mp4), b"video").unwrap();
+    fs::write(source.path().join(other), b"video").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--prefer-ext", "mkv,mp4"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    assert!(!target.path().join("Test Show").join(mp4).exists());
+    assert!(target.path().join("Test Show").join(other).exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn follow_symlinks_terminates_on_symlink_loop() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let season = source.path().join("season");
+    fs::create_dir(&season).unwrap();
+    write_episode(&season);
+    std::os::unix::fs::symlink(source.path(), season.join("loop")).unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--follow-symlinks"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("符号链接环"));
+}
+
+#[test]
+fn renumber_closes_episode_gaps() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    for episode in ["02", "03", "05"] {
+        fs::write(
+            source
+                .path()
+                .join(format!("[ANi] Test Show - {episode} [1080P].mkv")),
+            b"video",
+        )
+        .unwrap();
+    }
+    let organize = |extra: &[&str]| {
+        run(aniorg()
+            .arg("--source")
+            .arg(source.path())
+            .arg("--target")
+            .arg(target.path())
+            .args(["--mode", "copy", "--renumber"])
+            .args(extra))
+    };
+
+    let preview = organize(&["--dry-run"]);
+    assert!(preview.status.success(), "{preview:?}");
+    let stderr = String::from_utf8_lossy(&preview.stderr);
+    assert!(stderr.contains("警告：--renumber"), "{stderr}");
+    assert!(stderr.contains("重编号 Test Show: 05 -> 03"), "{stderr}");
+    assert!(!target.path().join("Test Show").exists());
+
+    let output = organize(&[]);
+    assert!(output.status.success(), "{output:?}");
+    let mut names = fs::read_dir(target.path().join("Test Show"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(
+        names,
+        ["01 [1080P].mkv", "02 [1080P].mkv", "03 [1080P].mkv"]
+    );
+}
+
+#[test]
+fn hidden_directories_are_skipped_unless_included() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let trash = source.path().join("$RECYCLE.BIN");
+    fs::create_dir(&trash).unwrap();
+    write_episode(&trash);
+    let organize = |extra: &[&str]| {
+        run(aniorg()
+            .arg("--source")
+            .arg(source.path())
+            .arg("--target")
+            .arg(target.path())
+            .args(["--mode", "copy"])
+            .args(extra))
+    };
+
+    let output = organize(&[]);
+    assert!(output.status.success(), "{output:?}");
+    assert!(!organized(target.path()));
+
+    let output = organize(&["--include-hidden"]);
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+}
+
+#[test]
+fn episode_kind_files_go_to_kind_directory() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let movie = "[ANi] 劇場版 測試 - 電影 [1080P].mp4";
+    fs::write(source.path().join(movie), b"video").unwrap();
+    let organize = |target: &Path, extra: &[&str]| {
+        run(aniorg()
+            .arg("--source")
+            .arg(source.path())
+            .arg("--target")
+            .arg(target)
+            .args(["--mode", "copy"])
+            .args(extra))
+    };
+
+    let output = organize(target.path(), &[]);
+    assert!(output.status.success(), "{output:?}");
+    assert!(target
+        .path()
+        .join("測試")
+        .join("剧场版")
+        .join(movie)
+        .exists());
+
+    let kept = tempfile::tempdir().unwrap();
+    let output = organize(kept.path(), &["--keep-kind-keyword"]);
+    assert!(output.status.success(), "{output:?}");
+    assert!(kept
+        .path()
+        .join("劇場版 測試")
+        .join("剧场版")
+        .join(movie)
+        .exists());
+}
+
+#[test]
+fn specials_stay_with_regular_episodes_by_default() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let special = "[ANi] Test Show - 12.5 [1080P].mkv";
+    fs::write(source.path().join(special), b"video").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(target.path().join("Test Show").join(special).exists());
+}
+
+#[test]
+fn specials_dir_names_special_episode_directory() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let special = "[ANi] Test Show - 12.5 [1080P].mkv";
+    write_episode(source.path());
+    fs::write(source.path().join(special), b"video").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--specials-dir", "特典"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    assert!(target
+        .path()
+        .join("Test Show")
+        .join("特典")
+        .join(special)
+        .exists());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--specials-dir", "a/b"]));
+    assert!(!output.status.success());
+}
+
+#[test]
+fn relative_shows_paths_relative_to_common_parent() {
+    let root = tempfile::tempdir().unwrap();
+    let source = root.path().join("downloads");
+    let target = root.path().join("anime");
+    fs::create_dir(&source).unwrap();
+    fs::create_dir(&target).unwrap();
+    write_episode(&source);
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(&source)
+        .arg("--target")
+        .arg(&target)
+        .args(["--dry-run", "--relative"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let expected = format!(
+        "[DRY-RUN] {} -> {}",
+        Path::new("downloads").join(EPISODE).display(),
+        Path::new("anime").join("Test Show").join(EPISODE).display()
+    );
+    assert!(stdout.contains(&expected), "{stdout}");
+    assert!(
+        !stdout.contains(&root.path().display().to_string()),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn dry_run_plan_out_can_be_applied_later() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let second = "[ANi] Test Show - 02 [1080P].mkv";
+    fs::write(source.path().join(second), b"video").unwrap();
+    let plan = target.path().join("plan.json");
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--dry-run", "--plan-out"])
+        .arg(&plan));
+    assert!(output.status.success(), "{output:?}");
+    assert!(!organized(target.path()));
+
+    fs::remove_file(source.path().join(second)).unwrap();
+    let output = run(aniorg().arg("apply").arg(&plan));
+
+    assert!(!output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("源文件已不存在"));
+    assert!(organized(target.path()));
+    assert!(!target.path().join("Test Show").join(second).exists());
+}
+
+#[test]
+fn plan_out_merges_operations_from_every_target() {
+    let source = tempfile::tempdir().unwrap();
+    let first = tempfile::tempdir().unwrap();
+    let second = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let plan = source.path().join("plan.json");
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(first.path())
+        .arg("--target")
+        .arg(second.path())
+        .args(["--mode", "copy", "--dry-run", "--plan-out"])
+        .arg(&plan));
+    assert!(output.status.success(), "{output:?}");
+
+    let output = run(aniorg().arg("apply").arg(&plan));
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(first.path()));
+    assert!(organized(second.path()));
+}
+
+#[test]
+fn apply_keeps_conflict_options_from_dry_run() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let existing = target.path().join("Test Show").join(EPISODE);
+    fs::create_dir_all(existing.parent().unwrap()).unwrap();
+    fs::write(&existing, b"larger old").unwrap();
+    let plan = source.path().join("plan.json");
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--backup", "--on-conflict", "keep-larger"])
+        .args(["--dry-run", "--plan-out"])
+        .arg(&plan));
+    assert!(output.status.success(), "{output:?}");
+
+    let output = run(aniorg().arg("apply").arg(&plan));
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(fs::read(&existing).unwrap(), b"larger old");
+}
+
+#[test]
+fn apply_atomic_rolls_back_completed_moves() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let second = "[ANi] Test Show - 02 [1080P].mkv";
+    fs::write(source.path().join(second), b"video").unwrap();
+    let plan = target.path().join("plan.json");
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "move", "--dry-run", "--plan-out"])
+        .arg(&plan));
+    assert!(output.status.success(), "{output:?}");
+
+    fs::remove_file(source.path().join(second)).unwrap();
+    let output = run(aniorg().arg("apply").arg("--atomic").arg(&plan));
+
+    assert!(!output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("已撤销"));
+    assert_eq!(fs::read(source.path().join(EPISODE)).unwrap(), b"video");
+    assert!(!target.path().join("Test Show").exists());
+}
+
+#[test]
+fn sort_controls_dry_run_order() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    for (episode, size) in [("01", 30), ("02", 10), ("03", 20)] {
+        fs::write(
+            source
+                .path()
+                .join(format!("[ANi] Test Show - {episode} [1080P].mkv")),
+            vec![0u8; size],
+        )
+        .unwrap();
+    }
+    let dry_run_order = |sort: &str| {
+        let output = run(aniorg()
+            .arg("--source")
+            .arg(source.path())
+            .arg("--target")
+            .arg(target.path())
+            .args(["--dry-run", "--sort", sort]));
+        assert!(output.status.success(), "{output:?}");
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| line.starts_with("[DRY-RUN]"))
+            .map(|line| line.split(" - ").nth(1).unwrap()[..2].to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(dry_run_order("name"), ["01", "02", "03"]);
+    assert_eq!(dry_run_order("size"), ["02", "03", "01"]);
+}
+
+#[test]
+fn limit_rate_copies_file_and_rejects_zero() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let organize = |rate: &str| {
+        run(aniorg()
+            .arg("--source")
+            .arg(source.path())
+            .arg("--target")
+            .arg(target.path())
+            .args(["--mode", "copy", "--limit-rate", rate]))
+    };
+
+    assert!(!organize("0").status.success());
+    assert!(!organized(target.path()));
+
+    let output = organize("1048576");
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        fs::read(target.path().join("Test Show").join(EPISODE)).unwrap(),
+        b"video"
+    );
+    assert!(source.path().join(EPISODE).exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn verify_links_accepts_hard_linked_target() {
+    use std::os::unix::fs::MetadataExt;
+
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .arg("--verify-links"));
+
+    assert!(output.status.success(), "{output:?}");
+    let linked = target.path().join("Test Show").join(EPISODE);
+    assert_eq!(fs::metadata(linked).unwrap().nlink(), 2);
+}
+
+#[test]
+fn verify_readable_accepts_readable_target_and_requires_flag_for_retries() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args([
+            "--mode",
+            "copy",
+            "--verify-readable",
+            "--verify-readable-retries",
+            "2",
+        ]));
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .args(["--verify-readable-retries", "2"]));
+    assert!(!output.status.success(), "{output:?}");
+}
+
+#[test]
+fn glob_source_organizes_only_matching_files() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    for (directory, name) in [
+        ("a", "[ANi] Test Show - 01 [1080P].mkv"),
+        ("b", "[ANi] Test Show - 02 [1080P].mkv"),
+        ("b", "[ANi] Test Show - 03 [1080P].mp4"),
+        ("c", "[Sub] Test Show - 04 [1080P].mkv"),
+        (".", "[ANi] Test Show - 05 [1080P].mkv"),
+    ] {
+        fs::create_dir_all(source.path().join(directory)).unwrap();
+        fs::write(source.path().join(directory).join(name), b"video").unwrap();
+    }
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path().join("*").join("[ANi]*.mkv"))
+        .arg("--target")
+        .arg(target.path()));
+
+    assert!(output.status.success(), "{output:?}");
+    let mut organized = fs::read_dir(target.path().join("Test Show"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect::<Vec<_>>();
+    organized.sort();
+    assert_eq!(
+        organized,
+        [
+            "[ANi] Test Show - 01 [1080P].mkv",
+            "[ANi] Test Show - 02 [1080P].mkv"
+        ]
+    );
+}
+
+#[test]
+fn glob_source_without_matches_reports_error() {
+    let source = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let pattern = source.path().join("*.avi");
+
+    let output = run(aniorg().arg("--source").arg(&pattern).arg("--dry-run"));
+
+    assert!(!output.status.success(), "{output:?}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("通配符没有匹配到任何文件"), "{stderr}");
+    assert!(stderr.contains(&*pattern.to_string_lossy()), "{stderr}");
+}
+
+#[test]
+fn dry_run_estimates_copy_time() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    fs::write(source.path().join(EPISODE), vec![0u8; 3000]).unwrap();
+    let estimate = |mode: &str| {
+        let output = run(aniorg()
+            .arg("--source")
+            .arg(source.path())
+            .arg("--target")
+            .arg(target.path())
+            .args(["--dry-run", "--mode", mode, "--throughput", "1000"]));
+        assert!(output.status.success(), "{output:?}");
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find(|line| line.starts_with("预计耗时"))
+            .unwrap()
+            .to_string()
+    };
+
+    assert_eq!(
+        estimate("copy"),
+        "预计耗时：约 3秒（共 2.9 KB，按 1000 B/s 估算）"
+    );
+    assert_eq!(estimate("link"), "预计耗时：即时（link 模式，共 2.9 KB）");
+}
+
+#[test]
+fn dry_run_estimates_new_dirs_and_existing_targets() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    fs::write(
+        source.path().join("[ANi] Other Show - 01 [1080P].mkv"),
+        b"video",
+    )
+    .unwrap();
+    fs::create_dir(target.path().join("Test Show")).unwrap();
+    fs::write(target.path().join("Test Show").join(EPISODE), b"old").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--dry-run", "--mode", "move"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.lines().any(|line| line
+            == "预计操作：将创建 1 个新目录，移动 2 个文件，其中 1 个目标已存在（内容相同则跳过，否则覆盖）"),
+        "{stdout}"
+    );
+    assert!(!target.path().join("Other Show").exists());
+}
+
+#[test]
+fn raw_anime_name_keeps_parsed_name_for_directory() {
+    let source = tempfile::tempdir().unwrap();
+    let name = "[ANi] Test Show 总集篇 - 13 [1080P].mkv";
+    fs::write(source.path().join(name), b"video").unwrap();
+    let organize = |raw: bool| {
+        let target = tempfile::tempdir().unwrap();
+        let mut command = aniorg();
+        command
+            .arg("--source")
+            .arg(source.path())
+            .arg("--target")
+            .arg(target.path())
+            .args(["--mode", "copy"]);
+        if raw {
+            command.arg("--raw-anime-name");
+        }
+        let output = run(&mut command);
+        assert!(output.status.success(), "{output:?}");
+        target
+    };
+
+    let cleaned = organize(false);
+    assert!(cleaned.path().join("Test Show/总集篇").join(name).exists());
+
+    let raw = organize(true);
+    assert!(raw
+        .path()
+        .join("Test Show 总集篇/总集篇")
+        .join(name)
+        .exists());
+    assert!(!raw.path().join("Test Show").exists());
+
+    let output = run(aniorg().arg("--source").arg(source.path()).args([
+        "--raw-anime-name",
+        "--season-mode",
+        "--dry-run",
+    ]));
+    assert!(!output.status.success(), "{output:?}");
+}
+
+#[test]
+fn mode_accepts_aliases_and_lists_choices_for_invalid_values() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "mv"]));
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    assert!(!source.path().join(EPISODE).exists());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .env("ANIORG_MODE", "hardlnk"));
+    assert!(!output.status.success(), "{output:?}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("无效的操作模式 \"hardlnk\""), "{stderr}");
+    assert!(stderr.contains("link（别名 hardlink、ln）"), "{stderr}");
+}
+
+#[test]
+fn loose_episode_organizes_resolution_glued_names() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    fs::write(source.path().join("Anime-1080p-01.mkv"), b"video").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy"]));
+    assert!(output.status.success(), "{output:?}");
+    assert!(!target.path().join("Anime").exists());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--loose-episode"]));
+    assert!(output.status.success(), "{output:?}");
+    assert!(target
+        .path()
+        .join("Anime")
+        .join("Anime-1080p-01.mkv")
+        .exists());
+}
+
+#[test]
+fn title_case_normalizes_english_anime_directory() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    fs::write(
+        source
+            .path()
+            .join("[ANi] test show the final season - 01 [1080P].mkv"),
+        b"video",
+    )
+    .unwrap();
+    fs::write(
+        source
+            .path()
+            .join("[ANi] Test Show The Final Season - 02 [1080P].mkv"),
+        b"video",
+    )
+    .unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--title-case"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let dirs: Vec<_> = fs::read_dir(target.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(dirs, ["Test Show the Final Season"]);
+}
+
+#[test]
+fn batch_runs_tasks_in_order_and_sums_results() {
+    let first = tempfile::tempdir().unwrap();
+    let second = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(first.path());
+    fs::write(
+        second.path().join("[ANi] Other Show - 02 [1080P].mkv"),
+        b"video",
+    )
+    .unwrap();
+    let tasks = target.path().join("tasks.txt");
+    fs::write(
+        &tasks,
+        format!(
+            "# two tasks\n--source {:?} --target {:?}\n\n--source {:?} --target {:?} --mode copy\n",
+            first.path(),
+            target.path(),
+            second.path(),
+            target.path()
+        ),
+    )
+    .unwrap();
+
+    let output = run(aniorg().arg("batch").arg(&tasks));
+
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let first_task = stdout.find("==> 任务 1").unwrap();
+    let second_task = stdout.find("==> 任务 2").unwrap();
+    assert!(first_task < second_task, "{stdout}");
+    assert!(
+        stdout.contains("批处理完成：任务出错0个；文件成功2个，已存在跳过0个，失败0个"),
+        "{stdout}"
+    );
+    assert!(organized(target.path()));
+    assert!(target
+        .path()
+        .join("Other Show/[ANi] Other Show - 02 [1080P].mkv")
+        .exists());
+    assert!(second
+        .path()
+        .join("[ANi] Other Show - 02 [1080P].mkv")
+        .exists());
+}
+
+#[test]
+fn batch_continues_after_failed_task() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let tasks = target.path().join("tasks.txt");
+    fs::write(
+        &tasks,
+        format!(
+            "--source {:?}\n--source {:?} --target {:?}\n",
+            source.path().join("missing"),
+            source.path(),
+            target.path()
+        ),
+    )
+    .unwrap();
+
+    let output = run(aniorg().arg("batch").arg(&tasks));
+
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("任务 1 执行失败"));
+    assert!(organized(target.path()));
+}
+
+#[test]
+fn coverage_reports_ratio_and_failure_reasons_without_touching_files() {
+    let source = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    fs::write(source.path().join("[ANi] Test Show [1080P].mkv"), b"video").unwrap();
+    fs::write(source.path().join("Test Show - 02.mkv"), b"video").unwrap();
+    fs::write(source.path().join("notes.txt"), b"text").unwrap();
+
+    let output = run(aniorg().arg("coverage").arg("--source").arg(source.path()));
+
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("扫描文件：3 个"), "{stdout}");
+    assert!(stdout.contains("可解析：1 个（33.3%）"), "{stdout}");
+    assert!(stdout.contains("未找到集数：1 个"), "{stdout}");
+    assert!(stdout.contains("缺少 [发布组] 前缀：1 个"), "{stdout}");
+    assert!(stdout.contains("Test Show - 02.mkv"), "{stdout}");
+    assert_eq!(fs::read_dir(source.path()).unwrap().count(), 4);
+}
+
+#[test]
+fn target_template_routes_files_by_year() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    for name in [
+        "[ANi] Old Show (2023) - 01 [1080P].mkv",
+        "[ANi] New Show - 01 [2024][1080P].mkv",
+        "[ANi] Test Show - 01 [1080P].mkv",
+    ] {
+        fs::write(source.path().join(name), b"video").unwrap();
+    }
+    let template = target.path().join("{year}").join("{anime}");
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(&template)
+        .args(["--mode", "copy"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(target
+        .path()
+        .join("2023/Old Show (2023)/[ANi] Old Show (2023) - 01 [1080P].mkv")
+        .exists());
+    assert!(target
+        .path()
+        .join("2024/New Show/[ANi] New Show - 01 [2024][1080P].mkv")
+        .exists());
+    assert!(target
+        .path()
+        .join("Unknown/Test Show/[ANi] Test Show - 01 [1080P].mkv")
+        .exists());
+}
+
+#[test]
+fn target_template_rejects_unknown_placeholder() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path().join("{season}"))
+        .arg("--dry-run"));
+
+    assert!(!output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("未知占位符"));
+}
+
+#[test]
+fn state_skips_unchanged_files_and_reprocesses_changed_ones() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let state = tempfile::tempdir().unwrap();
+    let state_file = state.path().join("state.json");
+    write_episode(source.path());
+    let organize = || {
+        run(aniorg()
+            .arg("--source")
+            .arg(source.path())
+            .arg("--target")
+            .arg(target.path())
+            .arg("--state")
+            .arg(&state_file)
+            .args(["--mode", "copy"]))
+    };
+
+    let output = organize();
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    assert!(state_file.exists());
+
+    fs::remove_dir_all(target.path().join("Test Show")).unwrap();
+    let output = organize();
+    assert!(output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stdout).contains("上次已处理且未变动"));
+    assert!(!organized(target.path()));
+
+    fs::write(source.path().join(EPISODE), b"updated video").unwrap();
+    let output = organize();
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+}
+
+#[test]
+fn state_is_shared_across_multiple_targets() {
+    let source = tempfile::tempdir().unwrap();
+    let first = tempfile::tempdir().unwrap();
+    let second = tempfile::tempdir().unwrap();
+    let state = tempfile::tempdir().unwrap();
+    let state_file = state.path().join("state.json");
+    write_episode(source.path());
+    let organize = || {
+        run(aniorg()
+            .arg("--source")
+            .arg(source.path())
+            .arg("--target")
+            .arg(first.path())
+            .arg("--target")
+            .arg(second.path())
+            .arg("--state")
+            .arg(&state_file)
+            .args(["--mode", "copy"]))
+    };
+
+    let output = organize();
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(first.path()));
+    assert!(organized(second.path()));
+
+    fs::remove_dir_all(second.path().join("Test Show")).unwrap();
+    let output = organize();
+    assert!(output.status.success(), "{output:?}");
+    assert!(!organized(second.path()));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        stdout.matches("上次已处理且未变动 1 个").count(),
+        2,
+        "{stdout}"
+    );
+}
+
+fn processed_summary(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find(|line| line.starts_with("处理完成"))
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[test]
+fn target_inside_source_is_not_rescanned() {
+    let source = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let library = source.path().join("library");
+    fs::create_dir(&library).unwrap();
+    let organize = || {
+        run(aniorg()
+            .arg("--source")
+            .arg(source.path())
+            .arg("--target")
+            .arg(&library)
+            .args(["--mode", "copy"]))
+    };
+
+    let first = organize();
+    assert!(first.status.success(), "{first:?}");
+    assert!(organized(&library));
+
+    let second = organize();
+    assert!(second.status.success(), "{second:?}");
+    assert!(
+        processed_summary(&second).contains("总计1个文件，成功0个，已存在跳过1个"),
+        "{second:?}"
+    );
+    assert!(!library.join("Test Show").join("Test Show").exists());
+    assert_eq!(fs::read_dir(library.join("Test Show")).unwrap().count(), 1);
+}
+
+#[test]
+fn target_equal_to_source_skips_organized_files() {
+    let source = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let organize = || {
+        run(aniorg()
+            .arg("--source")
+            .arg(source.path())
+            .args(["--mode", "move"]))
+    };
+
+    let first = organize();
+    assert!(first.status.success(), "{first:?}");
+    assert!(organized(source.path()));
+    assert!(!source.path().join(EPISODE).exists());
+
+    let second = organize();
+    assert!(second.status.success(), "{second:?}");
+    assert!(
+        processed_summary(&second).contains("成功0个，已存在跳过1个"),
+        "{second:?}"
+    );
+    assert!(organized(source.path()));
+    assert!(!source.path().join("Test Show").join("Test Show").exists());
+}
+
+#[cfg(feature = "archives")]
+#[test]
+fn extract_archives_organizes_video_inside_zip() {
+    use std::io::Write;
+
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let mut writer =
+        zip::ZipWriter::new(fs::File::create(source.path().join("Test Show.zip")).unwrap());
+    writer
+        .start_file(EPISODE, zip::write::SimpleFileOptions::default())
+        .unwrap();
+    writer.write_all(b"video").unwrap();
+    writer.finish().unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--extract-archives"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    let leftovers = fs::read_dir(target.path())
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with(".aniorg-extract-")
+        })
+        .count();
+    assert_eq!(leftovers, 0, "extraction directory should be removed");
+}
+
+#[test]
+fn summary_by_anime_prints_per_anime_counts_and_sizes() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    fs::write(
+        source.path().join("[ANi] Test Show - 02 [1080P].mkv"),
+        b"video",
+    )
+    .unwrap();
+    fs::write(source.path().join("[ANi] Other - 01 [1080P].mkv"), b"video").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--summary-by-anime"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let rows = stdout
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert!(
+        rows.contains(&vec!["Other", "1", "0", "5", "B"]),
+        "{stdout}"
+    );
+    assert!(
+        rows.contains(&vec!["Test", "Show", "2", "0", "10", "B"]),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn season_format_controls_season_directory_name() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args([
+            "--mode",
+            "copy",
+            "--season-mode",
+            "--season-format",
+            "S{n:02}",
+        ]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(target
+        .path()
+        .join("Test Show")
+        .join("S01")
+        .join(EPISODE)
+        .exists());
+}
+
+#[test]
+fn dedup_by_content_skips_identical_release() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    fs::write(
+        source.path().join("[A] Test Show - 01 [1080P].mkv"),
+        b"video",
+    )
+    .unwrap();
+    fs::write(
+        source.path().join("[B] Test Show - 01 [1080P].mkv"),
+        b"video",
+    )
+    .unwrap();
+    fs::write(
+        source.path().join("[C] Test Show - 01 [720P].mkv"),
+        b"other",
+    )
+    .unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--dedup-by-content"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let organized = fs::read_dir(target.path().join("Test Show"))
+        .unwrap()
+        .count();
+    assert_eq!(organized, 2);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("内容重复 1 个"));
+}
+
+#[test]
+fn quiet_prints_nothing_on_success() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--quiet"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(output.stdout.is_empty(), "{output:?}");
+    assert!(output.stderr.is_empty(), "{output:?}");
+    assert!(organized(target.path()));
+}
+
+#[test]
+fn quiet_dry_run_prints_nothing() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--dry-run", "--quiet"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(output.stdout.is_empty(), "{output:?}");
+    assert!(output.stderr.is_empty(), "{output:?}");
+    assert!(!organized(target.path()));
+}
+
+#[test]
+fn quiet_exit_code_reflects_failures() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    // 目标位置被同名目录占据，复制必然失败。
+    fs::create_dir_all(target.path().join("Test Show").join(EPISODE)).unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--quiet"]));
+
+    assert_eq!(output.status.code(), Some(4), "{output:?}");
+    assert!(output.stdout.is_empty(), "{output:?}");
+}
+
+#[test]
+fn quiet_conflicts_with_verbose() {
+    let source = tempfile::tempdir().unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .args(["--quiet", "--verbose"]));
+
+    assert!(!output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--verbose"));
+}
+
+#[test]
+fn exit_code_is_zero_when_all_files_succeed() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy"]));
+
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+}
+
+#[test]
+fn exit_code_is_two_when_some_files_fail() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    fs::write(
+        source.path().join("[ANi] Test Show - 02 [1080P].mkv"),
+        b"video",
+    )
+    .unwrap();
+    // 第 1 集的目标位置被同名目录占据，复制必然失败。
+    fs::create_dir_all(target.path().join("Test Show").join(EPISODE)).unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy"]));
+
+    assert_eq!(output.status.code(), Some(3), "{output:?}");
+    assert!(target
+        .path()
+        .join("Test Show")
+        .join("[ANi] Test Show - 02 [1080P].mkv")
+        .is_file());
+}
+
+#[test]
+fn exit_code_is_one_on_run_error() {
+    let source = tempfile::tempdir().unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path().join("missing"))
+        .args(["--mode", "copy"]));
+
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+}
+
+#[test]
+fn aliases_merge_alternate_titles_into_canonical_directory() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let aliases = source.path().join("aliases.toml");
+    fs::write(&aliases, "\"sousou no frieren\" = \"Frieren\"\n").unwrap();
+    fs::write(
+        source
+            .path()
+            .join("[ANi] Sousou no Frieren - 01 [1080P].mkv"),
+        b"video",
+    )
+    .unwrap();
+    fs::write(
+        source.path().join("[ANi] Frieren - 02 [1080P].mkv"),
+        b"video",
+    )
+    .unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .arg("--aliases")
+        .arg(&aliases)
+        .args(["--mode", "copy"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(!target.path().join("Sousou no Frieren").exists());
+    assert_eq!(
+        fs::read_dir(target.path().join("Frieren")).unwrap().count(),
+        2
+    );
+}
+
+#[test]
+fn newer_than_skips_old_files() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let old = source.path().join("[ANi] Test Show - 02 [1080P].mkv");
+    fs::write(&old, b"video").unwrap();
+    fs::File::options()
+        .write(true)
+        .open(&old)
+        .unwrap()
+        .set_modified(SystemTime::now() - Duration::from_secs(30 * 86_400))
+        .unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--newer-than", "7d"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    assert!(!target
+        .path()
+        .join("Test Show")
+        .join("[ANi] Test Show - 02 [1080P].mkv")
+        .exists());
+}
+
+#[test]
+fn episode_range_format_rejects_missing_placeholders() {
+    let source = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg().arg("--source").arg(source.path()).args([
+        "--name-separator",
+        " ",
+        "--episode-range-format",
+        "{start}~",
+    ]));
+
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("无效的区间集数模板"));
+}
+
+#[test]
+fn max_filename_len_truncates_long_tags() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    fs::write(
+        source
+            .path()
+            .join("[ANi] Test Show - 01 [1080P][Baha][WEB-DL][AAC AVC][CHT].mkv"),
+        b"video",
+    )
+    .unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--max-filename-len", "36"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(target
+        .path()
+        .join("Test Show")
+        .join("[ANi] Test Show - 01 [1080P].mkv")
+        .exists());
+}
+
+#[test]
+fn max_filename_len_rejects_tiny_limits() {
+    let source = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .args(["--max-filename-len", "8"]));
+
+    assert_eq!(output.status.code(), Some(2), "{output:?}");
+}
+
+#[test]
+fn primary_title_selects_alt_title_as_directory() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let filename = "[ANi] 测试 ／ Test Show - 01 [1080P].mkv";
+    fs::write(source.path().join(filename), b"video").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--primary-title", "2"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(target.path().join("Test Show").join(filename).exists());
+    assert!(!target.path().join("测试").exists());
+}
+
+#[test]
+fn alt_titles_use_first_title_by_default() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let filename = "[ANi] 测试 ／ Test Show - 01 [1080P].mkv";
+    fs::write(source.path().join(filename), b"video").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(target.path().join("测试").join(filename).exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn auto_mode_hard_links_on_same_filesystem() {
+    use std::os::unix::fs::MetadataExt;
+
+    let root = tempfile::tempdir().unwrap();
+    let (source, target) = (root.path().join("source"), root.path().join("target"));
+    fs::create_dir_all(&source).unwrap();
+    fs::create_dir_all(&target).unwrap();
+    write_episode(&source);
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(&source)
+        .arg("--target")
+        .arg(&target)
+        .args(["--mode", "auto"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(&target));
+    assert_eq!(fs::metadata(source.join(EPISODE)).unwrap().nlink(), 2);
+}
+
+#[test]
+fn summary_reports_elapsed_time_and_throughput() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("耗时 "), "{stdout}");
+    assert!(stdout.contains("共传输 "), "{stdout}");
+
+    let dry_run = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--dry-run"]));
+    assert!(!String::from_utf8_lossy(&dry_run.stdout).contains("共传输 "));
+}
+
+#[test]
+fn preserve_ext_case_keeps_extension_when_renaming() {
+    let organize = |extra: &[&str]| {
+        let source = tempfile::tempdir().unwrap();
+        let target = tempfile::tempdir().unwrap();
+        fs::write(
+            source.path().join("[ANi] Test Show - 01 [1080P].MKV"),
+            b"video",
+        )
+        .unwrap();
+        let output = run(aniorg()
+            .arg("--source")
+            .arg(source.path())
+            .arg("--target")
+            .arg(target.path())
+            .args(["--mode", "copy", "--name-separator", " "])
+            .args(extra));
+        assert!(output.status.success(), "{output:?}");
+        let mut names: Vec<_> = fs::read_dir(target.path().join("Test Show"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    };
+
+    assert_eq!(organize(&[]), ["01 [1080P].mkv"]);
+    assert_eq!(organize(&["--preserve-ext-case"]), ["01 [1080P].MKV"]);
+}
+
+#[test]
+fn newer_than_rejects_invalid_value() {
+    let source = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .args(["--newer-than", "yesterday"]));
+
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("无效的时间阈值"));
+}
+
+#[test]
+fn episodes_limits_organized_episodes() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    for episode in 1..=6 {
+        fs::write(
+            source
+                .path()
+                .join(format!("[ANi] Test Show - {episode:02} [1080P].mkv")),
+            b"video",
+        )
+        .unwrap();
+    }
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--episodes", "2-3,5"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let mut organized: Vec<_> = fs::read_dir(target.path().join("Test Show"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    organized.sort();
+    assert_eq!(
+        organized,
+        [
+            "[ANi] Test Show - 02 [1080P].mkv",
+            "[ANi] Test Show - 03 [1080P].mkv",
+            "[ANi] Test Show - 05 [1080P].mkv",
+        ]
+    );
+}
+
+#[test]
+fn episodes_rejects_invalid_range() {
+    let source = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .args(["--episodes", "10-5"]));
+
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("无效的集数范围"));
+}
+
+#[test]
+fn exclude_group_skips_listed_publishers() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    for (group, episode) in [("ANi", 1), ("Bad", 2), ("other", 3)] {
+        fs::write(
+            source
+                .path()
+                .join(format!("[{group}] Test Show - {episode:02} [1080P].mkv")),
+            b"video",
+        )
+        .unwrap();
+    }
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--exclude-group", "bad,Other"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let organized: Vec<_> = fs::read_dir(target.path().join("Test Show"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(organized, ["[ANi] Test Show - 01 [1080P].mkv"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("总计1个文件"), "{stdout}");
+    assert!(stdout.contains("被过滤 2 个"), "{stdout}");
+}
+
+#[test]
+fn case_insensitive_merge_uses_existing_directory_variant() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    fs::create_dir(target.path().join("test show")).unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--case-insensitive-merge"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(target.path().join("test show").join(EPISODE).is_file());
+    let dirs: Vec<_> = fs::read_dir(target.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(dirs, ["test show"]);
+}
+
+#[test]
+fn failures_out_lists_failed_sources_for_from_file_retry() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let lists = tempfile::tempdir().unwrap();
+    let failures = lists.path().join("failures.txt");
+    write_episode(source.path());
+    let other = "[ANi] Other Show - 01 [1080P].mkv";
+    fs::write(source.path().join(other), b"video").unwrap();
+    // 番剧目录位置被同名文件占用，该集整理失败
+    fs::write(target.path().join("Test Show"), b"blocker").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--failures-out"])
+        .arg(&failures));
+
+    assert_eq!(output.status.code(), Some(3), "{output:?}");
+    assert!(target.path().join("Other Show").join(other).is_file());
+    let listed = fs::read_to_string(&failures).unwrap();
+    assert_eq!(
+        listed,
+        format!("{}\n", source.path().join(EPISODE).display())
+    );
+
+    fs::remove_file(target.path().join("Test Show")).unwrap();
+    fs::remove_dir_all(target.path().join("Other Show")).unwrap();
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--from-file"])
+        .arg(&failures));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    assert!(!target.path().join("Other Show").exists());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("总计1个文件，成功1个"));
+}
+
+#[test]
+fn failures_out_collects_failures_from_every_target() {
+    let source = tempfile::tempdir().unwrap();
+    let first = tempfile::tempdir().unwrap();
+    let second = tempfile::tempdir().unwrap();
+    let lists = tempfile::tempdir().unwrap();
+    let failures = lists.path().join("failures.txt");
+    write_episode(source.path());
+    // 只有第一个目标的番剧目录位置被同名文件占用
+    fs::write(first.path().join("Test Show"), b"blocker").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(first.path())
+        .arg("--target")
+        .arg(second.path())
+        .args(["--mode", "copy", "--failures-out"])
+        .arg(&failures));
+
+    assert!(!output.status.success(), "{output:?}");
+    assert!(organized(second.path()));
+    assert_eq!(
+        fs::read_to_string(&failures).unwrap(),
+        format!("{}\n", source.path().join(EPISODE).display())
+    );
+}
+
+#[test]
+fn empty_files_are_skipped_unless_allowed() {
+    let source = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let empty = "[ANi] Test Show - 02 [1080P].mkv";
+    fs::write(source.path().join(empty), b"").unwrap();
+    let organize = |target: &Path, extra: &[&str]| {
+        run(aniorg()
+            .arg("--source")
+            .arg(source.path())
+            .arg("--target")
+            .arg(target)
+            .args(["--mode", "copy"])
+            .args(extra))
+    };
+
+    let target = tempfile::tempdir().unwrap();
+    let output = organize(target.path(), &[]);
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    assert!(!target.path().join("Test Show").join(empty).exists());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("总计1个文件"), "{stdout}");
+    assert!(stdout.contains("空文件 1 个"), "{stdout}");
+
+    let target = tempfile::tempdir().unwrap();
+    let output = organize(target.path(), &["--allow-empty"]);
+    assert!(output.status.success(), "{output:?}");
+    assert!(target.path().join("Test Show").join(empty).is_file());
+}
+
+#[test]
+fn plex_preset_builds_season_dir_and_sxxeyy_name() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    fs::write(
+        source
+            .path()
+            .join("[ANi] Test Show 第二季 - 05 [1080P].mkv"),
+        b"video",
+    )
+    .unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--preset", "plex"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let series = target.path().join("Test Show");
+    assert!(series
+        .join("Season 01")
+        .join("Test Show - s01e01.mkv")
+        .is_file());
+    assert!(series
+        .join("Season 02")
+        .join("Test Show - s02e05.mkv")
+        .is_file());
+}
+
+#[test]
+fn bdmv_organizes_disc_directory_as_a_unit() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let disc = "[Group] Test Show [BDMV]";
+    let stream = source.path().join(disc).join("BDMV").join("STREAM");
+    fs::create_dir_all(&stream).unwrap();
+    fs::write(stream.join("00000.m2ts"), b"stream").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--include-ext", "mkv,m2ts", "--bdmv"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    let copied = target.path().join("Test Show").join(disc);
+    assert_eq!(
+        fs::read(copied.join("BDMV").join("STREAM").join("00000.m2ts")).unwrap(),
+        b"stream"
+    );
+    assert!(stream.join("00000.m2ts").is_file());
+    assert!(!target.path().join("00000.m2ts").exists());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("总计2个文件，成功2个"));
+}
+
+#[test]
+fn rerun_counts_existing_targets_as_skipped() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let organize = || {
+        run(aniorg()
+            .arg("--source")
+            .arg(source.path())
+            .arg("--target")
+            .arg(target.path())
+            .args(["--mode", "copy", "--output", "csv"]))
+    };
+
+    let first = organize();
+    let second = organize();
+
+    assert!(first.status.success(), "{first:?}");
+    assert!(second.status.success(), "{second:?}");
+    assert!(String::from_utf8_lossy(&first.stdout).contains(",copy,success"));
+    assert!(String::from_utf8_lossy(&second.stdout).contains(",copy,skipped"));
+    assert!(String::from_utf8_lossy(&second.stderr).contains("已存在跳过1个"));
+}
+
+#[cfg(unix)]
+#[test]
+fn leave_symlink_links_source_to_moved_file() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "move", "--leave-symlink"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let link = source.path().join(EPISODE);
+    assert!(fs::symlink_metadata(&link)
+        .unwrap()
+        .file_type()
+        .is_symlink());
+    assert_eq!(
+        fs::canonicalize(&link).unwrap(),
+        fs::canonicalize(target.path().join("Test Show").join(EPISODE)).unwrap()
+    );
+}
+
+#[test]
+fn leave_symlink_requires_move_mode() {
+    let source = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg().arg("--source").arg(source.path()).args([
+        "--mode",
+        "copy",
+        "--leave-symlink",
+    ]));
+
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--mode=move"));
+}
+
+#[test]
+fn multiple_targets_each_receive_a_link() {
+    let source = tempfile::tempdir().unwrap();
+    let first = tempfile::tempdir_in(source.path()).unwrap();
+    let second = tempfile::tempdir_in(source.path()).unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path().join(EPISODE))
+        .arg("--target")
+        .arg(first.path())
+        .arg("--target")
+        .arg(second.path())
+        .args(["--mode", "link"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(first.path()));
+    assert!(organized(second.path()));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        let links = fs::metadata(source.path().join(EPISODE)).unwrap().nlink();
+        assert_eq!(links, 3);
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.matches("==> 目标:").count(), 2, "{stdout}");
+}
+
+#[test]
+fn failing_target_does_not_stop_other_targets() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path().join("missing"))
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy"]));
+
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert!(organized(target.path()));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("处理失败"));
+}
+
+#[test]
+fn multiple_targets_reject_move_mode() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "move"]));
+
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert!(source.path().join(EPISODE).exists());
+}
+
+#[test]
+fn summary_breaks_down_skipped_files_by_reason() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    fs::write(source.path().join("notes.txt"), b"notes").unwrap();
+    fs::write(source.path().join("random clip.mkv"), b"clip").unwrap();
+    let old = source.path().join("[ANi] Test Show - 02 [1080P].mkv");
+    fs::write(&old, b"old").unwrap();
+    fs::File::options()
+        .write(true)
+        .open(&old)
+        .unwrap()
+        .set_modified(SystemTime::now() - Duration::from_secs(30 * 86_400))
+        .unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--newer-than", "7d"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("跳过明细：扩展名不符 1 个，命名不匹配 1 个，被过滤 1 个"),
+        "{stdout}"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn post_hook_receives_target_and_anime() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let log = target.path().join("hook.log");
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--post-hook"])
+        .arg(format!(
+            "sh -c 'printf \"%s|%s\" \"$1\" \"$2\" > \"$0\"' '{}' {{target}} '{{anime}}'",
+            log.display()
+        )));
+
+    assert!(output.status.success(), "{output:?}");
+    let expected = format!(
+        "{}|Test Show",
+        target.path().join("Test Show").join(EPISODE).display()
+    );
+    assert_eq!(fs::read_to_string(&log).unwrap(), expected);
+}
+
+#[cfg(unix)]
+#[test]
+fn post_hook_failure_only_warns_by_default() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--post-hook", "false"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("整理后命令执行失败"));
+}
+
+#[cfg(unix)]
+#[test]
+fn post_hook_failure_counts_as_failed_when_requested() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args([
+            "--mode",
+            "copy",
+            "--post-hook",
+            "false",
+            "--fail-on-hook-error",
+        ]));
+
+    assert_eq!(output.status.code(), Some(4), "{output:?}");
+}
+
+#[test]
+fn notify_webhook_receives_json_summary() {
+    use std::io::{Read, Write};
+
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = Vec::new();
+        let mut buffer = [0; 4096];
+        while !String::from_utf8_lossy(&request).contains("organize_finished") {
+            let read = stream.read(&mut buffer).unwrap();
+            if read == 0 {
+                break;
+            }
+            request.extend_from_slice(&buffer[..read]);
+        }
+        stream
+            .write_all(b"HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n")
+            .unwrap();
+        String::from_utf8_lossy(&request).into_owned()
+    });
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--notify", &url]));
+
+    assert!(output.status.success(), "{output:?}");
+    let request = server.join().unwrap();
+    assert!(request.starts_with("POST /hook"), "{request}");
+    assert!(request.contains("\"succeeded\":1"), "{request}");
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("通知发送失败"));
+}
+
+#[test]
+fn notify_webhook_failure_only_warns() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let closed = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/hook", closed.local_addr().unwrap());
+    drop(closed);
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--notify", &url]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("通知发送失败"));
+}
+
+#[test]
+fn on_conflict_keep_newer_skips_older_source() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let existing = target.path().join("Test Show").join(EPISODE);
+    fs::create_dir_all(existing.parent().unwrap()).unwrap();
+    fs::write(&existing, b"newer target").unwrap();
+    fs::File::options()
+        .write(true)
+        .open(source.path().join(EPISODE))
+        .unwrap()
+        .set_modified(std::time::SystemTime::UNIX_EPOCH)
+        .unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--on-conflict", "keep-newer"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(fs::read(&existing).unwrap(), b"newer target");
+    assert!(String::from_utf8_lossy(&output.stdout).contains("已存在的目标较新或较大"));
+}
+
+#[test]
+fn force_overwrites_existing_target_without_backup() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let show = target.path().join("Test Show");
+    fs::create_dir(&show).unwrap();
+    fs::write(show.join(EPISODE), b"stale").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--force"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(fs::read(show.join(EPISODE)).unwrap(), b"video");
+    assert_eq!(fs::read_dir(&show).unwrap().count(), 1);
+}
+
+#[test]
+fn force_conflicts_with_dry_run_and_backup() {
+    let source = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    for flag in ["--dry-run", "--backup"] {
+        let output = run(aniorg()
+            .arg("--source")
+            .arg(source.path())
+            .args(["--force", flag]));
+        assert!(!output.status.success(), "{flag}: {output:?}");
+        assert!(String::from_utf8_lossy(&output.stderr).contains("cannot be used with"));
+    }
+}
+
+#[test]
+fn name_separator_renames_target_files() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--name-separator", "."]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(target
+        .path()
+        .join("Test Show")
+        .join("01.[1080P].mkv")
+        .exists());
+}
+
+#[test]
+fn name_separator_rejects_path_separator() {
+    let source = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .args(["--name-separator", "/"]));
+
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("无效的文件名分隔符"));
+}
+
+#[test]
+fn debug_parse_prints_fields_and_fails_on_mismatch() {
+    let output = run(aniorg().args(["debug-parse", EPISODE]));
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("episode"), "{stdout}");
+    assert!(stdout.contains("\"[1080P]\""), "{stdout}");
+
+    let output = run(aniorg().args(["debug-parse", "[ANi] Test Show.mkv"]));
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("未找到集数"));
+}
+
+#[test]
+fn parse_prints_fields_or_reports_unparseable() {
+    let output = run(aniorg().args(["parse", "[ANi] Test Show - 01 [1080p].mkv"]));
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let fields = stdout
+        .lines()
+        .map(|line| line.split_once(' ').unwrap())
+        .map(|(name, value)| (name, value.trim()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        fields,
+        [
+            ("publisher", "ANi"),
+            ("anime", "Test Show"),
+            ("episode", "01"),
+            ("tags", "[1080p]"),
+            ("ext", ".mkv"),
+        ]
+    );
+
+    let output = run(aniorg().args(["parse", "[ANi] Test Show.mkv"]));
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("无法解析: [ANi] Test Show.mkv"));
+}
+
+#[test]
+fn apple_double_files_are_skipped_by_default() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    fs::write(source.path().join(format!("._{EPISODE}")), b"meta").unwrap();
+    fs::write(source.path().join(".DS_Store"), b"meta").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("跳过明细"), "{stdout}");
+    assert!(organized(target.path()));
+    assert_eq!(
+        fs::read_dir(target.path().join("Test Show"))
+            .unwrap()
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn with_metadata_carries_nfo_and_poster() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    let nfo = EPISODE.replace(".mkv", ".nfo");
+    fs::write(source.path().join(&nfo), b"<episodedetails/>").unwrap();
+    fs::write(source.path().join("poster.jpg"), b"jpg").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "move", "--with-metadata"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(organized(target.path()));
+    let show = target.path().join("Test Show");
+    assert_eq!(fs::read(show.join(&nfo)).unwrap(), b"<episodedetails/>");
+    assert_eq!(fs::read(show.join("poster.jpg")).unwrap(), b"jpg");
+    assert!(!source.path().join("poster.jpg").exists());
+}
+
+#[test]
+fn keep_apple_double_carries_companion_file() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    write_episode(source.path());
+    fs::write(source.path().join(format!("._{EPISODE}")), b"meta").unwrap();
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--keep-apple-double"]));
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        fs::read(target.path().join("Test Show").join(format!("._{EPISODE}"))).unwrap(),
+        b"meta"
+    );
+}
+
+#[test]
+fn exclude_ext_filters_files_matched_by_wildcard_include() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    for extension in ["mp4", "mpg", "mkv"] {
+        fs::write(
+            source
+                .path()
+                .join(format!("[ANi] Test Show - 01 [1080P].{extension}")),
+            b"video",
+        )
+        .unwrap();
+    }
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args([
+            "--mode",
+            "copy",
+            "--include-ext",
+            "mp*",
+            "--exclude-ext",
+            "mpg",
+        ]));
+
+    assert!(output.status.success(), "{output:?}");
+    let show = target.path().join("Test Show");
+    assert!(show.join("[ANi] Test Show - 01 [1080P].mp4").exists());
+    assert!(!show.join("[ANi] Test Show - 01 [1080P].mpg").exists());
+    assert!(!show.join("[ANi] Test Show - 01 [1080P].mkv").exists());
+}
+
+#[test]
+fn wildcard_exclude_ext_filters_default_extensions() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    for extension in ["mp4", "mkv", "mov"] {
+        fs::write(
+            source
+                .path()
+                .join(format!("[ANi] Test Show - 01 [1080P].{extension}")),
+            b"video",
+        )
+        .unwrap();
+    }
+
+    let output = run(aniorg()
+        .arg("--source")
+        .arg(source.path())
+        .arg("--target")
+        .arg(target.path())
+        .args(["--mode", "copy", "--exclude-ext", "m?v"]));
+
+    assert!(output.status.success(), "{output:?}");
+    let show = target.path().join("Test Show");
+    assert!(show.join("[ANi] Test Show - 01 [1080P].mp4").exists());
+    assert!(!show.join("[ANi] Test Show - 01 [1080P].mkv").exists());
+    assert!(!show.join("[ANi] Test Show - 01 [1080P].mov").exists());
+}
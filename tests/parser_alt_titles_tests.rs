@@ -0,0 +1,91 @@
+//! 多语言并列标题测试
+//!
+//! 番名中以 ` / `（或全角 ` ／ `）并列的中/日/英标题拆分为主标题与 `alt_titles`，
+//! 主标题可按序号切换；两侧无空格的斜杠属于番名本身。
+
+use anime_organizer::parser::{split_alt_titles, AnimeFileInfo, FilenameParser};
+
+#[test]
+fn splits_chinese_japanese_and_english_titles() {
+    let info = FilenameParser::parse_str(
+        "[ANi] 葬送的芙莉莲 / 葬送のフリーレン / Frieren - 01 [1080P][Baha].mp4",
+    )
+    .unwrap();
+    assert_eq!(info.anime_name, "葬送的芙莉莲");
+    assert_eq!(info.alt_titles, ["葬送のフリーレン", "Frieren"]);
+    assert_eq!(info.episode, "01");
+    assert_eq!(info.tags, "[1080P][Baha]");
+}
+
+#[test]
+fn splits_full_width_separator_used_on_disk() {
+    let info =
+        FilenameParser::parse("/downloads/[Sakurato] 间谍过家家 ／ SPY×FAMILY [01][1080P].mkv")
+            .unwrap();
+    assert_eq!(info.anime_name, "间谍过家家");
+    assert_eq!(info.alt_titles, ["SPY×FAMILY"]);
+    assert_eq!(info.episode, "01");
+}
+
+#[test]
+fn single_title_has_no_alt_titles() {
+    let info = FilenameParser::parse_str("[ANi] 测试 - 01 [1080P].mp4").unwrap();
+    assert_eq!(info.anime_name, "测试");
+    assert!(info.alt_titles.is_empty());
+}
+
+#[test]
+fn slash_without_spaces_is_part_of_the_name() {
+    let info = FilenameParser::parse_str("[VCB-Studio] Fate/Zero - 01 [1080P].mkv").unwrap();
+    assert_eq!(info.anime_name, "Fate/Zero");
+    assert!(info.alt_titles.is_empty());
+    assert_eq!(
+        split_alt_titles("Fate/stay night / フェイト"),
+        ("Fate/stay night".to_string(), vec!["フェイト".to_string()])
+    );
+}
+
+#[test]
+fn empty_parts_are_dropped() {
+    assert_eq!(
+        split_alt_titles("测试 /  / Test"),
+        ("测试".to_string(), vec!["Test".to_string()])
+    );
+}
+
+#[test]
+fn loose_rules_split_titles_too() {
+    let info = FilenameParser::parse_loose_str("测试 ／ Test Show-1080p-03.mkv").unwrap();
+    assert_eq!(info.anime_name, "测试");
+    assert_eq!(info.alt_titles, ["Test Show"]);
+    assert_eq!(info.episode, "03");
+}
+
+#[test]
+fn select_primary_title_by_position() {
+    let mut info = FilenameParser::parse_str(
+        "[ANi] 葬送的芙莉莲 / 葬送のフリーレン / Frieren - 01 [1080P].mp4",
+    )
+    .unwrap();
+
+    assert!(info.select_primary_title(3));
+    assert_eq!(info.anime_name, "Frieren");
+    assert_eq!(info.alt_titles, ["葬送的芙莉莲", "葬送のフリーレン"]);
+
+    assert!(info.select_primary_title(3));
+    assert_eq!(info.anime_name, "葬送のフリーレン");
+    assert_eq!(info.alt_titles, ["Frieren", "葬送的芙莉莲"]);
+}
+
+#[test]
+fn select_primary_title_out_of_range_keeps_titles() {
+    let mut info = AnimeFileInfo::builder()
+        .anime_name("测试")
+        .alt_titles(["Test"])
+        .build();
+
+    assert!(!info.select_primary_title(0));
+    assert!(!info.select_primary_title(3));
+    assert_eq!(info.anime_name, "测试");
+    assert_eq!(info.alt_titles, ["Test"]);
+}
@@ -0,0 +1,75 @@
+//! 全方括号格式解析测试
+//!
+//! `[组][番名][01][标签].ext`：第一段为发布组，第二段为番名，纯数字段为集数，其余为标签。
+
+use anime_organizer::parser::FilenameParser;
+
+#[test]
+fn parses_all_bracket_segments() {
+    let info = FilenameParser::parse_str("[Group][Anime Name][01][1080p].mkv").unwrap();
+    assert_eq!(info.publisher, "Group");
+    assert_eq!(info.anime_name, "Anime Name");
+    assert_eq!(info.episode, "01");
+    assert_eq!(info.tags, "[1080p]");
+    assert_eq!(info.extension, ".mkv");
+}
+
+#[test]
+fn parses_without_tags_and_with_long_episode() {
+    let info = FilenameParser::parse_str("[Group][Anime Name][7].mp4").unwrap();
+    assert_eq!(info.anime_name, "Anime Name");
+    assert_eq!(info.episode, "07");
+    assert_eq!(info.tags, "");
+
+    let info = FilenameParser::parse_str(
+        "[DBD-Raws][Boruto Naruto Next Generations][001][1080P][BDRip][HEVC-10bit][FLAC].mkv",
+    )
+    .unwrap();
+    assert_eq!(info.anime_name, "Boruto Naruto Next Generations");
+    assert_eq!(info.episode, "001");
+    assert_eq!(info.tags, "[1080P][BDRip][HEVC-10bit][FLAC]");
+}
+
+#[test]
+fn segments_before_episode_become_tags() {
+    let info =
+        FilenameParser::parse_str("[Group][Anime Name][WebRip][12][1080p][CHS].mkv").unwrap();
+    assert_eq!(info.anime_name, "Anime Name");
+    assert_eq!(info.episode, "12");
+    assert_eq!(info.tags, "[WebRip][1080p][CHS]");
+}
+
+#[test]
+fn spaces_between_segments_are_allowed() {
+    let info = FilenameParser::parse_str("[Group] [葬送的芙莉莲] [05] [1080p].mkv").unwrap();
+    assert_eq!(info.anime_name, "葬送的芙莉莲");
+    assert_eq!(info.episode, "05");
+    assert_eq!(info.tags, "[1080p]");
+}
+
+#[test]
+fn coexists_with_existing_formats() {
+    let info = FilenameParser::parse_str("[ANi] Anime Name - 01 [1080P][WEB-DL].mkv").unwrap();
+    assert_eq!(info.anime_name, "Anime Name");
+    assert_eq!(info.episode, "01");
+    assert_eq!(info.tags, "[1080P][WEB-DL]");
+
+    let info = FilenameParser::parse_str("[Group] Anime Name [01][1080p].mkv").unwrap();
+    assert_eq!(info.anime_name, "Anime Name");
+    assert_eq!(info.episode, "01");
+    assert_eq!(info.tags, "[1080p]");
+}
+
+#[test]
+fn brackets_without_numeric_segment_are_not_parsed() {
+    assert!(FilenameParser::parse_str("[Group][Anime Name][1080p].mkv").is_none());
+    assert!(FilenameParser::parse_str("[Group][Anime Name][00][1080p].mkv").is_none());
+}
+
+#[test]
+fn debug_parse_reports_segment_tags() {
+    let trace = FilenameParser::debug_parse("[Group][Anime Name][WebRip][12][1080p].mkv");
+    assert!(trace.failure.is_none());
+    assert_eq!(trace.field("anime").unwrap().value, "Anime Name");
+    assert_eq!(trace.field("tags").unwrap().value, "[WebRip][1080p]");
+}
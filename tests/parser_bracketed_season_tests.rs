@@ -0,0 +1,54 @@
+//! 番名括号季度标注测试
+//!
+//! `动漫名 (第二季)`、`动漫名（2nd Season）` 中的季度标注改写为 ` Season N` 后缀，
+//! 季号由 `season_number` 取出；年份等其他括号保持不变。
+
+use anime_organizer::parser::FilenameParser;
+
+#[test]
+fn chinese_bracketed_season_is_extracted() {
+    let info = FilenameParser::parse_str("[ANi] 测试动漫 (第二季) - 03 [1080P].mp4").unwrap();
+    assert_eq!(info.anime_name, "测试动漫 Season 2");
+    assert_eq!(info.series_name(), "测试动漫");
+    assert_eq!(info.season_number(), Some(2));
+    assert_eq!(info.episode, "03");
+
+    let info = FilenameParser::parse_str("[ANi] 测试动漫（第3季） - 01 [1080P].mp4").unwrap();
+    assert_eq!(info.series_name(), "测试动漫");
+    assert_eq!(info.season_number(), Some(3));
+}
+
+#[test]
+fn english_bracketed_season_is_extracted() {
+    let info =
+        FilenameParser::parse_str("[SweetSub] 测试动漫（2nd Season） - 05 [1080P].mkv").unwrap();
+    assert_eq!(info.anime_name, "测试动漫 Season 2");
+    assert_eq!(info.season_number(), Some(2));
+
+    let info =
+        FilenameParser::parse_str("[Nekomoe] Test Anime (Season 3) - 05 [1080P].mkv").unwrap();
+    assert_eq!(info.series_name(), "Test Anime");
+    assert_eq!(info.season_number(), Some(3));
+}
+
+#[test]
+fn year_brackets_are_kept() {
+    let info = FilenameParser::parse_str("[ANi] 测试动漫 (2024) - 01 [1080P].mp4").unwrap();
+    assert_eq!(info.anime_name, "测试动漫 (2024)");
+    assert_eq!(info.year(), Some(2024));
+    assert_eq!(info.season_number(), None);
+
+    let info =
+        FilenameParser::parse_str("[ANi] 测试动漫 (2024) (第二季) - 01 [1080P].mp4").unwrap();
+    assert_eq!(info.anime_name, "测试动漫 (2024) Season 2");
+    assert_eq!(info.year(), Some(2024));
+    assert_eq!(info.season_number(), Some(2));
+}
+
+#[test]
+fn bracketed_season_applies_to_primary_title_only() {
+    let info =
+        FilenameParser::parse_str("[ANi] 测试动漫 (第二季) / Test Anime - 01 [1080P].mp4").unwrap();
+    assert_eq!(info.anime_name, "测试动漫 Season 2");
+    assert_eq!(info.alt_titles, ["Test Anime"]);
+}
@@ -0,0 +1,58 @@
+//! 无空格紧凑命名测试
+//!
+//! `[组]番名-01[1080p].mp4` 这类发布组后直接接番名、番名与集数间仅有 `-` 的命名，
+//! 与带空格的标准写法共存。
+
+use anime_organizer::parser::FilenameParser;
+
+#[test]
+fn compact_name_with_tags() {
+    let info = FilenameParser::parse_str("[SweetSub]妖精的尾巴-01[1080p].mp4").unwrap();
+    assert_eq!(info.publisher, "SweetSub");
+    assert_eq!(info.anime_name, "妖精的尾巴");
+    assert_eq!(info.episode, "01");
+    assert_eq!(info.tags, "[1080p]");
+    assert_eq!(info.extension, ".mp4");
+}
+
+#[test]
+fn compact_name_without_tags() {
+    let info = FilenameParser::parse_str("[Group]Anime-12.mkv").unwrap();
+    assert_eq!(info.anime_name, "Anime");
+    assert_eq!(info.episode, "12");
+    assert_eq!(info.tags, "");
+}
+
+#[test]
+fn compact_name_uses_last_dash() {
+    let info = FilenameParser::parse_str("[Group]Re-Zero-2-05[1080p][CHS].mkv").unwrap();
+    assert_eq!(info.anime_name, "Re-Zero-2");
+    assert_eq!(info.episode, "05");
+    assert_eq!(info.tags, "[1080p][CHS]");
+}
+
+#[test]
+fn spaced_format_is_unchanged() {
+    let compact = FilenameParser::parse_str("[SweetSub]妖精的尾巴-01[1080p].mp4").unwrap();
+    let spaced = FilenameParser::parse_str("[SweetSub] 妖精的尾巴 - 01 [1080p].mp4").unwrap();
+    assert_eq!(compact.anime_name, spaced.anime_name);
+    assert_eq!(compact.episode, spaced.episode);
+    assert_eq!(compact.tags, spaced.tags);
+
+    let info = FilenameParser::parse_str("[ANi] Re-Zero - 03 [1080P].mp4").unwrap();
+    assert_eq!(info.anime_name, "Re-Zero");
+    assert_eq!(info.episode, "03");
+}
+
+#[test]
+fn compact_rule_rejects_resolution_and_spaced_dash() {
+    for filename in [
+        "[Group]Anime-720[WEB].mkv",
+        "[Group]Anime-1080[WEB].mkv",
+        "[Group]Anime -01[1080p].mkv",
+        "[Sub] Show Name-1080p-03.mkv",
+        "[Group]Anime-x264-03[WEB].mkv",
+    ] {
+        assert!(FilenameParser::parse_str(filename).is_none(), "{filename}");
+    }
+}
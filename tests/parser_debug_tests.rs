@@ -0,0 +1,81 @@
+//! `FilenameParser::debug_parse` tests
+//!
+//! The trace must agree with `parse_str` and explain where parsing stops.
+
+use anime_organizer::parser::FilenameParser;
+
+fn raw_and_value(trace: &anime_organizer::parser::ParseTrace, name: &str) -> (String, String) {
+    let field = trace.field(name).unwrap();
+    (field.raw.clone(), field.value.clone())
+}
+
+#[test]
+fn test_trace_records_raw_and_processed_values() {
+    let trace = FilenameParser::debug_parse("[ ANi ] Test Show - 1 (1080p) x264.MKV");
+    assert_eq!(trace.failure, None);
+    assert_eq!(
+        raw_and_value(&trace, "publisher"),
+        (" ANi ".to_string(), "ANi".to_string())
+    );
+    assert_eq!(
+        raw_and_value(&trace, "anime"),
+        ("Test Show ".to_string(), "Test Show".to_string())
+    );
+    assert_eq!(
+        raw_and_value(&trace, "episode"),
+        ("1".to_string(), "01".to_string())
+    );
+    assert_eq!(
+        raw_and_value(&trace, "tags"),
+        ("(1080p) x264".to_string(), "[1080p][x264]".to_string())
+    );
+    assert_eq!(
+        raw_and_value(&trace, "ext"),
+        (".MKV".to_string(), ".mkv".to_string())
+    );
+}
+
+#[test]
+fn test_trace_matches_parse_str() {
+    for filename in [
+        "[ANi] Title - S02E03 [1080P].mp4",
+        "[ANi] 魯邦三世 不死身的血族 - 電影 [1080P][Baha].mp4",
+        "[Sub] 测试 第十二話 [1080P].mkv",
+        "[dmhy] Test Show [05][1080P].mp4",
+    ] {
+        let info = FilenameParser::parse_str(filename).unwrap();
+        let trace = FilenameParser::debug_parse(filename);
+        assert_eq!(trace.failure, None, "{filename}");
+        let values = trace
+            .fields
+            .iter()
+            .map(|field| field.value.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            [
+                info.publisher.as_str(),
+                info.anime_name.as_str(),
+                info.episode.as_str(),
+                info.tags.as_str(),
+                info.extension.as_str(),
+            ],
+            "{filename}"
+        );
+    }
+}
+
+#[test]
+fn test_trace_reports_where_parsing_stops() {
+    let trace = FilenameParser::debug_parse("Test Show - 01.mkv");
+    assert!(trace.fields.is_empty());
+    assert!(trace.failure.unwrap().starts_with("publisher"));
+
+    let trace = FilenameParser::debug_parse("[ANi] Test Show.mkv");
+    assert_eq!(trace.fields.len(), 1);
+    assert!(trace.failure.unwrap().starts_with("anime/episode"));
+
+    let trace = FilenameParser::debug_parse("[ANi] Test Show - 01 [1080P].txt");
+    assert_eq!(trace.fields.len(), 3);
+    assert!(trace.failure.unwrap().starts_with("ext"));
+}
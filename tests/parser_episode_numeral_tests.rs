@@ -0,0 +1,98 @@
+//! 非阿拉伯数字集数解析测试
+//!
+//! 覆盖 `第一話`、`第01集`、罗马数字与中文数字集数的识别和补零。
+
+use anime_organizer::parser::{parse_episode_number, FilenameParser};
+
+#[test]
+fn converts_numeral_episodes() {
+    assert_eq!(parse_episode_number("第一話").as_deref(), Some("01"));
+    assert_eq!(parse_episode_number("第十二话").as_deref(), Some("12"));
+    assert_eq!(parse_episode_number("第3集").as_deref(), Some("03"));
+    assert_eq!(parse_episode_number("第 5 话").as_deref(), Some("05"));
+    assert_eq!(parse_episode_number("III").as_deref(), Some("03"));
+    assert_eq!(parse_episode_number("XIV").as_deref(), Some("14"));
+    assert_eq!(parse_episode_number("十二").as_deref(), Some("12"));
+    assert_eq!(parse_episode_number("二十").as_deref(), Some("20"));
+    assert_eq!(parse_episode_number("一百零五").as_deref(), Some("105"));
+    assert_eq!(parse_episode_number("7").as_deref(), Some("07"));
+}
+
+#[test]
+fn rejects_unrecognized_episodes() {
+    for raw in ["", "第話", "IIII", "VV", "iii", "Final", "十a", "第一季"] {
+        assert_eq!(parse_episode_number(raw), None, "{raw}");
+    }
+}
+
+#[test]
+fn parses_ordinal_episode_without_separator() {
+    let info = FilenameParser::parse_str("[Sub] 葬送的芙莉莲 第一話 [1080P].mkv").unwrap();
+    assert_eq!(info.anime_name, "葬送的芙莉莲");
+    assert_eq!(info.episode, "01");
+    assert_eq!(info.tags, "[1080P]");
+    assert_eq!(info.extension, ".mkv");
+}
+
+#[test]
+fn parses_arabic_ordinal_episodes() {
+    let info = FilenameParser::parse_str("[Sub] 测试动画 第01集 [1080P].mp4").unwrap();
+    assert_eq!(info.anime_name, "测试动画");
+    assert_eq!(info.episode, "01");
+    assert_eq!(info.tags, "[1080P]");
+
+    let spaced = FilenameParser::parse_str("[Sub] 测试动画 第 5 话 [1080P][简体].mkv").unwrap();
+    assert_eq!(spaced.anime_name, "测试动画");
+    assert_eq!(spaced.episode, "05");
+    assert_eq!(spaced.tags, "[1080P][简体]");
+}
+
+#[test]
+fn hyphen_episode_format_is_unaffected() {
+    let info = FilenameParser::parse_str("[Sub] 测试动画 - 05 [1080P].mkv").unwrap();
+    assert_eq!(info.anime_name, "测试动画");
+    assert_eq!(info.episode, "05");
+
+    let title = FilenameParser::parse_str("[Sub] 第1集的秘密 - 07 [1080P].mkv").unwrap();
+    assert_eq!(title.anime_name, "第1集的秘密");
+    assert_eq!(title.episode, "07");
+}
+
+#[test]
+fn parses_roman_and_cjk_episodes_after_separator() {
+    let roman = FilenameParser::parse_str("[Sub] Test Show - III [1080P].mkv").unwrap();
+    assert_eq!(roman.anime_name, "Test Show");
+    assert_eq!(roman.episode, "03");
+
+    let cjk = FilenameParser::parse_str("[Sub] 测试 - 十二 [1080P].mp4").unwrap();
+    assert_eq!(cjk.anime_name, "测试");
+    assert_eq!(cjk.episode, "12");
+}
+
+#[test]
+fn single_letters_and_large_roman_numerals_are_not_episodes() {
+    for filename in [
+        "[Sub] Test Show - X [1080P].mkv",
+        "[Sub] Test Show - C [1080P].mkv",
+        "[Sub] Test Show - L [1080P].mkv",
+        "[Sub] Test Show - I [1080P].mkv",
+        "[Sub] Test Show - XXI [1080P].mkv",
+        "[Sub] Test Show - CD [1080P].mkv",
+    ] {
+        assert!(FilenameParser::parse_str(filename).is_none(), "{filename}");
+    }
+
+    for (filename, episode) in [
+        ("[Sub] Test Show - II [1080P].mkv", "02"),
+        ("[Sub] Test Show - XVIII [1080P].mkv", "18"),
+        ("[Sub] Test Show - XX [1080P].mkv", "20"),
+    ] {
+        let info = FilenameParser::parse_str(filename).unwrap();
+        assert_eq!(info.episode, episode, "{filename}");
+    }
+}
+
+#[test]
+fn roman_title_suffix_without_separator_is_not_an_episode() {
+    assert!(FilenameParser::parse_str("[Sub] Rocky III [1080P].mkv").is_none());
+}
@@ -0,0 +1,85 @@
+//! 集数后分隔符测试
+//!
+//! 集数两侧都有破折号的命名（如 `Anime - 01 - [1080p].mkv`）中，集数后的 ` - `
+//! 只是分隔，不应进入标签；标准格式的解析结果保持不变。
+
+use anime_organizer::parser::FilenameParser;
+
+fn parse(filename: &str) -> (String, String, String, String) {
+    let info = FilenameParser::parse_str(filename).unwrap();
+    (info.anime_name, info.episode, info.tags, info.extension)
+}
+
+fn expected(anime: &str, episode: &str, tags: &str, ext: &str) -> (String, String, String, String) {
+    (
+        anime.to_string(),
+        episode.to_string(),
+        tags.to_string(),
+        ext.to_string(),
+    )
+}
+
+#[test]
+fn dash_after_episode_is_a_separator() {
+    for filename in [
+        "[ANi] Anime - 01 - [1080p].mkv",
+        "[ANi] Anime - 01 -[1080p].mkv",
+        "[ANi] Anime - 01-[1080p].mkv",
+        "[ANi] Anime - 01  -  [1080p].mkv",
+    ] {
+        assert_eq!(
+            parse(filename),
+            expected("Anime", "01", "[1080p]", ".mkv"),
+            "{filename}"
+        );
+    }
+}
+
+#[test]
+fn full_width_dashes_after_episode_are_separators() {
+    for filename in [
+        "[ANi] Anime - 01 – [1080p][CHT].mkv",
+        "[ANi] Anime - 01 — [1080p][CHT].mkv",
+    ] {
+        assert_eq!(
+            parse(filename),
+            expected("Anime", "01", "[1080p][CHT]", ".mkv"),
+            "{filename}"
+        );
+    }
+}
+
+#[test]
+fn dash_separator_before_bare_tags() {
+    assert_eq!(
+        parse("[ANi] Anime - 01 - 1080p WEB-DL.mkv"),
+        expected("Anime", "01", "[1080p][WEB-DL]", ".mkv")
+    );
+}
+
+#[test]
+fn raw_tags_exclude_separator() {
+    let trace = FilenameParser::debug_parse("[ANi] Anime - 01 - [1080p].mkv");
+    assert_eq!(trace.field("tags").unwrap().raw, "[1080p]");
+    assert_eq!(trace.field("ext").unwrap().raw, ".mkv");
+}
+
+#[test]
+fn standard_formats_are_unchanged() {
+    assert_eq!(
+        parse("[ANi] Anime - 01 [1080p].mkv"),
+        expected("Anime", "01", "[1080p]", ".mkv")
+    );
+    assert_eq!(
+        parse("[ANi] Anime - 01.mkv"),
+        expected("Anime", "01", "", ".mkv")
+    );
+    assert_eq!(
+        parse("[ANi] 测试 - 01 [1080P][Baha][WEB-DL][AAC AVC][CHT].mp4"),
+        expected("测试", "01", "[1080P][Baha][WEB-DL][AAC AVC][CHT]", ".mp4")
+    );
+    assert_eq!(
+        parse("[ANi] Anime - S01E02 - [1080p].mkv"),
+        parse("[ANi] Anime - S01E02 [1080p].mkv")
+    );
+}
@@ -0,0 +1,42 @@
+//! 扩展名大小写测试
+//!
+//! 解析时扩展名默认统一为小写；`restore_extension_case` 按原始文件名恢复大小写。
+
+use anime_organizer::parser::FilenameParser;
+
+#[test]
+fn extension_is_lowercased_by_default() {
+    let info = FilenameParser::parse_str("[ANi] 测试 - 01 [1080P].MKV").unwrap();
+    assert_eq!(info.extension, ".mkv");
+    assert_eq!(info.target_filename(), "01 [1080P].mkv");
+
+    let info = FilenameParser::parse("/downloads/[ANi] 测试 - 01 [1080P].Mp4").unwrap();
+    assert_eq!(info.extension, ".mp4");
+}
+
+#[test]
+fn restore_extension_case_keeps_original_spelling() {
+    let mut info = FilenameParser::parse("/downloads/[ANi] 测试 - 01 [1080P].Mp4").unwrap();
+    assert!(info.restore_extension_case());
+    assert_eq!(info.extension, ".Mp4");
+    assert_eq!(info.target_filename(), "01 [1080P].Mp4");
+
+    let mut info = FilenameParser::parse_loose_str("Anime-1080p-01.MKV").unwrap();
+    assert!(info.restore_extension_case());
+    assert_eq!(info.extension, ".MKV");
+}
+
+#[test]
+fn restore_extension_case_on_lowercase_name_is_a_no_op() {
+    let mut info = FilenameParser::parse_str("[ANi] 测试 - 01 [1080P].mkv").unwrap();
+    assert!(info.restore_extension_case());
+    assert_eq!(info.extension, ".mkv");
+}
+
+#[test]
+fn restore_extension_case_skips_bracketed_extension() {
+    let mut info = FilenameParser::parse_str("[ANi] 测试 - 01 [1080P][MKV]").unwrap();
+    assert_eq!(info.extension, ".mkv");
+    assert!(!info.restore_extension_case());
+    assert_eq!(info.extension, ".mkv");
+}
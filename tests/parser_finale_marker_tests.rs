@@ -0,0 +1,101 @@
+//! 完结标记测试
+//!
+//! 最后一集常在集数后标注 `END`、`[END]` 或 `完`，解析时剥离标记并记为 `is_final`，
+//! 集数与标签照常解析。
+
+use anime_organizer::parser::FilenameParser;
+
+#[test]
+fn bare_end_after_episode() {
+    let info = FilenameParser::parse_str("[ANi] Anime - 01 END [1080P].mkv").unwrap();
+    assert_eq!(info.anime_name, "Anime");
+    assert_eq!(info.episode, "01");
+    assert_eq!(info.tags, "[1080P]");
+    assert!(info.is_final);
+}
+
+#[test]
+fn bracketed_end_after_episode() {
+    let info = FilenameParser::parse_str("[ANi] Anime - 12 [END] [1080P].mkv").unwrap();
+    assert_eq!(info.episode, "12");
+    assert_eq!(info.tags, "[1080P]");
+    assert!(info.is_final);
+}
+
+#[test]
+fn chinese_finale_markers() {
+    for filename in [
+        "[ANi] 测试 - 12 完 [1080P].mp4",
+        "[ANi] 测试 - 12(完)[1080P].mp4",
+        "[ANi] 测试 - 12 【完结】 [1080P].mp4",
+        "[Sub] 测试 [12完][1080P].mp4",
+    ] {
+        let info = FilenameParser::parse_str(filename).unwrap();
+        assert_eq!(info.anime_name, "测试", "{filename}");
+        assert_eq!(info.episode, "12", "{filename}");
+        assert_eq!(info.tags, "[1080P]", "{filename}");
+        assert!(info.is_final, "{filename}");
+    }
+}
+
+#[test]
+fn marker_glued_to_episode_or_extension() {
+    let glued = FilenameParser::parse_str("[ANi] Anime - 12END [1080P].mkv").unwrap();
+    assert_eq!(glued.episode, "12");
+    assert!(glued.is_final);
+
+    let lowercase = FilenameParser::parse_str("[ANi] Anime - 12 end.mkv").unwrap();
+    assert_eq!(lowercase.episode, "12");
+    assert_eq!(lowercase.tags, "");
+    assert!(lowercase.is_final);
+}
+
+#[test]
+fn regular_episodes_are_not_final() {
+    let info = FilenameParser::parse_str("[ANi] Anime - 11 [1080P].mkv").unwrap();
+    assert!(!info.is_final);
+
+    // `Ending` 等单词不是完结标记
+    let info = FilenameParser::parse_str("[ANi] Anime - 01 Ending [1080P].mkv").unwrap();
+    assert_eq!(info.episode, "01");
+    assert!(info.tags.contains("Ending"));
+    assert!(!info.is_final);
+}
+
+#[test]
+fn markers_away_from_episode_are_kept() {
+    let info = FilenameParser::parse_str("[ANi] 86 End - 03 [1080P].mp4").unwrap();
+    assert_eq!(info.anime_name, "86 End");
+    assert_eq!(info.episode, "03");
+    assert!(!info.is_final);
+
+    let info = FilenameParser::parse_str("[ANi] Anime 2 完 - 05 [1080P].mp4").unwrap();
+    assert_eq!(info.anime_name, "Anime 2 完");
+    assert_eq!(info.episode, "05");
+    assert!(!info.is_final);
+
+    let info = FilenameParser::parse_str("[ANi] Anime - 05 [1080P][x264][END].mkv").unwrap();
+    assert_eq!(info.episode, "05");
+    assert!(info.tags.contains("END"), "{}", info.tags);
+    assert!(!info.is_final);
+}
+
+#[test]
+fn marker_after_episode_with_numbered_title() {
+    let info = FilenameParser::parse_str("[ANi] 86 End - 23 END [1080P].mp4").unwrap();
+    assert_eq!(info.anime_name, "86 End");
+    assert_eq!(info.episode, "23");
+    assert_eq!(info.tags, "[1080P]");
+    assert!(info.is_final);
+}
+
+#[test]
+fn debug_parse_reports_finale_marker() {
+    let trace = FilenameParser::debug_parse("[ANi] Anime - 12 [END] [1080P].mkv");
+    assert!(trace.failure.is_none());
+    let marker = trace.field("final").unwrap();
+    assert_eq!(
+        (marker.raw.as_str(), marker.value.as_str()),
+        ("[END]", "true")
+    );
+}
@@ -0,0 +1,48 @@
+//! 不可见字符清洗测试
+//!
+//! 覆盖文件名中零宽空格（U+200B）、BOM（U+FEFF）与异常空白的去除。
+
+use anime_organizer::parser::FilenameParser;
+
+#[test]
+fn zero_width_space_is_removed_from_anime_name() {
+    let info =
+        FilenameParser::parse_str("[ANi] Test\u{200B} Show\u{200B} - 01 [1080P].mkv").unwrap();
+
+    assert_eq!(info.anime_name, "Test Show");
+    assert_eq!(info.episode, "01");
+}
+
+#[test]
+fn leading_bom_and_trailing_invisible_chars_are_ignored() {
+    let info = FilenameParser::parse_str("\u{FEFF}[ANi] 测试 - 01 [1080P].mkv\u{200B}").unwrap();
+
+    assert_eq!(info.publisher, "ANi");
+    assert_eq!(info.anime_name, "测试");
+    assert_eq!(info.extension, ".mkv");
+}
+
+#[test]
+fn invisible_chars_are_removed_from_tags() {
+    let info =
+        FilenameParser::parse_str("[ANi] 测试 - 01 [1080P\u{FEFF}][CHT\u{200B}].mkv").unwrap();
+
+    assert_eq!(info.tags, "[1080P][CHT]");
+}
+
+#[test]
+fn whitespace_in_anime_name_is_normalized() {
+    let info = FilenameParser::parse_str("[ANi] 测试\u{3000}\u{00A0}动画  第二部 - 01 [1080P].mkv")
+        .unwrap();
+
+    assert_eq!(info.anime_name, "测试 动画 第二部");
+}
+
+#[test]
+fn debug_parse_reports_cleaned_values() {
+    let trace = FilenameParser::debug_parse("[ANi] Test\u{200B} Show - 01 [1080P].mkv");
+
+    let anime = trace.field("anime").unwrap();
+    assert_eq!(anime.raw.trim(), "Test\u{200B} Show");
+    assert_eq!(anime.value, "Test Show");
+}
@@ -0,0 +1,88 @@
+//! 宽松集数规则测试（best-effort）
+//!
+//! 画质与集数黏连的命名（如 `Anime-1080p-01.mkv`）在标准规则失败后，
+//! 取最后一个独立数字作为集数；分辨率、年份与编码不应被当作集数。
+//! 这些规则只是尽力而为，用例取自真实的黏连命名。
+
+use anime_organizer::parser::FilenameParser;
+
+#[test]
+fn best_effort_resolution_glued_to_episode() {
+    let info = FilenameParser::parse_loose_str("Anime-1080p-01.mkv").unwrap();
+    assert_eq!(info.publisher, "");
+    assert_eq!(info.anime_name, "Anime");
+    assert_eq!(info.episode, "01");
+    assert_eq!(info.tags, "[1080p]");
+    assert_eq!(info.extension, ".mkv");
+}
+
+#[test]
+fn best_effort_underscore_and_dot_separators() {
+    let info = FilenameParser::parse_loose_str("Sousou_no_Frieren_1080p_x265_12.mkv").unwrap();
+    assert_eq!(info.anime_name, "Sousou no Frieren");
+    assert_eq!(info.episode, "12");
+    assert_eq!(info.tags, "[1080p][x265]");
+
+    let info = FilenameParser::parse_loose_str("Bocchi.the.Rock.2022.720p.05.MP4").unwrap();
+    assert_eq!(info.anime_name, "Bocchi the Rock");
+    assert_eq!(info.episode, "05");
+    assert_eq!(info.tags, "[2022][720p]");
+    assert_eq!(info.year(), Some(2022));
+    assert_eq!(info.extension, ".mp4");
+}
+
+#[test]
+fn best_effort_keeps_publisher_and_version() {
+    for filename in [
+        "[Sub] Show Name-1080p-03v2.mkv",
+        "[Sub] Show Name-1080p-03.mkv",
+    ] {
+        let info = FilenameParser::parse_loose_str(filename).unwrap();
+        assert_eq!(info.publisher, "Sub", "{filename}");
+        assert_eq!(info.anime_name, "Show Name", "{filename}");
+        assert_eq!(info.episode, "03", "{filename}");
+        assert_eq!(info.tags, "[1080p]", "{filename}");
+    }
+}
+
+#[test]
+fn best_effort_digits_in_title_are_kept() {
+    let info = FilenameParser::parse_loose_str("86-1080p-WEB-07.mkv").unwrap();
+    assert_eq!(info.anime_name, "86");
+    assert_eq!(info.episode, "07");
+    assert_eq!(info.tags, "[1080p][WEB]");
+}
+
+#[test]
+fn best_effort_does_not_mistake_resolution_or_year_for_episode() {
+    for filename in [
+        "Anime-1080p.mkv",
+        "Anime-720.mkv",
+        "Anime-2024.mkv",
+        "Anime-4K-x264.mkv",
+        "01-1080p.mkv",
+        "Anime-1080p-01.txt",
+    ] {
+        assert!(
+            FilenameParser::parse_loose_str(filename).is_none(),
+            "{filename}"
+        );
+    }
+}
+
+#[test]
+fn standard_rules_take_precedence() {
+    let filename = "[ANi] Test - 01 [1080P][2024].mkv";
+    assert_eq!(
+        FilenameParser::parse_loose_str(filename),
+        FilenameParser::parse_str(filename)
+    );
+    assert!(FilenameParser::parse_str("Anime-1080p-01.mkv").is_none());
+}
+
+#[test]
+fn loose_path_keeps_original_path() {
+    let info = FilenameParser::parse_loose("/downloads/Anime-1080p-01.mkv").unwrap();
+    assert_eq!(info.anime_name, "Anime");
+    assert_eq!(info.original_path, "/downloads/Anime-1080p-01.mkv");
+}
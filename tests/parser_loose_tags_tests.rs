@@ -0,0 +1,43 @@
+//! 非方括号标签解析测试
+//!
+//! 圆括号与裸露标签应被识别并归一化为方括号形式，且不吞掉番名尾部。
+
+use anime_organizer::parser::FilenameParser;
+
+#[test]
+fn parses_parenthesized_tags() {
+    let info = FilenameParser::parse_str("[Sub] Test Show - 03 (1080p).mkv").unwrap();
+    assert_eq!(info.anime_name, "Test Show");
+    assert_eq!(info.episode, "03");
+    assert_eq!(info.tags, "[1080p]");
+    assert_eq!(info.extension, ".mkv");
+}
+
+#[test]
+fn parses_bare_tags() {
+    let info = FilenameParser::parse_str("[Sub] Test Show - 03 1080p.mkv").unwrap();
+    assert_eq!(info.anime_name, "Test Show");
+    assert_eq!(info.tags, "[1080p]");
+
+    let info = FilenameParser::parse_str("[Sub] Test Show - 03 1080p x264.mp4").unwrap();
+    assert_eq!(info.tags, "[1080p][x264]");
+    assert_eq!(info.extension, ".mp4");
+}
+
+#[test]
+fn mixed_brackets_are_normalized() {
+    let info = FilenameParser::parse_str("[Sub] Test Show - 03 (WEB-DL) [1080P] AAC.mkv").unwrap();
+    assert_eq!(info.tags, "[WEB-DL][1080P][AAC]");
+}
+
+#[test]
+fn anime_name_tail_is_not_taken_as_tags() {
+    let info = FilenameParser::parse_str("[Sub] Test Show (2024) - 05 (1080p).mkv").unwrap();
+    assert_eq!(info.anime_name, "Test Show (2024)");
+    assert_eq!(info.tags, "[1080p]");
+
+    let info = FilenameParser::parse_str("[Sub] Test Show 1080 - 05 720p.mkv").unwrap();
+    assert_eq!(info.anime_name, "Test Show 1080");
+    assert_eq!(info.episode, "05");
+    assert_eq!(info.tags, "[720p]");
+}
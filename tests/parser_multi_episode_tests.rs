@@ -0,0 +1,47 @@
+//! Multi-episode filename tests
+//!
+//! Covers merged episodes written as `01+02`, kept distinct from `01-02` ranges.
+
+use anime_organizer::parser::{compare_episodes, FilenameParser};
+use std::cmp::Ordering;
+
+#[test]
+fn test_parse_plus_joined_episodes() {
+    let info = FilenameParser::parse("[Group] Anime - 01+02 [1080p].mkv").unwrap();
+    assert_eq!(info.publisher, "Group");
+    assert_eq!(info.anime_name, "Anime");
+    assert_eq!(info.episode, "01+02");
+    assert_eq!(info.episode_numbers(), vec![1, 2]);
+    assert!(info.is_multi_episode());
+    assert_eq!(info.target_filename(), "01+02 [1080p].mkv");
+}
+
+#[test]
+fn test_parse_plus_joined_episodes_pads_each_part() {
+    let info = FilenameParser::parse("[Group] Anime - 1+2 [1080p].mkv").unwrap();
+    assert_eq!(info.episode, "01+02");
+    assert_eq!(info.target_filename(), "01+02 [1080p].mkv");
+}
+
+#[test]
+fn test_parse_three_plus_joined_episodes() {
+    let info = FilenameParser::parse("[Group] Anime - 11+12+13 [1080p].mkv").unwrap();
+    assert_eq!(info.episode, "11+12+13");
+    assert_eq!(info.episode_numbers(), vec![11, 12, 13]);
+}
+
+#[test]
+fn test_single_episode_is_not_multi() {
+    let info = FilenameParser::parse("[Group] Anime - 03 [1080p].mkv").unwrap();
+    assert_eq!(info.episode_numbers(), vec![3]);
+    assert!(!info.is_multi_episode());
+}
+
+#[test]
+fn test_plus_joined_episodes_sort_by_first_episode() {
+    let merged = FilenameParser::parse("[Group] Anime - 01+02 [1080p].mkv").unwrap();
+    let first = FilenameParser::parse("[Group] Anime - 01 [1080p].mkv").unwrap();
+    let third = FilenameParser::parse("[Group] Anime - 03 [1080p].mkv").unwrap();
+    assert_eq!(compare_episodes(&first, &merged), Ordering::Less);
+    assert_eq!(compare_episodes(&merged, &third), Ordering::Less);
+}
@@ -0,0 +1,124 @@
+//! Publisher parsing tests
+//!
+//! The publisher is everything inside the first pair of square brackets, trimmed,
+//! including spaces, parentheses and balanced inner square brackets.
+
+use anime_organizer::parser::FilenameParser;
+
+fn parse(filename: &str) -> (String, String, String, String) {
+    let info = FilenameParser::parse_str(filename).unwrap();
+    (info.publisher, info.anime_name, info.episode, info.tags)
+}
+
+#[test]
+fn test_publisher_with_spaces() {
+    for (filename, publisher) in [
+        (
+            "[Sub Group 字幕组] Test - 01 [1080P].mkv",
+            "Sub Group 字幕组",
+        ),
+        ("[ Sub Group ] Test - 01 [1080P].mkv", "Sub Group"),
+        (
+            "[桜都字幕组 & LoliHouse] Test - 01 [1080P].mkv",
+            "桜都字幕组 & LoliHouse",
+        ),
+    ] {
+        let (parsed, anime_name, episode, _) = parse(filename);
+        assert_eq!(parsed, publisher, "{filename}");
+        assert_eq!((anime_name.as_str(), episode.as_str()), ("Test", "01"));
+    }
+}
+
+#[test]
+fn test_publisher_with_inner_parentheses() {
+    for (filename, publisher) in [
+        ("[Group (内部)] Test - 01 [1080P].mkv", "Group (内部)"),
+        ("[Group（内部）] Test - 01 [1080P].mkv", "Group（内部）"),
+        (
+            "[Group (A) (B)] Test (2024) - 01 [1080P].mkv",
+            "Group (A) (B)",
+        ),
+    ] {
+        let (parsed, _, episode, tags) = parse(filename);
+        assert_eq!(parsed, publisher, "{filename}");
+        assert_eq!((episode.as_str(), tags.as_str()), ("01", "[1080P]"));
+    }
+}
+
+#[test]
+fn test_publisher_with_balanced_inner_brackets() {
+    assert_eq!(
+        parse("[Group [Raws]] Test - 01 [1080P].mkv"),
+        (
+            "Group [Raws]".to_string(),
+            "Test".to_string(),
+            "01".to_string(),
+            "[1080P]".to_string()
+        )
+    );
+    assert_eq!(parse("[A [B [C]]] Test - 02 [1080P].mkv").0, "A [B [C]]");
+}
+
+#[test]
+fn test_unbalanced_inner_bracket_ends_at_first_close() {
+    let (publisher, _, episode, tags) = parse("[Group [x] Test - 01 [1080P].mkv");
+    assert_eq!(publisher, "Group [x");
+    assert_eq!((episode.as_str(), tags.as_str()), ("01", "[1080P]"));
+}
+
+#[test]
+fn test_empty_publisher_is_rejected() {
+    assert!(FilenameParser::parse_str("[] Test - 01 [1080P].mkv").is_none());
+    assert!(FilenameParser::parse_str("[[]] Test - 01 [1080P].mkv").is_some());
+}
+
+#[test]
+fn test_normalize_publisher_strips_common_suffixes() {
+    use anime_organizer::parser::{normalize_publisher, DEFAULT_PUBLISHER_SUFFIXES};
+
+    for (publisher, normalized) in [
+        ("ANi-Raws", "ANi"),
+        ("Ohys-Raws", "Ohys"),
+        ("Group字幕组", "Group"),
+        ("喵萌奶茶屋 字幕組", "喵萌奶茶屋"),
+        ("Nekomoe Sub", "Nekomoe"),
+        ("Group_Subs", "Group"),
+        ("Group-raws", "Group"),
+        ("Group Sub 字幕组", "Group"),
+    ] {
+        assert_eq!(
+            normalize_publisher(publisher, DEFAULT_PUBLISHER_SUFFIXES),
+            normalized,
+            "{publisher}"
+        );
+    }
+}
+
+#[test]
+fn test_normalize_publisher_keeps_names_without_suffix() {
+    use anime_organizer::parser::{normalize_publisher, DEFAULT_PUBLISHER_SUFFIXES};
+
+    for publisher in [
+        "ANi",
+        "SweetSub",
+        "LoliHouse",
+        "Raws",
+        "字幕组",
+        "Group [Raws]",
+    ] {
+        assert_eq!(
+            normalize_publisher(publisher, DEFAULT_PUBLISHER_SUFFIXES),
+            publisher
+        );
+    }
+}
+
+#[test]
+fn test_normalize_publisher_with_custom_suffixes() {
+    use anime_organizer::parser::normalize_publisher;
+
+    let suffixes = ["Fansub".to_string(), "压制组".to_string()];
+    assert_eq!(normalize_publisher("Kirara Fansub", &suffixes), "Kirara");
+    assert_eq!(normalize_publisher("某某压制组", &suffixes), "某某");
+    assert_eq!(normalize_publisher("ANi-Raws", &suffixes), "ANi-Raws");
+}
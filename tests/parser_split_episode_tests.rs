@@ -0,0 +1,49 @@
+//! 分段放送集数解析测试
+//!
+//! 覆盖 `- 13A`、`- 13B` 这类集数后带单个字母的写法：数字部分补零，字母原样保留。
+
+use anime_organizer::parser::FilenameParser;
+
+fn parse(filename: &str) -> (String, String, String) {
+    let info = FilenameParser::parse_str(filename).unwrap();
+    (info.anime_name, info.episode, info.tags)
+}
+
+#[test]
+fn letter_suffix_is_kept_in_episode() {
+    for (filename, episode) in [
+        ("[ANi] Test - 13A [1080P].mkv", "13A"),
+        ("[ANi] Test - 13B [1080P].mkv", "13B"),
+        ("[ANi] Test - 5a [1080P].mkv", "05a"),
+        ("[ANi] Test - 07B.mkv", "07B"),
+    ] {
+        let (anime_name, parsed, _) = parse(filename);
+        assert_eq!(anime_name, "Test", "{filename}");
+        assert_eq!(parsed, episode, "{filename}");
+    }
+}
+
+#[test]
+fn tags_follow_split_episode() {
+    assert_eq!(
+        parse("[ANi] Test - 13A [1080P][CHT].mp4"),
+        (
+            "Test".to_string(),
+            "13A".to_string(),
+            "[1080P][CHT]".to_string()
+        )
+    );
+}
+
+#[test]
+fn resolution_markers_are_not_split_episodes() {
+    assert!(FilenameParser::parse_str("[ANi] Test - 1080p.mkv").is_none());
+    assert!(FilenameParser::parse_str("[ANi] Test - 4K.mkv").is_none());
+}
+
+#[test]
+fn episode_offset_keeps_part_letter() {
+    let mut info = FilenameParser::parse_str("[ANi] Test - 13B [1080P].mkv").unwrap();
+    info.apply_episode_offset(-12).unwrap();
+    assert_eq!(info.episode, "01B");
+}
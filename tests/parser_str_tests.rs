@@ -0,0 +1,64 @@
+//! `FilenameParser::parse_str` tests
+//!
+//! The string entry point must match `parse` without touching `std::path`.
+
+use anime_organizer::parser::FilenameParser;
+
+#[test]
+fn test_parse_str_basic_filename() {
+    let info = FilenameParser::parse_str("[ANi] 妖怪旅館營業中 貳 - 07 [1080P].mp4").unwrap();
+    assert_eq!(info.publisher, "ANi");
+    assert_eq!(info.anime_name, "妖怪旅館營業中 貳");
+    assert_eq!(info.episode, "07");
+    assert_eq!(info.tags, "[1080P]");
+    assert_eq!(info.extension, ".mp4");
+    assert_eq!(
+        info.original_path,
+        "[ANi] 妖怪旅館營業中 貳 - 07 [1080P].mp4"
+    );
+}
+
+#[test]
+fn test_parse_str_matches_parse_for_bare_filenames() {
+    for filename in [
+        "[ANi] Test - 1 [1080P][Baha][WEB-DL][AAC AVC][CHT].mp4",
+        "[Group] Anime - 01+02 [1080p].mkv",
+        "[ANi] Test - 12.5 [1080P].MKV",
+        "[ANi] Title - S02E03 [1080P].mp4",
+        "[ANi] 魯邦三世 不死身的血族 - 電影 [1080P][Baha].mp4",
+    ] {
+        assert_eq!(
+            FilenameParser::parse_str(filename),
+            FilenameParser::parse(filename),
+            "{filename}"
+        );
+    }
+}
+
+#[test]
+fn test_parse_str_keeps_input_as_original_path() {
+    let path = "downloads/[ANi] Test - 01 [1080P].mp4";
+    let from_path = FilenameParser::parse(path).unwrap();
+    assert_eq!(from_path.original_path, path);
+    assert_eq!(
+        FilenameParser::parse_str("[ANi] Test - 01 [1080P].mp4")
+            .unwrap()
+            .original_path,
+        "[ANi] Test - 01 [1080P].mp4"
+    );
+}
+
+#[test]
+fn test_parse_str_rejects_invalid_input() {
+    for filename in [
+        "",
+        "no publisher - 01.mp4",
+        "[ANi]",
+        "[ANi] Test - 01 [1080P].txt",
+    ] {
+        assert!(
+            FilenameParser::parse_str(filename).is_none(),
+            "{filename:?}"
+        );
+    }
+}
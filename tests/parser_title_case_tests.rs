@@ -0,0 +1,58 @@
+//! 番名标题大小写规范化测试
+//!
+//! 覆盖 `--title-case` 使用的 `parser::title_case`：英文单词首字母大写、缩写全大写、
+//! 虚词小写，中文名保持不变。
+
+use anime_organizer::parser::title_case;
+
+#[test]
+fn differently_cased_names_normalize_to_same_title() {
+    assert_eq!(title_case("the final season"), "The Final Season");
+    assert_eq!(title_case("THE FINAL SEASON"), "The Final Season");
+    assert_eq!(title_case("The Final Season"), "The Final Season");
+}
+
+#[test]
+fn known_acronyms_stay_uppercase() {
+    assert_eq!(title_case("test show ova"), "Test Show OVA");
+    assert_eq!(title_case("Test Show tv"), "Test Show TV");
+    assert_eq!(title_case("overlord iv"), "Overlord IV");
+    assert_eq!(title_case("test show (ova)"), "Test Show (OVA)");
+}
+
+#[test]
+fn small_words_are_lowercase_except_at_edges() {
+    assert_eq!(title_case("attack on titan"), "Attack on Titan");
+    assert_eq!(
+        title_case("the world of otome games"),
+        "The World of Otome Games"
+    );
+    assert_eq!(title_case("what we live for"), "What We Live For");
+}
+
+#[test]
+fn punctuation_separated_parts_are_capitalized() {
+    assert_eq!(title_case("re:zero kara hajimeru"), "Re:Zero Kara Hajimeru");
+    assert_eq!(title_case("kaguya-sama"), "Kaguya-Sama");
+    assert_eq!(title_case("don't toy with me"), "Don't Toy with Me");
+}
+
+#[test]
+fn chinese_names_are_unchanged() {
+    assert_eq!(title_case("葬送的芙莉莲"), "葬送的芙莉莲");
+    assert_eq!(title_case("间谍过家家 第二季"), "间谍过家家 第二季");
+}
+
+#[test]
+fn mixed_names_only_change_english_words() {
+    assert_eq!(
+        title_case("进击的巨人 the final season"),
+        "进击的巨人 The Final Season"
+    );
+}
+
+#[test]
+fn whitespace_is_preserved() {
+    assert_eq!(title_case("test  show"), "Test  Show");
+    assert_eq!(title_case(""), "");
+}
@@ -0,0 +1,42 @@
+//! Season directory template tests
+//!
+//! Covers `render_season_dir`, template validation and the season-1 fallback.
+
+use anime_organizer::parser::{render_season_dir, validate_season_format, FilenameParser};
+
+#[test]
+fn test_default_template() {
+    assert_eq!(render_season_dir("Season {n}", 1), "Season 1");
+    assert_eq!(render_season_dir("Season {n}", 12), "Season 12");
+}
+
+#[test]
+fn test_zero_padded_template() {
+    assert_eq!(render_season_dir("S{n:02}", 2), "S02");
+    assert_eq!(render_season_dir("S{n:02}", 12), "S12");
+    assert_eq!(render_season_dir("Season {n:03}", 7), "Season 007");
+}
+
+#[test]
+fn test_chinese_template() {
+    assert_eq!(render_season_dir("第{n}季", 2), "第2季");
+}
+
+#[test]
+fn test_missing_season_renders_as_first_season() {
+    let info = FilenameParser::parse_str("[ANi] Test - 01 [1080P].mp4").unwrap();
+    assert_eq!(info.season_dir_name_with("S{n:02}"), "S01");
+    assert_eq!(info.season_dir_name(), "Season 1");
+
+    let info = FilenameParser::parse_str("[ANi] Test 第二季 - 01 [1080P].mp4").unwrap();
+    assert_eq!(info.season_dir_name_with("第{n}季"), "第2季");
+}
+
+#[test]
+fn test_invalid_templates_are_rejected() {
+    assert!(validate_season_format("Season {n}").is_ok());
+    assert!(validate_season_format("S{n:02}").is_ok());
+    for template in ["Season", "S{n:x}", "Season/{n}", "{n:02"] {
+        assert!(validate_season_format(template).is_err(), "{template}");
+    }
+}
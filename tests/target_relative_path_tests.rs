@@ -0,0 +1,58 @@
+//! 相对目标路径测试
+//!
+//! 覆盖 `AnimeFileInfo::target_relative_path` 与带季目录层的相对路径生成。
+
+use anime_organizer::parser::{FilenameParser, DEFAULT_SEASON_FORMAT};
+use std::path::Path;
+
+#[test]
+fn relative_path_without_season_layer() {
+    let info = FilenameParser::parse_str("[ANi] Test Show - 01 [1080P][CHT].mkv").unwrap();
+
+    assert_eq!(
+        info.target_relative_path(),
+        Path::new("Test Show").join("01 [1080P][CHT].mkv")
+    );
+}
+
+#[test]
+fn relative_path_without_tags_omits_separator() {
+    let info = FilenameParser::parse_str("[ANi] Test Show - 05.mp4").unwrap();
+
+    assert_eq!(
+        info.target_relative_path(),
+        Path::new("Test Show").join("05.mp4")
+    );
+}
+
+#[test]
+fn relative_path_with_season_layer_splits_series_name() {
+    let info = FilenameParser::parse_str("[ANi] Test Show Season 2 - 03 [1080P].mkv").unwrap();
+
+    assert_eq!(
+        info.target_relative_path(),
+        Path::new("Test Show Season 2").join("03 [1080P].mkv")
+    );
+    assert_eq!(
+        info.target_relative_path_with_season(DEFAULT_SEASON_FORMAT),
+        Path::new("Test Show")
+            .join("Season 2")
+            .join("03 [1080P].mkv")
+    );
+    assert_eq!(
+        info.target_relative_path_with_season("S{n:02}"),
+        Path::new("Test Show").join("S02").join("03 [1080P].mkv")
+    );
+}
+
+#[test]
+fn season_layer_defaults_to_first_season() {
+    let info = FilenameParser::parse_str("[ANi] Test Show - 01 [1080P].mkv").unwrap();
+
+    assert_eq!(
+        info.target_relative_path_with_season(DEFAULT_SEASON_FORMAT),
+        Path::new("Test Show")
+            .join("Season 1")
+            .join("01 [1080P].mkv")
+    );
+}
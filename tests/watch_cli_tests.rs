@@ -0,0 +1,86 @@
+//! `aniorg watch` 子命令集成测试
+//!
+//! 启动真实的监听进程，写入新文件后等待其被整理到目标目录。
+
+use std::fs;
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const EPISODE: &str = "[ANi] Test Show - 01 [1080P].mkv";
+
+/// 结束时终止监听进程，避免测试失败后残留。
+struct Watcher(Child);
+
+impl Drop for Watcher {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+fn spawn_watch(source: &Path, target: &Path) -> Watcher {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aniorg"));
+    for (key, _) in std::env::vars_os() {
+        if key.to_string_lossy().starts_with("ANIORG_") {
+            command.env_remove(key);
+        }
+    }
+    let child = command
+        .arg("watch")
+        .arg("--source")
+        .arg(source)
+        .arg("--target")
+        .arg(target)
+        .args(["--mode", "copy", "--poll-ms", "50", "--settle-ms", "300"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    Watcher(child)
+}
+
+fn wait_for(path: &Path, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    while Instant::now() < deadline {
+        if path.exists() {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+    false
+}
+
+#[test]
+fn watch_organizes_new_file_after_it_settles() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let _watcher = spawn_watch(source.path(), target.path());
+    thread::sleep(Duration::from_millis(500));
+
+    fs::write(source.path().join(EPISODE), b"video").unwrap();
+
+    assert!(wait_for(
+        &target.path().join("Test Show").join(EPISODE),
+        Duration::from_secs(10)
+    ));
+}
+
+#[test]
+fn watch_ignores_files_present_at_startup() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    fs::write(source.path().join(EPISODE), b"video").unwrap();
+    let _watcher = spawn_watch(source.path(), target.path());
+    thread::sleep(Duration::from_millis(500));
+
+    let new_episode = "[ANi] Test Show - 02 [1080P].mkv";
+    fs::write(source.path().join(new_episode), b"video").unwrap();
+
+    assert!(wait_for(
+        &target.path().join("Test Show").join(new_episode),
+        Duration::from_secs(10)
+    ));
+    assert!(!target.path().join("Test Show").join(EPISODE).exists());
+}